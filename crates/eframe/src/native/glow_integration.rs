@@ -633,6 +633,7 @@ impl GlowWinitRunning<'_> {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -699,15 +700,7 @@ impl GlowWinitRunning<'_> {
                         egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                     }
                     ActionRequested::Paste => {
-                        if let Some(contents) = egui_winit.clipboard_text() {
-                            let contents = contents.replace("\r\n", "\n");
-                            if !contents.is_empty() {
-                                egui_winit
-                                    .egui_input_mut()
-                                    .events
-                                    .push(egui::Event::Paste(contents));
-                            }
-                        }
+                        egui_winit.paste_from_clipboard();
                     }
                 }
             }
@@ -1314,6 +1307,7 @@ impl GlutinWindowContext {
                 viewport_ui_cb,
                 mut commands,
                 repaint_delay: _, // ignored - we listened to the repaint callback instead
+                damage: _,
             },
         ) in viewport_output.clone()
         {
@@ -1493,6 +1487,7 @@ fn render_immediate_viewport(
         textures_delta,
         shapes,
         pixels_per_point,
+        damage: _, // We always repaint everything
         viewport_output,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
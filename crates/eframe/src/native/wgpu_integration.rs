@@ -654,6 +654,7 @@ impl WgpuWinitRunning<'_> {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -708,15 +709,7 @@ impl WgpuWinitRunning<'_> {
                     egui_winit.egui_input_mut().events.push(egui::Event::Copy);
                 }
                 ActionRequested::Paste => {
-                    if let Some(contents) = egui_winit.clipboard_text() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            egui_winit
-                                .egui_input_mut()
-                                .events
-                                .push(egui::Event::Paste(contents));
-                        }
-                    }
+                    egui_winit.paste_from_clipboard();
                 }
             }
         }
@@ -1026,6 +1019,7 @@ fn render_immediate_viewport(
         textures_delta,
         shapes,
         pixels_per_point,
+        damage: _, // We always repaint everything
         viewport_output,
     } = egui_ctx.run(input, |ctx| {
         viewport_ui_cb(ctx);
@@ -1111,6 +1105,7 @@ fn handle_viewport_output(
             viewport_ui_cb,
             mut commands,
             repaint_delay: _, // ignored - we listened to the repaint callback instead
+            damage: _,
         },
     ) in viewport_output.clone()
     {
@@ -16,6 +16,9 @@ pub struct AppRunner {
     last_save_time: f64,
     pub(crate) text_agent: TextAgent,
 
+    /// The clipboard formats (other than text) requested in the latest [`egui::PlatformOutput`].
+    pub(crate) paste_formats: Vec<egui::PasteFormat>,
+
     // If not empty, the painter should capture n frames from now.
     // zero means capture the exact next frame.
     screenshot_commands_with_frame_delay: Vec<(UserData, usize)>,
@@ -157,6 +160,7 @@ impl AppRunner {
             needs_repaint,
             last_save_time: now_sec(),
             text_agent,
+            paste_formats: vec![],
             screenshot_commands_with_frame_delay: vec![],
             textures_delta: Default::default(),
             clipped_primitives: None,
@@ -281,6 +285,7 @@ impl AppRunner {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage: _, // We always repaint everything
             viewport_output,
         } = full_output;
 
@@ -346,7 +351,7 @@ impl AppRunner {
         self.frame.info.cpu_usage = Some(cpu_usage_seconds);
     }
 
-    fn handle_platform_output(&self, platform_output: egui::PlatformOutput) {
+    fn handle_platform_output(&mut self, platform_output: egui::PlatformOutput) {
         #[cfg(feature = "web_screen_reader")]
         if self.egui_ctx.options(|o| o.screen_reader) {
             super::screen_reader::speak(&platform_output.events_description());
@@ -358,6 +363,7 @@ impl AppRunner {
             events: _,                    // already handled
             mutable_text_under_cursor: _, // TODO(#4569): https://github.com/emilk/egui/issues/4569
             ime,
+            paste_formats,
             accesskit_update: _,        // not currently implemented
             num_completed_passes: _,    // handled by `Context::run`
             request_discard_reasons: _, // handled by `Context::run`
@@ -377,6 +383,8 @@ impl AppRunner {
             }
         }
 
+        self.paste_formats = paste_formats;
+
         super::set_cursor_icon(cursor_icon);
 
         if self.has_focus() {
@@ -3,8 +3,8 @@ use crate::web::string_from_js_value;
 use super::{
     AppRunner, Closure, DEBUG_RESIZE, JsCast as _, JsValue, WebRunner, button_from_mouse_event,
     location_hash, modifiers_from_kb_event, modifiers_from_mouse_event, modifiers_from_wheel_event,
-    native_pixels_per_point, pos_from_mouse_event, prefers_color_scheme, primary_touch_pos,
-    push_touches, text_from_keyboard_event, translate_key,
+    native_pixels_per_point, pen_from_pointer_event, pos_from_mouse_event, prefers_color_scheme,
+    primary_touch_pos, push_touches, text_from_keyboard_event, translate_key,
 };
 
 use js_sys::Reflect;
@@ -93,6 +93,7 @@ pub(crate) fn install_event_handlers(runner_ref: &WebRunner) -> Result<(), JsVal
     install_pointerup(runner_ref, &document)?;
     install_pointerdown(runner_ref, &canvas)?;
     install_mouseleave(runner_ref, &canvas)?;
+    install_pen(runner_ref, &canvas, &document)?;
 
     install_touchstart(runner_ref, &canvas)?;
     // Use `document` here to notice if the user drag outside of the canvas:
@@ -335,6 +336,22 @@ fn install_copy_cut_paste(runner_ref: &WebRunner, target: &EventTarget) -> Resul
                     runner.needs_repaint.repaint_asap();
                 }
 
+                for format in &runner.paste_formats {
+                    // Images would need to be decoded asynchronously, so we only support textual formats:
+                    if let egui::PasteFormat::Mime(mime) = format
+                        && let Ok(data) = data.get_data(mime)
+                        && !data.is_empty()
+                    {
+                        runner.input.raw.events.push(egui::Event::PasteData(
+                            egui::PastedData::Mime {
+                                mime: mime.clone(),
+                                bytes: data.into_bytes().into(),
+                            },
+                        ));
+                        runner.needs_repaint.repaint_asap();
+                    }
+                }
+
                 // Use web options to tell if the web event should be propagated to parent elements based on the egui event.
                 if should_stop_propagation {
                     event.stop_propagation();
@@ -608,6 +625,15 @@ fn install_pointerup(runner_ref: &WebRunner, target: &EventTarget) -> Result<(),
 /// Returns true if the cursor is above the canvas, or if we're dragging something.
 /// Pass in the position in browser viewport coordinates (usually event.clientX/Y).
 fn is_interested_in_pointer_event(runner: &AppRunner, pos: egui::Pos2) -> bool {
+    let is_pointer_down = runner
+        .egui_ctx()
+        .input(|i| i.pointer.any_down() || i.any_touches());
+
+    is_hovering_canvas(runner, pos) || is_pointer_down
+}
+
+/// Is the canvas the top-most element at the given client position?
+fn is_hovering_canvas(runner: &AppRunner, pos: egui::Pos2) -> bool {
     let root_node = runner.canvas().get_root_node();
 
     let element_at_point = if let Some(document) = root_node.dyn_ref::<Document>() {
@@ -618,12 +644,7 @@ fn is_interested_in_pointer_event(runner: &AppRunner, pos: egui::Pos2) -> bool {
         None
     };
 
-    let is_hovering_canvas = element_at_point.is_some_and(|element| element.eq(runner.canvas()));
-    let is_pointer_down = runner
-        .egui_ctx()
-        .input(|i| i.pointer.any_down() || i.any_touches());
-
-    is_hovering_canvas || is_pointer_down
+    element_at_point.is_some_and(|element| element.eq(runner.canvas()))
 }
 
 fn install_mousemove(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsValue> {
@@ -675,6 +696,79 @@ fn install_mouseleave(runner_ref: &WebRunner, target: &EventTarget) -> Result<()
     )
 }
 
+/// Report pen pressure, tilt, buttons and hover.
+///
+/// This is in addition to the pointer events, which are handled by the other listeners.
+/// Like those, we listen for moves and releases on the `document`,
+/// so that lifting the pen outside of the canvas ends the contact.
+fn install_pen(
+    runner_ref: &WebRunner,
+    canvas: &EventTarget,
+    document: &EventTarget,
+) -> Result<(), JsValue> {
+    runner_ref.add_event_listener(
+        canvas,
+        "pointerdown",
+        |event: web_sys::PointerEvent, runner: &mut AppRunner| {
+            push_pen_event(runner, &event, false);
+        },
+    )?;
+
+    runner_ref.add_event_listener(
+        canvas,
+        "pointerleave",
+        |event: web_sys::PointerEvent, runner: &mut AppRunner| {
+            // While the pen touches the surface, the drag continues outside of the canvas:
+            let in_contact = event.buttons() & 1 != 0;
+            if !in_contact {
+                push_pen_event(runner, &event, true);
+            }
+        },
+    )?;
+
+    runner_ref.add_event_listener(
+        document,
+        "pointermove",
+        |event: web_sys::PointerEvent, runner: &mut AppRunner| {
+            let pos = egui::pos2(event.client_x() as f32, event.client_y() as f32);
+            if is_interested_in_pointer_event(runner, pos) {
+                push_pen_event(runner, &event, false);
+            }
+        },
+    )?;
+
+    runner_ref.add_event_listener(
+        document,
+        "pointerup",
+        |event: web_sys::PointerEvent, runner: &mut AppRunner| {
+            let pos = egui::pos2(event.client_x() as f32, event.client_y() as f32);
+            if is_interested_in_pointer_event(runner, pos) {
+                // Lifted outside of the canvas, so the pen is out of our reach:
+                let gone = !is_hovering_canvas(runner, pos);
+                push_pen_event(runner, &event, gone);
+            }
+        },
+    )?;
+
+    runner_ref.add_event_listener(
+        document,
+        "pointercancel",
+        |event: web_sys::PointerEvent, runner: &mut AppRunner| {
+            let pos = egui::pos2(event.client_x() as f32, event.client_y() as f32);
+            if is_interested_in_pointer_event(runner, pos) {
+                push_pen_event(runner, &event, true);
+            }
+        },
+    )
+}
+
+fn push_pen_event(runner: &mut AppRunner, event: &web_sys::PointerEvent, gone: bool) {
+    if let Some(pen) = pen_from_pointer_event(runner.canvas(), event, runner.egui_ctx(), gone) {
+        runner.input.raw.events.push(egui::Event::Pen(pen));
+        runner.needs_repaint.repaint();
+    }
+}
+
 fn install_touchstart(runner_ref: &WebRunner, target: &EventTarget) -> Result<(), JsValue> {
     runner_ref.add_event_listener(
         target,
@@ -922,6 +1016,7 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
     runner_ref.add_event_listener(target, "dragover", |event: web_sys::DragEvent, runner| {
         if let Some(data_transfer) = event.data_transfer() {
             runner.input.raw.hovered_files.clear();
+            let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
 
             // NOTE: data_transfer.files() is always empty in dragover
 
@@ -930,6 +1025,7 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                 if let Some(item) = items.get(i) {
                     runner.input.raw.hovered_files.push(egui::HoveredFile {
                         mime: item.type_(),
+                        pos: Some(pos),
                         ..Default::default()
                     });
                 }
@@ -937,11 +1033,10 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
 
             if runner.input.raw.hovered_files.is_empty() {
                 // Fallback: just preview anything. Needed on Desktop Safari.
-                runner
-                    .input
-                    .raw
-                    .hovered_files
-                    .push(egui::HoveredFile::default());
+                runner.input.raw.hovered_files.push(egui::HoveredFile {
+                    pos: Some(pos),
+                    ..Default::default()
+                });
             }
 
             runner.needs_repaint.repaint_asap();
@@ -964,6 +1059,7 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
             if let Some(data_transfer) = event.data_transfer() {
                 // TODO(https://github.com/emilk/egui/issues/3702): support dropping folders
                 runner.input.raw.hovered_files.clear();
+                let pos = pos_from_mouse_event(runner.canvas(), &event, runner.egui_ctx());
                 runner.needs_repaint.repaint_asap();
 
                 if let Some(files) = data_transfer.files() {
@@ -992,6 +1088,7 @@ fn install_drag_and_drop(runner_ref: &WebRunner, target: &EventTarget) -> Result
                                                     mime,
                                                     last_modified: Some(last_modified),
                                                     bytes: Some(bytes.into()),
+                                                    pos: Some(pos),
                                                     ..Default::default()
                                                 },
                                             );
@@ -24,6 +24,46 @@ pub fn button_from_mouse_event(event: &web_sys::MouseEvent) -> Option<egui::Poin
     }
 }
 
+/// The pen data of a `PointerEvent`, or `None` if the event is not from a pen.
+///
+/// Set `gone` when the pen has left the canvas (or the range of the tablet).
+pub fn pen_from_pointer_event(
+    canvas: &web_sys::HtmlCanvasElement,
+    event: &web_sys::PointerEvent,
+    ctx: &egui::Context,
+    gone: bool,
+) -> Option<egui::PenEvent> {
+    if event.pointer_type() != "pen" {
+        return None;
+    }
+
+    // See https://developer.mozilla.org/en-US/docs/Web/API/MouseEvent/buttons
+    let buttons = event.buttons();
+    let phase = if gone {
+        egui::PenPhase::Gone
+    } else if buttons & 1 != 0 {
+        egui::PenPhase::Contact
+    } else {
+        egui::PenPhase::Hover
+    };
+
+    Some(egui::PenEvent {
+        pos: pos_from_mouse_event(canvas, event, ctx),
+        phase,
+        pressure: if phase == egui::PenPhase::Contact {
+            event.pressure()
+        } else {
+            0.0
+        },
+        tilt: Some(egui::vec2(
+            (event.tilt_x() as f32).to_radians(),
+            (event.tilt_y() as f32).to_radians(),
+        )),
+        barrel_button: buttons & 2 != 0,
+        eraser: buttons & 32 != 0,
+    })
+}
+
 /// A single touch is translated to a pointer movement. When a second touch is added, the pointer
 /// should not jump to a different position. Therefore, we do not calculate the average position
 /// of all touches, but we keep using the same touch as long as it is available.
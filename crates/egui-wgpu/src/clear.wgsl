@@ -0,0 +1,19 @@
+// Used by `Renderer::clear_regions` to clear parts of the render target.
+// The color comes from the blend constant, so the fragment shader only outputs ones.
+
+// Meant to be called with 3 vertex indices: 0, 1, 2,
+// which draws one large triangle over the whole clip space.
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    var positions = array<vec2f, 3>(
+        vec2f(-1.0, -3.0),
+        vec2f(-1.0, 1.0),
+        vec2f(3.0, 1.0)
+    );
+    return vec4f(positions[vertex_index], 0.0, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4f(1.0);
+}
@@ -238,6 +238,9 @@ impl Default for RendererOptions {
 pub struct Renderer {
     pipeline: wgpu::RenderPipeline,
 
+    /// Used by [`Self::clear_regions`].
+    clear_pipeline: wgpu::RenderPipeline,
+
     index_buffer: SlicedBuffer,
     vertex_buffer: SlicedBuffer,
 
@@ -393,7 +396,7 @@ impl Renderer {
                     polygon_mode: wgpu::PolygonMode::default(),
                     strip_index_format: None,
                 },
-                depth_stencil,
+                depth_stencil: depth_stencil.clone(),
                 multisample: wgpu::MultisampleState {
                     alpha_to_coverage_enabled: false,
                     count: options.msaa_samples.max(1),
@@ -432,6 +435,54 @@ impl Renderer {
         )
         };
 
+        let clear_pipeline = {
+            profiling::scope!("create_clear_pipeline");
+            let module = device.create_shader_module(wgpu::include_wgsl!("clear.wgsl"));
+
+            // Output the blend constant, whatever was in the target before:
+            let replace_with_constant = wgpu::BlendComponent {
+                src_factor: wgpu::BlendFactor::Constant,
+                dst_factor: wgpu::BlendFactor::Zero,
+                operation: wgpu::BlendOperation::Add,
+            };
+
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("egui_clear_pipeline"),
+                layout: None,
+                vertex: wgpu::VertexState {
+                    module: &module,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                },
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    ..Default::default()
+                },
+                depth_stencil,
+                multisample: wgpu::MultisampleState {
+                    alpha_to_coverage_enabled: false,
+                    count: options.msaa_samples.max(1),
+                    mask: !0,
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &module,
+                    entry_point: Some("fs_main"),
+                    targets: &[Some(wgpu::ColorTargetState {
+                        format: output_color_format,
+                        blend: Some(wgpu::BlendState {
+                            color: replace_with_constant,
+                            alpha: replace_with_constant,
+                        }),
+                        write_mask: wgpu::ColorWrites::ALL,
+                    })],
+                    compilation_options: wgpu::PipelineCompilationOptions::default(),
+                }),
+                multiview: None,
+                cache: None,
+            })
+        };
+
         const VERTEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
             (std::mem::size_of::<Vertex>() * 1024) as _;
         const INDEX_BUFFER_START_CAPACITY: wgpu::BufferAddress =
@@ -439,6 +490,7 @@ impl Renderer {
 
         Self {
             pipeline,
+            clear_pipeline,
             vertex_buffer: SlicedBuffer {
                 buffer: create_vertex_buffer(device, VERTEX_BUFFER_START_CAPACITY),
                 slices: Vec::with_capacity(64),
@@ -477,6 +529,83 @@ impl Renderer {
     ) {
         profiling::function_scope!();
 
+        self.render_within(
+            render_pass,
+            paint_jobs,
+            screen_descriptor,
+            epaint::Rect::EVERYTHING,
+        );
+    }
+
+    /// Like [`Self::render`], but only renders within the given regions (in points),
+    /// e.g. `egui::FullOutput::damage`.
+    ///
+    /// The regions must not overlap.
+    /// Everything outside of them is left as is, so the render pass should load
+    /// (not clear) a target that still contains what was rendered last frame.
+    /// Clear the regions first with [`Self::clear_regions`].
+    ///
+    /// Paint callbacks are called once for each region they are in.
+    pub fn render_in_regions(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'static>,
+        paint_jobs: &[epaint::ClippedPrimitive],
+        screen_descriptor: &ScreenDescriptor,
+        regions: &[epaint::Rect],
+    ) {
+        profiling::function_scope!();
+
+        for region in regions {
+            self.render_within(render_pass, paint_jobs, screen_descriptor, *region);
+        }
+    }
+
+    /// Clear the given regions (in points) of the render target to `clear_color`,
+    /// e.g. before [`Self::render_in_regions`].
+    ///
+    /// A render pass can only clear all of its target when it begins,
+    /// so this draws over each region instead, replacing whatever was there.
+    /// `clear_color` is in the same color space as for [`wgpu::LoadOp::Clear`].
+    pub fn clear_regions(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'static>,
+        screen_descriptor: &ScreenDescriptor,
+        regions: &[epaint::Rect],
+        clear_color: wgpu::Color,
+    ) {
+        profiling::function_scope!();
+
+        let pixels_per_point = screen_descriptor.pixels_per_point;
+        let size_in_pixels = screen_descriptor.size_in_pixels;
+
+        render_pass.set_viewport(
+            0.0,
+            0.0,
+            size_in_pixels[0] as f32,
+            size_in_pixels[1] as f32,
+            0.0,
+            1.0,
+        );
+        render_pass.set_pipeline(&self.clear_pipeline);
+        render_pass.set_blend_constant(clear_color);
+
+        for region in regions {
+            let rect = ScissorRect::new(region, pixels_per_point, size_in_pixels);
+            if rect.width == 0 || rect.height == 0 {
+                continue;
+            }
+            render_pass.set_scissor_rect(rect.x, rect.y, rect.width, rect.height);
+            render_pass.draw(0..3, 0..1);
+        }
+    }
+
+    fn render_within(
+        &self,
+        render_pass: &mut wgpu::RenderPass<'static>,
+        paint_jobs: &[epaint::ClippedPrimitive],
+        screen_descriptor: &ScreenDescriptor,
+        region: epaint::Rect,
+    ) {
         let pixels_per_point = screen_descriptor.pixels_per_point;
         let size_in_pixels = screen_descriptor.size_in_pixels;
 
@@ -506,8 +635,9 @@ impl Renderer {
                 needs_reset = false;
             }
 
+            let clip_rect = clip_rect.intersect(region);
             {
-                let rect = ScissorRect::new(clip_rect, pixels_per_point, size_in_pixels);
+                let rect = ScissorRect::new(&clip_rect, pixels_per_point, size_in_pixels);
 
                 if rect.width == 0 || rect.height == 0 {
                     // Skip rendering zero-sized clip areas.
@@ -554,7 +684,7 @@ impl Renderer {
 
                     let info = PaintCallbackInfo {
                         viewport: callback.rect,
-                        clip_rect: *clip_rect,
+                        clip_rect,
                         pixels_per_point,
                         screen_size_px: size_in_pixels,
                     };
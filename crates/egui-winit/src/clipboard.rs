@@ -91,6 +91,54 @@ impl Clipboard {
         Some(self.clipboard.clone())
     }
 
+    /// Read something other than plain text from the clipboard, if it is there.
+    ///
+    /// Images and HTML are supported, with the "clipboard" feature.
+    pub fn get_data(&mut self, format: &egui::PasteFormat) -> Option<egui::PastedData> {
+        #[cfg(all(
+            not(any(target_os = "android", target_os = "ios")),
+            feature = "arboard",
+        ))]
+        if let Some(clipboard) = &mut self.arboard {
+            // NOTE: smithay-clipboard only supports text, so we use arboard here even on Wayland.
+            return match format {
+                egui::PasteFormat::Image => match clipboard.get_image() {
+                    Ok(image) => Some(egui::PastedData::Image(std::sync::Arc::new(
+                        egui::ColorImage::from_rgba_unmultiplied(
+                            [image.width, image.height],
+                            &image.bytes,
+                        ),
+                    ))),
+                    Err(arboard::Error::ContentNotAvailable) => None,
+                    Err(err) => {
+                        log::error!("arboard paste image error: {err}");
+                        None
+                    }
+                },
+                egui::PasteFormat::Mime(mime) if mime == "text/html" => {
+                    match clipboard.get().html() {
+                        Ok(html) => Some(egui::PastedData::Mime {
+                            mime: mime.clone(),
+                            bytes: html.into_bytes().into(),
+                        }),
+                        Err(arboard::Error::ContentNotAvailable) => None,
+                        Err(err) => {
+                            log::error!("arboard paste html error: {err}");
+                            None
+                        }
+                    }
+                }
+                egui::PasteFormat::Mime(mime) => {
+                    log::debug!("Pasting {mime:?} is not supported");
+                    None
+                }
+            };
+        }
+
+        _ = format;
+        None
+    }
+
     pub fn set_text(&mut self, text: String) {
         #[cfg(all(
             any(
@@ -87,6 +87,9 @@ pub struct State {
 
     clipboard: clipboard::Clipboard,
 
+    /// The clipboard formats (other than text) requested in the latest [`egui::PlatformOutput`].
+    paste_formats: Vec<egui::PasteFormat>,
+
     /// If `true`, mouse inputs will be treated as touches.
     /// Useful for debugging touch support in egui.
     ///
@@ -137,6 +140,7 @@ impl State {
             clipboard: clipboard::Clipboard::new(
                 display_target.display_handle().ok().map(|h| h.as_raw()),
             ),
+            paste_formats: Vec::new(),
 
             simulate_touch_screen: false,
             pointer_touch_id: None,
@@ -195,6 +199,25 @@ impl State {
         self.clipboard.set_text(text);
     }
 
+    /// Read the clipboard, and add the paste events to the egui input.
+    ///
+    /// Text is sent as [`egui::Event::Paste`], and the formats requested with
+    /// [`egui::Context::request_paste_format`] as [`egui::Event::PasteData`].
+    pub fn paste_from_clipboard(&mut self) {
+        if let Some(contents) = self.clipboard.get() {
+            let contents = contents.replace("\r\n", "\n");
+            if !contents.is_empty() {
+                self.egui_input.events.push(egui::Event::Paste(contents));
+            }
+        }
+
+        for format in &self.paste_formats {
+            if let Some(data) = self.clipboard.get_data(format) {
+                self.egui_input.events.push(egui::Event::PasteData(data));
+            }
+        }
+    }
+
     /// Returns [`false`] or the last value that [`Window::set_ime_allowed()`] was called with, used for debouncing.
     pub fn allow_ime(&self) -> bool {
         self.allow_ime
@@ -449,6 +472,8 @@ impl State {
                 }
             }
             WindowEvent::HoveredFile(path) => {
+                // winit doesn't report where the file is, and sends no `CursorMoved` during the drag,
+                // so we leave `pos` empty rather than guess:
                 self.egui_input.hovered_files.push(egui::HoveredFile {
                     path: Some(path.clone()),
                     ..Default::default()
@@ -676,26 +701,53 @@ impl State {
 
     fn on_touch(&mut self, window: &Window, touch: &winit::event::Touch) {
         let pixels_per_point = pixels_per_point(&self.egui_ctx, window);
+        let pos = egui::pos2(
+            touch.location.x as f32 / pixels_per_point,
+            touch.location.y as f32 / pixels_per_point,
+        );
+        let force = match touch.force {
+            Some(winit::event::Force::Normalized(force)) => Some(force as f32),
+            Some(winit::event::Force::Calibrated {
+                force,
+                max_possible_force,
+                ..
+            }) => Some((force / max_possible_force) as f32),
+            None => None,
+        };
 
         // Emit touch event
         self.egui_input.events.push(egui::Event::Touch {
             device_id: egui::TouchDeviceId(egui::epaint::util::hash(touch.device_id)),
             id: egui::TouchId::from(touch.id),
             phase: to_egui_touch_phase(touch.phase),
-            pos: egui::pos2(
-                touch.location.x as f32 / pixels_per_point,
-                touch.location.y as f32 / pixels_per_point,
-            ),
-            force: match touch.force {
-                Some(winit::event::Force::Normalized(force)) => Some(force as f32),
-                Some(winit::event::Force::Calibrated {
-                    force,
-                    max_possible_force,
-                    ..
-                }) => Some((force / max_possible_force) as f32),
-                None => None,
-            },
+            pos,
+            force,
         });
+
+        // winit only reports the altitude angle for styluses (e.g. the Apple Pencil), which in practice means iOS.
+        // It does not tell us the direction of the tilt, nor about pen hover, barrel buttons or erasers,
+        // and on desktop platforms a pen is reported as a plain mouse, so we can't send any `Event::Pen` there.
+        if let Some(winit::event::Force::Calibrated {
+            altitude_angle: Some(_),
+            ..
+        }) = touch.force
+        {
+            let phase = match touch.phase {
+                winit::event::TouchPhase::Started | winit::event::TouchPhase::Moved => {
+                    egui::PenPhase::Contact
+                }
+                winit::event::TouchPhase::Ended | winit::event::TouchPhase::Cancelled => {
+                    egui::PenPhase::Gone
+                }
+            };
+            self.egui_input
+                .events
+                .push(egui::Event::Pen(egui::PenEvent::new(
+                    pos,
+                    phase,
+                    force.unwrap_or_default(),
+                )));
+        }
         // If we're not yet translating a touch or we're translating this very
         // touch …
         if self.pointer_touch_id.is_none() || self.pointer_touch_id.unwrap_or_default() == touch.id
@@ -824,12 +876,7 @@ impl State {
                     self.egui_input.events.push(egui::Event::Copy);
                     return;
                 } else if is_paste_command(self.egui_input.modifiers, active_key) {
-                    if let Some(contents) = self.clipboard.get() {
-                        let contents = contents.replace("\r\n", "\n");
-                        if !contents.is_empty() {
-                            self.egui_input.events.push(egui::Event::Paste(contents));
-                        }
-                    }
+                    self.paste_from_clipboard();
                     return;
                 }
             }
@@ -888,6 +935,7 @@ impl State {
             events: _,                    // handled elsewhere
             mutable_text_under_cursor: _, // only used in eframe web
             ime,
+            paste_formats,
             accesskit_update,
             num_completed_passes: _,    // `egui::Context::run` handles this
             request_discard_reasons: _, // `egui::Context::run` handles this
@@ -907,6 +955,8 @@ impl State {
             }
         }
 
+        self.paste_formats = paste_formats;
+
         self.set_cursor_icon(window, cursor_icon);
 
         let allow_ime = ime.is_some();
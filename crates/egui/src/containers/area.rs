@@ -5,8 +5,9 @@
 use emath::GuiRounding as _;
 
 use crate::{
-    Align2, Context, Id, InnerResponse, LayerId, Layout, NumExt as _, Order, Pos2, Rect, Response,
-    Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState, emath, pos2,
+    Align2, Context, FocusScope, Id, InnerResponse, LayerId, Layout, NumExt as _, Order, Pos2,
+    Rect, Response, Sense, Ui, UiBuilder, UiKind, UiStackInfo, Vec2, WidgetRect, WidgetWithState,
+    emath, pos2,
 };
 
 /// State of an [`Area`] that is persisted between frames.
@@ -122,6 +123,7 @@ pub struct Area {
     fade_in: bool,
     layout: Layout,
     sizing_pass: bool,
+    focus_scope: Option<FocusScope>,
 }
 
 impl WidgetWithState for Area {
@@ -149,6 +151,7 @@ impl Area {
             fade_in: true,
             layout: Layout::default(),
             sizing_pass: false,
+            focus_scope: None,
         }
     }
 
@@ -221,6 +224,15 @@ impl Area {
         self
     }
 
+    /// Make the contents of the area a [`FocusScope`], identified by the area id.
+    ///
+    /// Default: none.
+    #[inline]
+    pub fn focus_scope(mut self, focus_scope: impl Into<Option<FocusScope>>) -> Self {
+        self.focus_scope = focus_scope.into();
+        self
+    }
+
     /// Explicitly set a sense.
     ///
     /// If not set, this will default to `Sense::drag()` if movable, `Sense::click()` if interactable, and `Sense::hover()` otherwise.
@@ -400,6 +412,7 @@ pub(crate) struct Prepared {
 
     fade_in: bool,
     layout: Layout,
+    focus_scope: Option<FocusScope>,
 }
 
 impl Area {
@@ -434,6 +447,7 @@ impl Area {
             fade_in,
             layout,
             sizing_pass: force_sizing_pass,
+            focus_scope,
         } = self;
 
         let constrain_rect = constrain_rect.unwrap_or_else(|| ctx.content_rect());
@@ -577,6 +591,7 @@ impl Area {
             sizing_pass,
             fade_in,
             layout,
+            focus_scope,
         }
     }
 }
@@ -626,6 +641,11 @@ impl Prepared {
             ui_builder = ui_builder.sizing_pass().invisible();
         }
 
+        if let Some(focus_scope) = self.focus_scope {
+            // Popped in `Self::end`:
+            ctx.memory_mut(|mem| mem.push_focus_scope(self.layer_id.id, focus_scope));
+        }
+
         let mut ui = Ui::new(ctx.clone(), self.layer_id.id, ui_builder);
         ui.set_clip_rect(self.constrain_rect); // Don't paint outside our bounds
 
@@ -653,6 +673,11 @@ impl Prepared {
         self.move_response.id
     }
 
+    /// The response for dragging the area around.
+    pub(crate) fn move_response(&self) -> &Response {
+        &self.move_response
+    }
+
     #[expect(clippy::needless_pass_by_value)] // intentional to swallow up `content_ui`.
     pub(crate) fn end(self, ctx: &Context, content_ui: Ui) -> Response {
         let Self {
@@ -661,9 +686,14 @@ impl Prepared {
             mut state,
             move_response: mut response,
             sizing_pass,
+            focus_scope,
             ..
         } = self;
 
+        if focus_scope.is_some() {
+            ctx.memory_mut(|mem| mem.pop_focus_scope());
+        }
+
         state.size = Some(content_ui.min_size());
 
         // Make sure we report back the correct size.
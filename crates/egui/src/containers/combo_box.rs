@@ -1,9 +1,14 @@
 use epaint::Shape;
 
 use crate::{
-    Align2, Context, Id, InnerResponse, NumExt as _, Painter, Popup, PopupCloseBehavior, Rect,
-    Response, ScrollArea, Sense, Stroke, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo,
-    WidgetText, WidgetType, epaint, style::StyleModifier, style::WidgetVisuals, vec2,
+    Align2, Button, Color32, Context, Id, InnerResponse, Key, Modifiers, NumExt as _, Painter,
+    Popup, PopupAnchor, PopupCloseBehavior, Rect, Response, ScrollArea, Sense, SetOpenCommand,
+    Stroke, TextEdit, TextStyle, TextWrapMode, Ui, UiBuilder, Vec2, WidgetInfo, WidgetText,
+    WidgetType, epaint,
+    style::StyleModifier,
+    style::WidgetVisuals,
+    text::{CCursor, CCursorRange, LayoutJob, TextFormat},
+    vec2,
 };
 
 #[expect(unused_imports)] // Documentation
@@ -45,6 +50,7 @@ pub struct ComboBox {
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
     popup_style: StyleModifier,
+    filterable: bool,
 }
 
 impl ComboBox {
@@ -60,6 +66,7 @@ impl ComboBox {
             wrap_mode: None,
             close_behavior: None,
             popup_style: StyleModifier::default(),
+            filterable: false,
         }
     }
 
@@ -76,6 +83,7 @@ impl ComboBox {
             wrap_mode: None,
             close_behavior: None,
             popup_style: StyleModifier::default(),
+            filterable: false,
         }
     }
 
@@ -91,6 +99,7 @@ impl ComboBox {
             wrap_mode: None,
             close_behavior: None,
             popup_style: StyleModifier::default(),
+            filterable: false,
         }
     }
 
@@ -188,7 +197,8 @@ impl ComboBox {
 
     /// Controls the close behavior for the popup.
     ///
-    /// By default, `PopupCloseBehavior::CloseOnClick` will be used.
+    /// By default, `PopupCloseBehavior::CloseOnClick` will be used,
+    /// or `PopupCloseBehavior::CloseOnClickOutside` for [`Self::filterable`] and [`Self::show_editable`].
     #[inline]
     pub fn close_behavior(mut self, close_behavior: PopupCloseBehavior) -> Self {
         self.close_behavior = Some(close_behavior);
@@ -205,6 +215,31 @@ impl ComboBox {
         self
     }
 
+    /// Show a text field at the top of the popup of [`Self::show_index`] to filter the entries.
+    ///
+    /// This has no effect on [`Self::show_ui`].
+    ///
+    /// Entries are fuzzy matched against the typed text, best matches first,
+    /// with the matching characters highlighted.
+    /// Use the up and down arrow keys to move through the entries, and enter to select one.
+    ///
+    /// Only the visible entries are laid out, so this works well with thousands of entries.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let fruits = ["Apple", "Banana", "Blueberry", "Cherry", "Grape"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Fruit")
+    ///     .filterable(true)
+    ///     .show_index(ui, &mut selected, fruits.len(), |i| fruits[i]);
+    /// # });
+    /// ```
+    #[inline]
+    pub fn filterable(mut self, filterable: bool) -> Self {
+        self.filterable = filterable;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
     ///
     /// Returns `InnerResponse { inner: None }` if the combo box is closed.
@@ -213,13 +248,22 @@ impl ComboBox {
         ui: &mut Ui,
         menu_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<Option<R>> {
-        self.show_ui_dyn(ui, Box::new(menu_contents))
+        self.show_ui_dyn(
+            ui,
+            Box::new(|ui, height| {
+                ScrollArea::vertical()
+                    .max_height(height)
+                    .show(ui, menu_contents)
+                    .inner
+            }),
+        )
     }
 
+    /// `menu_contents` is given the maximum height of the menu, and must handle scrolling itself.
     fn show_ui_dyn<'c, R>(
         self,
         ui: &mut Ui,
-        menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+        menu_contents: Box<dyn FnOnce(&mut Ui, f32) -> R + 'c>,
     ) -> InnerResponse<Option<R>> {
         let Self {
             id_salt,
@@ -231,6 +275,7 @@ impl ComboBox {
             wrap_mode,
             close_behavior,
             popup_style,
+            filterable: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_salt);
@@ -288,6 +333,10 @@ impl ComboBox {
         len: usize,
         get: impl Fn(usize) -> Text,
     ) -> Response {
+        if self.filterable {
+            return self.show_filtered_index(ui, selected, len, get);
+        }
+
         let slf = self.selected_text(get(*selected));
 
         let mut changed = false;
@@ -309,6 +358,220 @@ impl ComboBox {
         response
     }
 
+    fn show_filtered_index<Text: Into<WidgetText>>(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut usize,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        self.selected_text = get(*selected).into();
+        self.close_behavior
+            .get_or_insert(PopupCloseBehavior::CloseOnClickOutside);
+
+        let popup_id = Self::widget_to_popup_id(ui.make_persistent_id(self.id_salt));
+        let state_id = popup_id.with("filter");
+        if !Popup::is_id_open(ui.ctx(), popup_id) {
+            ui.data_mut(|data| data.remove::<FilterState>(state_id));
+        }
+
+        let current = *selected;
+        let inner = self.show_ui_dyn(
+            ui,
+            Box::new(|ui, height| {
+                let (mut state, is_new) = FilterState::load(ui.ctx(), state_id, Some(current));
+
+                let field_id = state_id.with("field");
+                let keys = if ui.memory(|mem| mem.has_focus(field_id)) {
+                    ListKeys::consume(ui)
+                } else {
+                    ListKeys::default()
+                };
+                let field = TextEdit::singleline(&mut state.query)
+                    .id(field_id)
+                    .hint_text("Search…")
+                    .desired_width(f32::INFINITY)
+                    .show(ui)
+                    .response;
+                if is_new {
+                    field.request_focus();
+                }
+
+                let texts = entry_texts(len, &get);
+                let query = state.query.clone();
+                let chosen = filtered_entries_ui(
+                    ui,
+                    &mut state,
+                    &query,
+                    &texts,
+                    Some(current),
+                    height,
+                    keys,
+                );
+                ui.data_mut(|data| data.insert_temp(state_id, state));
+                chosen
+            }),
+        );
+
+        let mut response = inner.response;
+        if let Some(Some(chosen)) = inner.inner {
+            Popup::close_id(ui.ctx(), popup_id);
+            if chosen != *selected {
+                *selected = chosen;
+                response.mark_changed();
+            }
+        }
+        response
+    }
+
+    /// A combo box where any text can be typed, with the entries as suggestions.
+    ///
+    /// The entries are filtered by the typed text, like with [`Self::filterable`].
+    /// Use the up and down arrow keys to move through them, and enter to pick one.
+    ///
+    /// Returns the response of the text field, which is [`Response::changed`]
+    /// when the text is edited or an entry is picked.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let fonts = ["Arial", "Courier", "Helvetica", "Times"];
+    /// let mut font = String::from("Arial");
+    /// egui::ComboBox::from_label("Font").show_editable(ui, &mut font, fonts.len(), |i| fonts[i]);
+    /// # });
+    /// ```
+    pub fn show_editable<Text: Into<WidgetText>>(
+        self,
+        ui: &mut Ui,
+        text: &mut String,
+        len: usize,
+        get: impl Fn(usize) -> Text,
+    ) -> Response {
+        let Self {
+            id_salt,
+            label,
+            selected_text: _,
+            width,
+            height,
+            icon,
+            wrap_mode: _,
+            close_behavior,
+            popup_style,
+            filterable: _,
+        } = self;
+
+        let edit_id = ui.make_persistent_id(id_salt);
+        let popup_id = Self::widget_to_popup_id(edit_id);
+        let state_id = popup_id.with("filter");
+        let is_popup_open = Popup::is_id_open(ui.ctx(), popup_id);
+        if !is_popup_open {
+            ui.data_mut(|data| data.remove::<FilterState>(state_id));
+        }
+
+        ui.horizontal(|ui| {
+            let keys = if ui.memory(|mem| mem.has_focus(edit_id)) {
+                ListKeys::consume(ui)
+            } else {
+                ListKeys::default()
+            };
+
+            let width = width.unwrap_or_else(|| ui.spacing().combo_width);
+            let icon_size = Vec2::splat(ui.spacing().icon_width);
+            let icon_width = icon_size.x + 2.0 * ui.spacing().button_padding.x;
+
+            let (mut response, icon_response) = ui
+                .scope(|ui| {
+                    ui.spacing_mut().item_spacing.x = 0.0;
+                    let response = TextEdit::singleline(text)
+                        .id(edit_id)
+                        .desired_width(width - icon_width - 2.0 * ui.spacing().button_padding.x)
+                        .show(ui)
+                        .response;
+                    let (icon_rect, icon_response) = ui.allocate_exact_size(
+                        vec2(icon_width, response.rect.height()),
+                        Sense::click(),
+                    );
+                    if ui.is_rect_visible(icon_rect) {
+                        let visuals = if is_popup_open {
+                            &ui.visuals().widgets.open
+                        } else {
+                            ui.style().interact(&icon_response)
+                        };
+                        let icon_rect = Rect::from_center_size(icon_rect.center(), icon_size)
+                            .expand(visuals.expansion);
+                        if let Some(icon) = icon {
+                            icon(ui, icon_rect, visuals, is_popup_open);
+                        } else {
+                            paint_default_icon(ui.painter(), icon_rect, visuals);
+                        }
+                    }
+                    (response, icon_response)
+                })
+                .inner;
+
+            let open_command = if icon_response.clicked() {
+                Some(SetOpenCommand::Toggle)
+            } else if response.changed() || (keys.delta > 0 && !is_popup_open) {
+                Some(SetOpenCommand::Bool(true))
+            } else {
+                None
+            };
+            if response.changed() {
+                // Only filter once the user has typed something:
+                ui.data_mut(|data| {
+                    data.get_temp_mut_or_insert_with(state_id, || FilterState::new(None))
+                        .filtering = true;
+                });
+            }
+
+            let anchor_rect = response.rect.union(icon_response.rect);
+            let height = height.unwrap_or_else(|| ui.spacing().combo_height);
+            let chosen = Popup::menu(&icon_response)
+                .id(popup_id)
+                .anchor(PopupAnchor::from(anchor_rect))
+                .open_memory(open_command)
+                .width(anchor_rect.width())
+                .close_behavior(close_behavior.unwrap_or(PopupCloseBehavior::CloseOnClickOutside))
+                .style(popup_style)
+                .show(|ui| {
+                    ui.set_min_width(ui.available_width());
+                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+
+                    let texts = entry_texts(len, &get);
+                    let current = texts.iter().position(|entry| entry == text);
+                    let (mut state, _) = FilterState::load(ui.ctx(), state_id, current);
+                    let query = if state.filtering { text.as_str() } else { "" };
+                    let chosen =
+                        filtered_entries_ui(ui, &mut state, query, &texts, current, height, keys);
+                    ui.data_mut(|data| data.insert_temp(state_id, state));
+                    chosen
+                })
+                .and_then(|r| r.inner);
+
+            if let Some(chosen) = chosen {
+                Popup::close_id(ui.ctx(), popup_id);
+                let entry = get(chosen).into();
+                if text != entry.text() {
+                    *text = entry.text().to_owned();
+                    response.mark_changed();
+                }
+
+                // Continue typing after the picked entry:
+                let mut edit_state = TextEdit::load_state(ui.ctx(), edit_id).unwrap_or_default();
+                edit_state
+                    .cursor
+                    .set_char_range(Some(CCursorRange::one(CCursor::new(text.chars().count()))));
+                edit_state.store(ui.ctx(), edit_id);
+            }
+
+            if let Some(label) = label {
+                let label_response = ui.label(label);
+                response = response.labelled_by(label_response.id);
+            }
+            response
+        })
+        .inner
+    }
+
     /// Check if the [`ComboBox`] with the given id has its popup menu currently opened.
     pub fn is_open(ctx: &Context, id: Id) -> bool {
         Popup::is_id_open(ctx, Self::widget_to_popup_id(id))
@@ -325,7 +588,7 @@ fn combo_box_dyn<'c, R>(
     ui: &mut Ui,
     button_id: Id,
     selected_text: WidgetText,
-    menu_contents: Box<dyn FnOnce(&mut Ui) -> R + 'c>,
+    menu_contents: Box<dyn FnOnce(&mut Ui, f32) -> R + 'c>,
     icon: Option<IconPainter>,
     wrap_mode: Option<TextWrapMode>,
     close_behavior: Option<PopupCloseBehavior>,
@@ -404,18 +667,13 @@ fn combo_box_dyn<'c, R>(
         .show(|ui| {
             ui.set_min_width(ui.available_width());
 
-            ScrollArea::vertical()
-                .max_height(height)
-                .show(ui, |ui| {
-                    // Often the button is very narrow, which means this popup
-                    // is also very narrow. Having wrapping on would therefore
-                    // result in labels that wrap very early.
-                    // Instead, we turn it off by default so that the labels
-                    // expand the width of the menu.
-                    ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
-                    menu_contents(ui)
-                })
-                .inner
+            // Often the button is very narrow, which means this popup
+            // is also very narrow. Having wrapping on would therefore
+            // result in labels that wrap very early.
+            // Instead, we turn it off by default so that the labels
+            // expand the width of the menu.
+            ui.style_mut().wrap_mode = Some(TextWrapMode::Extend);
+            menu_contents(ui, height)
         })
         .map(|r| r.inner);
 
@@ -489,3 +747,263 @@ fn paint_default_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
         Stroke::NONE,
     ));
 }
+
+// ----------------------------------------------------------------------------
+
+/// State of the popup of a filterable or editable [`ComboBox`], kept while it is open.
+#[derive(Clone)]
+struct FilterState {
+    /// The text typed into the filter field.
+    query: String,
+
+    /// Should the entries be filtered? Editable combo boxes show all entries until something is typed.
+    filtering: bool,
+
+    /// The index of the entry highlighted with the keyboard.
+    highlighted: Option<usize>,
+
+    /// The query the highlight was last updated for.
+    last_query: String,
+
+    scroll_offset: f32,
+
+    /// Scroll the highlighted entry into view this frame.
+    scroll_to_highlighted: bool,
+}
+
+impl FilterState {
+    fn new(highlighted: Option<usize>) -> Self {
+        Self {
+            query: String::new(),
+            filtering: true,
+            highlighted,
+            last_query: String::new(),
+            scroll_offset: 0.0,
+            scroll_to_highlighted: true,
+        }
+    }
+
+    /// Returns the state, and `true` if it was just created.
+    fn load(ctx: &Context, id: Id, highlighted: Option<usize>) -> (Self, bool) {
+        match ctx.data(|data| data.get_temp::<Self>(id)) {
+            Some(state) => (state, false),
+            None => (
+                Self {
+                    filtering: false,
+                    ..Self::new(highlighted)
+                },
+                true,
+            ),
+        }
+    }
+}
+
+/// Keyboard navigation of the entries, consumed from the text field that has focus.
+#[derive(Clone, Copy, Default)]
+struct ListKeys {
+    /// Number of steps down (positive) or up (negative).
+    delta: isize,
+    enter: bool,
+}
+
+impl ListKeys {
+    fn consume(ui: &Ui) -> Self {
+        ui.input_mut(|input| Self {
+            delta: input.count_and_consume_key(Modifiers::NONE, Key::ArrowDown) as isize
+                - input.count_and_consume_key(Modifiers::NONE, Key::ArrowUp) as isize,
+            enter: input.consume_key(Modifiers::NONE, Key::Enter),
+        })
+    }
+}
+
+/// The text of every entry, to filter them by.
+///
+/// Only call this while the popup is open, since it gets every entry.
+fn entry_texts<Text: Into<WidgetText>>(len: usize, get: impl Fn(usize) -> Text) -> Vec<String> {
+    (0..len).map(|i| get(i).into().text().to_owned()).collect()
+}
+
+/// Show the entries matching `query` in a virtualized list.
+///
+/// Returns the index of the entry that was clicked or picked with enter, if any.
+fn filtered_entries_ui(
+    ui: &mut Ui,
+    state: &mut FilterState,
+    query: &str,
+    texts: &[String],
+    selected: Option<usize>,
+    max_height: f32,
+    keys: ListKeys,
+) -> Option<usize> {
+    let mut entries: Vec<(usize, FuzzyMatch)> = texts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, text)| fuzzy_match(query, text).map(|m| (i, m)))
+        .collect();
+    entries.sort_by_key(|(_, m)| std::cmp::Reverse(m.score));
+
+    let mut position = state
+        .highlighted
+        .and_then(|highlighted| entries.iter().position(|(i, _)| *i == highlighted));
+    if state.last_query != query {
+        // Highlight the best match:
+        state.last_query = query.to_owned();
+        position = (!entries.is_empty()).then_some(0);
+        state.scroll_to_highlighted = true;
+    }
+    if keys.delta != 0 && !entries.is_empty() {
+        let last = entries.len() as isize - 1;
+        position = Some(match position {
+            Some(position) => (position as isize + keys.delta).clamp(0, last) as usize,
+            None if keys.delta > 0 => 0,
+            None => last as usize,
+        });
+        state.scroll_to_highlighted = true;
+    }
+    state.highlighted = position.map(|position| entries[position].0);
+
+    let mut chosen = if keys.enter { state.highlighted } else { None };
+
+    if entries.is_empty() {
+        ui.weak("No matches");
+        return chosen;
+    }
+
+    let spacing = ui.spacing().item_spacing.y;
+    let row_height = ui
+        .spacing()
+        .interact_size
+        .y
+        .max(ui.text_style_height(&TextStyle::Button) + 2.0 * ui.spacing().button_padding.y);
+
+    let mut scroll_area = ScrollArea::vertical()
+        .max_height(max_height)
+        .auto_shrink([false, true]);
+    if state.scroll_to_highlighted
+        && let Some(position) = position
+    {
+        let top = position as f32 * (row_height + spacing);
+        let offset = state
+            .scroll_offset
+            .max(top + row_height - max_height)
+            .min(top);
+        scroll_area = scroll_area.vertical_scroll_offset(offset);
+    }
+    state.scroll_to_highlighted = false;
+
+    let output = scroll_area.show_rows(ui, row_height, entries.len(), |ui, rows| {
+        for row in rows {
+            let (i, fuzzy) = &entries[row];
+            let where_to_put_background = ui.painter().add(Shape::Noop);
+            let job = highlighted_job(ui, &texts[*i], &fuzzy.matched);
+            let response = ui
+                .add(Button::selectable(selected == Some(*i), job).min_size(vec2(0.0, row_height)));
+            if position == Some(row) && selected != Some(*i) {
+                let visuals = &ui.visuals().widgets.hovered;
+                ui.painter().set(
+                    where_to_put_background,
+                    epaint::RectShape::filled(
+                        response.rect,
+                        visuals.corner_radius,
+                        visuals.weak_bg_fill,
+                    ),
+                );
+            }
+            if response.clicked() {
+                chosen = Some(*i);
+            }
+        }
+    });
+    state.scroll_offset = output.state.offset.y;
+
+    chosen
+}
+
+/// The text of an entry, with the fuzzy matched characters highlighted.
+fn highlighted_job(ui: &Ui, text: &str, matched: &[usize]) -> LayoutJob {
+    let font_id = TextStyle::Button.resolve(ui.style());
+    let normal = TextFormat::simple(font_id.clone(), Color32::PLACEHOLDER);
+    let strong_color = ui.visuals().strong_text_color();
+    let highlight = TextFormat {
+        underline: Stroke::new(1.0, strong_color),
+        ..TextFormat::simple(font_id, strong_color)
+    };
+
+    let mut job = LayoutJob::default();
+    let mut start = 0;
+    for &byte in matched {
+        let end = byte + text[byte..].chars().next().map_or(0, char::len_utf8);
+        job.append(&text[start..byte], 0.0, normal.clone());
+        job.append(&text[byte..end], 0.0, highlight.clone());
+        start = end;
+    }
+    job.append(&text[start..], 0.0, normal);
+    job
+}
+
+struct FuzzyMatch {
+    /// Higher is better.
+    score: i32,
+
+    /// Byte offsets of the matched characters.
+    matched: Vec<usize>,
+}
+
+/// Find the characters of `query`, in order, in `text`, ignoring case.
+///
+/// Matches at the start of words and consecutive matches score higher.
+/// An empty query matches everything.
+fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut score = 0;
+    let mut matched = Vec::new();
+    let mut text_chars = text.char_indices();
+    let mut prev: Option<char> = None;
+    let mut prev_matched = false;
+
+    for query_char in query.chars().filter(|c| !c.is_whitespace()) {
+        let mut gap = 0;
+        loop {
+            let (byte, c) = text_chars.next()?;
+            let is_word_start = match prev {
+                None => true,
+                Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase()),
+            };
+            prev = Some(c);
+            if c.to_lowercase().eq(query_char.to_lowercase()) {
+                score += 1;
+                if prev_matched && gap == 0 {
+                    score += 5;
+                }
+                if is_word_start {
+                    score += 8;
+                }
+                score -= gap.min(5);
+                matched.push(byte);
+                prev_matched = true;
+                break;
+            }
+            gap += 1;
+        }
+    }
+
+    Some(FuzzyMatch { score, matched })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    #[test]
+    fn test_fuzzy_match() {
+        let m = fuzzy_match("bb", "Blueberry").unwrap();
+        assert_eq!(m.matched, vec![0, 4]);
+        assert!(fuzzy_match("bx", "Blueberry").is_none());
+        assert!(fuzzy_match("", "anything").unwrap().matched.is_empty());
+
+        // Word starts and consecutive characters are preferred:
+        let score = |query, text| fuzzy_match(query, text).unwrap().score;
+        assert!(score("ne", "New York") > score("ne", "Penelope"));
+        assert!(score("york", "New York") > score("york", "Yo, rock"));
+        assert!(score("ny", "NewYork") > score("ny", "Nancy"));
+    }
+}
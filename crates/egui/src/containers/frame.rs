@@ -4,7 +4,12 @@ use crate::{
     InnerResponse, Response, Sense, Style, Ui, UiBuilder, UiKind, UiStackInfo, epaint,
     layers::ShapeIdx,
 };
-use epaint::{Color32, CornerRadius, Margin, MarginF32, Rect, Shadow, Shape, Stroke};
+use std::sync::Arc;
+
+use epaint::{
+    ClipMask, Color32, CornerRadius, CornerRadiusF32, Margin, MarginF32, Rect, Shadow, Shape,
+    Stroke,
+};
 
 /// A frame around some content, including margin, colors, etc.
 ///
@@ -89,6 +94,23 @@ use epaint::{Color32, CornerRadius, Margin, MarginF32, Rect, Shadow, Shape, Stro
 /// ```
 ///
 /// Note that you cannot change the margins after calling `begin`.
+///
+/// ## Clipping to rounded corners
+/// By default, the contents can spill over the rounded corners of the frame.
+/// Use [`Frame::clip_content`] to clip them:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// egui::Frame::default()
+///     .corner_radius(16.0)
+///     .clip_content(true)
+///     .show(ui, |ui| {
+///         ui.label("Clipped to the rounded corners");
+///     });
+/// # });
+/// ```
+///
+/// With [`Frame::begin`], you can also call [`Prepared::clip_content`] yourself.
 #[doc(alias = "border")]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -138,13 +160,19 @@ pub struct Frame {
 
     /// Optional drop-shadow behind the frame.
     pub shadow: Shadow,
+
+    /// Clip the contents to the inside of the frame, so they don't spill over the rounded corners.
+    ///
+    /// See [`Prepared::clip_content`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clip_content: bool,
 }
 
 #[test]
 fn frame_size() {
     assert_eq!(
         std::mem::size_of::<Frame>(),
-        32,
+        40, // The `Stroke` has room for a `LineJoin` and `LineCap`, and then there is `clip_content`
         "Frame changed size! If it shrank - good! Update this test. If it grew - bad! Try to find a way to avoid it."
     );
     assert!(
@@ -165,6 +193,7 @@ impl Frame {
         corner_radius: CornerRadius::ZERO,
         outer_margin: Margin::ZERO,
         shadow: Shadow::NONE,
+        clip_content: false,
     };
 
     /// No colors, no margins, no border.
@@ -319,6 +348,15 @@ impl Frame {
         self
     }
 
+    /// Clip the contents to the inside of the frame, so they don't spill over the rounded corners.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn clip_content(mut self, clip_content: bool) -> Self {
+        self.clip_content = clip_content;
+        self
+    }
+
     /// Opacity multiplier in gamma space.
     ///
     /// For instance, multiplying with `0.5`
@@ -438,6 +476,7 @@ impl Frame {
             corner_radius,
             outer_margin: _,
             shadow,
+            clip_content: _,
         } = *self;
 
         let widget_rect = self.widget_rect(content_rect);
@@ -490,10 +529,36 @@ impl Prepared {
         }
     }
 
-    /// Convenience for calling [`Self::allocate_space`] and [`Self::paint`].
+    /// Clip everything painted so far in [`Self::content_ui`] to the inside of the frame,
+    /// so that it doesn't spill over the rounded corners.
+    ///
+    /// Call this after adding the contents.
+    /// Shapes that already have a [`ClipMask`] (e.g. from an inner frame) keep theirs.
+    pub fn clip_content(&self, ui: &Ui) {
+        let frame = &self.frame;
+        let widget_rect = frame.widget_rect(self.content_ui.min_rect());
+        let mut corner_radius = CornerRadiusF32::from(frame.corner_radius);
+        corner_radius -= frame.stroke.width;
+        let clip_mask = Arc::new(ClipMask::rounded_rect(
+            widget_rect.shrink(frame.stroke.width),
+            corner_radius.at_least(0.0),
+        ));
+
+        let first_content_shape = ShapeIdx(self.where_to_put_background.0 + 1);
+        ui.ctx().graphics_mut(|graphics| {
+            let list = graphics.entry(ui.layer_id());
+            list.clip_range(first_content_shape, list.next_idx(), &clip_mask);
+        });
+    }
+
+    /// Convenience for calling [`Self::allocate_space`] and [`Self::paint`],
+    /// and [`Self::clip_content`] if [`Frame::clip_content`] is set.
     ///
     /// Returns the outer rect, i.e. including the outer margin.
     pub fn end(self, ui: &mut Ui) -> Response {
+        if self.frame.clip_content {
+            self.clip_content(ui);
+        }
         self.paint(ui);
         self.allocate_space(ui)
     }
@@ -1,4 +1,4 @@
-//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`Panel`], etc.
+//! Containers are pieces of the UI which wraps other pieces of UI. Examples: [`Window`], [`ScrollArea`], [`Resize`], [`Panel`], [`Splitter`], etc.
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
@@ -12,12 +12,15 @@ pub mod modal;
 pub mod old_popup;
 pub mod panel;
 mod popup;
+pub mod reorderable_list;
 pub(crate) mod resize;
 mod scene;
 pub mod scroll_area;
 mod sides;
+pub mod splitter;
 mod tooltip;
 pub(crate) mod window;
+pub mod window_layout;
 
 pub use {
     area::{Area, AreaState},
@@ -29,10 +32,13 @@ pub use {
     old_popup::*,
     panel::*,
     popup::*,
+    reorderable_list::{ListLocation, ListMove, ReorderableList, ReorderableListResponse},
     resize::Resize,
     scene::{DragPanButtons, Scene},
     scroll_area::ScrollArea,
     sides::Sides,
+    splitter::{Splitter, SplitterPane, SplitterState},
     tooltip::*,
     window::Window,
+    window_layout::{WindowArrangement, WindowTray},
 };
@@ -1,7 +1,8 @@
 use emath::{Align2, Vec2};
 
 use crate::{
-    Area, Color32, Context, Frame, Id, InnerResponse, Order, Response, Sense, Ui, UiBuilder, UiKind,
+    Area, Color32, Context, FocusScope, Frame, Id, InnerResponse, Order, Response, Sense, Ui,
+    UiBuilder, UiKind,
 };
 
 /// A modal dialog.
@@ -9,6 +10,9 @@ use crate::{
 /// Similar to a [`crate::Window`] but centered and with a backdrop that
 /// blocks input to the rest of the UI.
 ///
+/// Tab and Shift+Tab cycle through the widgets in the modal, and when the modal closes
+/// the keyboard focus goes back to the widget that had it when the modal opened.
+///
 /// You can show multiple modals on top of each other. The topmost modal will always be
 /// the most recently shown one.
 /// If multiple modals are newly shown in the same frame, the order of the modals is undefined
@@ -37,6 +41,7 @@ impl Modal {
     /// - sense: hover
     /// - anchor: center
     /// - order: foreground
+    /// - focus scope: [`FocusScope::TRAP`]
     pub fn default_area(id: Id) -> Area {
         Area::new(id)
             .kind(UiKind::Modal)
@@ -44,6 +49,7 @@ impl Modal {
             .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
             .order(Order::Foreground)
             .interactable(true)
+            .focus_scope(FocusScope::TRAP)
     }
 
     /// Set the frame of the modal.
@@ -5,8 +5,8 @@ use std::iter::once;
 use emath::{Align, Pos2, Rect, RectAlign, Vec2, vec2};
 
 use crate::{
-    Area, AreaState, Context, Frame, Id, InnerResponse, Key, LayerId, Layout, Order, Response,
-    Sense, Ui, UiKind, UiStackInfo,
+    Area, AreaState, Context, FocusScope, Frame, Id, InnerResponse, Key, LayerId, Layout, Order,
+    Response, Sense, Ui, UiKind, UiStackInfo,
     containers::menu::{MenuConfig, MenuState, menu_style},
     style::StyleModifier,
 };
@@ -185,6 +185,7 @@ pub struct Popup<'a> {
     layout: Layout,
     frame: Option<Frame>,
     style: StyleModifier,
+    focus_scope: Option<FocusScope>,
 }
 
 impl<'a> Popup<'a> {
@@ -207,6 +208,7 @@ impl<'a> Popup<'a> {
             layout: Layout::default(),
             frame: None,
             style: StyleModifier::default(),
+            focus_scope: Some(FocusScope::default()),
         }
     }
 
@@ -399,6 +401,16 @@ impl<'a> Popup<'a> {
         self
     }
 
+    /// Set how the popup treats keyboard focus, or `None` to not make it a focus scope.
+    ///
+    /// Default: [`FocusScope::default`], which restores the focus to the widget that had it
+    /// when the popup opened, once the popup closes.
+    #[inline]
+    pub fn focus_scope(mut self, focus_scope: impl Into<Option<FocusScope>>) -> Self {
+        self.focus_scope = focus_scope.into();
+        self
+    }
+
     /// Set the style for the popup contents.
     ///
     /// Default:
@@ -553,6 +565,7 @@ impl<'a> Popup<'a> {
             layout,
             frame,
             style,
+            focus_scope,
         } = self;
 
         if kind != PopupKind::Tooltip {
@@ -575,6 +588,7 @@ impl<'a> Popup<'a> {
             .fixed_pos(anchor)
             .sense(sense)
             .layout(layout)
+            .focus_scope(focus_scope)
             .info(info.unwrap_or_else(|| {
                 UiStackInfo::new(kind.into()).with_tag_value(
                     MenuConfig::MENU_CONFIG_TAG,
@@ -0,0 +1,289 @@
+//! A list whose items can be reordered with drag-and-drop. See [`ReorderableList`].
+
+use std::hash::Hash;
+
+use crate::{
+    DragAndDrop, Id, Pos2, Rangef, Rect, Response, Sense, Stroke, Ui, Vec2, style::ScrollAnimation,
+    vec2,
+};
+
+/// How close to the edge of the visible area the pointer must be for the list to scroll, in points.
+const AUTO_SCROLL_MARGIN: f32 = 32.0;
+
+/// How fast we scroll when the pointer is at (or beyond) the edge of the visible area, in points per second.
+const AUTO_SCROLL_SPEED: f32 = 600.0;
+
+/// The position of an item in a [`ReorderableList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListLocation {
+    /// The [`ReorderableListResponse::id`] of the list.
+    pub list: Id,
+
+    /// The index of the item in the list.
+    pub index: usize,
+}
+
+/// An item was dragged from one place to another.
+///
+/// Returned by the list the item was dropped onto, which may be different from the one it came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ListMove {
+    /// Where the item was.
+    pub from: ListLocation,
+
+    /// Where the item should go.
+    ///
+    /// The index is the one the item should have _after_ it has been removed from [`Self::from`],
+    /// so you can just `remove` and then `insert` it.
+    pub to: ListLocation,
+}
+
+impl ListMove {
+    /// Is this a move within a single list?
+    pub fn is_within_list(&self) -> bool {
+        self.from.list == self.to.list
+    }
+
+    /// Apply a move within a single list.
+    ///
+    /// Does nothing if the indices are out of bounds.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        if self.from.index < items.len() {
+            let item = items.remove(self.from.index);
+            items.insert(self.to.index.min(items.len()), item);
+        }
+    }
+
+    /// Apply a move from the list `source` to the (different) list `target`.
+    ///
+    /// Does nothing if the indices are out of bounds.
+    pub fn apply_between<T>(&self, source: &mut Vec<T>, target: &mut Vec<T>) {
+        if self.from.index < source.len() {
+            let item = source.remove(self.from.index);
+            target.insert(self.to.index.min(target.len()), item);
+        }
+    }
+}
+
+/// The payload of the item being dragged.
+#[derive(Clone, Copy, Debug)]
+struct DraggedItem {
+    group: Id,
+    from: ListLocation,
+}
+
+/// What [`ReorderableList::show`] returns.
+pub struct ReorderableListResponse {
+    /// The id of the list, as used in [`ListLocation::list`].
+    pub id: Id,
+
+    /// The response of the whole list.
+    pub response: Response,
+
+    /// An item was dropped onto this list this frame.
+    pub moved: Option<ListMove>,
+}
+
+/// A list whose items can be reordered by dragging them.
+///
+/// While dragging, the item follows the pointer, and a line shows where it will be inserted.
+/// Dragging close to the edge of an enclosing [`crate::ScrollArea`] scrolls it.
+///
+/// Lists in the same [`Self::group`] can exchange items.
+///
+/// The list does not change your items, but tells you what moved:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut items = vec!["First", "Second", "Third"];
+/// let list = egui::ReorderableList::new("my_list").show(ui, &items, |ui, _index, item| {
+///     ui.label(*item);
+/// });
+/// if let Some(moved) = list.moved {
+///     moved.apply(&mut items);
+/// }
+/// # });
+/// ```
+///
+/// The items are laid out in the direction of the [`Ui`]s layout.
+#[must_use = "You should call .show()"]
+#[derive(Clone, Copy, Debug)]
+pub struct ReorderableList {
+    id_salt: Id,
+    group: Option<Id>,
+    auto_scroll: bool,
+}
+
+impl ReorderableList {
+    /// The `id_salt` must be unique within the parent [`Ui`].
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            group: None,
+            auto_scroll: true,
+        }
+    }
+
+    /// Lists with the same group can move items between each other.
+    ///
+    /// By default, items can only be moved within the list itself.
+    #[inline]
+    pub fn group(mut self, group: impl Hash) -> Self {
+        self.group = Some(Id::new(group));
+        self
+    }
+
+    /// Scroll the enclosing [`crate::ScrollArea`] when an item is dragged close to its edge.
+    ///
+    /// Default: `true`.
+    #[inline]
+    pub fn auto_scroll(mut self, auto_scroll: bool) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+
+    /// Show the items, calling `item_ui` with the index of each.
+    ///
+    /// Each item can be dragged by any part of it not taken by an interactive widget.
+    pub fn show<T>(
+        self,
+        ui: &mut Ui,
+        items: &[T],
+        mut item_ui: impl FnMut(&mut Ui, usize, &T),
+    ) -> ReorderableListResponse {
+        let Self {
+            id_salt,
+            group,
+            auto_scroll,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let group = group.unwrap_or(id);
+        let axis = usize::from(ui.layout().is_vertical()); // x or y
+
+        let mut item_rects = Vec::with_capacity(items.len());
+        let list_rect = ui
+            .scope(|ui| {
+                for (index, item) in items.iter().enumerate() {
+                    let dragged = DraggedItem {
+                        group,
+                        from: ListLocation { list: id, index },
+                    };
+                    let response = ui
+                        .dnd_drag_source(id.with(index), dragged, |ui| item_ui(ui, index, item))
+                        .response;
+                    item_rects.push(response.rect);
+                }
+                if items.is_empty() {
+                    // Leave room to drop things on:
+                    let mut size = vec2(ui.available_width(), ui.available_height());
+                    size[axis] = ui.spacing().interact_size.y;
+                    ui.allocate_space(size);
+                }
+            })
+            .response
+            .rect;
+        let response = ui.interact(list_rect, id, Sense::hover());
+
+        let dragged =
+            DragAndDrop::payload::<DraggedItem>(ui.ctx()).filter(|dragged| dragged.group == group);
+        let mut moved = None;
+
+        if let Some(dragged) = dragged
+            && let Some(pointer) = ui.ctx().pointer_interact_pos()
+        {
+            if auto_scroll && list_rect.contains(pointer) {
+                scroll_near_edge(ui, pointer, axis);
+            }
+
+            // NOTE: we use `response.contains_pointer` here instead of `hovered`, because
+            // `hovered` is always false when another widget is being dragged.
+            if response.contains_pointer() {
+                let insert_index = item_rects
+                    .iter()
+                    .filter(|rect| rect.center()[axis] < pointer[axis])
+                    .count();
+
+                paint_insertion_line(ui, list_rect, &item_rects, insert_index, axis);
+
+                if ui.input(|i| i.pointer.any_released()) {
+                    DragAndDrop::clear_payload(ui.ctx());
+
+                    let from = dragged.from;
+                    let mut to = ListLocation {
+                        list: id,
+                        index: insert_index,
+                    };
+                    if from.list == id && from.index < to.index {
+                        // The item will be removed from before the insertion point:
+                        to.index -= 1;
+                    }
+                    if from != to {
+                        moved = Some(ListMove { from, to });
+                    }
+                }
+            }
+        }
+
+        ReorderableListResponse {
+            id,
+            response,
+            moved,
+        }
+    }
+}
+
+/// Show where a dropped item would end up.
+fn paint_insertion_line(
+    ui: &Ui,
+    list_rect: Rect,
+    item_rects: &[Rect],
+    insert_index: usize,
+    axis: usize,
+) {
+    let spacing = ui.spacing().item_spacing[axis];
+    let pos = match (
+        insert_index.checked_sub(1).and_then(|i| item_rects.get(i)),
+        item_rects.get(insert_index),
+    ) {
+        (Some(before), Some(after)) => 0.5 * (before.max[axis] + after.min[axis]),
+        (Some(before), None) => before.max[axis] + 0.5 * spacing,
+        (None, Some(after)) => after.min[axis] - 0.5 * spacing,
+        (None, None) => list_rect.min[axis],
+    };
+
+    let stroke = Stroke::new(2.0, ui.visuals().selection.stroke.color);
+    let cross_range = if axis == 0 {
+        list_rect.y_range()
+    } else {
+        list_rect.x_range()
+    };
+    let painter = ui.painter();
+    if axis == 0 {
+        painter.vline(pos, cross_range, stroke);
+    } else {
+        painter.hline(cross_range, pos, stroke);
+    }
+}
+
+/// Scroll the enclosing [`crate::ScrollArea`] if the pointer is close to the edge of the visible area.
+fn scroll_near_edge(ui: &Ui, pointer: Pos2, axis: usize) {
+    let visible = ui.clip_rect();
+    let visible = Rangef::new(visible.min[axis], visible.max[axis]);
+    if visible.span() < 3.0 * AUTO_SCROLL_MARGIN {
+        return;
+    }
+
+    // How far into the margin the pointer is, in 0-1:
+    let closeness = |distance: f32| (1.0 - distance / AUTO_SCROLL_MARGIN).clamp(0.0, 1.0);
+    let towards_start = closeness(pointer[axis] - visible.min);
+    let towards_end = closeness(visible.max - pointer[axis]);
+
+    let speed = AUTO_SCROLL_SPEED * (towards_start - towards_end);
+    if speed != 0.0 {
+        let mut delta = Vec2::ZERO;
+        delta[axis] = speed * ui.input(|i| i.stable_dt).min(0.1);
+        ui.scroll_with_delta_animation(delta, ScrollAnimation::none());
+        ui.ctx().request_repaint();
+    }
+}
@@ -403,10 +403,7 @@ pub fn paint_resize_corner_with_style(
         .pos_in_rect(rect)
         .round_to_pixels(ui.pixels_per_point());
     let mut w = 2.0;
-    let stroke = Stroke {
-        width: 1.0, // Set width to 1.0 to prevent overlapping
-        color: color.into(),
-    };
+    let stroke = Stroke::new(1.0, color); // Set width to 1.0 to prevent overlapping
 
     while w <= rect.width() && w <= rect.height() {
         painter.line_segment(
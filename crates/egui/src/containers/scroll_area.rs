@@ -8,8 +8,9 @@ use emath::GuiRounding as _;
 use epaint::Margin;
 
 use crate::{
-    Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Response, Sense, Ui, UiBuilder,
-    UiKind, UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap, remap_clamp,
+    Align, Context, CursorIcon, Id, NumExt as _, Pos2, Rangef, Rect, Response, Sense, Ui,
+    UiBuilder, UiKind, UiStackInfo, Vec2, Vec2b, emath, epaint, lerp, pass_state, pos2, remap,
+    remap_clamp, style::ScrollAnimation,
 };
 
 #[derive(Clone, Copy, Debug)]
@@ -52,6 +53,24 @@ pub struct State {
 
     /// Area that can be dragged. This is the size of the content from the last frame.
     interact_rect: Option<Rect>,
+
+    /// How far the content has been dragged past its edges, before applying the rubber band effect.
+    ///
+    /// Negative means past the start (top/left).
+    #[cfg_attr(feature = "serde", serde(skip))]
+    overscroll: Vec2,
+
+    /// The largest offset last frame, i.e. where the edges are.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    max_offset: Vec2,
+
+    /// The user scrolled, and we should snap once they stop.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    snap_pending: Vec2b,
+
+    /// When did the user last scroll?
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_user_scroll_time: f64,
 }
 
 impl Default for State {
@@ -66,6 +85,10 @@ impl Default for State {
             scroll_start_offset_from_top_left: [None; 2],
             scroll_stuck_to_end: Vec2b::TRUE,
             interact_rect: None,
+            overscroll: Vec2::ZERO,
+            max_offset: Vec2::ZERO,
+            snap_pending: Vec2b::FALSE,
+            last_user_scroll_time: f64::NEG_INFINITY,
         }
     }
 }
@@ -83,6 +106,53 @@ impl State {
     pub fn velocity(&self) -> Vec2 {
         self.vel
     }
+
+    /// Scroll by `delta` along axis `d`, moving into the overscroll past the edges.
+    fn scroll_with_overscroll(&mut self, d: usize, delta: f32) {
+        let position = self.offset[d] + self.overscroll[d] + delta;
+        self.offset[d] = position.at_most(self.max_offset[d]).at_least(0.0);
+        self.overscroll[d] = position - self.offset[d];
+    }
+
+    /// Smoothly scroll to `target_offset` along axis `d`.
+    fn animate_to(&mut self, d: usize, target_offset: f32, now: f64, animation: ScrollAnimation) {
+        let distance = (target_offset - self.offset[d]).abs();
+        if distance < 0.5 {
+            self.offset[d] = target_offset;
+            return;
+        }
+        let animation_duration = (distance / animation.points_per_second)
+            .clamp(animation.duration.min, animation.duration.max);
+        self.offset_target[d] = Some(ScrollingToTarget {
+            animation_time_span: (now, now + animation_duration as f64),
+            target_offset,
+        });
+        self.vel[d] = 0.0;
+    }
+}
+
+/// Kinetic scrolling slows down by this many points per second squared.
+const KINETIC_FRICTION: f32 = 1000.0;
+
+/// How quickly overscroll springs back once released, per second.
+const OVERSCROLL_SPRING_RATE: f32 = 12.0;
+
+/// How quickly a fling that hits an edge slows down in the overscroll, per second.
+const OVERSCROLL_DECELERATION_RATE: f32 = 25.0;
+
+/// How long after the last mouse wheel scroll we wait before snapping, in seconds.
+const SNAP_DELAY: f64 = 0.15;
+
+/// The rubber band effect: the further you pull, the less the content moves.
+///
+/// The result approaches `limit` as `overscroll` grows.
+fn rubber_band(overscroll: f32, limit: f32) -> f32 {
+    if limit <= 0.0 {
+        return 0.0;
+    }
+    let coefficient = 0.55;
+    let pulled = overscroll.abs() / limit * coefficient;
+    overscroll.signum() * limit * (1.0 - 1.0 / (pulled + 1.0))
 }
 
 pub struct ScrollAreaOutput<R> {
@@ -101,6 +171,17 @@ pub struct ScrollAreaOutput<R> {
 
     /// Where on the screen the content is (excludes scroll bars).
     pub inner_rect: Rect,
+
+    /// How far the content is pulled past its edges, with the rubber band effect applied.
+    ///
+    /// Negative means the content is pulled past its start, e.g. down from the top.
+    /// Always zero unless [`ScrollArea::overscroll`] is enabled.
+    pub overscroll: Vec2,
+
+    /// The user let go after pulling the content down past the threshold of [`ScrollArea::pull_to_refresh`].
+    ///
+    /// This is only `true` for a single frame.
+    pub refresh_requested: bool,
 }
 
 /// Indicate whether the horizontal and vertical scroll bars must be always visible, hidden or visible when needed.
@@ -306,6 +387,10 @@ pub struct ScrollArea {
 
     /// If false, `scroll_to_*` functions will not be animated
     animated: bool,
+
+    snap: Option<ScrollSnap>,
+    overscroll: bool,
+    pull_to_refresh: Option<f32>,
 }
 
 impl ScrollArea {
@@ -354,6 +439,9 @@ impl ScrollArea {
             content_margin: None,
             stick_to_end: Vec2b::FALSE,
             animated: true,
+            snap: None,
+            overscroll: false,
+            pull_to_refresh: None,
         }
     }
 
@@ -634,6 +722,124 @@ impl ScrollArea {
         self.stick_to_end[1] = stick;
         self
     }
+
+    /// Once the user stops scrolling, smoothly scroll to the nearest snap point.
+    ///
+    /// A fling is snapped to the point nearest to where it would have come to rest.
+    /// The start and end of the content are always snap points.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::containers::scroll_area::ScrollSnap;
+    /// let row_height = ui.spacing().interact_size.y;
+    /// let row_height_with_spacing = row_height + ui.spacing().item_spacing.y;
+    /// egui::ScrollArea::vertical()
+    ///     .snap(ScrollSnap::Interval(row_height_with_spacing))
+    ///     .show_rows(ui, row_height, 1000, |ui, rows| {
+    ///         for row in rows {
+    ///             let _ = ui.button(format!("Row {row}"));
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn snap(mut self, snap: ScrollSnap) -> Self {
+        self.snap = Some(snap);
+        self
+    }
+
+    /// Allow dragging the content past its edges, with a rubber band effect,
+    /// springing back when released.
+    ///
+    /// This also allows dragging content that fits in the scroll area.
+    /// Only applies to dragging the content (see [`ScrollSource::drag`]), e.g. on touch screens.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn overscroll(mut self, overscroll: bool) -> Self {
+        self.overscroll = overscroll;
+        self
+    }
+
+    /// Report [`ScrollAreaOutput::refresh_requested`] when the user pulls the content down
+    /// at least `threshold` points past the top, and then lets go.
+    ///
+    /// How far the content is pulled is in [`ScrollAreaOutput::overscroll`],
+    /// which you can use to show an indicator.
+    ///
+    /// This enables [`Self::overscroll`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let output = egui::ScrollArea::vertical()
+    ///     .pull_to_refresh(60.0)
+    ///     .show(ui, |ui| {
+    ///         ui.label("Pull me down");
+    ///     });
+    /// if output.refresh_requested {
+    ///     // Reload the contents
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn pull_to_refresh(mut self, threshold: f32) -> Self {
+        self.overscroll = true;
+        self.pull_to_refresh = Some(threshold);
+        self
+    }
+}
+
+/// Where a [`ScrollArea`] comes to rest, see [`ScrollArea::snap`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ScrollSnap {
+    /// Snap to multiples of this many points, e.g. the row height (including spacing)
+    /// when using [`ScrollArea::show_rows`].
+    Interval(f32),
+
+    /// Snap to these offsets, in points from the start of the content.
+    Offsets(Vec<f32>),
+
+    /// Snap to the widgets marked with [`Response::scroll_snap_target`],
+    /// aligning them with the start, center, or end of the scroll area.
+    Children(Align),
+}
+
+impl ScrollSnap {
+    /// The snap point nearest to `offset` along axis `d`, within `0..=max_offset`.
+    ///
+    /// `child_rects` are relative to the content.
+    fn nearest(
+        &self,
+        d: usize,
+        offset: f32,
+        max_offset: f32,
+        viewport_size: f32,
+        child_rects: &[Rect],
+    ) -> f32 {
+        let max_offset = max_offset.at_least(0.0);
+        let nearest = |points: &mut dyn Iterator<Item = f32>| {
+            points
+                .chain([0.0, max_offset])
+                .map(|point| point.clamp(0.0, max_offset))
+                .min_by(|a, b| (a - offset).abs().total_cmp(&(b - offset).abs()))
+                .unwrap_or(offset)
+        };
+        match self {
+            Self::Interval(interval) => {
+                if *interval <= 0.0 {
+                    return offset;
+                }
+                let below = (offset / interval).floor() * interval;
+                nearest(&mut [below, below + interval].into_iter())
+            }
+            Self::Offsets(offsets) => nearest(&mut offsets.iter().copied()),
+            Self::Children(align) => nearest(&mut child_rects.iter().map(|rect| {
+                let range = Rangef::new(rect.min[d], rect.max[d]);
+                let factor = align.to_factor();
+                lerp(range, factor) - factor * viewport_size
+            })),
+        }
+    }
 }
 
 struct Prepared {
@@ -683,6 +889,16 @@ struct Prepared {
     background_drag_response: Option<Response>,
 
     animated: bool,
+
+    snap: Option<ScrollSnap>,
+
+    /// Snap targets meant for [`ScrollArea`]s up the stack, restored when we are done.
+    saved_scroll_snap_rects: Vec<Rect>,
+
+    /// How far the content is pulled past its edges, after the rubber band effect.
+    overscroll: Vec2,
+
+    refresh_requested: bool,
 }
 
 impl ScrollArea {
@@ -704,6 +920,9 @@ impl ScrollArea {
             content_margin: _, // Used elsewhere
             stick_to_end,
             animated,
+            snap,
+            overscroll,
+            pull_to_refresh,
         } = self;
 
         let ctx = ui.ctx().clone();
@@ -719,6 +938,9 @@ impl ScrollArea {
 
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
+        if !overscroll {
+            state.overscroll = Vec2::ZERO;
+        }
 
         let show_bars: Vec2b = match scroll_bar_visibility {
             ScrollBarVisibility::AlwaysHidden => Vec2b::FALSE,
@@ -768,7 +990,16 @@ impl ScrollArea {
             }
         }
 
-        let content_max_rect = Rect::from_min_size(inner_rect.min - state.offset, content_max_size);
+        // The content moves less than the user drags past the edges:
+        let visual_overscroll = Vec2::new(
+            rubber_band(state.overscroll.x, inner_size.x),
+            rubber_band(state.overscroll.y, inner_size.y),
+        );
+
+        let content_max_rect = Rect::from_min_size(
+            inner_rect.min - state.offset - visual_overscroll,
+            content_max_size,
+        );
 
         // Round to pixels to avoid widgets appearing to "float" when scrolling fractional amounts:
         let content_max_rect = content_max_rect
@@ -799,75 +1030,103 @@ impl ScrollArea {
             content_ui.set_clip_rect(content_clip_rect);
         }
 
-        let viewport = Rect::from_min_size(Pos2::ZERO + state.offset, inner_size);
+        let viewport =
+            Rect::from_min_size(Pos2::ZERO + state.offset + visual_overscroll, inner_size);
         let dt = ui.input(|i| i.stable_dt).at_most(0.1);
+        let mut refresh_requested = false;
 
-        let background_drag_response =
-            if scroll_source.drag && ui.is_enabled() && state.content_is_too_large.any() {
-                // Drag contents to scroll (for touch screens mostly).
-                // We must do this BEFORE adding content to the `ScrollArea`,
-                // or we will steal input from the widgets we contain.
-                let content_response_option = state
-                    .interact_rect
-                    .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
-
+        let background_drag_response = if scroll_source.drag
+            && ui.is_enabled()
+            && (state.content_is_too_large.any() || overscroll)
+        {
+            // Drag contents to scroll (for touch screens mostly).
+            // We must do this BEFORE adding content to the `ScrollArea`,
+            // or we will steal input from the widgets we contain.
+            let content_response_option = state
+                .interact_rect
+                .map(|rect| ui.interact(rect, id.with("area"), Sense::drag()));
+
+            if content_response_option
+                .as_ref()
+                .is_some_and(|response| response.dragged())
+            {
+                for d in 0..2 {
+                    if direction_enabled[d] {
+                        let delta = ui.input(|input| input.pointer.delta()[d]);
+                        if overscroll {
+                            state.scroll_with_overscroll(d, -delta);
+                        } else {
+                            state.offset[d] -= delta;
+                        }
+                        state.scroll_stuck_to_end[d] = false;
+                        state.offset_target[d] = None;
+                    }
+                }
+            } else {
+                // Apply the cursor velocity to the scroll area when the user releases the drag.
                 if content_response_option
                     .as_ref()
-                    .is_some_and(|response| response.dragged())
+                    .is_some_and(|response| response.drag_stopped())
                 {
-                    for d in 0..2 {
-                        if direction_enabled[d] {
-                            ui.input(|input| {
-                                state.offset[d] -= input.pointer.delta()[d];
-                            });
-                            state.scroll_stuck_to_end[d] = false;
-                            state.offset_target[d] = None;
-                        }
-                    }
-                } else {
-                    // Apply the cursor velocity to the scroll area when the user releases the drag.
-                    if content_response_option
-                        .as_ref()
-                        .is_some_and(|response| response.drag_stopped())
-                    {
-                        state.vel = direction_enabled.to_vec2()
-                            * ui.input(|input| input.pointer.velocity());
+                    state.vel =
+                        direction_enabled.to_vec2() * ui.input(|input| input.pointer.velocity());
+
+                    if let Some(threshold) = pull_to_refresh {
+                        refresh_requested = direction_enabled[1]
+                            && rubber_band(state.overscroll.y, inner_size.y) <= -threshold;
                     }
-                    for d in 0..2 {
-                        // Kinetic scrolling
-                        let stop_speed = 20.0; // Pixels per second.
-                        let friction_coeff = 1000.0; // Pixels per second squared.
-
-                        let friction = friction_coeff * dt;
-                        if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
-                            state.vel[d] = 0.0;
+                }
+                for d in 0..2 {
+                    // Kinetic scrolling
+                    let stop_speed = 20.0; // Pixels per second.
+
+                    let friction = KINETIC_FRICTION * dt;
+                    if friction > state.vel[d].abs() || state.vel[d].abs() < stop_speed {
+                        state.vel[d] = 0.0;
+                    } else {
+                        state.vel[d] -= friction * state.vel[d].signum();
+                        // Offset has an inverted coordinate system compared to
+                        // the velocity, so we subtract it instead of adding it
+                        if overscroll {
+                            state.scroll_with_overscroll(d, -state.vel[d] * dt);
+                            if state.overscroll[d] != 0.0 {
+                                // Hit the edge: slow down quickly, then spring back.
+                                state.vel[d] *= (-OVERSCROLL_DECELERATION_RATE * dt).exp();
+                            }
                         } else {
-                            state.vel[d] -= friction * state.vel[d].signum();
-                            // Offset has an inverted coordinate system compared to
-                            // the velocity, so we subtract it instead of adding it
                             state.offset[d] -= state.vel[d] * dt;
-                            ctx.request_repaint();
                         }
+                        ctx.request_repaint();
                     }
-                }
 
-                // Set the desired mouse cursors.
-                if let Some(response) = &content_response_option {
-                    if response.dragged()
-                        && let Some(cursor) = on_drag_cursor
-                    {
-                        ui.ctx().set_cursor_icon(cursor);
-                    } else if response.hovered()
-                        && let Some(cursor) = on_hover_cursor
-                    {
-                        ui.ctx().set_cursor_icon(cursor);
+                    // Spring back from the overscroll:
+                    if state.vel[d] == 0.0 && state.overscroll[d] != 0.0 {
+                        state.overscroll[d] *= (-OVERSCROLL_SPRING_RATE * dt).exp();
+                        if state.overscroll[d].abs() < 0.5 {
+                            state.overscroll[d] = 0.0;
+                        }
+                        ctx.request_repaint();
                     }
                 }
+            }
 
-                content_response_option
-            } else {
-                None
-            };
+            // Set the desired mouse cursors.
+            if let Some(response) = &content_response_option {
+                if response.dragged()
+                    && let Some(cursor) = on_drag_cursor
+                {
+                    ui.ctx().set_cursor_icon(cursor);
+                } else if response.hovered()
+                    && let Some(cursor) = on_hover_cursor
+                {
+                    ui.ctx().set_cursor_icon(cursor);
+                }
+            }
+
+            content_response_option
+        } else {
+            None
+        };
 
         // Scroll with an animation if we have a target offset (that hasn't been cleared by the code
         // above).
@@ -900,9 +1159,13 @@ impl ScrollArea {
             }
         }
 
-        let saved_scroll_target = content_ui
-            .ctx()
-            .pass_state_mut(|state| std::mem::take(&mut state.scroll_target));
+        let (saved_scroll_target, saved_scroll_snap_rects) =
+            content_ui.ctx().pass_state_mut(|state| {
+                (
+                    std::mem::take(&mut state.scroll_target),
+                    std::mem::take(&mut state.scroll_snap_rects),
+                )
+            });
 
         Prepared {
             id,
@@ -922,6 +1185,10 @@ impl ScrollArea {
             saved_scroll_target,
             background_drag_response,
             animated,
+            snap,
+            saved_scroll_snap_rects,
+            overscroll: visual_overscroll,
+            refresh_requested,
         }
     }
 
@@ -1010,6 +1277,9 @@ impl ScrollArea {
         let id = prepared.id;
         let inner_rect = prepared.inner_rect;
 
+        let overscroll = prepared.overscroll;
+        let refresh_requested = prepared.refresh_requested;
+
         let inner = crate::Frame::NONE
             .inner_margin(margin)
             .show(&mut prepared.content_ui, |ui| {
@@ -1024,6 +1294,8 @@ impl ScrollArea {
             state,
             content_size,
             inner_rect,
+            overscroll,
+            refresh_requested,
         }
     }
 }
@@ -1049,6 +1321,10 @@ impl Prepared {
             saved_scroll_target,
             background_drag_response,
             animated,
+            snap,
+            saved_scroll_snap_rects,
+            overscroll: _,
+            refresh_requested: _,
         } = self;
 
         let content_size = content_ui.min_size();
@@ -1131,12 +1407,13 @@ impl Prepared {
         }
 
         // Restore scroll target meant for ScrollAreas up the stack (if any)
-        ui.ctx().pass_state_mut(|state| {
+        let snap_rects = ui.ctx().pass_state_mut(|state| {
             for d in 0..2 {
                 if saved_scroll_target[d].is_some() {
                     state.scroll_target[d] = saved_scroll_target[d].clone();
                 }
             }
+            std::mem::replace(&mut state.scroll_snap_rects, saved_scroll_snap_rects)
         });
 
         let inner_rect = {
@@ -1173,6 +1450,9 @@ impl Prepared {
             && ui.ctx().dragged_id().is_none()
             || is_dragging_background;
 
+        // Did the user scroll this frame? Used for snapping.
+        let mut user_scrolled = Vec2b::new(is_dragging_background, is_dragging_background);
+
         if scroll_source.mouse_wheel && ui.is_enabled() && is_hovering_outer_rect {
             let always_scroll_enabled_direction = ui.style().always_scroll_the_only_direction
                 && direction_enabled[0] != direction_enabled[1];
@@ -1206,6 +1486,7 @@ impl Prepared {
 
                         state.scroll_stuck_to_end[d] = false;
                         state.offset_target[d] = None;
+                        user_scrolled[d] = true;
                     }
                 }
             }
@@ -1381,6 +1662,7 @@ impl Prepared {
                 // some manual action taken, scroll not stuck
                 state.scroll_stuck_to_end[d] = false;
                 state.offset_target[d] = None;
+                user_scrolled[d] = true;
             } else {
                 state.scroll_start_offset_from_top_left[d] = None;
             }
@@ -1481,6 +1763,56 @@ impl Prepared {
         let available_offset = content_size - inner_rect.size();
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
+        state.max_offset = available_offset;
+
+        if let Some(snap) = &snap {
+            let now = ui.input(|i| i.time);
+            let animation = ui.style().scroll_animation;
+            // Relative to the start of the content:
+            let content_origin = content_ui.min_rect().min.to_vec2();
+            let snap_rects: Vec<Rect> = snap_rects
+                .iter()
+                .map(|rect| rect.translate(-content_origin))
+                .collect();
+            for d in 0..2 {
+                if !direction_enabled[d] || state.offset_target[d].is_some() {
+                    continue;
+                }
+                let nearest = |offset: f32| {
+                    snap.nearest(
+                        d,
+                        offset,
+                        available_offset[d],
+                        inner_rect.size()[d],
+                        &snap_rects,
+                    )
+                };
+
+                if user_scrolled[d] {
+                    state.snap_pending[d] = true;
+                    state.last_user_scroll_time = now;
+                } else if state.overscroll[d] != 0.0 {
+                    // Wait for the spring back.
+                } else if state.vel[d] != 0.0 {
+                    // A fling: snap to where it would have come to rest.
+                    let rest_offset = state.offset[d]
+                        - state.vel[d] * state.vel[d].abs() / (2.0 * KINETIC_FRICTION);
+                    state.animate_to(d, nearest(rest_offset), now, animation);
+                    state.snap_pending[d] = false;
+                    ui.ctx().request_repaint();
+                } else if state.snap_pending[d] {
+                    let idle_time = now - state.last_user_scroll_time;
+                    if idle_time < SNAP_DELAY {
+                        ui.ctx()
+                            .request_repaint_after_secs((SNAP_DELAY - idle_time) as f32);
+                    } else {
+                        state.animate_to(d, nearest(state.offset[d]), now, animation);
+                        state.snap_pending[d] = false;
+                        ui.ctx().request_repaint();
+                    }
+                }
+            }
+        }
 
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
@@ -0,0 +1,470 @@
+//! A container dividing a [`Ui`] into resizable panes. See [`Splitter`].
+
+use emath::GuiRounding as _;
+
+use crate::{
+    Context, CursorIcon, Id, InnerResponse, NumExt as _, Rangef, Rect, Sense, Stroke, Ui,
+    UiBuilder, WidgetInfo, WidgetType,
+};
+
+/// The persisted state of a [`Splitter`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SplitterState {
+    /// The fraction of the available space given to each pane, summing up to `1.0`.
+    pub ratios: Vec<f32>,
+
+    /// Which panes are collapsed to zero size.
+    pub collapsed: Vec<bool>,
+
+    /// The size of the pane before the dragged divider, when the drag started.
+    ///
+    /// The divider follows the total drag delta from there,
+    /// so that a collapsed pane notices when it is dragged back out.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    drag_start_size: Option<f32>,
+}
+
+impl SplitterState {
+    /// Load the state of the [`Splitter`] with the given id from memory.
+    pub fn load(ctx: &Context, id: Id) -> Option<Self> {
+        ctx.data_mut(|d| d.get_persisted(id))
+    }
+
+    /// Store the state in memory, to be picked up by the [`Splitter`] with the given id next frame.
+    pub fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_persisted(id, self));
+    }
+
+    /// Is the pane with the given index collapsed to zero size?
+    pub fn is_collapsed(&self, pane: usize) -> bool {
+        self.collapsed.get(pane).copied().unwrap_or(false)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Size constraints of a single pane of a [`Splitter`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SplitterPane {
+    weight: f32,
+    size_range: Rangef,
+    collapsible: bool,
+}
+
+impl Default for SplitterPane {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl SplitterPane {
+    /// A pane which will initially get a share of the space proportional to `weight`.
+    ///
+    /// Double-clicking a divider resets the panes on both sides of it to these proportions.
+    #[inline]
+    pub fn new(weight: f32) -> Self {
+        Self {
+            weight: weight.at_least(0.0),
+            size_range: Rangef::new(0.0, f32::INFINITY),
+            collapsible: false,
+        }
+    }
+
+    /// The pane will never be made smaller than this (unless collapsed).
+    #[inline]
+    pub fn min_size(mut self, min_size: f32) -> Self {
+        self.size_range.min = min_size.at_least(0.0);
+        self.size_range.max = self.size_range.max.at_least(self.size_range.min);
+        self
+    }
+
+    /// The pane will never be made larger than this.
+    #[inline]
+    pub fn max_size(mut self, max_size: f32) -> Self {
+        self.size_range.max = max_size.at_least(self.size_range.min);
+        self
+    }
+
+    /// If `true`, dragging a divider past half of [`Self::min_size`] will collapse the pane to zero size.
+    ///
+    /// Dragging the divider back out again restores it.
+    #[inline]
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Divides a [`Ui`] into several panes separated by draggable dividers.
+///
+/// Unlike [`crate::Panel`], a [`Splitter`] can be used anywhere, and can have any number of panes.
+/// The panes take up all available space, and the ratio between them is persisted.
+///
+/// Double-click a divider to reset the panes next to it to their initial proportions.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui::containers::{Splitter, SplitterPane};
+/// Splitter::horizontal("my_splitter")
+///     .pane(SplitterPane::new(1.0).min_size(50.0).collapsible(true))
+///     .pane(SplitterPane::new(2.0).min_size(100.0))
+///     .show(ui, |index, ui| {
+///         ui.label(format!("Pane {index}"));
+///     });
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Clone, Debug)]
+pub struct Splitter {
+    id_salt: Id,
+    horizontal: bool,
+    panes: Vec<SplitterPane>,
+    divider_width: Option<f32>,
+}
+
+impl Splitter {
+    /// The panes will be laid out left-to-right, separated by vertical dividers.
+    pub fn horizontal(id_salt: impl std::hash::Hash) -> Self {
+        Self::new(id_salt, true)
+    }
+
+    /// The panes will be laid out top-down, separated by horizontal dividers.
+    pub fn vertical(id_salt: impl std::hash::Hash) -> Self {
+        Self::new(id_salt, false)
+    }
+
+    fn new(id_salt: impl std::hash::Hash, horizontal: bool) -> Self {
+        Self {
+            id_salt: Id::new(id_salt),
+            horizontal,
+            panes: Vec::new(),
+            divider_width: None,
+        }
+    }
+
+    /// Add a pane.
+    #[inline]
+    pub fn pane(mut self, pane: SplitterPane) -> Self {
+        self.panes.push(pane);
+        self
+    }
+
+    /// Add `count` panes with the same constraints.
+    #[inline]
+    pub fn panes(mut self, pane: SplitterPane, count: usize) -> Self {
+        self.panes.extend(std::iter::repeat_n(pane, count));
+        self
+    }
+
+    /// The space between two panes.
+    ///
+    /// Default: [`crate::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn divider_width(mut self, divider_width: f32) -> Self {
+        self.divider_width = Some(divider_width);
+        self
+    }
+}
+
+impl Splitter {
+    /// Show the panes. `add_contents` is called once for each pane, with its index.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnMut(usize, &mut Ui) -> R,
+    ) -> InnerResponse<Vec<R>> {
+        self.show_dyn(ui, Box::new(add_contents))
+    }
+
+    fn show_dyn<'c, R>(
+        self,
+        ui: &mut Ui,
+        mut add_contents: Box<dyn FnMut(usize, &mut Ui) -> R + 'c>,
+    ) -> InnerResponse<Vec<R>> {
+        let Self {
+            id_salt,
+            horizontal,
+            panes,
+            divider_width,
+        } = self;
+
+        let id = ui.make_persistent_id(id_salt);
+        let num_panes = panes.len();
+        let divider_width = divider_width.unwrap_or_else(|| {
+            if horizontal {
+                ui.spacing().item_spacing.x
+            } else {
+                ui.spacing().item_spacing.y
+            }
+        });
+
+        let rect = ui.available_rect_before_wrap();
+        let (along, across) = if horizontal {
+            (rect.x_range(), rect.y_range())
+        } else {
+            (rect.y_range(), rect.x_range())
+        };
+        let total =
+            (along.span() - num_panes.saturating_sub(1) as f32 * divider_width).at_least(0.0);
+
+        let default_ratios = default_ratios(&panes);
+        let mut state = SplitterState::load(ui.ctx(), id)
+            .filter(|state| state.ratios.len() == num_panes)
+            .unwrap_or_else(|| SplitterState {
+                ratios: default_ratios.clone(),
+                collapsed: vec![false; num_panes],
+                drag_start_size: None,
+            });
+        state.collapsed.resize(num_panes, false);
+
+        // Apply any ongoing drag before laying out the panes, to avoid a frame of latency:
+        let total_drag_delta = ui.input(|i| i.pointer.total_drag_delta());
+        let mut any_dragged = false;
+        for divider in 0..num_panes.saturating_sub(1) {
+            let divider_id = id.with(("divider", divider));
+            let Some(response) = ui.ctx().read_response(divider_id) else {
+                continue;
+            };
+            if response.double_clicked() {
+                reset_divider(&mut state, &default_ratios, divider);
+            } else if response.dragged() {
+                any_dragged = true;
+                if let Some(delta) = total_drag_delta {
+                    let delta = if horizontal { delta.x } else { delta.y };
+                    drag_divider(&mut state, &panes, total, divider, delta);
+                }
+            }
+        }
+        if !any_dragged {
+            state.drag_start_size = None;
+        }
+
+        let sizes = pane_sizes(&state, &panes, total);
+
+        let mut inner = Vec::with_capacity(num_panes);
+        let mut dividers = Vec::with_capacity(num_panes.saturating_sub(1));
+        let mut pos = along.min;
+        for (index, size) in sizes.iter().enumerate() {
+            let pane_range = Rangef::new(pos, pos + size);
+            let pane_rect = if horizontal {
+                Rect::from_x_y_ranges(pane_range, across)
+            } else {
+                Rect::from_x_y_ranges(across, pane_range)
+            }
+            .round_ui();
+
+            let mut pane_ui = ui.new_child(
+                UiBuilder::new()
+                    .id_salt(("pane", index))
+                    .max_rect(pane_rect)
+                    .layout(*ui.layout()),
+            );
+            pane_ui.set_clip_rect(pane_ui.clip_rect().intersect(pane_rect));
+            if state.is_collapsed(index) || *size <= 0.0 {
+                pane_ui.set_invisible();
+            }
+            inner.push(add_contents(index, &mut pane_ui));
+
+            pos += size;
+            if index + 1 < num_panes {
+                dividers.push(Rangef::new(pos, pos + divider_width));
+                pos += divider_width;
+            }
+        }
+
+        // Interact with the dividers on top of the contents,
+        // so that e.g. a `ScrollArea` in a pane doesn't eat the input.
+        let grab_radius = ui.style().interaction.resize_grab_radius_side;
+        for (divider, range) in dividers.into_iter().enumerate() {
+            let divider_id = id.with(("divider", divider));
+            let (interact_rect, line_rect) = if horizontal {
+                (
+                    Rect::from_x_y_ranges(range.expand(grab_radius), across),
+                    Rect::from_x_y_ranges(range, across),
+                )
+            } else {
+                (
+                    Rect::from_x_y_ranges(across, range.expand(grab_radius)),
+                    Rect::from_x_y_ranges(across, range),
+                )
+            };
+            let response = ui.interact(interact_rect, divider_id, Sense::click_and_drag());
+
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(if horizontal {
+                    CursorIcon::ResizeHorizontal
+                } else {
+                    CursorIcon::ResizeVertical
+                });
+            }
+
+            let stroke = if response.dragged() {
+                ui.style().visuals.widgets.active.fg_stroke // highly visible
+            } else if response.hovered() {
+                ui.style().visuals.widgets.hovered.fg_stroke // highly visible
+            } else {
+                ui.style().visuals.widgets.noninteractive.bg_stroke // dim
+            };
+            paint_divider(ui, line_rect, horizontal, stroke);
+        }
+
+        state.store(ui.ctx(), id);
+
+        let response = ui.allocate_rect(rect, Sense::hover());
+        response.widget_info(|| WidgetInfo::new(WidgetType::Panel));
+        InnerResponse::new(inner, response)
+    }
+}
+
+fn paint_divider(ui: &Ui, line_rect: Rect, horizontal: bool, stroke: Stroke) {
+    let center = line_rect.center();
+    if horizontal {
+        ui.painter().vline(center.x, line_rect.y_range(), stroke);
+    } else {
+        ui.painter().hline(line_rect.x_range(), center.y, stroke);
+    }
+}
+
+fn default_ratios(panes: &[SplitterPane]) -> Vec<f32> {
+    let total_weight: f32 = panes.iter().map(|pane| pane.weight).sum();
+    if total_weight <= 0.0 {
+        vec![1.0 / panes.len().at_least(1) as f32; panes.len()]
+    } else {
+        panes
+            .iter()
+            .map(|pane| pane.weight / total_weight)
+            .collect()
+    }
+}
+
+/// Reset the two panes next to `divider` to their initial proportions,
+/// keeping their combined share of the space.
+fn reset_divider(state: &mut SplitterState, default_ratios: &[f32], divider: usize) {
+    let (a, b) = (divider, divider + 1);
+    let combined = state.ratios[a] + state.ratios[b];
+    let default_combined = default_ratios[a] + default_ratios[b];
+    if 0.0 < default_combined {
+        state.ratios[a] = combined * default_ratios[a] / default_combined;
+        state.ratios[b] = combined * default_ratios[b] / default_combined;
+    }
+    state.collapsed[a] = false;
+    state.collapsed[b] = false;
+}
+
+/// Move the divider between pane `divider` and `divider + 1`
+/// to `total_drag_delta` points from where it was when the drag started.
+fn drag_divider(
+    state: &mut SplitterState,
+    panes: &[SplitterPane],
+    total: f32,
+    divider: usize,
+    total_drag_delta: f32,
+) {
+    if total <= 0.0 {
+        return;
+    }
+
+    let (a, b) = (divider, divider + 1);
+    let sizes = pane_sizes(state, panes, total);
+    let combined = sizes[a] + sizes[b];
+
+    // Where the divider wants to be, measured from the start of pane `a`:
+    let mut size_a = *state.drag_start_size.get_or_insert(sizes[a]) + total_drag_delta;
+
+    // Collapsed panes re-open once dragged past half their min size:
+    if state.collapsed[a] && 0.5 * panes[a].size_range.min <= size_a {
+        state.collapsed[a] = false;
+    }
+    if state.collapsed[b] && 0.5 * panes[b].size_range.min <= combined - size_a {
+        state.collapsed[b] = false;
+    }
+
+    let collapse_threshold = |pane: usize| {
+        let pane = &panes[pane];
+        pane.collapsible.then_some(0.5 * pane.size_range.min)
+    };
+
+    if let Some(threshold) = collapse_threshold(a)
+        && size_a < threshold
+    {
+        state.collapsed[a] = true;
+        size_a = 0.0;
+    } else if let Some(threshold) = collapse_threshold(b)
+        && combined - size_a < threshold
+    {
+        state.collapsed[b] = true;
+        size_a = combined;
+    } else {
+        let range_a = if state.collapsed[a] {
+            Rangef::point(0.0)
+        } else {
+            panes[a].size_range
+        };
+        let range_b = if state.collapsed[b] {
+            Rangef::point(0.0)
+        } else {
+            panes[b].size_range
+        };
+        let min_a = range_a.min.max(combined - range_b.max);
+        let max_a = range_a.max.min(combined - range_b.min);
+        if min_a <= max_a {
+            size_a = size_a.clamp(min_a, max_a);
+        } else {
+            size_a = sizes[a];
+        }
+    }
+
+    state.ratios[a] = size_a / total;
+    state.ratios[b] = (combined - size_a) / total;
+}
+
+/// The size of each pane, respecting the min/max sizes of the panes.
+fn pane_sizes(state: &SplitterState, panes: &[SplitterPane], total: f32) -> Vec<f32> {
+    let ranges: Vec<Rangef> = panes
+        .iter()
+        .enumerate()
+        .map(|(index, pane)| {
+            if state.is_collapsed(index) {
+                Rangef::point(0.0)
+            } else {
+                pane.size_range
+            }
+        })
+        .collect();
+
+    let mut sizes: Vec<f32> = state
+        .ratios
+        .iter()
+        .zip(&ranges)
+        .map(|(ratio, range)| (ratio * total).clamp(range.min, range.max))
+        .collect();
+
+    // Give any leftover (or missing) space to the panes that can still grow (or shrink):
+    for _ in 0..panes.len() {
+        let leftover = total - sizes.iter().sum::<f32>();
+        if leftover.abs() < 0.5 {
+            break;
+        }
+        let adjustable: Vec<usize> = (0..sizes.len())
+            .filter(|&i| {
+                if 0.0 < leftover {
+                    sizes[i] < ranges[i].max
+                } else {
+                    ranges[i].min < sizes[i]
+                }
+            })
+            .collect();
+        if adjustable.is_empty() {
+            break;
+        }
+        let share = leftover / adjustable.len() as f32;
+        for i in adjustable {
+            sizes[i] = (sizes[i] + share).clamp(ranges[i].min, ranges[i].max);
+        }
+    }
+
+    sizes
+}
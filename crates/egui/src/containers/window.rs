@@ -9,7 +9,7 @@ use crate::collapsing_header::CollapsingState;
 use crate::*;
 
 use super::scroll_area::{ScrollBarVisibility, ScrollSource};
-use super::{Area, Frame, Resize, ScrollArea, area, resize};
+use super::{Area, Frame, Resize, ScrollArea, area, resize, window_layout};
 
 /// Builder for a floating window which can be dragged, closed, collapsed, resized and scrolled (off by default).
 ///
@@ -19,6 +19,7 @@ use super::{Area, Frame, Resize, ScrollArea, area, resize};
 /// * if the window has a scroll area (off by default)
 /// * if the window can be collapsed (minimized) to just the title bar (yes, by default)
 /// * if there should be a close button (none by default)
+/// * if the window snaps to edges, can be tiled, or minimized to a [`crate::WindowTray`] (no, by default)
 ///
 /// ```
 /// # egui::__run_test_ctx(|ctx| {
@@ -44,6 +45,9 @@ pub struct Window<'open> {
     default_open: bool,
     with_title_bar: bool,
     fade_out: bool,
+    snap: bool,
+    tiling: bool,
+    minimizable: bool,
 }
 
 impl<'open> Window<'open> {
@@ -52,7 +56,9 @@ impl<'open> Window<'open> {
     /// If you need a changing title, you must call `window.id(…)` with a fixed id.
     pub fn new(title: impl Into<WidgetText>) -> Self {
         let title = title.into().fallback_text_style(TextStyle::Heading);
-        let area = Area::new(Id::new(title.text())).kind(UiKind::Window);
+        let area = Area::new(Id::new(title.text()))
+            .kind(UiKind::Window)
+            .focus_scope(FocusScope::default());
         Self {
             title,
             open: None,
@@ -67,6 +73,9 @@ impl<'open> Window<'open> {
             default_open: true,
             with_title_bar: true,
             fade_out: true,
+            snap: false,
+            tiling: false,
+            minimizable: false,
         }
     }
 
@@ -106,6 +115,17 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Set how the window treats keyboard focus, or `None` to not make it a focus scope.
+    ///
+    /// Use [`FocusScope::TRAP`] to keep Tab-navigation inside the window.
+    ///
+    /// Default: [`FocusScope::default`], which restores focus when the window closes.
+    #[inline]
+    pub fn focus_scope(mut self, focus_scope: impl Into<Option<FocusScope>>) -> Self {
+        self.area = self.area.focus_scope(focus_scope);
+        self
+    }
+
     /// If `false` the window will be immovable.
     #[inline]
     pub fn movable(mut self, movable: bool) -> Self {
@@ -349,6 +369,42 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// While dragged, snap the window to nearby edges of the screen, the central area and other windows?
+    ///
+    /// Guides are shown for the edges the window snapped to.
+    /// The snap distance is set by [`crate::style::Interaction::window_snap_distance`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn snap(mut self, snap: bool) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    /// Tile the window to half or a quarter of the central area ([`Context::available_rect`])
+    /// when it is dropped at one of its edges or corners?
+    ///
+    /// A preview of the tile is shown while dragging.
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn tiling(mut self, tiling: bool) -> Self {
+        self.tiling = tiling;
+        self
+    }
+
+    /// Add a minimize-button to the title bar?
+    ///
+    /// A minimized window is hidden until it is restored,
+    /// e.g. from a [`crate::WindowTray`] or with [`crate::window_layout::restore`].
+    ///
+    /// Default: `false`.
+    #[inline]
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
     /// Show title bar on top of the window?
     /// If `false`, the window will not be collapsible nor have a close-button.
     #[inline]
@@ -438,6 +494,9 @@ impl Window<'_> {
             default_open,
             with_title_bar,
             fade_out,
+            snap,
+            tiling,
+            minimizable,
         } = self;
 
         let header_color =
@@ -455,6 +514,11 @@ impl Window<'_> {
             return None;
         }
 
+        let registration = window_layout::register(ctx, area.layer(), title.text());
+        if registration.minimized && !ctx.memory(|mem| mem.everything_is_visible()) {
+            return None;
+        }
+
         let area_id = area.id;
         let area_layer_id = area.layer();
         let resize_id = area_id.with("resize");
@@ -526,6 +590,14 @@ impl Window<'_> {
                 &mut area,
                 resize_id,
             );
+
+            if let Some(rect) = registration.requested_rect {
+                set_window_rect(ctx, &mut area, resize_id, margins, rect);
+            }
+
+            if !resize_interaction.any_dragged() {
+                snap_and_tile(ctx, &mut area, resize_id, margins, snap, tiling);
+            }
         }
 
         let mut area_content_ui = area.content_ui(ctx);
@@ -548,7 +620,7 @@ impl Window<'_> {
                 let title_bar = TitleBar::new(
                     &frame.content_ui,
                     title,
-                    show_close_button,
+                    usize::from(show_close_button) + usize::from(minimizable),
                     collapsible,
                     window_frame,
                     title_bar_height_with_margin,
@@ -630,6 +702,7 @@ impl Window<'_> {
                     open.as_deref_mut(),
                     &mut collapsing,
                     collapsible,
+                    minimizable.then_some(area_id),
                 );
             }
 
@@ -817,6 +890,68 @@ impl ResizeInteraction {
     }
 }
 
+/// Move and resize the window so that its outer rect becomes `rect`.
+fn set_window_rect(
+    ctx: &Context,
+    area: &mut area::Prepared,
+    resize_id: Id,
+    margins: Vec2,
+    mut rect: Rect,
+) {
+    if area.constrain() {
+        rect = Context::constrain_window_rect_to_area(rect, area.constrain_rect());
+    }
+    area.state_mut().set_left_top_pos(rect.left_top());
+
+    if let Some(mut state) = resize::State::load(ctx, resize_id) {
+        state.requested_size = Some(rect.size() - margins);
+        state.store(ctx, resize_id);
+    }
+}
+
+/// Snap the window to nearby edges while it is dragged,
+/// and tile it when it is dropped at an edge of the central area.
+fn snap_and_tile(
+    ctx: &Context,
+    area: &mut area::Prepared,
+    resize_id: Id,
+    margins: Vec2,
+    snap: bool,
+    tiling: bool,
+) {
+    let move_response = area.move_response();
+    let (dragged, drag_stopped) = (move_response.dragged(), move_response.drag_stopped());
+    if !dragged && !drag_stopped {
+        return;
+    }
+    let layer_id = move_response.layer_id;
+
+    let available_rect = ctx.available_rect();
+    if tiling
+        && let Some(pointer) = ctx.input(|i| i.pointer.latest_pos())
+        && let Some(tile) = window_layout::WindowTile::at(
+            pointer,
+            available_rect,
+            ctx.style().interaction.window_snap_distance,
+        )
+    {
+        let tile_rect = tile.rect(available_rect);
+        if drag_stopped {
+            set_window_rect(ctx, area, resize_id, margins, tile_rect);
+        } else {
+            window_layout::paint_tile_preview(ctx, layer_id, tile_rect);
+        }
+        return;
+    }
+
+    if snap && dragged {
+        let rect = area.state().rect();
+        let containers = [area.constrain_rect(), available_rect];
+        let snapped = window_layout::snap_rect(ctx, layer_id, rect, &containers);
+        area.state_mut().set_left_top_pos(snapped.left_top());
+    }
+}
+
 fn resize_response(
     resize_interaction: ResizeInteraction,
     ctx: &Context,
@@ -1135,7 +1270,7 @@ impl TitleBar {
     fn new(
         ui: &Ui,
         title: WidgetText,
-        show_close_button: bool,
+        num_right_buttons: usize,
         collapsible: bool,
         window_frame: Frame,
         title_bar_height_with_margin: f32,
@@ -1160,9 +1295,11 @@ impl TitleBar {
             TextStyle::Heading,
         );
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title should be centered):
-            2.0 * (left_pad + button_size.x + item_spacing.x) + title_galley.size().x
+        let minimum_width = if collapsible || num_right_buttons > 0 {
+            // If at least one button is shown we make room for both sides (since title should be centered):
+            let buttons_per_side = num_right_buttons.max(1) as f32;
+            2.0 * (left_pad + buttons_per_side * (button_size.x + item_spacing.x))
+                + title_galley.size().x
         } else {
             left_pad + title_galley.size().x + left_pad
         };
@@ -1196,6 +1333,7 @@ impl TitleBar {
     ///   title if `collapsible` is `true`
     /// - `collapsible`: if `true`, double click on the title bar will be handled for a change
     ///   of `collapsing` state
+    /// - `minimize_id`: if `Some`, renders a "Minimize" button that minimizes the window with that id
     fn ui(
         self,
         ui: &mut Ui,
@@ -1203,6 +1341,7 @@ impl TitleBar {
         open: Option<&mut bool>,
         collapsing: &mut CollapsingState,
         collapsible: bool,
+        minimize_id: Option<Id>,
     ) {
         let window_frame = self.window_frame;
         let title_inner_rect = self.inner_rect;
@@ -1227,11 +1366,21 @@ impl TitleBar {
             });
         }
 
+        let mut right_buttons = 0;
+
         if let Some(open) = open {
             // Add close button now that we know our full width:
             if self.close_button_ui(ui).clicked() {
                 *open = false;
             }
+            right_buttons += 1;
+        }
+
+        if let Some(window_id) = minimize_id {
+            if self.minimize_button_ui(ui, right_buttons).clicked() {
+                window_layout::minimize(ui.ctx(), window_id);
+            }
+            right_buttons += 1;
         }
 
         let text_pos =
@@ -1259,8 +1408,11 @@ impl TitleBar {
                 .hline(title_inner_rect.x_range(), y, window_frame.stroke);
         }
 
-        // Don't cover the close- and collapse buttons:
-        let double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        // Don't cover the close-, minimize- and collapse buttons:
+        let mut double_click_rect = title_inner_rect.shrink2(vec2(32.0, 0.0));
+        if right_buttons > 1 {
+            double_click_rect.max.x -= (right_buttons - 1) as f32 * self.inner_rect.height();
+        }
 
         if false {
             ui.ctx().debug_painter().debug_rect(
@@ -1295,6 +1447,26 @@ impl TitleBar {
         let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
         close_button(ui, button_rect)
     }
+
+    /// Paints the "Minimize" button to the left of `index` other buttons
+    /// at the right side of the title bar, and processes clicks on it.
+    fn minimize_button_ui(&self, ui: &mut Ui, index: usize) -> Response {
+        let slot_width = self.inner_rect.height();
+        let slot = Rect::from_min_max(
+            pos2(
+                self.inner_rect.right() - (index + 1) as f32 * slot_width,
+                self.inner_rect.top(),
+            ),
+            pos2(
+                self.inner_rect.right() - index as f32 * slot_width,
+                self.inner_rect.bottom(),
+            ),
+        );
+        let button_size = Vec2::splat(ui.spacing().icon_width);
+        let button_rect = Rect::from_center_size(slot.center(), button_size);
+        let button_rect = button_rect.round_to_pixels(ui.pixels_per_point());
+        minimize_button(ui, button_rect)
+    }
 }
 
 /// Paints the "Close" button of the window and processes clicks on it.
@@ -1324,3 +1496,22 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+/// Paints the "Minimize" button of the window and processes clicks on it.
+///
+/// The button is a `_` symbol painted with the current foreground stroke.
+fn minimize_button(ui: &mut Ui, rect: Rect) -> Response {
+    let minimize_id = ui.auto_id_with("window_minimize_button");
+    let response = ui.interact(rect, minimize_id, Sense::click());
+    response.widget_info(|| {
+        WidgetInfo::labeled(WidgetType::Button, ui.is_enabled(), "Minimize window")
+    });
+
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    ui.painter()
+        .hline(rect.x_range(), rect.bottom(), visuals.fg_stroke);
+    response
+}
@@ -0,0 +1,563 @@
+//! Helpers for managing several [`Window`]s at once:
+//! arranging them, tiling them to the edges of the central area, and minimizing them to a tray.
+//!
+//! ```
+//! # egui::__run_test_ctx(|ctx| {
+//! use egui::window_layout::{WindowArrangement, WindowTray};
+//!
+//! // Let the user cycle, cascade and tile windows with the keyboard:
+//! egui::window_layout::arrange_with_keyboard(ctx);
+//!
+//! // Show minimized windows in a tray at the bottom of the screen
+//! // (before any central panel):
+//! WindowTray::new().show(ctx);
+//!
+//! egui::Window::new("Notes")
+//!     .snap(true)
+//!     .tiling(true)
+//!     .minimizable(true)
+//!     .show(ctx, |ui| {
+//!         ui.label("Drag me to an edge!");
+//!     });
+//! # });
+//! ```
+//!
+//! [`Window`]: crate::Window
+
+use crate::{
+    Button, Context, Id, InnerResponse, Key, KeyboardShortcut, LayerId, Modifiers, NumExt as _,
+    Order, Panel, Pos2, Rangef, Rect, Shape, Stroke, Vec2, pos2, vec2,
+};
+
+/// The suggested keyboard shortcuts for arranging windows.
+///
+/// See [`arrange_with_keyboard`].
+pub mod kb_shortcuts {
+    use super::{Key, KeyboardShortcut, Modifiers};
+
+    /// Bring the bottom-most window to the top (`Cmd` + `` ` ``).
+    pub const CYCLE_WINDOWS: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND, Key::Backtick);
+
+    /// Cascade all windows (`Cmd` + `Alt` + `C`).
+    pub const CASCADE_WINDOWS: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::ALT), Key::C);
+
+    /// Tile all windows in a grid (`Cmd` + `Alt` + `T`).
+    pub const TILE_WINDOWS: KeyboardShortcut =
+        KeyboardShortcut::new(Modifiers::COMMAND.plus(Modifiers::ALT), Key::T);
+}
+
+/// How to arrange all windows, used by [`arrange_windows`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowArrangement {
+    /// Stack the windows diagonally from the top left corner, keeping their sizes.
+    Cascade,
+
+    /// Lay the windows out in a grid that fills the central area.
+    Tile,
+}
+
+/// Part of the central area a window can be tiled to by dragging it to an edge or corner.
+///
+/// See [`crate::Window::tiling`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WindowTile {
+    /// The left half.
+    Left,
+
+    /// The right half.
+    Right,
+
+    /// The top half.
+    Top,
+
+    /// The bottom half.
+    Bottom,
+
+    /// The top left quarter.
+    TopLeft,
+
+    /// The top right quarter.
+    TopRight,
+
+    /// The bottom left quarter.
+    BottomLeft,
+
+    /// The bottom right quarter.
+    BottomRight,
+}
+
+impl WindowTile {
+    /// The tile the pointer is over, if it is within `margin` of an edge of `area`.
+    ///
+    /// Close to a corner (within a quarter of the side) gives a quarter tile,
+    /// otherwise a half tile.
+    pub fn at(pointer: Pos2, area: Rect, margin: f32) -> Option<Self> {
+        let near_left = pointer.x <= area.left() + margin;
+        let near_right = pointer.x >= area.right() - margin;
+        let near_top = pointer.y <= area.top() + margin;
+        let near_bottom = pointer.y >= area.bottom() - margin;
+
+        let corner_x = (area.width() / 4.0).at_least(margin);
+        let corner_y = (area.height() / 4.0).at_least(margin);
+        let cornerish_left = pointer.x <= area.left() + corner_x;
+        let cornerish_right = pointer.x >= area.right() - corner_x;
+        let cornerish_top = pointer.y <= area.top() + corner_y;
+        let cornerish_bottom = pointer.y >= area.bottom() - corner_y;
+
+        if near_left || near_right {
+            Some(match (near_left, cornerish_top, cornerish_bottom) {
+                (true, true, _) => Self::TopLeft,
+                (true, _, true) => Self::BottomLeft,
+                (true, _, _) => Self::Left,
+                (false, true, _) => Self::TopRight,
+                (false, _, true) => Self::BottomRight,
+                (false, _, _) => Self::Right,
+            })
+        } else if near_top || near_bottom {
+            Some(match (near_top, cornerish_left, cornerish_right) {
+                (true, true, _) => Self::TopLeft,
+                (true, _, true) => Self::TopRight,
+                (true, _, _) => Self::Top,
+                (false, true, _) => Self::BottomLeft,
+                (false, _, true) => Self::BottomRight,
+                (false, _, _) => Self::Bottom,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The part of `area` covered by this tile.
+    pub fn rect(self, area: Rect) -> Rect {
+        let center = area.center();
+        let (x_range, y_range) = match self {
+            Self::Left => (area.left()..=center.x, area.top()..=area.bottom()),
+            Self::Right => (center.x..=area.right(), area.top()..=area.bottom()),
+            Self::Top => (area.left()..=area.right(), area.top()..=center.y),
+            Self::Bottom => (area.left()..=area.right(), center.y..=area.bottom()),
+            Self::TopLeft => (area.left()..=center.x, area.top()..=center.y),
+            Self::TopRight => (center.x..=area.right(), area.top()..=center.y),
+            Self::BottomLeft => (area.left()..=center.x, center.y..=area.bottom()),
+            Self::BottomRight => (center.x..=area.right(), center.y..=area.bottom()),
+        };
+        Rect::from_x_y_ranges(x_range, y_range)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Every [`crate::Window`] registers itself here each pass,
+/// so that we can arrange, cycle and restore them.
+#[derive(Clone, Default)]
+struct WindowRegistry {
+    /// In order of first appearance, so the tray stays stable.
+    windows: Vec<(Id, WindowEntry)>,
+
+    /// The last pass in which [`Self::prune`] ran.
+    pruned_pass: u64,
+}
+
+#[derive(Clone)]
+struct WindowEntry {
+    title: String,
+    layer_id: LayerId,
+
+    /// The last pass in which [`crate::Window::show`] was called.
+    last_pass: u64,
+
+    minimized: bool,
+
+    /// Where the window should go the next time it is shown.
+    requested_rect: Option<Rect>,
+}
+
+impl WindowRegistry {
+    fn id() -> Id {
+        Id::new("__window_registry")
+    }
+
+    fn read<R>(ctx: &Context, reader: impl FnOnce(&Self) -> R) -> R {
+        Self::write(ctx, |registry| reader(registry))
+    }
+
+    fn write<R>(ctx: &Context, writer: impl FnOnce(&mut Self) -> R) -> R {
+        let pass_nr = ctx.cumulative_pass_nr();
+        ctx.data_mut(|d| {
+            let registry = d.get_temp_mut_or_default::<Self>(Self::id());
+            registry.prune(pass_nr);
+            writer(registry)
+        })
+    }
+
+    /// Forget the windows that were not shown in the previous pass,
+    /// so that they are no longer arranged, and the registry doesn't grow forever.
+    fn prune(&mut self, pass_nr: u64) {
+        if self.pruned_pass != pass_nr {
+            self.pruned_pass = pass_nr;
+            self.windows
+                .retain(|(_, entry)| entry.last_pass + 1 >= pass_nr);
+        }
+    }
+
+    fn entry_mut(&mut self, id: Id) -> Option<&mut WindowEntry> {
+        self.windows
+            .iter_mut()
+            .find_map(|(entry_id, entry)| (*entry_id == id).then_some(entry))
+    }
+
+    /// Windows that were shown this pass or the previous one.
+    fn alive(&self, pass_nr: u64) -> impl Iterator<Item = (Id, &WindowEntry)> {
+        self.windows
+            .iter()
+            .filter(move |(_, entry)| entry.last_pass + 1 >= pass_nr)
+            .map(|(id, entry)| (*id, entry))
+    }
+}
+
+/// What a window needs to know about itself when it is shown.
+pub(crate) struct Registration {
+    pub minimized: bool,
+    pub requested_rect: Option<Rect>,
+}
+
+/// Called by every [`crate::Window`] at the start of its `show`.
+pub(crate) fn register(ctx: &Context, layer_id: LayerId, title: &str) -> Registration {
+    let pass_nr = ctx.cumulative_pass_nr();
+    WindowRegistry::write(ctx, |registry| {
+        if let Some(entry) = registry.entry_mut(layer_id.id) {
+            entry.title = title.to_owned();
+            entry.layer_id = layer_id;
+            entry.last_pass = pass_nr;
+            Registration {
+                minimized: entry.minimized,
+                requested_rect: entry.requested_rect.take(),
+            }
+        } else {
+            registry.windows.push((
+                layer_id.id,
+                WindowEntry {
+                    title: title.to_owned(),
+                    layer_id,
+                    last_pass: pass_nr,
+                    minimized: false,
+                    requested_rect: None,
+                },
+            ));
+            Registration {
+                minimized: false,
+                requested_rect: None,
+            }
+        }
+    })
+}
+
+/// Ask a window to move to the given outer rect the next time it is shown.
+pub fn request_window_rect(ctx: &Context, window_id: Id, rect: Rect) {
+    WindowRegistry::write(ctx, |registry| {
+        if let Some(entry) = registry.entry_mut(window_id) {
+            entry.requested_rect = Some(rect);
+        }
+    });
+    ctx.request_repaint();
+}
+
+/// Is the window with the given id currently minimized to the [`WindowTray`]?
+///
+/// The id of a window is the id of its title, unless set with [`crate::Window::id`].
+pub fn is_minimized(ctx: &Context, window_id: Id) -> bool {
+    WindowRegistry::read(ctx, |registry| {
+        registry
+            .windows
+            .iter()
+            .any(|(id, entry)| *id == window_id && entry.minimized)
+    })
+}
+
+/// Minimize (hide) a window. It can be restored from the [`WindowTray`], or with [`restore`].
+pub fn minimize(ctx: &Context, window_id: Id) {
+    WindowRegistry::write(ctx, |registry| {
+        if let Some(entry) = registry.entry_mut(window_id) {
+            entry.minimized = true;
+        }
+    });
+    ctx.request_repaint();
+}
+
+/// Restore a minimized window and bring it to the top.
+pub fn restore(ctx: &Context, window_id: Id) {
+    let layer_id = WindowRegistry::write(ctx, |registry| {
+        let entry = registry.entry_mut(window_id)?;
+        entry.minimized = false;
+        Some(entry.layer_id)
+    });
+    if let Some(layer_id) = layer_id {
+        ctx.move_to_top(layer_id);
+    }
+    ctx.request_repaint();
+}
+
+/// The visible (not minimized) windows, back-to-front, with their outer rects.
+fn visible_windows(ctx: &Context) -> Vec<(LayerId, Rect)> {
+    let pass_nr = ctx.cumulative_pass_nr();
+    let layer_ids: Vec<LayerId> = WindowRegistry::read(ctx, |registry| {
+        registry
+            .alive(pass_nr)
+            .filter(|(_, entry)| !entry.minimized)
+            .map(|(_, entry)| entry.layer_id)
+            .collect()
+    });
+
+    ctx.memory(|mem| {
+        let areas = mem.areas();
+        areas
+            .order()
+            .iter()
+            .filter(|layer_id| layer_ids.contains(layer_id) && areas.is_visible(layer_id))
+            .filter_map(|layer_id| Some((*layer_id, areas.get(layer_id.id)?.rect())))
+            .collect()
+    })
+}
+
+/// Move and resize all visible windows in the central area ([`Context::available_rect`]).
+pub fn arrange_windows(ctx: &Context, arrangement: WindowArrangement) {
+    let windows = visible_windows(ctx);
+    if windows.is_empty() {
+        return;
+    }
+
+    let available = ctx.available_rect();
+    let spacing = ctx.style().spacing.clone();
+
+    match arrangement {
+        WindowArrangement::Cascade => {
+            let step = Vec2::splat(spacing.interact_size.y + spacing.window_margin.sum().y);
+            let max_size = available.size() - step * (windows.len() - 1) as f32;
+            for (i, (layer_id, rect)) in windows.iter().enumerate() {
+                let min = available.min + step * i as f32;
+                let size = rect.size().min(max_size.max(Vec2::ZERO));
+                request_window_rect(ctx, layer_id.id, Rect::from_min_size(min, size));
+            }
+        }
+        WindowArrangement::Tile => {
+            let columns = (windows.len() as f32).sqrt().ceil() as usize;
+            let rows = windows.len().div_ceil(columns);
+            let gap = spacing.item_spacing;
+            let cell_size = vec2(
+                (available.width() - gap.x * (columns - 1) as f32) / columns as f32,
+                (available.height() - gap.y * (rows - 1) as f32) / rows as f32,
+            );
+            for (i, (layer_id, _)) in windows.iter().enumerate() {
+                let (column, row) = (i % columns, i / columns);
+                let min = available.min
+                    + vec2(
+                        column as f32 * (cell_size.x + gap.x),
+                        row as f32 * (cell_size.y + gap.y),
+                    );
+                request_window_rect(ctx, layer_id.id, Rect::from_min_size(min, cell_size));
+            }
+        }
+    }
+
+    for (layer_id, _) in windows {
+        ctx.move_to_top(layer_id); // keep the current stacking order
+    }
+}
+
+/// Bring the bottom-most visible window to the top.
+pub fn cycle_windows(ctx: &Context) {
+    if let Some((layer_id, _)) = visible_windows(ctx).first() {
+        ctx.move_to_top(*layer_id);
+    }
+}
+
+/// Cycle, cascade or tile the windows using the shortcuts in [`kb_shortcuts`].
+///
+/// Call this once per frame, e.g. at the start of your app's `ui` function.
+pub fn arrange_with_keyboard(ctx: &Context) {
+    if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::CYCLE_WINDOWS)) {
+        cycle_windows(ctx);
+    }
+    if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::CASCADE_WINDOWS)) {
+        arrange_windows(ctx, WindowArrangement::Cascade);
+    }
+    if ctx.input_mut(|i| i.consume_shortcut(&kb_shortcuts::TILE_WINDOWS)) {
+        arrange_windows(ctx, WindowArrangement::Tile);
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Move `rect` so that its edges snap to nearby edges of `containers` and of other visible windows.
+///
+/// Paints guides for the edges that were snapped to.
+pub(crate) fn snap_rect(ctx: &Context, layer_id: LayerId, rect: Rect, containers: &[Rect]) -> Rect {
+    let distance = ctx.style().interaction.window_snap_distance;
+    if distance <= 0.0 {
+        return rect;
+    }
+
+    // Candidate lines: (position, extent along the other axis).
+    let mut x_lines: Vec<(f32, Rangef)> = vec![];
+    let mut y_lines: Vec<(f32, Rangef)> = vec![];
+
+    for container in containers {
+        x_lines.push((container.left(), container.y_range()));
+        x_lines.push((container.right(), container.y_range()));
+        y_lines.push((container.top(), container.x_range()));
+        y_lines.push((container.bottom(), container.x_range()));
+    }
+
+    for (other_layer, other) in visible_windows(ctx) {
+        if other_layer == layer_id {
+            continue;
+        }
+        if rect.y_range().intersects(other.y_range().expand(distance)) {
+            x_lines.push((other.left(), other.y_range()));
+            x_lines.push((other.right(), other.y_range()));
+        }
+        if rect.x_range().intersects(other.x_range().expand(distance)) {
+            y_lines.push((other.top(), other.x_range()));
+            y_lines.push((other.bottom(), other.x_range()));
+        }
+    }
+
+    let dx = closest_snap([rect.left(), rect.right()], &x_lines, distance);
+    let dy = closest_snap([rect.top(), rect.bottom()], &y_lines, distance);
+
+    let snapped = rect.translate(vec2(
+        dx.map_or(0.0, |(delta, _)| delta),
+        dy.map_or(0.0, |(delta, _)| delta),
+    ));
+
+    let painter = ctx.layer_painter(LayerId::new(
+        Order::Foreground,
+        layer_id.id.with("snap_guides"),
+    ));
+    let stroke = Stroke::new(1.0, ctx.style().visuals.selection.stroke.color);
+    if let Some((_, (x, extent))) = dx {
+        let y_range = Rangef::new(
+            extent.min.min(snapped.top()),
+            extent.max.max(snapped.bottom()),
+        );
+        painter.add(Shape::line_segment(
+            [pos2(x, y_range.min), pos2(x, y_range.max)],
+            stroke,
+        ));
+    }
+    if let Some((_, (y, extent))) = dy {
+        let x_range = Rangef::new(
+            extent.min.min(snapped.left()),
+            extent.max.max(snapped.right()),
+        );
+        painter.add(Shape::line_segment(
+            [pos2(x_range.min, y), pos2(x_range.max, y)],
+            stroke,
+        ));
+    }
+
+    snapped
+}
+
+/// The smallest offset that moves one of `edges` onto one of `lines`, if within `distance`.
+fn closest_snap(
+    edges: [f32; 2],
+    lines: &[(f32, Rangef)],
+    distance: f32,
+) -> Option<(f32, (f32, Rangef))> {
+    let mut best: Option<(f32, (f32, Rangef))> = None;
+    for edge in edges {
+        for &line in lines {
+            let delta = line.0 - edge;
+            if delta.abs() <= distance && best.is_none_or(|(best, _)| delta.abs() < best.abs()) {
+                best = Some((delta, line));
+            }
+        }
+    }
+    best
+}
+
+/// Paint a preview of where a window would be tiled to.
+pub(crate) fn paint_tile_preview(ctx: &Context, layer_id: LayerId, rect: Rect) {
+    let visuals = &ctx.style().visuals;
+    let painter = ctx.layer_painter(LayerId::new(
+        Order::Foreground,
+        layer_id.id.with("tile_preview"),
+    ));
+    painter.rect(
+        rect,
+        visuals.window_corner_radius,
+        visuals.selection.bg_fill.gamma_multiply(0.25),
+        visuals.selection.stroke,
+        crate::StrokeKind::Inside,
+    );
+}
+
+// ----------------------------------------------------------------------------
+
+/// A bottom panel listing all minimized windows, with buttons to restore them.
+///
+/// Windows can be minimized with [`crate::Window::minimizable`] or [`minimize`].
+/// The tray is only shown while there are minimized windows.
+///
+/// Like any [`Panel`], it should be shown before the [`crate::CentralPanel`].
+#[must_use = "You should call .show()"]
+pub struct WindowTray {
+    id: Id,
+}
+
+impl Default for WindowTray {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WindowTray {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new("window_tray"),
+        }
+    }
+
+    /// Change the id of the underlying panel.
+    #[inline]
+    pub fn id(mut self, id: impl Into<Id>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Shows the tray if any window is minimized.
+    ///
+    /// The inner value is the id of the window that was restored this frame, if any.
+    pub fn show(self, ctx: &Context) -> Option<InnerResponse<Option<Id>>> {
+        let pass_nr = ctx.cumulative_pass_nr();
+        let minimized: Vec<(Id, String)> = WindowRegistry::read(ctx, |registry| {
+            registry
+                .alive(pass_nr)
+                .filter(|(_, entry)| entry.minimized)
+                .map(|(id, entry)| (id, entry.title.clone()))
+                .collect()
+        });
+        if minimized.is_empty() {
+            return None;
+        }
+
+        Some(Panel::bottom(self.id).show(ctx, |ui| {
+            let mut restored = None;
+            ui.horizontal_wrapped(|ui| {
+                for (id, title) in minimized {
+                    if ui
+                        .add(Button::new(title))
+                        .on_hover_text("Restore window")
+                        .clicked()
+                    {
+                        restore(ui.ctx(), id);
+                        restored = Some(id);
+                    }
+                }
+            });
+            restored
+        }))
+    }
+}
@@ -17,9 +17,9 @@ use epaint::{
 use crate::{
     Align2, CursorIcon, DeferredViewportUiCallback, FontDefinitions, Grid, Id, ImmediateViewport,
     ImmediateViewportRendererCallback, Key, KeyboardShortcut, Label, LayerId, Memory,
-    ModifierNames, Modifiers, NumExt as _, Order, Painter, RawInput, Response, RichText,
-    SafeAreaInsets, ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions, Ui,
-    ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet,
+    ModifierNames, Modifiers, NavigationEvent, NumExt as _, Order, Painter, RawInput, Response,
+    RichText, SafeAreaInsets, ScrollArea, Sense, Style, TextStyle, TextureHandle, TextureOptions,
+    Ui, ViewportBuilder, ViewportCommand, ViewportId, ViewportIdMap, ViewportIdPair, ViewportIdSet,
     ViewportOutput, Widget as _, WidgetRect, WidgetText,
     animation_manager::AnimationManager,
     containers::{self, area::AreaState},
@@ -244,6 +244,12 @@ pub struct ViewportState {
     // ----------------------
     // Cross-frame statistics:
     pub num_multipass_in_row: usize,
+
+    /// Meshes from the last [`Context::tessellate`] of this viewport.
+    pub tessellation_cache: epaint::TessellationCache,
+
+    /// What was painted last frame, see [`crate::Options::damage_tracking`].
+    damage_tracker: crate::damage::DamageTracker,
 }
 
 /// What called [`Context::request_repaint`] or [`Context::request_discard`]?
@@ -453,6 +459,8 @@ impl ContextImpl {
         let viewport = self.viewports.entry(self.viewport_id()).or_default();
 
         self.memory.begin_pass(&new_raw_input, &all_viewport_ids);
+        self.memory
+            .navigate_focus_spatially(&viewport.prev_pass.widgets, false);
 
         viewport.input = std::mem::take(&mut viewport.input).begin_pass(
             new_raw_input,
@@ -1251,6 +1259,11 @@ impl Context {
             plugins.on_widget_under_pointer(self, &w);
         }
 
+        if allow_focus && res.gained_focus() && self.memory(|mem| mem.focus_visible()) {
+            // Keyboard (or gamepad) navigation should never leave the focused widget out of view:
+            res.scroll_to_me(None);
+        }
+
         if allow_focus && w.sense.is_focusable() {
             // Make sure anything that can receive focus has an AccessKit node.
             // TODO(mwcampbell): For nodes that are filled from widget info,
@@ -1382,7 +1395,9 @@ impl Context {
             if enabled
                 && sense.senses_click()
                 && memory.has_focus(id)
-                && (input.key_pressed(Key::Space) || input.key_pressed(Key::Enter))
+                && (input.key_pressed(Key::Space)
+                    || input.key_pressed(Key::Enter)
+                    || input.navigation_pressed(NavigationEvent::Activate))
             {
                 // Space/enter works like a primary click for e.g. selected buttons
                 res.flags.set(Flags::FAKE_PRIMARY_CLICKED, true);
@@ -1596,6 +1611,24 @@ impl Context {
         self.send_cmd(crate::OutputCommand::CopyImage(image));
     }
 
+    /// Ask the integration to also paste the given format (e.g. images or HTML), if it is on the clipboard.
+    ///
+    /// Plain text is always pasted, as [`crate::Event::Paste`].
+    /// Other formats are only pasted, as [`crate::Event::PasteData`], if some widget asks for them.
+    /// The request only lasts for this pass, so call this every pass you want to receive the format,
+    /// e.g. while your widget has keyboard focus.
+    ///
+    /// Which formats are supported depends on the integration.
+    /// `egui-winit` supports [`crate::PasteFormat::Image`] and HTML,
+    /// and the `eframe` web backend supports any textual mime type.
+    pub fn request_paste_format(&self, format: crate::PasteFormat) {
+        self.output_mut(|o| {
+            if !o.paste_formats.contains(&format) {
+                o.paste_formats.push(format);
+            }
+        });
+    }
+
     fn can_show_modifier_symbols(&self) -> bool {
         let ModifierNames {
             alt,
@@ -2354,6 +2387,8 @@ impl Context {
         let plugins = self.read(|ctx| ctx.plugins.ordered_plugins());
         plugins.on_end_pass(self);
 
+        self.paint_focus_ring();
+
         #[cfg(debug_assertions)]
         self.debug_painting();
 
@@ -2369,6 +2404,39 @@ impl Context {
         self.end_pass()
     }
 
+    /// Only let the given widget be hit within this shape.
+    ///
+    /// See [`Response::with_hit_shape`].
+    pub(crate) fn set_widget_hit_shape(&self, id: Id, shape: crate::HitShape) {
+        self.write(|ctx| ctx.viewport().this_pass.widgets.set_hit_shape(id, shape));
+    }
+
+    /// Paint the [`crate::style::FocusRing`] around the focused widget,
+    /// if the focus was moved there with the keyboard.
+    fn paint_focus_ring(&self) {
+        if !self.memory(|mem| mem.focus_visible()) {
+            return;
+        }
+        let Some(focused) = self.memory(|mem| mem.focused()) else {
+            return;
+        };
+        let Some(widget) = self.write(|ctx| ctx.viewport().this_pass.widgets.get(focused).copied())
+        else {
+            return;
+        };
+        let ring = self.style().visuals.focus_ring;
+        if ring.stroke.is_empty() || !widget.rect.is_positive() {
+            return;
+        }
+        let rect = widget.rect.expand(ring.expansion);
+        let painter = Painter::new(
+            self.clone(),
+            widget.layer_id,
+            rect.expand(ring.stroke.width),
+        );
+        painter.rect_stroke(rect, ring.corner_radius, ring.stroke, StrokeKind::Outside);
+    }
+
     /// Called at the end of the pass.
     #[cfg(debug_assertions)]
     fn debug_painting(&self) {
@@ -2529,6 +2597,9 @@ impl ContextImpl {
 
         viewport.repaint.cumulative_pass_nr += 1;
 
+        // Handle `Memory::move_focus` calls made during this pass:
+        self.memory
+            .navigate_focus_spatially(&viewport.this_pass.widgets, true);
         self.memory.end_pass(&viewport.this_pass.used_ids);
 
         if let Some(fonts) = self.fonts.as_mut() {
@@ -2568,9 +2639,27 @@ impl ContextImpl {
             }
         }
 
-        let shapes = viewport
+        let layers = viewport
             .graphics
-            .drain(self.memory.areas().order(), &self.memory.to_global);
+            .drain_layers(self.memory.areas().order(), &self.memory.to_global);
+
+        let viewport_rect = viewport.input.viewport_rect();
+        let damage = if self.memory.options.damage_tracking {
+            viewport.damage_tracker.update(
+                &layers,
+                viewport_rect,
+                pixels_per_point,
+                !textures_delta.is_empty(),
+            )
+        } else {
+            viewport.damage_tracker.clear();
+            vec![viewport_rect]
+        };
+
+        let mut shapes = Vec::with_capacity(layers.iter().map(|(_, list)| list.len()).sum());
+        for (_, mut list) in layers {
+            shapes.append(&mut list);
+        }
 
         let mut repaint_needed = false;
 
@@ -2658,6 +2747,11 @@ impl ContextImpl {
                         viewport_ui_cb: viewport.viewport_ui_cb.clone(),
                         commands,
                         repaint_delay: viewport.repaint.repaint_delay,
+                        damage: if id == ended_viewport_id {
+                            damage.clone()
+                        } else {
+                            vec![]
+                        },
                     },
                 )
             })
@@ -2684,6 +2778,7 @@ impl ContextImpl {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage,
             viewport_output,
         }
     }
@@ -2702,9 +2797,9 @@ impl Context {
     ) -> Vec<ClippedPrimitive> {
         profiling::function_scope!();
 
-        // A tempting optimization is to reuse the tessellation from last frame if the
-        // shapes are the same, but just comparing the shapes takes about 50% of the time
-        // it takes to tessellate them, so it is not a worth optimization.
+        // Comparing shapes takes about 50% of the time it takes to tessellate them,
+        // so reusing the meshes of the last frame is opt-in, and only for shapes
+        // that are slow to tessellate. See `TessellationOptions::cache_meshes`.
 
         self.write(|ctx| {
             let tessellation_options = ctx.memory.options.tessellation_options;
@@ -2719,6 +2814,12 @@ impl Context {
                     .texture_atlas()
             };
 
+            let tessellation_cache = &mut ctx
+                .viewports
+                .entry(ctx.last_viewport)
+                .or_default()
+                .tessellation_cache;
+
             let paint_stats = PaintStats::from_shapes(&shapes);
             let clipped_primitives = {
                 profiling::scope!("tessellator::tessellate_shapes");
@@ -2728,9 +2829,11 @@ impl Context {
                     texture_atlas.size(),
                     texture_atlas.prepared_discs(),
                 )
-                .tessellate_shapes(shapes)
+                .tessellate_shapes_with_cache(shapes, tessellation_cache)
             };
-            ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+            ctx.paint_stats = paint_stats
+                .with_clipped_primitives(&clipped_primitives)
+                .with_tessellation_cache(tessellation_cache);
             clipped_primitives
         })
     }
@@ -0,0 +1,424 @@
+//! Find the parts of the screen that changed since the previous frame.
+
+use std::collections::VecDeque;
+
+use ahash::HashMap;
+use epaint::{ClippedShape, Rect, Shape, pos2};
+
+use crate::LayerId;
+
+/// More damage rectangles than this are merged into one.
+const MAX_DAMAGE_RECTS: usize = 8;
+
+/// How many frames a [`DamageHistory`] remembers.
+const MAX_BUFFER_AGE: usize = 4;
+
+/// Remembers what was painted in a viewport last frame,
+/// to find what changed in the next one.
+///
+/// See [`crate::Options::damage_tracking`].
+#[derive(Default)]
+pub(crate) struct DamageTracker {
+    prev: Option<PaintedFrame>,
+}
+
+struct PaintedFrame {
+    pixels_per_point: f32,
+    viewport_rect: Rect,
+    layers: Vec<(LayerId, Vec<ClippedShape>)>,
+}
+
+impl DamageTracker {
+    /// Forget the previous frame, so that the next one is damaged everywhere.
+    pub fn clear(&mut self) {
+        self.prev = None;
+    }
+
+    /// Compare the layers painted this frame with those of the previous frame.
+    ///
+    /// Returns non-overlapping rectangles, in points, aligned to the physical pixel grid.
+    /// If `textures_changed` is set, everything is damaged,
+    /// since any shape may be showing an updated texture.
+    pub fn update(
+        &mut self,
+        layers: &[(LayerId, Vec<ClippedShape>)],
+        viewport_rect: Rect,
+        pixels_per_point: f32,
+        textures_changed: bool,
+    ) -> Vec<Rect> {
+        profiling::function_scope!();
+
+        let prev = self.prev.replace(PaintedFrame {
+            pixels_per_point,
+            viewport_rect,
+            layers: layers.to_vec(),
+        });
+
+        let Some(prev) = prev else {
+            return vec![viewport_rect];
+        };
+        if textures_changed
+            || prev.pixels_per_point != pixels_per_point
+            || prev.viewport_rect != viewport_rect
+        {
+            return vec![viewport_rect];
+        }
+
+        let mut damage = DamageRects {
+            rects: vec![],
+            pixels_per_point,
+        };
+
+        // Layers that are painted in both frames, in painting order:
+        let prev_layers: HashMap<LayerId, &[ClippedShape]> = prev
+            .layers
+            .iter()
+            .map(|(layer_id, shapes)| (*layer_id, shapes.as_slice()))
+            .collect();
+        let new_layers: HashMap<LayerId, &[ClippedShape]> = layers
+            .iter()
+            .map(|(layer_id, shapes)| (*layer_id, shapes.as_slice()))
+            .collect();
+        let prev_order = prev
+            .layers
+            .iter()
+            .map(|(layer_id, _)| layer_id)
+            .filter(|layer_id| new_layers.contains_key(layer_id));
+        let new_order = layers
+            .iter()
+            .map(|(layer_id, _)| layer_id)
+            .filter(|layer_id| prev_layers.contains_key(layer_id));
+
+        for (prev_layer_id, new_layer_id) in prev_order.zip(new_order) {
+            if prev_layer_id == new_layer_id {
+                damage.add_changed(prev_layers[prev_layer_id], new_layers[new_layer_id]);
+            } else {
+                // The layers have been reordered, so they may cover each other differently:
+                damage.add_all(prev_layers[prev_layer_id]);
+                damage.add_all(new_layers[prev_layer_id]);
+                damage.add_all(prev_layers[new_layer_id]);
+                damage.add_all(new_layers[new_layer_id]);
+            }
+        }
+
+        // Layers that appeared or disappeared:
+        for (layer_id, shapes) in &prev.layers {
+            if !new_layers.contains_key(layer_id) {
+                damage.add_all(shapes);
+            }
+        }
+        for (layer_id, shapes) in layers {
+            if !prev_layers.contains_key(layer_id) {
+                damage.add_all(shapes);
+            }
+        }
+
+        damage.finish(viewport_rect)
+    }
+}
+
+struct DamageRects {
+    rects: Vec<Rect>,
+    pixels_per_point: f32,
+}
+
+impl DamageRects {
+    fn add(&mut self, clipped_shape: &ClippedShape) {
+        // Leave room for the feathering:
+        let rect = clipped_shape
+            .shape
+            .visual_bounding_rect()
+            .expand(1.0 / self.pixels_per_point)
+            .intersect(clipped_shape.clip_rect);
+        if rect.is_positive() {
+            self.rects.push(rect);
+        }
+    }
+
+    fn add_all(&mut self, shapes: &[ClippedShape]) {
+        for clipped_shape in shapes {
+            self.add(clipped_shape);
+        }
+    }
+
+    /// Compare two versions of the same layer.
+    ///
+    /// Only the shapes between the first and the last change are compared,
+    /// so that e.g. adding or removing a single shape doesn't move the rest.
+    fn add_changed(&mut self, prev: &[ClippedShape], new: &[ClippedShape]) {
+        // Paint callbacks can paint something different even if the shape is the same:
+        let same = |a: &ClippedShape, b: &ClippedShape| a == b && !contains_callback(&a.shape);
+
+        let prefix = prev.iter().zip(new).take_while(|(a, b)| same(a, b)).count();
+        let (prev, new) = (&prev[prefix..], &new[prefix..]);
+
+        let suffix = prev
+            .iter()
+            .rev()
+            .zip(new.iter().rev())
+            .take_while(|(a, b)| same(a, b))
+            .count();
+        let (prev, new) = (&prev[..prev.len() - suffix], &new[..new.len() - suffix]);
+
+        self.add_all(prev);
+        self.add_all(new);
+    }
+
+    /// Round out to whole pixels, and merge until no rectangles overlap.
+    fn finish(self, viewport_rect: Rect) -> Vec<Rect> {
+        let Self {
+            mut rects,
+            pixels_per_point,
+        } = self;
+
+        for rect in &mut rects {
+            *rect = Rect::from_min_max(
+                pos2(
+                    (rect.min.x * pixels_per_point).floor() / pixels_per_point,
+                    (rect.min.y * pixels_per_point).floor() / pixels_per_point,
+                ),
+                pos2(
+                    (rect.max.x * pixels_per_point).ceil() / pixels_per_point,
+                    (rect.max.y * pixels_per_point).ceil() / pixels_per_point,
+                ),
+            )
+            .intersect(viewport_rect);
+        }
+        rects.retain(|rect| rect.is_positive());
+
+        merge_overlapping(rects)
+    }
+}
+
+fn contains_callback(shape: &Shape) -> bool {
+    match shape {
+        Shape::Callback(_) => true,
+        Shape::Vec(shapes) => shapes.iter().any(contains_callback),
+        _ => false,
+    }
+}
+
+/// Remembers the damage of the last few frames,
+/// for backends that take turns painting into two or more buffers.
+///
+/// With double or triple buffering, the buffer that is painted next
+/// doesn't contain the previous frame, but one from further back.
+/// How many frames back is its _buffer age_, e.g. from `EGL_EXT_buffer_age`.
+/// Everything that changed since then has to be repainted,
+/// not just the [`crate::FullOutput::damage`] of the newest frame.
+///
+/// ```
+/// # let viewport_rect = egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0));
+/// # let damage = vec![];
+/// let mut history = egui::DamageHistory::default();
+///
+/// // Each frame:
+/// history.push(damage); // from `FullOutput::damage`
+/// let buffer_age = 2; // from the swapchain
+/// let regions = history.damage_for_buffer_age(buffer_age, viewport_rect);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DamageHistory {
+    /// The damage of the last few frames, newest last.
+    frames: VecDeque<Vec<Rect>>,
+}
+
+impl DamageHistory {
+    /// Remember the damage of a new frame, e.g. [`crate::FullOutput::damage`].
+    pub fn push(&mut self, damage: Vec<Rect>) {
+        if self.frames.len() == MAX_BUFFER_AGE {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(damage);
+    }
+
+    /// Forget all frames, e.g. when the swapchain is recreated.
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    /// What needs to be repainted in a buffer that was last painted `buffer_age` frames ago,
+    /// for it to show the newest frame.
+    ///
+    /// A `buffer_age` of `1` means the buffer contains the previous frame,
+    /// so this is only the damage of the newest frame.
+    /// A `buffer_age` of `0` means that the contents of the buffer are unknown,
+    /// so the whole `viewport_rect` is returned.
+    /// So it is if the buffer is older than the frames that are remembered.
+    ///
+    /// The returned rectangles don't overlap.
+    pub fn damage_for_buffer_age(&self, buffer_age: usize, viewport_rect: Rect) -> Vec<Rect> {
+        if buffer_age == 0 || self.frames.len() < buffer_age {
+            return vec![viewport_rect];
+        }
+        let rects = self
+            .frames
+            .iter()
+            .rev()
+            .take(buffer_age)
+            .flatten()
+            .copied()
+            .collect();
+        merge_overlapping(rects)
+    }
+}
+
+/// Merge rectangles until none of them overlap,
+/// and all of them if there are too many.
+pub(crate) fn merge_overlapping(mut rects: Vec<Rect>) -> Vec<Rect> {
+    if MAX_DAMAGE_RECTS * MAX_DAMAGE_RECTS < rects.len() {
+        return merge_all(&rects);
+    }
+
+    'merge: loop {
+        for i in 0..rects.len() {
+            for j in i + 1..rects.len() {
+                if rects[i].intersects(rects[j]) {
+                    rects[i] = rects[i].union(rects.swap_remove(j));
+                    continue 'merge;
+                }
+            }
+        }
+        break;
+    }
+
+    if MAX_DAMAGE_RECTS < rects.len() {
+        merge_all(&rects)
+    } else {
+        rects
+    }
+}
+
+fn merge_all(rects: &[Rect]) -> Vec<Rect> {
+    vec![rects.iter().fold(Rect::NOTHING, |a, b| a.union(*b))]
+}
+
+#[cfg(test)]
+mod tests {
+    use epaint::{Color32, Shape, Stroke};
+
+    use super::*;
+    use crate::{Id, Order};
+
+    fn rect_shape(rect: Rect) -> ClippedShape {
+        ClippedShape::new(
+            Rect::EVERYTHING,
+            Shape::rect_filled(rect, 0.0, Color32::RED),
+        )
+    }
+
+    #[test]
+    fn only_changed_shapes_are_damaged() {
+        let viewport_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let layer_id = LayerId::new(Order::Background, Id::new("layer"));
+        let a = Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 20.0));
+        let b = Rect::from_min_max(pos2(50.0, 50.0), pos2(60.0, 60.0));
+        let cursor = ClippedShape::new(
+            Rect::EVERYTHING,
+            Shape::line_segment(
+                [pos2(30.0, 10.0), pos2(30.0, 20.0)],
+                Stroke::new(1.0, Color32::WHITE),
+            ),
+        );
+
+        let mut tracker = DamageTracker::default();
+        let layers = vec![(layer_id, vec![rect_shape(a), cursor.clone(), rect_shape(b)])];
+        assert_eq!(
+            tracker.update(&layers, viewport_rect, 1.0, false),
+            vec![viewport_rect],
+            "The first frame is damaged everywhere"
+        );
+        assert_eq!(tracker.update(&layers, viewport_rect, 1.0, false), vec![]);
+
+        // A blinking cursor:
+        let layers = vec![(layer_id, vec![rect_shape(a), rect_shape(b)])];
+        let damage = tracker.update(&layers, viewport_rect, 1.0, false);
+        assert_eq!(
+            damage,
+            vec![Rect::from_min_max(pos2(28.0, 8.0), pos2(32.0, 22.0))]
+        );
+
+        assert_eq!(
+            tracker.update(&layers, viewport_rect, 1.0, true),
+            vec![viewport_rect],
+            "Changed textures damages everything"
+        );
+    }
+
+    #[test]
+    fn nested_callbacks_are_always_damaged() {
+        let viewport_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let layer_id = LayerId::new(Order::Background, Id::new("layer"));
+        let callback = Shape::Callback(epaint::PaintCallback {
+            rect: Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 20.0)),
+            callback: std::sync::Arc::new(()),
+        });
+        let layers = vec![(
+            layer_id,
+            vec![ClippedShape::new(
+                Rect::EVERYTHING,
+                Shape::Vec(vec![callback]),
+            )],
+        )];
+
+        let mut tracker = DamageTracker::default();
+        tracker.update(&layers, viewport_rect, 1.0, false);
+        assert_eq!(
+            tracker.update(&layers, viewport_rect, 1.0, false),
+            vec![Rect::from_min_max(pos2(9.0, 9.0), pos2(21.0, 21.0))]
+        );
+    }
+
+    #[test]
+    fn damage_history_unions_by_buffer_age() {
+        let viewport_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let a = Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 20.0));
+        let b = Rect::from_min_max(pos2(15.0, 15.0), pos2(30.0, 30.0));
+
+        let mut history = DamageHistory::default();
+        history.push(vec![a]);
+        history.push(vec![]);
+        history.push(vec![b]);
+
+        assert_eq!(
+            history.damage_for_buffer_age(0, viewport_rect),
+            vec![viewport_rect]
+        );
+        assert_eq!(history.damage_for_buffer_age(1, viewport_rect), vec![b]);
+        assert_eq!(history.damage_for_buffer_age(2, viewport_rect), vec![b]);
+        assert_eq!(
+            history.damage_for_buffer_age(3, viewport_rect),
+            vec![a.union(b)]
+        );
+        assert_eq!(
+            history.damage_for_buffer_age(4, viewport_rect),
+            vec![viewport_rect]
+        );
+    }
+
+    #[test]
+    fn damage_rects_do_not_overlap() {
+        let viewport_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+        let layer_id = LayerId::new(Order::Background, Id::new("layer"));
+
+        let mut tracker = DamageTracker::default();
+        tracker.update(&[(layer_id, vec![])], viewport_rect, 1.0, false);
+
+        let layers = vec![(
+            layer_id,
+            vec![
+                rect_shape(Rect::from_min_max(pos2(10.0, 10.0), pos2(20.0, 20.0))),
+                rect_shape(Rect::from_min_max(pos2(15.0, 15.0), pos2(30.0, 30.0))),
+                rect_shape(Rect::from_min_max(pos2(70.0, 70.0), pos2(80.0, 80.0))),
+            ],
+        )];
+        let damage = tracker.update(&layers, viewport_rect, 1.0, false);
+        assert_eq!(
+            damage,
+            vec![
+                Rect::from_min_max(pos2(9.0, 9.0), pos2(31.0, 31.0)),
+                Rect::from_min_max(pos2(69.0, 69.0), pos2(81.0, 81.0)),
+            ]
+        );
+    }
+}
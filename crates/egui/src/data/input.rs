@@ -367,6 +367,32 @@ pub struct HoveredFile {
 
     /// With the `eframe` web backend, this is set to the mime-type of the file (if available).
     pub mime: String,
+
+    /// Where the file is being held, in points, if known.
+    ///
+    /// Set by the `eframe` web backend.
+    /// `winit` doesn't report where files are held, so this is always `None` on native.
+    ///
+    /// If `None`, each file goes to the first drop zone that accepts it,
+    /// see [`crate::Response::dnd_hover_files`].
+    pub pos: Option<Pos2>,
+}
+
+impl HoveredFile {
+    /// Does this file have one of the given extensions (case insensitive, without the leading dot)?
+    ///
+    /// An empty list accepts all files.
+    /// If the backend does not tell us the file name (e.g. on web), the mime type is checked instead,
+    /// and a file of unknown type is accepted.
+    pub fn has_extension(&self, extensions: &[&str]) -> bool {
+        if extensions.is_empty() {
+            return true;
+        }
+        if let Some(path) = &self.path {
+            return path_has_extension(path, extensions);
+        }
+        self.mime.is_empty() || mime_has_extension(&self.mime, extensions)
+    }
 }
 
 /// A file dropped into egui.
@@ -387,6 +413,52 @@ pub struct DroppedFile {
 
     /// Set by the `eframe` web backend.
     pub bytes: Option<std::sync::Arc<[u8]>>,
+
+    /// Where the file was dropped, in points, if known.
+    ///
+    /// Set by the `eframe` web backend.
+    /// `winit` doesn't report where files are dropped, so this is always `None` on native.
+    ///
+    /// If `None`, each file goes to the first drop zone that accepts it,
+    /// see [`crate::Response::dnd_release_files`].
+    pub pos: Option<Pos2>,
+}
+
+impl DroppedFile {
+    /// Does this file have one of the given extensions (case insensitive, without the leading dot)?
+    ///
+    /// An empty list accepts all files.
+    pub fn has_extension(&self, extensions: &[&str]) -> bool {
+        if extensions.is_empty() {
+            return true;
+        }
+        if let Some(path) = &self.path {
+            path_has_extension(path, extensions)
+        } else if !self.name.is_empty() {
+            path_has_extension(std::path::Path::new(&self.name), extensions)
+        } else {
+            mime_has_extension(&self.mime, extensions)
+        }
+    }
+}
+
+fn path_has_extension(path: &std::path::Path, extensions: &[&str]) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            extensions
+                .iter()
+                .any(|accepted| accepted.eq_ignore_ascii_case(extension))
+        })
+}
+
+/// `image/png` matches `png`.
+fn mime_has_extension(mime: &str, extensions: &[&str]) -> bool {
+    mime.rsplit('/').next().is_some_and(|subtype| {
+        extensions
+            .iter()
+            .any(|accepted| accepted.eq_ignore_ascii_case(subtype))
+    })
 }
 
 /// An input event generated by the integration.
@@ -404,6 +476,13 @@ pub enum Event {
     /// The integration detected a "paste" event (e.g. Cmd+V).
     Paste(String),
 
+    /// The user pasted something other than plain text, e.g. an image or HTML.
+    ///
+    /// The integration only sends this for the formats requested with
+    /// [`crate::Context::request_paste_format`].
+    /// Any plain text on the clipboard is sent separately, as [`Self::Paste`].
+    PasteData(PastedData),
+
     /// Text input, e.g. via keyboard.
     ///
     /// When the user presses enter/return, do not send a [`Text`](Event::Text) (just [`Key::Enter`]).
@@ -517,6 +596,20 @@ pub enum Event {
         force: Option<f32>,
     },
 
+    /// A pen (stylus) moved, touched the surface, or left the range of the tablet.
+    ///
+    /// Report this *in addition to* [`Self::PointerMoved`], [`Self::PointerButton`], [`Self::PointerGone`],
+    /// so that widgets can be used with the pen as usual.
+    ///
+    /// Which integrations report this:
+    /// * eframe on the web: everything, using the browser's pointer events.
+    /// * `egui-winit` (and thus native eframe): only pen contact and pressure on iOS,
+    ///   because `winit` doesn't report pen hover, tilt, barrel buttons or erasers.
+    ///   On Windows, macOS and Linux a pen is a mouse, and no [`Self::Pen`] events are sent.
+    ///
+    /// See [`crate::PointerState::pen`].
+    Pen(PenEvent),
+
     /// A raw mouse wheel event as sent by the backend.
     ///
     /// Used for scrolling.
@@ -550,6 +643,12 @@ pub enum Event {
     /// An assistive technology (e.g. screen reader) requested an action.
     AccessKitActionRequest(accesskit::ActionRequest),
 
+    /// An abstract navigation command, e.g. from a gamepad, a TV remote or a kiosk keypad.
+    ///
+    /// Unlike the arrow keys, these always move the focus,
+    /// even if the focused widget (e.g. a [`crate::TextEdit`]) would otherwise use the keys.
+    Navigate(NavigationEvent),
+
     /// The reply of a screenshot requested with [`crate::ViewportCommand::Screenshot`].
     Screenshot {
         viewport_id: crate::ViewportId,
@@ -561,6 +660,95 @@ pub enum Event {
     },
 }
 
+/// A clipboard format other than plain text, that a widget can ask to receive on paste.
+///
+/// See [`crate::Context::request_paste_format`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PasteFormat {
+    /// An image, sent as [`PastedData::Image`].
+    Image,
+
+    /// Data of the given mime type, e.g. `text/html`, sent as [`PastedData::Mime`].
+    Mime(String),
+}
+
+impl PasteFormat {
+    /// HTML, e.g. copied from a web browser or a word processor.
+    pub fn html() -> Self {
+        Self::Mime("text/html".to_owned())
+    }
+}
+
+/// Something that was pasted, other than plain text. See [`Event::PasteData`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PastedData {
+    /// An image, e.g. a screenshot.
+    Image(std::sync::Arc<ColorImage>),
+
+    /// Data of some mime type, e.g. `text/html`.
+    Mime {
+        mime: String,
+        bytes: std::sync::Arc<[u8]>,
+    },
+}
+
+impl PastedData {
+    /// The format of this data.
+    pub fn format(&self) -> PasteFormat {
+        match self {
+            Self::Image(_) => PasteFormat::Image,
+            Self::Mime { mime, .. } => PasteFormat::Mime(mime.clone()),
+        }
+    }
+
+    /// The data as text, if it is of a textual mime type (like `text/html`) and valid UTF-8.
+    pub fn text(&self) -> Option<&str> {
+        match self {
+            Self::Image(_) => None,
+            Self::Mime { mime, bytes } => {
+                if mime.starts_with("text/") {
+                    std::str::from_utf8(bytes).ok()
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// A navigation command that a backend can feed from any input device, e.g. a gamepad.
+///
+/// See [`Event::Navigate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum NavigationEvent {
+    /// Move the focus to the closest widget above (e.g. D-pad up).
+    Up,
+
+    /// Move the focus to the closest widget below.
+    Down,
+
+    /// Move the focus to the closest widget to the left.
+    Left,
+
+    /// Move the focus to the closest widget to the right.
+    Right,
+
+    /// Move the focus to the next widget in tab order (e.g. a right shoulder button).
+    Next,
+
+    /// Move the focus to the previous widget in tab order.
+    Previous,
+
+    /// Click the focused widget, like pressing Enter or Space (e.g. the A button).
+    Activate,
+
+    /// Treated like pressing Escape: closes popups and clears the focus (e.g. the B button).
+    Cancel,
+}
+
 /// IME event.
 ///
 /// See <https://docs.rs/winit/latest/winit/event/enum.Ime.html>
@@ -1217,6 +1405,71 @@ pub enum TouchPhase {
     Cancel,
 }
 
+/// The state of a pen (stylus), as reported by [`Event::Pen`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PenEvent {
+    /// Position of the tip of the pen, in points.
+    pub pos: Pos2,
+
+    /// Is the pen hovering, touching the surface, or gone?
+    pub phase: PenPhase,
+
+    /// How hard the pen is pressed against the surface,
+    /// from 0.0 (no pressure) to 1.0 (maximum pressure).
+    ///
+    /// Always 0.0 while hovering.
+    pub pressure: f32,
+
+    /// How much the pen is tilted away from being perpendicular to the surface, in radians.
+    ///
+    /// `x` is positive when the top of the pen leans to the right,
+    /// and `y` is positive when it leans towards the user.
+    ///
+    /// `None` if the platform does not report tilt.
+    pub tilt: Option<Vec2>,
+
+    /// Is the button on the side (barrel) of the pen held down?
+    pub barrel_button: bool,
+
+    /// Is the eraser end of the pen being used (or is the eraser button held down)?
+    pub eraser: bool,
+}
+
+impl PenEvent {
+    /// A pen at the given position, not tilted and with no buttons down.
+    pub fn new(pos: Pos2, phase: PenPhase, pressure: f32) -> Self {
+        Self {
+            pos,
+            phase,
+            pressure,
+            tilt: None,
+            barrel_button: false,
+            eraser: false,
+        }
+    }
+
+    /// Is the pen touching the surface?
+    #[inline]
+    pub fn is_in_contact(&self) -> bool {
+        self.phase == PenPhase::Contact
+    }
+}
+
+/// In what phase a [`PenEvent`] is in.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum PenPhase {
+    /// The pen is close to the surface, but not touching it.
+    Hover,
+
+    /// The pen is touching the surface.
+    Contact,
+
+    /// The pen has moved out of range of the surface.
+    Gone,
+}
+
 /// The unit associated with the numeric value of a mouse wheel event
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
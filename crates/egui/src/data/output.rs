@@ -28,6 +28,23 @@ pub struct FullOutput {
     /// You can pass this to [`crate::Context::tessellate`] together with [`Self::shapes`].
     pub pixels_per_point: f32,
 
+    /// The parts of the viewport that changed since the previous frame, in points.
+    ///
+    /// Only the area within these rectangles needs to be repainted,
+    /// if what was painted last frame is still there.
+    /// The rectangles don't overlap, and are aligned to the physical pixel grid.
+    /// If nothing changed, this is empty.
+    ///
+    /// Unless [`crate::Options::damage_tracking`] is on, this is always the whole viewport.
+    ///
+    /// This is relative to the previous frame. If you take turns painting into
+    /// two or more buffers (double or triple buffering), use a [`crate::DamageHistory`]
+    /// to also repaint what changed since the buffer was last painted.
+    ///
+    /// This is the damage of the viewport that [`Self::shapes`] are for.
+    /// It is also in [`crate::ViewportOutput::damage`] of that viewport.
+    pub damage: Vec<crate::Rect>,
+
     /// All the active viewports, including the root.
     ///
     /// It is up to the integration to spawn a native window for each viewport,
@@ -45,6 +62,7 @@ impl FullOutput {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage,
             viewport_output,
         } = newer;
 
@@ -53,6 +71,10 @@ impl FullOutput {
         self.shapes = shapes; // Only paint the latest
         self.pixels_per_point = pixels_per_point; // Use latest
 
+        // The older output was never painted, so also repaint what changed in it:
+        self.damage.extend(damage);
+        self.damage = crate::damage::merge_overlapping(std::mem::take(&mut self.damage));
+
         for (id, new_viewport) in viewport_output {
             match self.viewport_output.entry(id) {
                 Entry::Vacant(entry) => {
@@ -125,6 +147,14 @@ pub struct PlatformOutput {
     /// Useful for IME.
     pub ime: Option<IMEOutput>,
 
+    /// The clipboard formats, other than plain text, that widgets want to receive if the user pastes.
+    ///
+    /// The integration should remember these, and on the next paste
+    /// send whichever of them are on the clipboard as [`crate::Event::PasteData`].
+    ///
+    /// See [`crate::Context::request_paste_format`].
+    pub paste_formats: Vec<crate::PasteFormat>,
+
     /// The difference in the widget tree since last frame.
     ///
     /// NOTE: this needs to be per-viewport.
@@ -174,6 +204,7 @@ impl PlatformOutput {
             mut events,
             mutable_text_under_cursor,
             ime,
+            paste_formats,
             accesskit_update,
             num_completed_passes,
             mut request_discard_reasons,
@@ -184,6 +215,7 @@ impl PlatformOutput {
         self.events.append(&mut events);
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.ime = ime.or(self.ime);
+        self.paste_formats = paste_formats;
         self.num_completed_passes += num_completed_passes;
         self.request_discard_reasons
             .append(&mut request_discard_reasons);
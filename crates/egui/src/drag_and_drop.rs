@@ -1,6 +1,6 @@
 use std::{any::Any, sync::Arc};
 
-use crate::{Context, CursorIcon, Plugin};
+use crate::{Context, CursorIcon, Id, Plugin, Pos2};
 
 /// Plugin for tracking drag-and-drop payload.
 ///
@@ -14,6 +14,11 @@ use crate::{Context, CursorIcon, Plugin};
 /// - [`crate::Response::dnd_hover_payload`]
 /// - [`crate::Response::dnd_release_payload`]
 ///
+/// For files dragged from outside the app (e.g. from a file browser), see:
+/// - [`crate::Ui::dnd_file_drop_zone`]
+/// - [`crate::Response::dnd_hover_files`]
+/// - [`crate::Response::dnd_release_files`]
+///
 /// This is a built-in plugin in egui, automatically registered during [`Context`] creation.
 ///
 /// See [this example](https://github.com/emilk/egui/blob/main/crates/egui_demo_lib/src/demo/drag_and_drop.rs).
@@ -22,6 +27,10 @@ use crate::{Context, CursorIcon, Plugin};
 pub struct DragAndDrop {
     /// The current drag-and-drop payload, if any. Automatically cleared when drag ends.
     payload: Option<Arc<dyn Any + Send + Sync>>,
+
+    /// For each of the [`crate::RawInput::hovered_files`] without a position,
+    /// the first drop zone this pass that accepts it.
+    file_hover_zones: Vec<Option<Id>>,
 }
 
 impl Plugin for DragAndDrop {
@@ -33,6 +42,8 @@ impl Plugin for DragAndDrop {
     ///
     /// This needs to happen at frame start so we can properly capture the escape key.
     fn on_begin_pass(&mut self, ctx: &Context) {
+        self.file_hover_zones.clear();
+
         let has_any_payload = self.payload.is_some();
 
         if has_any_payload {
@@ -137,4 +148,38 @@ impl DragAndDrop {
     pub fn has_any_payload(ctx: &Context) -> bool {
         ctx.plugin::<Self>().lock().payload.is_some()
     }
+
+    /// Is the user holding files from outside the app over it?
+    ///
+    /// See [`crate::RawInput::hovered_files`].
+    pub fn is_hovering_files(ctx: &Context) -> bool {
+        ctx.input(|i| !i.raw.hovered_files.is_empty())
+    }
+
+    /// Where the files from outside the app are being held (or were dropped this frame), in points.
+    ///
+    /// `None` if no files are held, or if the integration doesn't report where they are,
+    /// which is always the case with `winit`.
+    pub fn file_drag_pos(ctx: &Context) -> Option<Pos2> {
+        ctx.input(|i| {
+            i.raw
+                .hovered_files
+                .iter()
+                .find_map(|file| file.pos)
+                .or_else(|| i.raw.dropped_files.iter().find_map(|file| file.pos))
+        })
+    }
+
+    /// Let the drop zone with the given id show the hovered file at `index`,
+    /// unless an earlier drop zone already does this pass.
+    ///
+    /// Used for files without a known position.
+    pub(crate) fn claim_hovered_file(ctx: &Context, index: usize, zone_id: Id) -> bool {
+        let dnd = ctx.plugin::<Self>();
+        let mut dnd = dnd.lock();
+        if dnd.file_hover_zones.len() <= index {
+            dnd.file_hover_zones.resize(index + 1, None);
+        }
+        *dnd.file_hover_zones[index].get_or_insert(zone_id) == zone_id
+    }
 }
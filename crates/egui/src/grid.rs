@@ -46,6 +46,22 @@ impl State {
         self.row_heights.get(row).copied()
     }
 
+    /// Combined width of the columns in `cols`, including the spacing between them.
+    fn span_width(&self, cols: std::ops::Range<usize>, x_spacing: f32) -> f32 {
+        let num_cols = cols.len();
+        cols.map(|col| self.col_width(col).unwrap_or(0.0))
+            .sum::<f32>()
+            + num_cols.saturating_sub(1) as f32 * x_spacing
+    }
+
+    /// Combined height of the rows in `rows`, including the spacing between them.
+    fn span_height(&self, rows: std::ops::Range<usize>, y_spacing: f32) -> f32 {
+        let num_rows = rows.len();
+        rows.map(|row| self.row_height(row).unwrap_or(0.0))
+            .sum::<f32>()
+            + num_rows.saturating_sub(1) as f32 * y_spacing
+    }
+
     fn full_width(&self, x_spacing: f32) -> f32 {
         self.col_widths.iter().sum::<f32>()
             + (self.col_widths.len().at_least(1) - 1) as f32 * x_spacing
@@ -54,6 +70,17 @@ impl State {
 
 // ----------------------------------------------------------------------------
 
+/// A cell that spans more than one row.
+///
+/// The columns it covers are skipped in the following rows,
+/// and its height is distributed onto its last row once that row ends.
+#[derive(Clone, Debug)]
+struct RowSpan {
+    cols: std::ops::Range<usize>,
+    rows: std::ops::Range<usize>,
+    height: f32,
+}
+
 // type alias for boxed function to determine row color during grid generation
 type ColorPickerFn = Box<dyn Send + Sync + Fn(usize, &Style) -> Option<Color32>>;
 
@@ -83,6 +110,12 @@ pub(crate) struct GridLayout {
     // Cursor:
     col: usize,
     row: usize,
+
+    /// Number of columns and rows the next cell spans, set with [`Ui::set_grid_span`].
+    span: (usize, usize),
+
+    /// Cells spanning several rows that cover cells of the current or coming rows.
+    row_spans: Vec<RowSpan>,
 }
 
 impl GridLayout {
@@ -117,6 +150,9 @@ impl GridLayout {
 
             col: 0,
             row: 0,
+
+            span: (1, 1),
+            row_spans: Vec::new(),
         }
     }
 }
@@ -134,24 +170,80 @@ impl GridLayout {
             .unwrap_or(self.min_cell_size.y)
     }
 
+    fn span_cols(&self) -> std::ops::Range<usize> {
+        self.col..self.col + self.span.0
+    }
+
+    fn span_rows(&self) -> std::ops::Range<usize> {
+        self.row..self.row + self.span.1
+    }
+
+    fn prev_span_width(&self) -> f32 {
+        self.span_cols()
+            .map(|col| self.prev_col_width(col))
+            .sum::<f32>()
+            + (self.span.0 - 1) as f32 * self.spacing.x
+    }
+
+    fn prev_span_height(&self) -> f32 {
+        self.span_rows()
+            .map(|row| self.prev_row_height(row))
+            .sum::<f32>()
+            + (self.span.1 - 1) as f32 * self.spacing.y
+    }
+
+    /// Make the next cell span `num_cols` columns and `num_rows` rows.
+    pub(crate) fn set_span(&mut self, num_cols: usize, num_rows: usize) {
+        let mut num_cols = num_cols.at_least(1);
+        if let Some(num_columns) = self.num_columns {
+            num_cols = num_cols.at_most(num_columns.saturating_sub(self.col).at_least(1));
+        }
+        self.span = (num_cols, num_rows.at_least(1));
+    }
+
+    fn is_covered(&self, col: usize) -> bool {
+        self.row_spans
+            .iter()
+            .any(|span| span.rows.contains(&self.row) && span.cols.contains(&col))
+    }
+
+    /// Move the cursor past any columns covered by cells from rows above.
+    fn skip_covered_cells(&mut self, cursor: &mut Rect) {
+        while self.is_covered(self.col) {
+            cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
+            self.col += 1;
+        }
+    }
+
     pub(crate) fn wrap_text(&self) -> bool {
         self.max_cell_size.x.is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
-        let is_last_column = Some(self.col + 1) == self.num_columns;
+        let is_last_column = Some(self.col + self.span.0) == self.num_columns;
 
         let width = if is_last_column {
             // The first frame we don't really know the widths of the previous columns,
             // so returning a big available width here can cause trouble.
             if self.is_first_frame {
-                self.curr_state
-                    .col_width(self.col)
-                    .unwrap_or(self.min_cell_size.x)
+                if 1 < self.span.0 {
+                    self.curr_state.span_width(self.span_cols(), self.spacing.x)
+                } else {
+                    self.curr_state
+                        .col_width(self.col)
+                        .unwrap_or(self.min_cell_size.x)
+                }
             } else {
                 (self.initial_available.right() - region.cursor.left())
                     .at_most(self.max_cell_size.x)
             }
+        } else if 1 < self.span.0 {
+            // Spanning cells get the combined width of the columns they cover:
+            if self.is_first_frame {
+                self.curr_state.span_width(self.span_cols(), self.spacing.x)
+            } else {
+                self.prev_span_width()
+            }
         } else if self.max_cell_size.x.is_finite() {
             // TODO(emilk): should probably heed `prev_state` here too
             self.max_cell_size.x
@@ -165,7 +257,7 @@ impl GridLayout {
         };
 
         // If something above was wider, we can be wider:
-        let width = width.max(self.curr_state.col_width(self.col).unwrap_or(0.0));
+        let width = width.max(self.curr_state.span_width(self.span_cols(), self.spacing.x));
 
         let available = region.max_rect.intersect(region.cursor);
 
@@ -178,8 +270,12 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
-        let height = self.prev_row_height(self.row);
+        let width = self.prev_state.span_width(self.span_cols(), self.spacing.x);
+        let height = if 1 < self.span.1 {
+            self.prev_span_height()
+        } else {
+            self.prev_row_height(self.row)
+        };
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size).round_ui()
     }
@@ -203,8 +299,8 @@ impl GridLayout {
             let debug_expand_height = self.style.debug.show_expand_height;
             if debug_expand_width || debug_expand_height {
                 let rect = widget_rect;
-                let too_wide = rect.width() > self.prev_col_width(self.col);
-                let too_high = rect.height() > self.prev_row_height(self.row);
+                let too_wide = rect.width() > self.prev_span_width();
+                let too_high = rect.height() > self.prev_span_height();
 
                 if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
                     let painter = self.ctx.debug_painter();
@@ -227,13 +323,52 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
-        self.curr_state
-            .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        let (num_cols, num_rows) = self.span;
+
+        if num_cols == 1 {
+            self.curr_state
+                .set_min_col_width(self.col, widget_rect.width().max(self.min_cell_size.x));
+        } else {
+            // Make sure all spanned columns exist, then put any missing width in the last one:
+            let last_col = self.col + num_cols - 1;
+            for col in self.span_cols() {
+                self.curr_state.set_min_col_width(col, self.min_cell_size.x);
+            }
+            let spanned_width = self
+                .span_cols()
+                .map(|col| {
+                    let prev = self.prev_state.col_width(col).unwrap_or(0.0);
+                    let curr = self.curr_state.col_width(col).unwrap_or(0.0);
+                    prev.max(curr)
+                })
+                .sum::<f32>()
+                + (num_cols - 1) as f32 * self.spacing.x;
+            let missing = widget_rect.width() - spanned_width;
+            if 0.0 < missing {
+                let last_width = self.curr_state.col_width(last_col).unwrap_or(0.0);
+                let last_width = last_width.max(self.prev_state.col_width(last_col).unwrap_or(0.0));
+                self.curr_state
+                    .set_min_col_width(last_col, last_width + missing);
+            }
+        }
 
-        cursor.min.x += self.prev_col_width(self.col) + self.spacing.x;
-        self.col += 1;
+        if num_rows == 1 {
+            self.curr_state
+                .set_min_row_height(self.row, widget_rect.height().max(self.min_cell_size.y));
+        } else {
+            // The height is distributed once we know the heights of the spanned rows.
+            self.row_spans.push(RowSpan {
+                cols: self.span_cols(),
+                rows: self.span_rows(),
+                height: widget_rect.height(),
+            });
+        }
+
+        cursor.min.x += self.prev_span_width() + self.spacing.x;
+        self.col += num_cols;
+        self.span = (1, 1);
+
+        self.skip_covered_cells(cursor);
     }
 
     fn paint_row(&self, cursor: &Rect, painter: &Painter) {
@@ -257,6 +392,25 @@ impl GridLayout {
     }
 
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
+        // Cells spanning down to this row may need it to be taller:
+        let row = self.row;
+        let min_row_height = self.min_cell_size.y;
+        for span in &self.row_spans {
+            if span.rows.end == row + 1 {
+                let above = self
+                    .curr_state
+                    .span_height(span.rows.start..row, self.spacing.y);
+                let above = if span.rows.start < row {
+                    above + self.spacing.y
+                } else {
+                    above
+                };
+                let needed = (span.height - above).at_least(min_row_height);
+                self.curr_state.set_min_row_height(row, needed);
+            }
+        }
+        self.row_spans.retain(|span| row + 1 < span.rows.end);
+
         cursor.min.x = self.initial_available.min.x;
         cursor.min.y += self.spacing.y;
         cursor.min.y += self
@@ -266,8 +420,11 @@ impl GridLayout {
 
         self.col = 0;
         self.row += 1;
+        self.span = (1, 1);
 
         self.paint_row(cursor, painter);
+
+        self.skip_covered_cells(cursor);
     }
 
     pub(crate) fn save(&self) {
@@ -290,6 +447,9 @@ impl GridLayout {
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
 ///
+/// A cell can span several columns and/or rows by calling [`Ui::set_grid_span`]
+/// before adding it. Cells covered by a cell spanning from a row above are skipped automatically.
+///
 /// ```
 /// # egui::__run_test_ui(|ui| {
 /// egui::Grid::new("some_unique_id").show(ui, |ui| {
@@ -305,6 +465,10 @@ impl GridLayout {
 ///     ui.horizontal(|ui| { ui.label("Same"); ui.label("cell"); });
 ///     ui.label("Third row, second column");
 ///     ui.end_row();
+///
+///     ui.set_grid_span(3, 1);
+///     ui.heading("Fourth row, spanning three columns");
+///     ui.end_row();
 /// });
 /// # });
 /// ```
@@ -2,7 +2,9 @@ use ahash::HashMap;
 
 use emath::TSTransform;
 
-use crate::{LayerId, Pos2, Rect, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet};
+use crate::{
+    HitShape, IdMap, LayerId, Pos2, Rect, Sense, WidgetRect, WidgetRects, ahash, emath, id::IdSet,
+};
 
 /// Result of a hit-test against [`WidgetRects`].
 ///
@@ -71,7 +73,7 @@ pub fn hit_test(
 
             let pos_in_layer = pos_in_layers.get(&w.layer_id).copied().unwrap_or(pos);
             // TODO(emilk): we should probably do the distance testing in global space instead
-            let dist_sq = w.interact_rect.distance_sq_to_pos(pos_in_layer);
+            let dist_sq = distance_sq(w, widgets.hit_shape(w.id), pos_in_layer);
 
             // In tie, pick last = topmost.
             if dist_sq <= closest_dist_sq {
@@ -93,6 +95,20 @@ pub fn hit_test(
 
     close.retain(|rect| !rect.interact_rect.any_nan()); // Protect against bad input and transforms
 
+    // The few close widgets with a non-rectangular hit shape, in global coordinates:
+    let hit_shapes: IdMap<HitShape> = close
+        .iter()
+        .filter_map(|w| {
+            let shape = widgets.hit_shape(w.id)?;
+            Some((
+                w.id,
+                layer_to_global
+                    .get(&w.layer_id)
+                    .map_or_else(|| shape.clone(), |to_global| shape.transform(*to_global)),
+            ))
+        })
+        .collect();
+
     // When using layer transforms it is common to stack layers close to each other.
     // For instance, you may have a resize-separator on a panel, with two
     // transform-layers on either side.
@@ -117,7 +133,8 @@ pub fn hit_test(
     let mut included_layers: ahash::HashSet<LayerId> = Default::default();
     for hit in close.iter().rev() {
         included_layers.insert(hit.layer_id);
-        let hit_covers_search_area = contains_circle(hit.interact_rect, pos, search_radius);
+        let hit_covers_search_area = contains_circle(hit.interact_rect, pos, search_radius)
+            && !hit_shapes.contains_key(&hit.id); // a shape could have holes in its corners
         if hit_covers_search_area {
             break; // nothing behind this layer could ever be interacted with
         }
@@ -152,11 +169,16 @@ pub fn hit_test(
 
     close.retain(|c| !hidden.contains(&c.id));
 
-    let mut hits = hit_test_on_close(&close, pos);
+    let mut hits = hit_test_on_close(&close, &hit_shapes, pos);
 
     hits.contains_pointer = close
         .iter()
-        .filter(|widget| widget.interact_rect.contains(pos))
+        .filter(|widget| {
+            widget.interact_rect.contains(pos)
+                && hit_shapes
+                    .get(&widget.id)
+                    .is_none_or(|shape| shape.contains(pos))
+        })
         .copied()
         .collect();
 
@@ -195,22 +217,30 @@ pub fn hit_test(
     hits
 }
 
+/// Distance to the part of the widget that can be hit, i.e. the interact rect and the hit shape (if any).
+fn distance_sq(widget: &WidgetRect, hit_shape: Option<&HitShape>, pos: Pos2) -> f32 {
+    let dist_sq = widget.interact_rect.distance_sq_to_pos(pos);
+    hit_shape.map_or(dist_sq, |shape| dist_sq.max(shape.distance_sq_to_pos(pos)))
+}
+
 /// Returns true if the rectangle contains the whole circle.
 fn contains_circle(interact_rect: emath::Rect, pos: Pos2, radius: f32) -> bool {
     interact_rect.shrink(radius).contains(pos)
 }
 
-fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
+fn hit_test_on_close(close: &[WidgetRect], hit_shapes: &IdMap<HitShape>, pos: Pos2) -> WidgetHits {
     #![allow(clippy::collapsible_else_if)]
 
     // First find the best direct hits:
     let hit_click = find_closest_within(
         close.iter().copied().filter(|w| w.sense.senses_click()),
+        hit_shapes,
         pos,
         0.0,
     );
     let hit_drag = find_closest_within(
         close.iter().copied().filter(|w| w.sense.senses_drag()),
+        hit_shapes,
         pos,
         0.0,
     );
@@ -224,6 +254,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
                     .iter()
                     .copied()
                     .filter(|w| w.sense.senses_click() || w.sense.senses_drag()),
+                hit_shapes,
                 pos,
             );
 
@@ -253,6 +284,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
 
             let closest_click = find_closest(
                 close.iter().copied().filter(|w| w.sense.senses_click()),
+                hit_shapes,
                 pos,
             );
             if let Some(closest_click) = closest_click {
@@ -314,6 +346,7 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
                         .iter()
                         .copied()
                         .filter(|w| w.sense.senses_drag() && w.id != hit_drag.id),
+                    hit_shapes,
                     pos,
                 );
 
@@ -406,12 +439,17 @@ fn hit_test_on_close(close: &[WidgetRect], pos: Pos2) -> WidgetHits {
     }
 }
 
-fn find_closest(widgets: impl Iterator<Item = WidgetRect>, pos: Pos2) -> Option<WidgetRect> {
-    find_closest_within(widgets, pos, f32::INFINITY)
+fn find_closest(
+    widgets: impl Iterator<Item = WidgetRect>,
+    hit_shapes: &IdMap<HitShape>,
+    pos: Pos2,
+) -> Option<WidgetRect> {
+    find_closest_within(widgets, hit_shapes, pos, f32::INFINITY)
 }
 
 fn find_closest_within(
     widgets: impl Iterator<Item = WidgetRect>,
+    hit_shapes: &IdMap<HitShape>,
     pos: Pos2,
     max_dist: f32,
 ) -> Option<WidgetRect> {
@@ -422,7 +460,7 @@ fn find_closest_within(
             continue;
         }
 
-        let dist_sq = widget.interact_rect.distance_sq_to_pos(pos);
+        let dist_sq = distance_sq(&widget, hit_shapes.get(&widget.id), pos);
 
         if let Some(closest) = closest
             && dist_sq == closest_dist_sq
@@ -507,22 +545,22 @@ mod tests {
         ];
 
         // Perfect hit:
-        let hits = hit_test_on_close(&widgets, pos2(15.0, 15.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(15.0, 15.0));
         assert_eq!(hits.click.unwrap().id, Id::new("click"));
         assert_eq!(hits.drag.unwrap().id, Id::new("bg-area"));
 
         // Close hit:
-        let hits = hit_test_on_close(&widgets, pos2(5.0, 5.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(5.0, 5.0));
         assert_eq!(hits.click.unwrap().id, Id::new("click"));
         assert_eq!(hits.drag.unwrap().id, Id::new("bg-area"));
 
         // Perfect hit:
-        let hits = hit_test_on_close(&widgets, pos2(105.0, 15.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(105.0, 15.0));
         assert_eq!(hits.click.unwrap().id, Id::new("click-and-drag"));
         assert_eq!(hits.drag.unwrap().id, Id::new("click-and-drag"));
 
         // Close hit - should still ignore the drag-background so as not to confuse the user:
-        let hits = hit_test_on_close(&widgets, pos2(105.0, 5.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(105.0, 5.0));
         assert_eq!(hits.click.unwrap().id, Id::new("click-and-drag"));
         assert_eq!(hits.drag.unwrap().id, Id::new("click-and-drag"));
     }
@@ -557,23 +595,112 @@ mod tests {
         }
 
         // In the middle of the bg-left-label:
-        let hits = hit_test_on_close(&widgets, pos2(25.0, 50.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(25.0, 50.0));
         assert_eq!(hits.click.unwrap().id, Id::new("bg-left-label"));
         assert_eq!(hits.drag.unwrap().id, Id::new("bg-left-label"));
 
         // On both the left click-and-drag and thin handle, but the thin handle is on top and should win:
-        let hits = hit_test_on_close(&widgets, pos2(35.0, 50.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(35.0, 50.0));
         assert_eq!(hits.click, None);
         assert_eq!(hits.drag.unwrap().id, Id::new("thin-drag-handle"));
 
         // Only on the thin-drag-handle:
-        let hits = hit_test_on_close(&widgets, pos2(50.0, 50.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(50.0, 50.0));
         assert_eq!(hits.click, None);
         assert_eq!(hits.drag.unwrap().id, Id::new("thin-drag-handle"));
 
         // On both the thin handle and right label. The label is on top and should win
-        let hits = hit_test_on_close(&widgets, pos2(65.0, 50.0));
+        let hits = hit_test_on_close(&widgets, &Default::default(), pos2(65.0, 50.0));
         assert_eq!(hits.click.unwrap().id, Id::new("fg-right-label"));
         assert_eq!(hits.drag.unwrap().id, Id::new("fg-right-label"));
     }
+
+    #[test]
+    fn hit_shape_contains() {
+        // Circle, with a point exactly on the edge:
+        let circle = HitShape::circle(pos2(10.0, 10.0), 5.0);
+        assert!(circle.contains(pos2(10.0, 10.0)));
+        assert!(circle.contains(pos2(15.0, 10.0)));
+        assert!(!circle.contains(pos2(15.1, 10.0)));
+        assert!(
+            !circle.contains(pos2(14.0, 14.0)),
+            "outside, but within the bounding rect"
+        );
+
+        // Rounded rect, with points on the straight edges and in a cut-off corner:
+        let rounded =
+            HitShape::rounded_rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 50.0)), 10.0);
+        assert!(rounded.contains(pos2(50.0, 0.0)));
+        assert!(rounded.contains(pos2(100.0, 25.0)));
+        assert!(rounded.contains(pos2(3.0, 3.0)));
+        assert!(!rounded.contains(pos2(1.0, 1.0)));
+        assert!(!rounded.contains(pos2(50.0, 50.1)));
+
+        // A corner radius larger than the rect is a stadium:
+        let stadium =
+            HitShape::rounded_rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 20.0)), 50.0);
+        assert!(stadium.contains(pos2(50.0, 0.0)));
+        assert!(stadium.contains(pos2(0.0, 10.0)));
+        assert!(!stadium.contains(pos2(1.0, 1.0)));
+
+        // A square rotated by 45°, in both winding orders:
+        let diamond = vec![
+            pos2(10.0, 0.0),
+            pos2(20.0, 10.0),
+            pos2(10.0, 20.0),
+            pos2(0.0, 10.0),
+        ];
+        for points in [diamond.clone(), diamond.into_iter().rev().collect()] {
+            let diamond = HitShape::convex_polygon(points);
+            assert!(diamond.contains(pos2(10.0, 10.0)));
+            assert!(diamond.contains(pos2(15.0, 5.0)), "on an edge");
+            assert!(diamond.contains(pos2(20.0, 10.0)), "on a corner");
+            assert!(!diamond.contains(pos2(1.0, 1.0)), "in the bounding rect");
+            assert!(!diamond.contains(pos2(20.1, 10.0)));
+        }
+
+        // Degenerate polygons only contain their edges:
+        let line = HitShape::convex_polygon(vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(5.0, 0.0)]);
+        assert!(line.contains(pos2(5.0, 0.0)));
+        assert!(!line.contains(pos2(11.0, 0.0)), "on the line, past the end");
+        assert!(!line.contains(pos2(5.0, 1.0)));
+        let point = HitShape::convex_polygon(vec![pos2(5.0, 5.0); 3]);
+        assert!(point.contains(pos2(5.0, 5.0)));
+        assert!(!point.contains(pos2(6.0, 5.0)));
+        assert!(!HitShape::convex_polygon(vec![]).contains(pos2(0.0, 0.0)));
+
+        // Polylines:
+        let polyline =
+            HitShape::polyline(vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)], 2.0);
+        assert!(polyline.contains(pos2(5.0, 2.0)));
+        assert!(polyline.contains(pos2(12.0, 5.0)));
+        assert!(!polyline.contains(pos2(5.0, 5.0)), "inside the bend");
+        assert!(HitShape::polyline(vec![pos2(0.0, 0.0)], 1.0).contains(pos2(1.0, 0.0)));
+        assert!(!HitShape::polyline(vec![], 1.0).contains(pos2(0.0, 0.0)));
+    }
+
+    #[test]
+    fn hit_shape_is_respected_by_hit_test() {
+        let widgets = vec![
+            wr(
+                Id::new("back"),
+                Sense::click(),
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0)),
+            ),
+            wr(
+                Id::new("knob"),
+                Sense::click(),
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(20.0, 20.0)),
+            ),
+        ];
+        let mut hit_shapes = IdMap::default();
+        hit_shapes.insert(Id::new("knob"), HitShape::circle(pos2(10.0, 10.0), 10.0));
+
+        let hits = hit_test_on_close(&widgets, &hit_shapes, pos2(10.0, 10.0));
+        assert_eq!(hits.click.unwrap().id, Id::new("knob"));
+
+        // In the corner of the knob's rect, but outside the circle:
+        let hits = hit_test_on_close(&widgets, &hit_shapes, pos2(1.0, 1.0));
+        assert_eq!(hits.click.unwrap().id, Id::new("back"));
+    }
 }
@@ -0,0 +1,150 @@
+//! Recognizing gestures (long-press, swipe, double-tap-and-drag) from pointer input.
+//!
+//! The thresholds come from the caller, usually from [`crate::style::Interaction`].
+//! See [`crate::Response::long_pressed`], [`crate::Response::swiped`] and [`crate::Response::double_tap_dragged`]
+//! for recognizing gestures on a specific widget.
+
+use crate::{
+    PointerButton,
+    emath::{Pos2, Vec2},
+};
+
+use super::InputState;
+
+/// The main direction of a [`Swipe`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum SwipeDirection {
+    /// Towards negative x.
+    Left,
+
+    /// Towards positive x.
+    Right,
+
+    /// Towards negative y, i.e. towards the top of the screen.
+    Up,
+
+    /// Towards positive y, i.e. towards the bottom of the screen.
+    Down,
+}
+
+impl SwipeDirection {
+    /// The direction of the dominant axis of `delta`.
+    pub fn from_delta(delta: Vec2) -> Self {
+        if delta.x.abs() >= delta.y.abs() {
+            if delta.x < 0.0 {
+                Self::Left
+            } else {
+                Self::Right
+            }
+        } else if delta.y < 0.0 {
+            Self::Up
+        } else {
+            Self::Down
+        }
+    }
+
+    /// A unit vector pointing in this direction.
+    pub fn to_vec2(self) -> Vec2 {
+        match self {
+            Self::Left => Vec2::LEFT,
+            Self::Right => Vec2::RIGHT,
+            Self::Up => Vec2::UP,
+            Self::Down => Vec2::DOWN,
+        }
+    }
+}
+
+/// A quick flick of the pointer (usually a finger) that was just released.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Swipe {
+    /// Where the swipe started.
+    pub start_pos: Pos2,
+
+    /// How far the pointer moved from the start of the swipe, in points.
+    pub delta: Vec2,
+
+    /// The velocity of the pointer when it was released, in points per second.
+    pub velocity: Vec2,
+
+    /// The main direction of [`Self::delta`].
+    pub direction: SwipeDirection,
+}
+
+impl InputState {
+    /// Has the primary button been held down without moving for `duration` seconds?
+    ///
+    /// Returns `true` only on the first frame after the duration has passed.
+    /// Use [`Self::long_press_remaining`] to know when to repaint.
+    pub fn long_press(&self, duration: f32) -> bool {
+        self.long_press_held().is_some_and(|held| {
+            let duration = duration as f64;
+            duration <= held && held - (self.unstable_dt as f64) < duration
+        })
+    }
+
+    /// If a long-press may be under way, how many seconds until it is recognized?
+    pub fn long_press_remaining(&self, duration: f32) -> Option<f32> {
+        let held = self.long_press_held()?;
+        let remaining = duration as f64 - held;
+        (0.0 < remaining).then_some(remaining as f32)
+    }
+
+    /// For how long has the primary button been held down without moving?
+    fn long_press_held(&self) -> Option<f64> {
+        let pointer = &self.pointer;
+        if pointer.primary_down() && !pointer.has_moved_too_much_for_a_click {
+            pointer.press_start_time.map(|start| self.time - start)
+        } else {
+            None
+        }
+    }
+
+    /// Was the primary button released this frame at the end of a swipe?
+    ///
+    /// A swipe must cover at least `min_distance` points
+    /// and be moving at least `min_velocity` points per second (in the direction of the swipe)
+    /// when it is released.
+    pub fn swipe(&self, min_distance: f32, min_velocity: f32) -> Option<Swipe> {
+        let pointer = &self.pointer;
+        if !pointer.button_released(PointerButton::Primary) {
+            return None;
+        }
+        let start_pos = pointer.released_press_origin?;
+        let end_pos = pointer.interact_pos?;
+
+        let delta = end_pos - start_pos;
+        let direction = SwipeDirection::from_delta(delta);
+        let velocity = pointer.velocity;
+
+        (min_distance <= delta.length() && min_velocity <= velocity.dot(direction.to_vec2()))
+            .then_some(Swipe {
+                start_pos,
+                delta,
+                velocity,
+                direction,
+            })
+    }
+
+    /// Is the pointer being dragged by a press that came quickly after a tap (click)?
+    ///
+    /// This is commonly used for one-finger zooming on touch screens.
+    pub fn is_double_tap_drag(&self) -> bool {
+        let pointer = &self.pointer;
+        pointer.press_follows_click && pointer.primary_down() && pointer.is_decidedly_dragging()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn swipe_direction() {
+        use SwipeDirection::{Down, Left, Right, Up};
+        assert_eq!(SwipeDirection::from_delta(Vec2::new(10.0, 3.0)), Right);
+        assert_eq!(SwipeDirection::from_delta(Vec2::new(-10.0, 3.0)), Left);
+        assert_eq!(SwipeDirection::from_delta(Vec2::new(1.0, -3.0)), Up);
+        assert_eq!(SwipeDirection::from_delta(Vec2::new(1.0, 3.0)), Down);
+    }
+}
@@ -1,3 +1,4 @@
+mod gestures;
 mod touch_state;
 mod wheel_state;
 
@@ -8,8 +9,8 @@ use crate::{
 };
 use crate::{
     data::input::{
-        Event, EventFilter, KeyboardShortcut, Modifiers, NUM_POINTER_BUTTONS, PointerButton,
-        RawInput, TouchDeviceId, ViewportInfo,
+        Event, EventFilter, KeyboardShortcut, Modifiers, NUM_POINTER_BUTTONS, NavigationEvent,
+        PenEvent, PenPhase, PointerButton, RawInput, TouchDeviceId, ViewportInfo,
     },
     input_state::wheel_state::WheelState,
 };
@@ -19,6 +20,7 @@ use std::{
 };
 
 pub use crate::Key;
+pub use gestures::{Swipe, SwipeDirection};
 pub use touch_state::MultiTouchInfo;
 use touch_state::TouchState;
 
@@ -396,6 +398,24 @@ impl InputState {
 
         self.wheel.smooth_wheel_delta = Vec2::ZERO;
 
+        // A navigation "cancel" (e.g. the B button on a gamepad) closes popups etc, just like Escape:
+        let num_cancels = new
+            .events
+            .iter()
+            .filter(|event| matches!(event, Event::Navigate(NavigationEvent::Cancel)))
+            .count();
+        for _ in 0..num_cancels {
+            for pressed in [true, false] {
+                new.events.push(Event::Key {
+                    key: Key::Escape,
+                    physical_key: None,
+                    pressed,
+                    repeat: false,
+                    modifiers: new.modifiers,
+                });
+            }
+        }
+
         for event in &mut new.events {
             match event {
                 Event::Key {
@@ -762,6 +782,15 @@ impl InputState {
             .count()
     }
 
+    /// Was the given [`NavigationEvent`] sent this frame?
+    ///
+    /// [`NavigationEvent::Cancel`] is also reported as a press of [`Key::Escape`].
+    pub fn navigation_pressed(&self, desired: NavigationEvent) -> bool {
+        self.events
+            .iter()
+            .any(|event| matches!(event, Event::Navigate(nav) if *nav == desired))
+    }
+
     /// Is the given key currently held down?
     pub fn key_down(&self, desired_key: Key) -> bool {
         self.keys_down.contains(&desired_key)
@@ -1017,6 +1046,16 @@ pub struct PointerState {
     /// `None` if no mouse button is down.
     press_start_time: Option<f64>,
 
+    /// Where did the click/drag that was released this frame originate?
+    ///
+    /// Used for recognizing swipes.
+    released_press_origin: Option<Pos2>,
+
+    /// Did the current press come shortly after a click?
+    ///
+    /// Used for recognizing double-tap-and-drag.
+    press_follows_click: bool,
+
     /// Set to `true` if the pointer has moved too much (since being pressed)
     /// for it to be registered as a click.
     pub(crate) has_moved_too_much_for_a_click: bool,
@@ -1041,6 +1080,12 @@ pub struct PointerState {
     /// All button events that occurred this frame
     pub(crate) pointer_events: Vec<PointerEvent>,
 
+    /// The latest state of the pen, if one is in range.
+    pen: Option<PenEvent>,
+
+    /// All pen events that occurred this frame.
+    pen_events: Vec<PenEvent>,
+
     /// Input state management configuration.
     ///
     /// This gets copied from `egui::Options` at the start of each frame for convenience.
@@ -1061,12 +1106,16 @@ impl Default for PointerState {
             down: Default::default(),
             press_origin: None,
             press_start_time: None,
+            released_press_origin: None,
+            press_follows_click: false,
             has_moved_too_much_for_a_click: false,
             started_decidedly_dragging: false,
             last_click_time: f64::NEG_INFINITY,
             last_last_click_time: f64::NEG_INFINITY,
             last_move_time: f64::NEG_INFINITY,
             pointer_events: vec![],
+            pen: None,
+            pen_events: vec![],
             options: Default::default(),
         }
     }
@@ -1081,6 +1130,8 @@ impl PointerState {
         self.options = options;
 
         self.pointer_events.clear();
+        self.pen_events.clear();
+        self.released_press_origin = None;
 
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
@@ -1128,6 +1179,8 @@ impl PointerState {
                     if pressed {
                         self.press_origin = Some(pos);
                         self.press_start_time = Some(time);
+                        self.press_follows_click =
+                            (time - self.last_click_time) < self.options.max_double_click_delay;
                         self.has_moved_too_much_for_a_click = false;
                         self.pointer_events.push(PointerEvent::Pressed {
                             position: pos,
@@ -1165,7 +1218,7 @@ impl PointerState {
                         self.pointer_events
                             .push(PointerEvent::Released { click, button });
 
-                        self.press_origin = None;
+                        self.released_press_origin = self.press_origin.take();
                         self.press_start_time = None;
                     }
 
@@ -1182,6 +1235,10 @@ impl PointerState {
                     clear_history_after_velocity_calculation = true;
                 }
                 Event::MouseMoved(delta) => *self.motion.get_or_insert(Vec2::ZERO) += *delta,
+                Event::Pen(pen) => {
+                    self.pen = (pen.phase != PenPhase::Gone).then_some(*pen);
+                    self.pen_events.push(*pen);
+                }
                 _ => {}
             }
         }
@@ -1490,6 +1547,45 @@ impl PointerState {
             })
     }
 
+    /// The latest state of the pen (stylus), if one is in range of the surface.
+    ///
+    /// This requires the integration to send [`Event::Pen`].
+    #[inline]
+    pub fn pen(&self) -> Option<&PenEvent> {
+        self.pen.as_ref()
+    }
+
+    /// Is a pen close to the surface, but not touching it?
+    #[inline]
+    pub fn is_pen_hovering(&self) -> bool {
+        self.pen.is_some_and(|pen| pen.phase == PenPhase::Hover)
+    }
+
+    /// How hard the pen is pressed against the surface, from 0 to 1.
+    ///
+    /// `None` if there is no pen touching the surface.
+    #[inline]
+    pub fn pen_pressure(&self) -> Option<f32> {
+        self.pen
+            .filter(PenEvent::is_in_contact)
+            .map(|pen| pen.pressure)
+    }
+
+    /// Is the eraser end of the pen being used?
+    #[inline]
+    pub fn is_pen_eraser(&self) -> bool {
+        self.pen.is_some_and(|pen| pen.eraser)
+    }
+
+    /// All pen events this frame, in order.
+    ///
+    /// A pen often reports many positions per frame.
+    /// Use all of them (and not just [`Self::pen`]) for smooth, pressure-sensitive strokes.
+    #[inline]
+    pub fn pen_events(&self) -> &[PenEvent] {
+        &self.pen_events
+    }
+
     /// Is the primary button currently down?
     #[inline(always)]
     pub fn primary_down(&self) -> bool {
@@ -1619,11 +1715,15 @@ impl PointerState {
             down,
             press_origin,
             press_start_time,
+            released_press_origin,
+            press_follows_click,
             has_moved_too_much_for_a_click,
             started_decidedly_dragging,
             last_click_time,
             last_last_click_time,
             pointer_events,
+            pen,
+            pen_events,
             last_move_time,
             options: _,
         } = self;
@@ -1640,6 +1740,8 @@ impl PointerState {
         ui.label(format!("down: {down:#?}"));
         ui.label(format!("press_origin: {press_origin:?}"));
         ui.label(format!("press_start_time: {press_start_time:?} s"));
+        ui.label(format!("released_press_origin: {released_press_origin:?}"));
+        ui.label(format!("press_follows_click: {press_follows_click}"));
         ui.label(format!(
             "has_moved_too_much_for_a_click: {has_moved_too_much_for_a_click}"
         ));
@@ -1650,5 +1752,7 @@ impl PointerState {
         ui.label(format!("last_last_click_time: {last_last_click_time:#?}"));
         ui.label(format!("last_move_time: {last_move_time:#?}"));
         ui.label(format!("pointer_events: {pointer_events:?}"));
+        ui.label(format!("pen: {pen:?}"));
+        ui.label(format!("pen_events: {pen_events:?}"));
     }
 }
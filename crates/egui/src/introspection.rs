@@ -97,6 +97,10 @@ impl Widget for &epaint::stats::PaintStats {
                 clipped_primitives,
                 vertices,
                 indices,
+                cache_hits,
+                cache_misses,
+                cached_vertices,
+                cached_indices,
             } = self;
 
             ui.label("Intermediate:");
@@ -124,6 +128,14 @@ impl Widget for &epaint::stats::PaintStats {
             label(ui, indices, "indices").on_hover_text("Three 32-bit indices per triangles");
             ui.add_space(10.0);
 
+            ui.label("Tessellation cache:");
+            ui.label(format!("{cache_hits:6} reused shapes"))
+                .on_hover_text("Shapes that were the same as last frame");
+            ui.label(format!("{cache_misses:6} new shapes"));
+            label(ui, cached_vertices, "vertices");
+            label(ui, cached_indices, "indices");
+            ui.add_space(10.0);
+
             // ui.label("Total:");
             // ui.label(self.total().format(""));
         })
@@ -152,6 +164,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 bezier_tolerance,
                 epsilon: _,
                 parallel_tessellation,
+                cache_meshes,
                 validate_meshes,
             } = self;
 
@@ -179,6 +192,8 @@ impl Widget for &mut epaint::TessellationOptions {
                 ).on_hover_text("Only available if epaint was compiled with the rayon feature")
                 .on_disabled_hover_text("epaint was not compiled with the rayon feature");
 
+            ui.checkbox(cache_meshes, "Cache meshes").on_hover_text("Reuse the meshes of shapes that haven't changed since the last frame.");
+
             ui.checkbox(validate_meshes, "Validate meshes").on_hover_text("Check that incoming meshes are valid, i.e. that all indices are in range, etc.");
 
             ui.collapsing("Align to pixel grid", |ui| {
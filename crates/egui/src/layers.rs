@@ -2,7 +2,9 @@
 //! are sometimes painted behind or in front of other things.
 
 use crate::{Id, IdMap, Rect, ahash, epaint};
-use epaint::{ClippedShape, Shape, emath::TSTransform};
+use std::sync::Arc;
+
+use epaint::{ClipMask, ClippedShape, Shape, emath::TSTransform};
 
 /// Different layer categories
 #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -132,7 +134,7 @@ impl PaintList {
     #[inline(always)]
     pub fn add(&mut self, clip_rect: Rect, shape: Shape) -> ShapeIdx {
         let idx = self.next_idx();
-        self.0.push(ClippedShape { clip_rect, shape });
+        self.0.push(ClippedShape::new(clip_rect, shape));
         idx
     }
 
@@ -140,7 +142,7 @@ impl PaintList {
         self.0.extend(
             shapes
                 .into_iter()
-                .map(|shape| ClippedShape { clip_rect, shape }),
+                .map(|shape| ClippedShape::new(clip_rect, shape)),
         );
     }
 
@@ -158,7 +160,7 @@ impl PaintList {
             return;
         }
 
-        self.0[idx.0] = ClippedShape { clip_rect, shape };
+        self.0[idx.0] = ClippedShape::new(clip_rect, shape);
     }
 
     /// Set the given shape to be empty (a `Shape::Noop`).
@@ -174,17 +176,26 @@ impl PaintList {
 
     /// Transform each [`Shape`] and clip rectangle by this much, in-place
     pub fn transform(&mut self, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0 {
-            *clip_rect = transform.mul_rect(*clip_rect);
-            shape.transform(transform);
+        for clipped_shape in &mut self.0 {
+            clipped_shape.transform(transform);
         }
     }
 
     /// Transform each [`Shape`] and clip rectangle in range by this much, in-place
     pub fn transform_range(&mut self, start: ShapeIdx, end: ShapeIdx, transform: TSTransform) {
-        for ClippedShape { clip_rect, shape } in &mut self.0[start.0..end.0] {
-            *clip_rect = transform.mul_rect(*clip_rect);
-            shape.transform(transform);
+        for clipped_shape in &mut self.0[start.0..end.0] {
+            clipped_shape.transform(transform);
+        }
+    }
+
+    /// Clip the shapes in range against this [`ClipMask`].
+    ///
+    /// Shapes that already have a clip mask keep theirs.
+    pub fn clip_range(&mut self, start: ShapeIdx, end: ShapeIdx, clip_mask: &Arc<ClipMask>) {
+        for clipped_shape in &mut self.0[start.0..end.0] {
+            if clipped_shape.clip_mask().is_none() {
+                clipped_shape.set_clip_mask(Some(Arc::clone(clip_mask)));
+            }
         }
     }
 
@@ -221,9 +232,23 @@ impl GraphicLayers {
         area_order: &[LayerId],
         to_global: &ahash::HashMap<LayerId, TSTransform>,
     ) -> Vec<ClippedShape> {
+        let layers = self.drain_layers(area_order, to_global);
+        let mut all_shapes = Vec::with_capacity(layers.iter().map(|(_, list)| list.len()).sum());
+        for (_, mut list) in layers {
+            all_shapes.append(&mut list);
+        }
+        all_shapes
+    }
+
+    /// Like [`Self::drain`], but keeps the shapes of each layer apart.
+    pub fn drain_layers(
+        &mut self,
+        area_order: &[LayerId],
+        to_global: &ahash::HashMap<LayerId, TSTransform>,
+    ) -> Vec<(LayerId, Vec<ClippedShape>)> {
         profiling::function_scope!();
 
-        let mut all_shapes: Vec<_> = Default::default();
+        let mut all_layers: Vec<_> = Default::default();
 
         for &order in &Order::ALL {
             let order_map = &mut self.0[order as usize];
@@ -243,7 +268,7 @@ impl GraphicLayers {
                             clipped_shape.transform(*to_global);
                         }
                     }
-                    all_shapes.append(&mut list.0);
+                    all_layers.push((*layer_id, std::mem::take(&mut list.0)));
                 }
             }
 
@@ -260,10 +285,10 @@ impl GraphicLayers {
                     }
                 }
 
-                all_shapes.append(&mut list.0);
+                all_layers.push((layer_id, std::mem::take(&mut list.0)));
             }
         }
 
-        all_shapes
+        all_layers
     }
 }
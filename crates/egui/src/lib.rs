@@ -410,6 +410,7 @@ mod atomics;
 pub mod cache;
 pub mod containers;
 mod context;
+mod damage;
 mod data;
 pub mod debug_text;
 mod drag_and_drop;
@@ -465,8 +466,9 @@ pub use emath::{
     remap_clamp, vec2,
 };
 pub use epaint::{
-    ClippedPrimitive, ColorImage, CornerRadius, ImageData, Margin, Mesh, PaintCallback,
-    PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind, TextureHandle, TextureId, mutex,
+    ClipMask, ClippedPrimitive, ColorImage, CornerRadius, FillRule, ImageData, LineCap, LineJoin,
+    Margin, Mesh, PaintCallback, PaintCallbackInfo, Shadow, Shape, Stroke, StrokeKind,
+    TextureHandle, TextureId, mutex,
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TextureOptions, TextureWrapMode, TexturesDelta},
 };
@@ -483,6 +485,7 @@ pub use self::{
     atomics::*,
     containers::{menu::MenuBar, *},
     context::{Context, RepaintCause, RequestRepaintInfo},
+    damage::DamageHistory,
     data::{
         Key, UserData,
         input::*,
@@ -495,11 +498,14 @@ pub use self::{
     epaint::text::TextWrapMode,
     grid::Grid,
     id::{Id, IdMap},
-    input_state::{InputOptions, InputState, MultiTouchInfo, PointerState, SurrenderFocusOn},
+    input_state::{
+        InputOptions, InputState, MultiTouchInfo, PointerState, SurrenderFocusOn, Swipe,
+        SwipeDirection,
+    },
     layers::{LayerId, Order},
     layout::*,
     load::SizeHint,
-    memory::{FocusDirection, Memory, Options, Theme, ThemePreference},
+    memory::{FocusDirection, FocusScope, Memory, Options, Theme, ThemePreference},
     painter::Painter,
     plugin::Plugin,
     response::{InnerResponse, Response},
@@ -510,7 +516,7 @@ pub use self::{
     ui_builder::UiBuilder,
     ui_stack::*,
     viewport::*,
-    widget_rect::{WidgetRect, WidgetRects},
+    widget_rect::{HitShape, WidgetRect, WidgetRects},
     widget_text::{RichText, WidgetText},
     widgets::*,
 };
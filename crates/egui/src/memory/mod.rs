@@ -6,8 +6,8 @@ use ahash::{HashMap, HashSet};
 use epaint::emath::TSTransform;
 
 use crate::{
-    EventFilter, Id, IdMap, LayerId, Order, Pos2, Rangef, RawInput, Rect, Style, Vec2, ViewportId,
-    ViewportIdMap, ViewportIdSet, area, vec2,
+    EventFilter, Id, IdMap, LayerId, Order, Pos2, RawInput, Rect, Style, ViewportId, ViewportIdMap,
+    ViewportIdSet, WidgetRects, area,
 };
 
 mod theme;
@@ -178,6 +178,43 @@ impl FocusDirection {
 
 // ----------------------------------------------------------------------------
 
+/// How a group of widgets treats keyboard focus.
+///
+/// A scope is attached to a [`crate::Ui`] with [`crate::Ui::focus_scope`],
+/// or to an [`crate::Area`] with [`crate::Area::focus_scope`].
+/// [`crate::Window`]s and [`crate::Popup`]s have a scope by default,
+/// and [`crate::Modal`]s have a trapping one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FocusScope {
+    /// If `true`, Tab and Shift+Tab (and the arrow keys) cycle within the scope instead of leaving it.
+    ///
+    /// While a trapping scope is shown, pressing Tab with the focus outside of it moves the focus into it.
+    pub trap: bool,
+
+    /// If `true`, focus goes back to the widget that had it when the scope first appeared,
+    /// once the scope disappears while containing the focus.
+    pub restore_focus: bool,
+}
+
+impl Default for FocusScope {
+    fn default() -> Self {
+        Self {
+            trap: false,
+            restore_focus: true,
+        }
+    }
+}
+
+impl FocusScope {
+    /// A scope that keeps Tab-navigation inside of it, and restores focus when it closes.
+    pub const TRAP: Self = Self {
+        trap: true,
+        restore_focus: true,
+    };
+}
+
+// ----------------------------------------------------------------------------
+
 /// Some global options that you can read and write.
 ///
 /// See also [`crate::style::DebugOptions`].
@@ -244,6 +281,13 @@ pub struct Options {
     /// (<https://github.com/rerun-io/rerun/issues/5018>).
     pub repaint_on_widget_change: bool,
 
+    /// If `true`, compare what is painted with the previous frame,
+    /// and report the parts of the screen that changed in [`crate::FullOutput::damage`].
+    ///
+    /// This lets the backend repaint only those parts.
+    /// It costs a copy of all shapes each frame, so it is off by default.
+    pub damage_tracking: bool,
+
     /// Maximum number of passes to run in one frame.
     ///
     /// Set to `1` for pure single-pass immediate mode.
@@ -306,6 +350,7 @@ impl Default for Options {
             zoom_with_keyboard: true,
             tessellation_options: Default::default(),
             repaint_on_widget_change: false,
+            damage_tracking: false,
             max_passes: NonZeroUsize::new(2).unwrap(),
             screen_reader: false,
             warn_on_id_clash: cfg!(debug_assertions),
@@ -363,6 +408,7 @@ impl Options {
             zoom_with_keyboard,
             tessellation_options,
             repaint_on_widget_change,
+            damage_tracking,
             max_passes,
             screen_reader: _, // needs to come from the integration
             warn_on_id_clash,
@@ -386,6 +432,9 @@ impl Options {
                     "Repaint if any widget moves or changes id",
                 );
 
+                ui.checkbox(damage_tracking, "Track damaged regions")
+                    .on_hover_text("Find what parts of the screen changed since the last frame");
+
                 ui.horizontal(|ui| {
                     ui.label("Zoom factor:");
                     ui.add(crate::DragValue::new(zoom_factor).range(0.10..=10.0));
@@ -472,13 +521,6 @@ pub(crate) struct Focus {
 
     id_requested_by_accesskit: Option<accesskit::NodeId>,
 
-    /// If set, the next widget that is interested in focus will automatically get it.
-    /// Probably because the user pressed Tab.
-    give_to_next: bool,
-
-    /// The last widget interested in focus.
-    last_interested: Option<Id>,
-
     /// Set when looking for widget with navigational keys like arrows, tab, shift+tab.
     focus_direction: FocusDirection,
 
@@ -488,8 +530,59 @@ pub(crate) struct Focus {
     /// The top-most modal layer from the current frame.
     top_modal_layer_current_frame: Option<LayerId>,
 
-    /// A cache of widget IDs that are interested in focus with their corresponding rectangles.
-    focus_widgets_cache: IdMap<Rect>,
+    /// Was the focus last moved with the keyboard or another navigation input (rather than the pointer)?
+    ///
+    /// If so, we show the [`crate::style::FocusRing`].
+    focus_visible: bool,
+
+    /// The widgets interested in focus during the previous pass, in the order they were added.
+    tab_order: Vec<FocusCandidate>,
+
+    /// The widgets interested in focus so far this pass, in the order they were added.
+    tab_order_current: Vec<FocusCandidate>,
+
+    /// Index into [`Self::tab_order_current`].
+    tab_order_index: IdMap<usize>,
+
+    /// All focus scopes shown this pass or the previous one.
+    scopes: IdMap<FocusScopeState>,
+
+    /// The scopes we are currently inside of, innermost last.
+    scope_stack: Vec<Id>,
+
+    /// Incremented every time a new scope appears.
+    scope_counter: u64,
+}
+
+/// A widget that is interested in focus.
+#[derive(Clone, Copy, Debug)]
+struct FocusCandidate {
+    id: Id,
+
+    /// The innermost scope the widget is in.
+    scope: Option<Id>,
+
+    /// Positive indices come first (in increasing order), then zero in the order the widgets were added.
+    /// Negative indices are skipped by Tab.
+    tab_index: i32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct FocusScopeState {
+    scope: FocusScope,
+    parent: Option<Id>,
+
+    /// The widget that had focus when the scope appeared.
+    opener: Option<Id>,
+
+    /// Value of [`Focus::scope_counter`] when the scope appeared. The newest trap is the active one.
+    opened: u64,
+
+    /// Was the scope shown this pass?
+    alive: bool,
+
+    /// Did a widget in this scope have focus at the end of the previous pass?
+    had_focus: bool,
 }
 
 /// The widget with focus.
@@ -563,6 +656,42 @@ impl Focus {
                 self.focus_direction = cardinality;
             }
 
+            if let crate::Event::Navigate(navigation) = event {
+                use crate::NavigationEvent;
+                self.focus_direction = match navigation {
+                    NavigationEvent::Up => FocusDirection::Up,
+                    NavigationEvent::Down => FocusDirection::Down,
+                    NavigationEvent::Left => FocusDirection::Left,
+                    NavigationEvent::Right => FocusDirection::Right,
+                    NavigationEvent::Next => FocusDirection::Next,
+                    NavigationEvent::Previous => FocusDirection::Previous,
+                    NavigationEvent::Cancel => {
+                        self.focused_widget = None;
+                        FocusDirection::None
+                    }
+                    NavigationEvent::Activate => self.focus_direction, // handled by the focused widget
+                };
+            }
+
+            if self.focus_direction != FocusDirection::None {
+                self.focus_visible = true;
+            }
+            if let crate::Event::PointerButton { pressed: true, .. } = event {
+                self.focus_visible = false;
+            }
+
+            if matches!(
+                self.focus_direction,
+                FocusDirection::Next | FocusDirection::Previous
+            ) {
+                // Use the tab order of the previous pass, so the new widget has focus for this whole pass:
+                let forward = self.focus_direction == FocusDirection::Next;
+                if let Some(id) = self.next_in_tab_order(&self.tab_order, forward) {
+                    self.focused_widget = Some(FocusWidget::new(id));
+                }
+                self.reset_focus();
+            }
+
             if let crate::Event::AccessKitActionRequest(accesskit::ActionRequest {
                 action: accesskit::Action::Focus,
                 target,
@@ -575,10 +704,16 @@ impl Focus {
     }
 
     pub(crate) fn end_pass(&mut self, used_ids: &IdMap<Rect>) {
-        if self.focus_direction.is_cardinal()
-            && let Some(found_widget) = self.find_widget_in_direction(used_ids)
-        {
-            self.focused_widget = Some(FocusWidget::new(found_widget));
+        if matches!(
+            self.focus_direction,
+            FocusDirection::Next | FocusDirection::Previous
+        ) {
+            // `Memory::move_focus` was called during the pass:
+            let forward = self.focus_direction == FocusDirection::Next;
+            if let Some(id) = self.next_in_tab_order(&self.tab_order_current, forward) {
+                self.focused_widget = Some(FocusWidget::new(id));
+            }
+            self.reset_focus();
         }
 
         if let Some(focused_widget) = self.focused_widget {
@@ -591,55 +726,188 @@ impl Focus {
             }
         }
 
+        self.end_pass_scopes();
+
         self.top_modal_layer = self.top_modal_layer_current_frame.take();
     }
 
-    pub(crate) fn had_focus_last_frame(&self, id: Id) -> bool {
-        self.id_previous_frame == Some(id)
+    /// Restore focus for closed scopes, and start a new tab order.
+    fn end_pass_scopes(&mut self) {
+        let focus_is_on_live_widget = self
+            .focused()
+            .is_some_and(|id| self.tab_order_index.contains_key(&id));
+
+        let mut closed: Vec<(Id, FocusScopeState)> = self
+            .scopes
+            .iter()
+            .filter(|(_, state)| !state.alive)
+            .map(|(id, state)| (*id, *state))
+            .collect();
+        // Newest first, so that the opener of the oldest closed scope wins:
+        closed.sort_by_key(|(_, state)| std::cmp::Reverse(state.opened));
+        for (_, state) in &closed {
+            if state.scope.restore_focus
+                && state.had_focus
+                && !focus_is_on_live_widget
+                && let Some(opener) = state.opener
+            {
+                self.focused_widget = Some(FocusWidget::new(opener));
+            }
+        }
+        for (id, _) in closed {
+            self.scopes.remove(&id);
+        }
+
+        let focused_scope = self
+            .focused()
+            .and_then(|id| self.tab_order_index.get(&id))
+            .and_then(|&index| self.tab_order_current[index].scope);
+        let had_focus: Vec<(Id, bool)> = self
+            .scopes
+            .keys()
+            .map(|&id| (id, self.scope_contains(focused_scope, id)))
+            .collect();
+        for (id, had_focus) in had_focus {
+            if let Some(state) = self.scopes.get_mut(&id) {
+                state.had_focus = had_focus;
+                state.alive = false;
+            }
+        }
+
+        self.tab_order = std::mem::take(&mut self.tab_order_current);
+        self.tab_order_index.clear();
+        self.scope_stack.clear();
+    }
+
+    fn push_scope(&mut self, id: Id, scope: FocusScope) {
+        let parent = self.scope_stack.last().copied();
+        let focused = self.focused();
+        let counter = &mut self.scope_counter;
+        let state = self.scopes.entry(id).or_insert_with(|| {
+            *counter += 1;
+            FocusScopeState {
+                scope,
+                parent,
+                opener: focused,
+                opened: *counter,
+                alive: false,
+                had_focus: false,
+            }
+        });
+        state.scope = scope;
+        state.parent = parent;
+        state.alive = true;
+        self.scope_stack.push(id);
+    }
+
+    fn pop_scope(&mut self) {
+        self.scope_stack.pop();
+    }
+
+    /// Is `scope`, or one of its parents, the scope `ancestor`?
+    fn scope_contains(&self, mut scope: Option<Id>, ancestor: Id) -> bool {
+        while let Some(id) = scope {
+            if id == ancestor {
+                return true;
+            }
+            scope = self.scopes.get(&id).and_then(|state| state.parent);
+        }
+        false
+    }
+
+    /// The innermost trapping scope that `scope` is in.
+    fn innermost_trap(&self, mut scope: Option<Id>) -> Option<Id> {
+        while let Some(id) = scope {
+            let state = self.scopes.get(&id)?;
+            if state.scope.trap {
+                return Some(id);
+            }
+            scope = state.parent;
+        }
+        None
+    }
+
+    /// The scope that keyboard navigation is limited to, if any.
+    fn navigation_domain(&self, order: &[FocusCandidate]) -> Option<Id> {
+        let focused_scope = self
+            .focused()
+            .and_then(|id| order.iter().find(|candidate| candidate.id == id))
+            .map(|candidate| candidate.scope);
+
+        let active_trap = self
+            .scopes
+            .iter()
+            .filter(|(_, state)| state.scope.trap)
+            .max_by_key(|(_, state)| state.opened)
+            .map(|(id, _)| *id);
+
+        match (active_trap, focused_scope) {
+            (Some(trap), Some(scope)) if self.scope_contains(scope, trap) => {
+                self.innermost_trap(scope)
+            }
+            (Some(trap), _) => Some(trap),
+            (None, Some(scope)) => self.innermost_trap(scope),
+            (None, None) => None,
+        }
+    }
+
+    /// The widget after (or before) the focused one in tab order,
+    /// wrapping around within the navigation domain.
+    fn next_in_tab_order(&self, order: &[FocusCandidate], forward: bool) -> Option<Id> {
+        let domain = self.navigation_domain(order);
+        let mut candidates: Vec<&FocusCandidate> = order
+            .iter()
+            .filter(|candidate| candidate.tab_index >= 0)
+            .filter(|candidate| {
+                domain.is_none_or(|domain| self.scope_contains(candidate.scope, domain))
+            })
+            .collect();
+        candidates.sort_by_key(|candidate| {
+            if candidate.tab_index > 0 {
+                (0, candidate.tab_index)
+            } else {
+                (1, 0)
+            }
+        });
+
+        let len = candidates.len();
+        if len == 0 {
+            return None;
+        }
+        let position = self
+            .focused()
+            .and_then(|id| candidates.iter().position(|candidate| candidate.id == id));
+        let next = match (position, forward) {
+            (Some(i), true) => (i + 1) % len,
+            (Some(i), false) => (i + len - 1) % len,
+            (None, true) => 0,
+            (None, false) => len - 1,
+        };
+        Some(candidates[next].id)
     }
 
     fn interested_in_focus(&mut self, id: Id) {
         if self.id_requested_by_accesskit == Some(id.accesskit_id()) {
             self.focused_widget = Some(FocusWidget::new(id));
             self.id_requested_by_accesskit = None;
-            self.give_to_next = false;
             self.reset_focus();
         }
 
-        // The rect is updated at the end of the frame.
-        self.focus_widgets_cache
-            .entry(id)
-            .or_insert(Rect::EVERYTHING);
-
-        if self.give_to_next && !self.had_focus_last_frame(id) {
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.give_to_next = false;
-        } else if self.focused() == Some(id) {
-            if self.focus_direction == FocusDirection::Next {
-                self.focused_widget = None;
-                self.give_to_next = true;
-                self.reset_focus();
-            } else if self.focus_direction == FocusDirection::Previous {
-                self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
-                self.reset_focus();
-            }
-        } else if self.focus_direction == FocusDirection::Next
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
-            self.focused_widget = Some(FocusWidget::new(id));
-            self.reset_focus();
-        } else if self.focus_direction == FocusDirection::Previous
-            && self.focused_widget.is_none()
-            && !self.give_to_next
-        {
-            // nothing has focus and the user pressed Shift+Tab - give focus to the last widgets that wants it:
-            self.focused_widget = self.last_interested.map(FocusWidget::new);
-            self.reset_focus();
+        if !self.tab_order_index.contains_key(&id) {
+            self.tab_order_index
+                .insert(id, self.tab_order_current.len());
+            self.tab_order_current.push(FocusCandidate {
+                id,
+                scope: self.scope_stack.last().copied(),
+                tab_index: 0,
+            });
         }
+    }
 
-        self.last_interested = Some(id);
+    fn set_tab_index(&mut self, id: Id, tab_index: i32) {
+        if let Some(&index) = self.tab_order_index.get(&id) {
+            self.tab_order_current[index].tab_index = tab_index;
+        }
     }
 
     fn set_modal_layer(&mut self, layer_id: LayerId) {
@@ -654,77 +922,107 @@ impl Focus {
         self.focus_direction = FocusDirection::None;
     }
 
-    fn find_widget_in_direction(&mut self, new_rects: &IdMap<Rect>) -> Option<Id> {
-        // NOTE: `new_rects` here include some widgets _not_ interested in focus.
-
-        /// * negative if `a` is left of `b`
-        /// * positive if `a` is right of `b`
-        /// * zero if the ranges overlap significantly
-        fn range_diff(a: Rangef, b: Rangef) -> f32 {
-            let has_significant_overlap = a.intersection(b).span() >= 0.5 * b.span().min(a.span());
-            if has_significant_overlap {
-                0.0
-            } else {
-                a.center() - b.center()
-            }
+    /// Move the focus to the closest widget in the (cardinal) focus direction, if any.
+    ///
+    /// `current_pass` selects between the widgets interested in focus this pass or the previous one,
+    /// and `widget_rects` must be from the same pass.
+    fn navigate_spatially(
+        &mut self,
+        widget_rects: &WidgetRects,
+        to_global: &HashMap<LayerId, TSTransform>,
+        current_pass: bool,
+    ) {
+        if !self.focus_direction.is_cardinal() {
+            return;
         }
+        let order = if current_pass {
+            &self.tab_order_current
+        } else {
+            &self.tab_order
+        };
+        let global_rect = |id: Id| {
+            let widget = widget_rects.get(id)?;
+            Some(
+                to_global
+                    .get(&widget.layer_id)
+                    .map_or(widget.rect, |transform| *transform * widget.rect),
+            )
+        };
+        if let Some(id) = self.find_widget_in_direction(order, global_rect) {
+            self.focused_widget = Some(FocusWidget::new(id));
+        }
+        self.reset_focus();
+    }
 
-        let current_focused = self.focused_widget?;
-
-        // In what direction we are looking for the next widget.
-        let search_direction = match self.focus_direction {
-            FocusDirection::Up => Vec2::UP,
-            FocusDirection::Right => Vec2::RIGHT,
-            FocusDirection::Down => Vec2::DOWN,
-            FocusDirection::Left => Vec2::LEFT,
+    fn find_widget_in_direction(
+        &self,
+        order: &[FocusCandidate],
+        global_rect: impl Fn(Id) -> Option<Rect>,
+    ) -> Option<Id> {
+        let domain = self.navigation_domain(order);
+        let candidates = order
+            .iter()
+            .filter(|candidate| {
+                domain.is_none_or(|domain| self.scope_contains(candidate.scope, domain))
+            })
+            .filter_map(|candidate| Some((candidate.id, global_rect(candidate.id)?)));
+
+        let (axis, sign) = match self.focus_direction {
+            FocusDirection::Up => (1, -1.0),
+            FocusDirection::Right => (0, 1.0),
+            FocusDirection::Down => (1, 1.0),
+            FocusDirection::Left => (0, -1.0),
             _ => {
                 return None;
             }
         };
 
-        // Update cache with new rects
-        self.focus_widgets_cache.retain(|id, old_rect| {
-            if let Some(new_rect) = new_rects.get(id) {
-                *old_rect = *new_rect;
-                true // Keep the item
-            } else {
-                false // Remove the item
-            }
-        });
-
-        let current_rect = self.focus_widgets_cache.get(&current_focused.id)?;
+        let Some((current_id, current)) =
+            self.focused().and_then(|id| Some((id, global_rect(id)?)))
+        else {
+            // Nothing focused: start at the top-left (or bottom-right, when going backwards):
+            return candidates
+                .min_by(|(_, a), (_, b)| {
+                    let (a, b) = (sign * a.center(), sign * b.center());
+                    a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x))
+                })
+                .map(|(id, _)| id);
+        };
 
+        let other_axis = 1 - axis;
         let mut best_score = f32::INFINITY;
         let mut best_id = None;
 
-        // iteration order should only matter in case of a tie, and that should be very rare
-        #[expect(clippy::iter_over_hash_type)]
-        for (candidate_id, candidate_rect) in &self.focus_widgets_cache {
-            if *candidate_id == current_focused.id {
+        for (candidate_id, candidate) in candidates {
+            if candidate_id == current_id {
                 continue;
             }
 
-            // There is a lot of room for improvement here.
-            let to_candidate = vec2(
-                range_diff(candidate_rect.x_range(), current_rect.x_range()),
-                range_diff(candidate_rect.y_range(), current_rect.y_range()),
-            );
-
-            let acos_angle = to_candidate.normalized().dot(search_direction);
-
-            // Only interested in widgets that fall in a 90° cone (±45°)
-            // of the search direction.
-            let is_in_search_cone = 0.5_f32.sqrt() <= acos_angle;
-            if is_in_search_cone {
-                let distance = to_candidate.length();
-
-                // There is a lot of room for improvement here.
-                let score = distance / (acos_angle * acos_angle);
+            // The candidate must be further along in the search direction…
+            let is_ahead = sign * (candidate.center()[axis] - current.center()[axis]) > 0.0
+                && sign * (candidate.max[axis] - current.max[axis]) > 0.0
+                && sign * (candidate.min[axis] - current.min[axis]) > 0.0;
+            if !is_ahead {
+                continue;
+            }
 
-                if score < best_score {
-                    best_score = score;
-                    best_id = Some(*candidate_id);
-                }
+            // …and we prefer it close, and lined up with the current widget:
+            let along = if sign > 0.0 {
+                candidate.min[axis] - current.max[axis]
+            } else {
+                current.min[axis] - candidate.max[axis]
+            }
+            .max(0.0);
+            let across = (candidate.min[other_axis] - current.max[other_axis])
+                .max(current.min[other_axis] - candidate.max[other_axis])
+                .max(0.0);
+            let center_offset =
+                (candidate.center()[other_axis] - current.center()[other_axis]).abs();
+
+            let score = along + 2.0 * across + 0.1 * center_offset;
+            if score < best_score {
+                best_score = score;
+                best_id = Some(candidate_id);
             }
         }
 
@@ -879,6 +1177,26 @@ impl Memory {
         self.focus_mut().focus_direction = direction;
     }
 
+    /// Was the focus last moved with the keyboard (or another navigation input), rather than the pointer?
+    ///
+    /// When true, egui paints the [`crate::style::FocusRing`] around the focused widget.
+    pub fn focus_visible(&self) -> bool {
+        self.focus().is_some_and(|focus| focus.focus_visible) && self.focused().is_some()
+    }
+
+    /// Handle arrow-key (and [`crate::NavigationEvent`]) focus movement,
+    /// using the widgets of the previous pass (`current_pass == false`) or of this one.
+    pub(crate) fn navigate_focus_spatially(
+        &mut self,
+        widget_rects: &WidgetRects,
+        current_pass: bool,
+    ) {
+        let Some(focus) = self.focus.get_mut(&self.viewport_id) else {
+            return;
+        };
+        focus.navigate_spatially(widget_rects, &self.to_global, current_pass);
+    }
+
     /// Returns true if
     /// - this layer is the top-most modal layer or above it
     /// - there is no modal layer
@@ -920,6 +1238,31 @@ impl Memory {
         self.focus_mut().interested_in_focus(id);
     }
 
+    /// Set the position of a widget in the Tab order for this pass.
+    ///
+    /// Widgets with a positive index are visited first, in increasing order,
+    /// followed by all widgets with index zero (the default) in the order they were added.
+    /// Widgets with a negative index are skipped by Tab, but can still be focused in other ways.
+    ///
+    /// Must be called after the widget has registered interest in focus, every pass.
+    /// See also [`crate::Response::tab_index`].
+    pub fn set_tab_index(&mut self, id: Id, tab_index: i32) {
+        self.focus_mut().set_tab_index(id, tab_index);
+    }
+
+    /// Start a [`FocusScope`]. All widgets interested in focus until the matching
+    /// [`Self::pop_focus_scope`] belong to it.
+    ///
+    /// You probably want to use [`crate::Ui::focus_scope`] instead.
+    pub fn push_focus_scope(&mut self, id: Id, scope: FocusScope) {
+        self.focus_mut().push_scope(id, scope);
+    }
+
+    /// End the [`FocusScope`] started by the last call to [`Self::push_focus_scope`].
+    pub fn pop_focus_scope(&mut self) {
+        self.focus_mut().pop_scope();
+    }
+
     /// Limit focus to widgets on the given layer and above.
     /// If this is called multiple times per frame, the top layer wins.
     pub fn set_modal_layer(&mut self, layer_id: LayerId) {
@@ -1,14 +1,15 @@
-use std::sync::Arc;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use emath::GuiRounding as _;
 use epaint::{
-    CircleShape, ClippedShape, CornerRadius, PathStroke, RectShape, Shape, Stroke, StrokeKind,
+    ArcShape, CircleShape, ClipMask, ClippedShape, CornerRadius, PathStroke, RectShape, Shape,
+    Stroke, StrokeKind, TextPathShape,
     text::{FontsView, Galley, LayoutJob},
 };
 
 use crate::{
     Color32, Context, FontId,
-    emath::{Align2, Pos2, Rangef, Rect, Vec2},
+    emath::{Align, Align2, Pos2, Rangef, Rect, Vec2},
     layers::{LayerId, PaintList, ShapeIdx},
 };
 
@@ -32,6 +33,9 @@ pub struct Painter {
     /// This means nothing outside of this rectangle will be visible on screen.
     clip_rect: Rect,
 
+    /// If set, everything painted in this [`Painter`] will also be clipped against this.
+    clip_mask: Option<Arc<ClipMask>>,
+
     /// If set, all shapes will have their colors modified to be closer to this.
     /// This is used to implement grayed out interfaces.
     fade_to_color: Option<Color32>,
@@ -51,6 +55,7 @@ impl Painter {
             pixels_per_point,
             layer_id,
             clip_rect,
+            clip_mask: None,
             fade_to_color: None,
             opacity_factor: 1.0,
         }
@@ -74,6 +79,16 @@ impl Painter {
         new_self
     }
 
+    /// Create a painter that also clips everything against the given [`ClipMask`],
+    /// e.g. to paint an image within a circle.
+    ///
+    /// This replaces any clip mask of the parent [`Painter`].
+    pub fn with_clip_mask(&self, clip_mask: ClipMask) -> Self {
+        let mut new_self = self.clone();
+        new_self.clip_mask = Some(Arc::new(clip_mask));
+        new_self
+    }
+
     /// Redirect where you are painting.
     ///
     /// It is undefined behavior to change the [`LayerId`]
@@ -190,6 +205,18 @@ impl Painter {
         self.clip_rect = clip_rect;
     }
 
+    /// If set, everything painted in this [`Painter`] will also be clipped against this.
+    #[inline]
+    pub fn clip_mask(&self) -> Option<&ClipMask> {
+        self.clip_mask.as_deref()
+    }
+
+    /// Clip everything painted from now on against this [`ClipMask`], on top of [`Self::clip_rect`].
+    #[inline]
+    pub fn set_clip_mask(&mut self, clip_mask: Option<ClipMask>) {
+        self.clip_mask = clip_mask.map(Arc::new);
+    }
+
     /// Useful for pixel-perfect rendering of lines that are one pixel wide (or any odd number of pixels).
     #[inline]
     pub fn round_to_pixel_center(&self, point: f32) -> f32 {
@@ -239,6 +266,18 @@ impl Painter {
         self.ctx.graphics_mut(|g| writer(g.entry(self.layer_id)))
     }
 
+    /// Like [`Self::paint_list`], but clips the added shapes against our clip mask.
+    fn add_to_paint_list<R>(&self, writer: impl FnOnce(&mut PaintList) -> R) -> R {
+        self.paint_list(|list| {
+            let start = list.next_idx();
+            let result = writer(list);
+            if let Some(clip_mask) = &self.clip_mask {
+                list.clip_range(start, list.next_idx(), clip_mask);
+            }
+            result
+        })
+    }
+
     fn transform_shape(&self, shape: &mut Shape) {
         if let Some(fade_to_color) = self.fade_to_color {
             tint_shape_towards(shape, fade_to_color);
@@ -257,7 +296,7 @@ impl Painter {
         } else {
             let mut shape = shape.into();
             self.transform_shape(&mut shape);
-            self.paint_list(|l| l.add(self.clip_rect, shape))
+            self.add_to_paint_list(|l| l.add(self.clip_rect, shape))
         }
     }
 
@@ -273,9 +312,9 @@ impl Painter {
                 self.transform_shape(&mut shape);
                 shape
             });
-            self.paint_list(|l| l.extend(self.clip_rect, shapes));
+            self.add_to_paint_list(|l| l.extend(self.clip_rect, shapes));
         } else {
-            self.paint_list(|l| l.extend(self.clip_rect, shapes));
+            self.add_to_paint_list(|l| l.extend(self.clip_rect, shapes));
         }
     }
 
@@ -286,7 +325,12 @@ impl Painter {
         }
         let mut shape = shape.into();
         self.transform_shape(&mut shape);
-        self.paint_list(|l| l.set(idx, self.clip_rect, shape));
+        self.paint_list(|l| {
+            l.set(idx, self.clip_rect, shape);
+            if let Some(clip_mask) = &self.clip_mask {
+                l.mutate_shape(idx, |c| c.set_clip_mask(Some(Arc::clone(clip_mask))));
+            }
+        });
     }
 
     /// Access all shapes added this frame.
@@ -417,6 +461,64 @@ impl Painter {
         })
     }
 
+    /// An arc of a circle.
+    ///
+    /// The `angles` are in radians, with 0 pointing right and increasing clockwise.
+    /// Use [`ArcShape`] directly for elliptical arcs.
+    pub fn arc(
+        &self,
+        center: Pos2,
+        radius: f32,
+        angles: RangeInclusive<f32>,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(ArcShape::arc(center, Vec2::splat(radius), angles, stroke))
+    }
+
+    /// A pie slice of a circle.
+    ///
+    /// The `angles` are in radians, with 0 pointing right and increasing clockwise.
+    /// Use [`ArcShape`] directly for elliptical slices.
+    pub fn sector(
+        &self,
+        center: Pos2,
+        radius: f32,
+        angles: RangeInclusive<f32>,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(ArcShape::sector(
+            center,
+            Vec2::splat(radius),
+            angles,
+            fill_color,
+            stroke,
+        ))
+    }
+
+    /// A slice of a ring, between two circles.
+    ///
+    /// The `angles` are in radians, with 0 pointing right and increasing clockwise.
+    /// Use [`ArcShape`] directly for elliptical rings.
+    pub fn annulus_segment(
+        &self,
+        center: Pos2,
+        outer_radius: f32,
+        inner_radius: f32,
+        angles: RangeInclusive<f32>,
+        fill_color: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> ShapeIdx {
+        self.add(ArcShape::annulus_segment(
+            center,
+            Vec2::splat(outer_radius),
+            Vec2::splat(inner_radius),
+            angles,
+            fill_color,
+            stroke,
+        ))
+    }
+
     /// See also [`Self::rect_filled`] and [`Self::rect_stroke`].
     pub fn rect(
         &self,
@@ -521,6 +623,29 @@ impl Painter {
         rect
     }
 
+    /// Lay out and paint some text along a path, e.g. a flattened curve.
+    ///
+    /// The `align` decides if the text goes at the start, the middle or the end of the path.
+    /// The text is painted on the left side of the path, when following it.
+    ///
+    /// Returns where the text ended up.
+    /// See [`TextPathShape`] for more options.
+    #[expect(clippy::needless_pass_by_value)]
+    pub fn text_on_path(
+        &self,
+        path: Vec<Pos2>,
+        align: Align,
+        text: impl ToString,
+        font_id: FontId,
+        text_color: Color32,
+    ) -> Rect {
+        let galley = self.layout_no_wrap(text.to_string(), font_id, text_color);
+        let shape = TextPathShape::new(path, galley, text_color).with_align(align);
+        let rect = shape.visual_bounding_rect();
+        self.add(shape);
+        rect
+    }
+
     /// Will wrap text at the given width and line break at `\n`.
     ///
     /// Paint the results with [`Self::galley`].
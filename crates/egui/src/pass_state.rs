@@ -224,6 +224,9 @@ pub struct PassState {
     /// as when swiping down on a touch-screen or track-pad with natural scrolling.
     pub scroll_delta: (Vec2, style::ScrollAnimation),
 
+    /// Snap targets for the current scroll area, see [`crate::containers::scroll_area::ScrollSnap::Children`].
+    pub scroll_snap_rects: Vec<Rect>,
+
     pub accesskit_state: Option<AccessKitPassState>,
 
     /// Highlight these widgets the next pass.
@@ -245,6 +248,7 @@ impl Default for PassState {
             used_by_panels: Rect::NAN,
             scroll_target: [None, None],
             scroll_delta: (Vec2::default(), style::ScrollAnimation::none()),
+            scroll_snap_rects: Vec::new(),
             accesskit_state: None,
             highlight_next_pass: Default::default(),
 
@@ -267,6 +271,7 @@ impl PassState {
             used_by_panels,
             scroll_target,
             scroll_delta,
+            scroll_snap_rects,
             accesskit_state,
             highlight_next_pass,
 
@@ -283,6 +288,7 @@ impl PassState {
         *used_by_panels = Rect::NOTHING;
         *scroll_target = [None, None];
         *scroll_delta = Default::default();
+        scroll_snap_rects.clear();
 
         #[cfg(debug_assertions)]
         {
@@ -31,6 +31,13 @@ impl Placer {
         }
     }
 
+    /// Make the next grid cell span several columns and rows. Does nothing outside a grid.
+    pub(crate) fn set_grid_span(&mut self, num_cols: usize, num_rows: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_span(num_cols, num_rows);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn grid(&self) -> Option<&grid::GridLayout> {
         self.grid.as_ref()
@@ -333,6 +333,26 @@ impl Response {
         self.ctx.memory_mut(|mem| mem.request_focus(self.id));
     }
 
+    /// Set the position of this widget in the Tab order.
+    ///
+    /// Widgets with a positive index are visited first, in increasing order,
+    /// then all widgets with index zero (the default) in the order they were added.
+    /// A negative index means Tab skips the widget.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.button("Visited second");
+    /// ui.button("Visited first").tab_index(1);
+    /// # });
+    /// ```
+    ///
+    /// See also [`crate::Memory::set_tab_index`].
+    pub fn tab_index(self, tab_index: i32) -> Self {
+        self.ctx
+            .memory_mut(|mem| mem.set_tab_index(self.id, tab_index));
+        self
+    }
+
     /// Surrender keyboard focus for this widget.
     pub fn surrender_focus(&self) {
         self.ctx.memory_mut(|mem| mem.surrender_focus(self.id));
@@ -439,6 +459,92 @@ impl Response {
         }
     }
 
+    /// Was the pointer held still on this widget for [`crate::style::Interaction::long_press_duration`]?
+    ///
+    /// Returns `true` only on one frame, while the pointer is still down.
+    /// Unlike [`Self::long_touched`] this works for both mouse and touch,
+    /// and does not open a context menu.
+    ///
+    /// The widget must sense clicks or drags.
+    pub fn long_pressed(&self) -> bool {
+        if !self.enabled() || !self.is_pointer_button_down_on() {
+            return false;
+        }
+        let duration = self.ctx.style().interaction.long_press_duration;
+        let (long_pressed, remaining) = self
+            .ctx
+            .input(|i| (i.long_press(duration), i.long_press_remaining(duration)));
+        if let Some(remaining) = remaining {
+            // Make sure we get a frame when the long-press is recognized:
+            self.ctx.request_repaint_after_secs(remaining);
+        }
+        long_pressed
+    }
+
+    /// Was this widget swiped this frame?
+    ///
+    /// A swipe is a drag that covers at least [`crate::style::Interaction::swipe_min_distance`],
+    /// and is released while moving at least [`crate::style::Interaction::swipe_min_velocity`].
+    ///
+    /// The widget must sense drags.
+    pub fn swiped(&self) -> Option<crate::Swipe> {
+        if !self.drag_stopped_by(PointerButton::Primary) {
+            return None;
+        }
+        let interaction = &self.ctx.style().interaction;
+        let mut swipe = self.ctx.input(|i| {
+            i.swipe(
+                interaction.swipe_min_distance,
+                interaction.swipe_min_velocity,
+            )
+        })?;
+        if let Some(from_global) = self.ctx.layer_transform_from_global(self.layer_id) {
+            swipe.start_pos = from_global * swipe.start_pos;
+            swipe.delta *= from_global.scaling;
+            swipe.velocity *= from_global.scaling;
+        }
+        Some(swipe)
+    }
+
+    /// Is this widget being dragged by a press that came quickly after a tap?
+    ///
+    /// On touch screens this is commonly used for one-finger zooming,
+    /// using [`Self::drag_delta`] as the zoom input.
+    ///
+    /// The widget must sense drags.
+    pub fn double_tap_dragged(&self) -> bool {
+        self.dragged_by(PointerButton::Primary) && self.ctx.input(|i| i.is_double_tap_drag())
+    }
+
+    /// A multi-touch gesture (e.g. two-finger pan, pinch or rotate) that started on this widget.
+    ///
+    /// Returns `None` if there is no multi-touch gesture, or it started somewhere else.
+    /// [`crate::MultiTouchInfo::start_pos`], [`crate::MultiTouchInfo::center_pos`] and
+    /// [`crate::MultiTouchInfo::translation_delta`] are in the coordinate space of the widget.
+    ///
+    /// See also [`Context::multi_touch`].
+    pub fn multi_touch(&self) -> Option<crate::MultiTouchInfo> {
+        if !self.enabled() {
+            return None;
+        }
+        let mut touch = self.ctx.multi_touch()?;
+        if self
+            .ctx
+            .layer_id_at(touch.start_pos)
+            .is_some_and(|layer_id| layer_id != self.layer_id)
+        {
+            return None;
+        }
+        if let Some(from_global) = self.ctx.layer_transform_from_global(self.layer_id) {
+            touch.start_pos = from_global * touch.start_pos;
+            touch.center_pos = from_global * touch.center_pos;
+            touch.translation_delta *= from_global.scaling;
+        }
+        self.interact_rect
+            .contains(touch.start_pos)
+            .then_some(touch)
+    }
+
     /// If the user started dragging this widget this frame, store the payload for drag-and-drop.
     #[doc(alias = "drag and drop")]
     pub fn dnd_set_drag_payload<Payload: Any + Send + Sync>(&self, payload: Payload) {
@@ -484,6 +590,71 @@ impl Response {
         }
     }
 
+    /// Drag-and-Drop: The files from outside the app (e.g. a file browser) being held over this widget.
+    ///
+    /// Only files with one of the given `extensions` (e.g. `&["png", "jpg"]`) are returned.
+    /// An empty list of extensions accepts all files.
+    ///
+    /// Use this to highlight the widget the files will be dropped onto.
+    ///
+    /// If the integration doesn't report where the files are (see [`crate::HoveredFile::pos`]),
+    /// each file is returned for the first widget this pass that accepts it,
+    /// which is also where [`Self::dnd_release_files`] will drop it.
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_hover_files(&self, extensions: &[&str]) -> Vec<crate::HoveredFile> {
+        let files: Vec<(usize, crate::HoveredFile)> = self.ctx.input(|i| {
+            i.raw
+                .hovered_files
+                .iter()
+                .cloned()
+                .enumerate()
+                .filter(|(_, file)| file.has_extension(extensions))
+                .collect()
+        });
+        files
+            .into_iter()
+            .filter(|(index, file)| match file.pos {
+                Some(pos) => self.contains_file_pos(pos),
+                None => crate::DragAndDrop::claim_hovered_file(&self.ctx, *index, self.id),
+            })
+            .map(|(_, file)| file)
+            .collect()
+    }
+
+    /// Drag-and-Drop: The files from outside the app (e.g. a file browser) dropped onto this widget this frame.
+    ///
+    /// Only files with one of the given `extensions` (e.g. `&["png", "jpg"]`) are returned.
+    /// An empty list of extensions accepts all files.
+    ///
+    /// The returned files are removed from [`crate::RawInput::dropped_files`],
+    /// so no other drop zone will receive them.
+    /// If the integration doesn't report where the files were dropped (see [`crate::DroppedFile::pos`]),
+    /// they go to the first widget that accepts them.
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_release_files(&self, extensions: &[&str]) -> Vec<crate::DroppedFile> {
+        let dropped_files = self
+            .ctx
+            .input_mut(|i| std::mem::take(&mut i.raw.dropped_files));
+        let (accepted, rest) = dropped_files.into_iter().partition(|file| {
+            file.has_extension(extensions) && file.pos.is_none_or(|pos| self.contains_file_pos(pos))
+        });
+        self.ctx.input_mut(|i| i.raw.dropped_files = rest);
+        accepted
+    }
+
+    /// Is a file from outside the app held over (or dropped onto) this widget at `pos`?
+    fn contains_file_pos(&self, pos: Pos2) -> bool {
+        let local_pos = self
+            .ctx
+            .layer_transform_from_global(self.layer_id)
+            .map_or(pos, |transform| transform * pos);
+        self.interact_rect.contains(local_pos)
+            && self
+                .ctx
+                .layer_id_at(pos)
+                .is_none_or(|layer_id| layer_id == self.layer_id)
+    }
+
     /// Where the pointer (mouse/touch) were when this widget was clicked or dragged.
     ///
     /// `None` if the widget is not being interacted with.
@@ -784,6 +955,29 @@ impl Response {
         });
     }
 
+    /// Make the enclosing [`crate::ScrollArea`] snap to this widget,
+    /// if it uses [`crate::containers::scroll_area::ScrollSnap::Children`].
+    ///
+    /// Call this every frame, for every widget that should be a snap target.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui::{Align, containers::scroll_area::ScrollSnap};
+    /// egui::ScrollArea::vertical()
+    ///     .snap(ScrollSnap::Children(Align::Min))
+    ///     .show(ui, |ui| {
+    ///         for i in 0..100 {
+    ///             ui.heading(format!("Page {i}")).scroll_snap_target();
+    ///             ui.label("Lorem ipsum");
+    ///         }
+    ///     });
+    /// # });
+    /// ```
+    pub fn scroll_snap_target(&self) {
+        self.ctx
+            .pass_state_mut(|state| state.scroll_snap_rects.push(self.rect));
+    }
+
     /// For accessibility.
     ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
@@ -1014,6 +1208,24 @@ impl Response {
     pub fn with_new_rect(self, rect: Rect) -> Self {
         Self { rect, ..self }
     }
+
+    /// Only let the widget be hovered and clicked within this shape, instead of its whole rectangle.
+    ///
+    /// Useful for round knobs, diagonal connections in a node graph, rotated items, etc.
+    /// The shape is in the same coordinates as [`Self::rect`].
+    ///
+    /// Like all interaction in egui, this takes effect from the next frame.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::click());
+    /// let response = response.with_hit_shape(egui::HitShape::circle(rect.center(), 16.0));
+    /// # });
+    /// ```
+    pub fn with_hit_shape(self, shape: crate::HitShape) -> Self {
+        self.ctx.set_widget_hit_shape(self.id, shape);
+        self
+    }
 }
 
 /// See [`Response::union`].
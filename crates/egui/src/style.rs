@@ -875,6 +875,12 @@ pub struct Interaction {
     /// Radius of the interactive area of the corner of a window during drag-to-resize.
     pub resize_grab_radius_corner: f32,
 
+    /// How close (in points) a dragged [`crate::Window`] must come to an edge
+    /// before it snaps to it.
+    ///
+    /// Only used for windows with [`crate::Window::snap`] enabled.
+    pub window_snap_distance: f32,
+
     /// If `false`, tooltips will show up anytime you hover anything, even if mouse is still moving
     pub show_tooltips_only_when_still: bool,
 
@@ -896,6 +902,23 @@ pub struct Interaction {
     /// The default is `true`, but text selection can be slightly glitchy,
     /// so you may want to disable it.
     pub multi_widget_text_select: bool,
+
+    /// How long (in seconds) the pointer must be held still on a widget
+    /// for it to register as a long-press.
+    ///
+    /// See [`crate::Response::long_pressed`].
+    pub long_press_duration: f32,
+
+    /// How far (in points) the pointer must move for a [`crate::Swipe`].
+    ///
+    /// See [`crate::Response::swiped`].
+    pub swipe_min_distance: f32,
+
+    /// How fast (in points per second) the pointer must be moving when released
+    /// for it to register as a [`crate::Swipe`].
+    ///
+    /// See [`crate::Response::swiped`].
+    pub swipe_min_velocity: f32,
 }
 
 /// Look and feel of the text cursor.
@@ -931,6 +954,44 @@ impl Default for TextCursorStyle {
     }
 }
 
+/// Look and feel of the ring around the widget with keyboard focus.
+///
+/// The ring is only shown when the focus was moved with the keyboard
+/// or another navigation input (e.g. a gamepad), not when a widget was clicked.
+///
+/// No ring is painted by default. To show one:
+/// ```
+/// # egui::__run_test_ctx(|ctx| {
+/// ctx.style_mut(|style| {
+///     style.visuals.focus_ring.stroke = egui::Stroke::new(2.0, style.visuals.selection.stroke.color);
+/// });
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct FocusRing {
+    /// The color and width of the ring.
+    ///
+    /// Default: [`Stroke::NONE`], i.e. no ring.
+    pub stroke: Stroke,
+
+    /// How far outside the widget rectangle to paint the ring.
+    pub expansion: f32,
+
+    pub corner_radius: CornerRadius,
+}
+
+impl Default for FocusRing {
+    fn default() -> Self {
+        Self {
+            stroke: Stroke::NONE,
+            expansion: 2.0,
+            corner_radius: CornerRadius::same(4),
+        }
+    }
+}
+
 /// Controls the visual style (colors etc) of egui.
 ///
 /// You can change the visuals of a [`Ui`] with [`Ui::visuals_mut`]
@@ -1028,6 +1089,9 @@ pub struct Visuals {
     /// How the text cursor acts.
     pub text_cursor: TextCursorStyle,
 
+    /// The ring around the widget with keyboard focus.
+    pub focus_ring: FocusRing,
+
     /// Allow child widgets to be just on the border and still have a stroke with some thickness
     pub clip_rect_margin: f32,
 
@@ -1393,11 +1457,15 @@ impl Default for Interaction {
             interact_radius: 5.0,
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
+            window_snap_distance: 10.0,
             show_tooltips_only_when_still: true,
             tooltip_delay: 0.5,
             tooltip_grace_time: 0.2,
             selectable_labels: true,
             multi_widget_text_select: true,
+            long_press_duration: 0.5,
+            swipe_min_distance: 30.0,
+            swipe_min_velocity: 300.0,
         }
     }
 }
@@ -1447,6 +1515,8 @@ impl Visuals {
 
             text_cursor: Default::default(),
 
+            focus_ring: Default::default(),
+
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
@@ -1938,11 +2008,15 @@ impl Interaction {
             interact_radius,
             resize_grab_radius_side,
             resize_grab_radius_corner,
+            window_snap_distance,
             show_tooltips_only_when_still,
             tooltip_delay,
             tooltip_grace_time,
             selectable_labels,
             multi_widget_text_select,
+            long_press_duration,
+            swipe_min_distance,
+            swipe_min_velocity,
         } = self;
 
         ui.spacing_mut().item_spacing = vec2(12.0, 8.0);
@@ -1964,6 +2038,10 @@ impl Interaction {
                 ui.add(DragValue::new(resize_grab_radius_corner).range(0.0..=20.0));
                 ui.end_row();
 
+                ui.label("window_snap_distance").on_hover_text("How close a dragged window must come to an edge before it snaps to it.");
+                ui.add(DragValue::new(window_snap_distance).range(0.0..=40.0));
+                ui.end_row();
+
                 ui.label("Tooltip delay").on_hover_text(
                     "Delay in seconds before showing tooltips after the mouse stops moving",
                 );
@@ -1985,6 +2063,32 @@ impl Interaction {
                         .suffix(" s"),
                 );
                 ui.end_row();
+
+                ui.label("Long-press duration").on_hover_text(
+                    "How long the pointer must be held still on a widget for a long-press",
+                );
+                ui.add(
+                    DragValue::new(long_press_duration)
+                        .range(0.1..=2.0)
+                        .speed(0.05)
+                        .suffix(" s"),
+                );
+                ui.end_row();
+
+                ui.label("Swipe min distance")
+                    .on_hover_text("How far the pointer must move for a swipe");
+                ui.add(DragValue::new(swipe_min_distance).range(0.0..=200.0));
+                ui.end_row();
+
+                ui.label("Swipe min velocity").on_hover_text(
+                    "How fast the pointer must be moving when released for a swipe",
+                );
+                ui.add(
+                    DragValue::new(swipe_min_velocity)
+                        .range(0.0..=5000.0)
+                        .suffix(" pt/s"),
+                );
+                ui.end_row();
             });
 
         ui.checkbox(
@@ -2137,6 +2241,8 @@ impl Visuals {
 
             text_cursor,
 
+            focus_ring,
+
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
@@ -2266,6 +2372,10 @@ impl Visuals {
             text_cursor.ui(ui);
         });
 
+        ui.collapsing("Focus ring", |ui| {
+            focus_ring.ui(ui);
+        });
+
         ui.collapsing("Window", |ui| {
             Grid::new("window")
                 .num_columns(2)
@@ -2404,6 +2514,30 @@ fn text_alpha_from_coverage_ui(ui: &mut Ui, text_alpha_from_coverage: &mut Alpha
     });
 }
 
+impl FocusRing {
+    fn ui(&mut self, ui: &mut Ui) {
+        let Self {
+            stroke,
+            expansion,
+            corner_radius,
+        } = self;
+
+        Grid::new("focus_ring").num_columns(2).show(ui, |ui| {
+            ui.label("Stroke");
+            ui.add(stroke);
+            ui.end_row();
+
+            ui.label("Expansion");
+            ui.add(DragValue::new(expansion).speed(0.1).range(0.0..=8.0));
+            ui.end_row();
+
+            ui.label("Corner radius");
+            ui.add(corner_radius);
+            ui.end_row();
+        });
+    }
+}
+
 impl TextCursorStyle {
     fn ui(&mut self, ui: &mut Ui) {
         let Self {
@@ -2739,7 +2873,7 @@ impl Widget for &mut Shadow {
 
 impl Widget for &mut Stroke {
     fn ui(self, ui: &mut Ui) -> Response {
-        let Stroke { width, color } = self;
+        let Stroke { width, color, .. } = self;
 
         ui.horizontal(|ui| {
             ui.add(DragValue::new(width).speed(0.1).range(0.0..=1e9))
@@ -2765,6 +2899,7 @@ impl Widget for &mut crate::Frame {
             shadow,
             fill,
             stroke,
+            clip_content,
         } = self;
 
         crate::Grid::new("frame")
@@ -2796,6 +2931,10 @@ impl Widget for &mut crate::Frame {
                 ui.label("Stroke");
                 ui.add(stroke);
                 ui.end_row();
+
+                ui.label("Clip content");
+                ui.checkbox(clip_content, "");
+                ui.end_row();
             })
             .response
     }
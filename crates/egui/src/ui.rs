@@ -2509,6 +2509,32 @@ impl Ui {
         InnerResponse::new(ret, response)
     }
 
+    /// Group the widgets in `add_contents` into a [`crate::FocusScope`].
+    ///
+    /// With [`crate::FocusScope::TRAP`], Tab and Shift+Tab cycle through the widgets in the scope
+    /// instead of leaving it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.focus_scope("form", egui::FocusScope::TRAP, |ui| {
+    ///     ui.button("First");
+    ///     ui.button("Last, Tab goes back to First");
+    /// });
+    /// # });
+    /// ```
+    pub fn focus_scope<R>(
+        &mut self,
+        id_salt: impl Hash,
+        scope: crate::FocusScope,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let id = self.id().with(id_salt);
+        self.ctx().memory_mut(|mem| mem.push_focus_scope(id, scope));
+        let inner = self.scope(add_contents);
+        self.ctx().memory_mut(|mem| mem.pop_focus_scope());
+        inner
+    }
+
     /// Redirect shapes to another paint layer.
     ///
     /// ```
@@ -2822,6 +2848,31 @@ impl Ui {
             .end_row(self.spacing().item_spacing, &self.painter().clone());
     }
 
+    /// Make the next cell in a [`crate::Grid`] span `num_cols` columns and `num_rows` rows.
+    ///
+    /// The cell gets the combined size of the cells it covers (including the spacing between them),
+    /// and cells covered in the following rows are skipped.
+    /// The span is capped by [`crate::Grid::num_columns`], if set.
+    ///
+    /// Does nothing outside of a grid.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::Grid::new("form").num_columns(2).show(ui, |ui| {
+    ///     ui.set_grid_span(2, 1);
+    ///     ui.strong("Section header");
+    ///     ui.end_row();
+    ///
+    ///     ui.label("Name");
+    ///     ui.text_edit_singleline(&mut String::new());
+    ///     ui.end_row();
+    /// });
+    /// # });
+    /// ```
+    pub fn set_grid_span(&mut self, num_cols: usize, num_rows: usize) {
+        self.placer.set_grid_span(num_cols, num_rows);
+    }
+
     /// Set row height in horizontal wrapping layout.
     pub fn set_row_height(&mut self, height: f32) {
         self.placer.set_row_height(height);
@@ -3052,6 +3103,59 @@ impl Ui {
         (InnerResponse { inner, response }, payload)
     }
 
+    /// Surround the given ui with a frame which
+    /// changes colors when files from outside the app (e.g. a file browser) are held over it.
+    ///
+    /// Only files with one of the given `extensions` (e.g. `&["png", "jpg"]`) are accepted.
+    /// An empty list of extensions accepts all files.
+    ///
+    /// Returns the files dropped onto it this frame, if any.
+    ///
+    /// The given frame is used for its margins, but the color is ignored.
+    #[doc(alias = "drag and drop")]
+    pub fn dnd_file_drop_zone<R>(
+        &mut self,
+        frame: Frame,
+        extensions: &[&str],
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> (InnerResponse<R>, Vec<crate::DroppedFile>) {
+        let is_hovering_files = DragAndDrop::is_hovering_files(self.ctx());
+        let can_accept_hovered_files = self.ctx().input(|i| {
+            i.raw
+                .hovered_files
+                .iter()
+                .any(|file| file.has_extension(extensions))
+        });
+
+        let mut frame = frame.begin(self);
+        let inner = add_contents(&mut frame.content_ui);
+        let response = frame.allocate_space(self);
+
+        let style = if !response.dnd_hover_files(extensions).is_empty() {
+            self.visuals().widgets.active
+        } else {
+            self.visuals().widgets.inactive
+        };
+
+        let mut fill = style.bg_fill;
+        let mut stroke = style.bg_stroke;
+
+        if is_hovering_files && !can_accept_hovered_files {
+            // Show that these files can't be dropped here:
+            fill = self.visuals().disable(fill);
+            stroke.color = self.visuals().disable(stroke.color);
+        }
+
+        frame.frame.fill = fill;
+        frame.frame.stroke = stroke;
+
+        frame.paint(self);
+
+        let files = response.dnd_release_files(extensions);
+
+        (InnerResponse { inner, response }, files)
+    }
+
     /// Create a new Scope and transform its contents via a [`emath::TSTransform`].
     /// This only affects visuals, inputs will not be transformed. So this is mostly useful
     /// to create visual effects on interactions, e.g. scaling a button on hover / click.
@@ -1215,6 +1215,12 @@ pub struct ViewportOutput {
     ///
     /// If the duration is zero, schedule a repaint immediately.
     pub repaint_delay: std::time::Duration,
+
+    /// The parts of the viewport that changed since it was last painted, in points.
+    ///
+    /// This is the same as [`crate::FullOutput::damage`] for the viewport that was just painted,
+    /// and empty for all the others, since they have nothing new to paint.
+    pub damage: Vec<crate::Rect>,
 }
 
 impl ViewportOutput {
@@ -1227,6 +1233,7 @@ impl ViewportOutput {
             viewport_ui_cb,
             mut commands,
             repaint_delay,
+            damage,
         } = newer;
 
         self.parent = parent;
@@ -1235,6 +1242,10 @@ impl ViewportOutput {
         self.viewport_ui_cb = viewport_ui_cb;
         self.commands.append(&mut commands);
         self.repaint_delay = self.repaint_delay.min(repaint_delay);
+
+        // The older output was never painted, so also repaint what changed in it:
+        self.damage.extend(damage);
+        self.damage = crate::damage::merge_overlapping(std::mem::take(&mut self.damage));
     }
 }
 
@@ -1,7 +1,215 @@
 use ahash::HashMap;
 
+use emath::{Pos2, TSTransform, Vec2};
+use epaint::CornerRadiusF32;
+
 use crate::{Id, IdMap, LayerId, Rect, Sense, WidgetInfo};
 
+/// The interactive area of a widget, when it is not its whole rectangle.
+///
+/// For instance, a circular knob should not be hovered or clicked in the corners of its bounding rectangle.
+///
+/// The hit shape is in local layer coordinates (like [`WidgetRect::rect`]),
+/// and is always clipped to [`WidgetRect::interact_rect`].
+///
+/// Set it with [`crate::Response::with_hit_shape`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum HitShape {
+    /// A filled circle.
+    Circle {
+        /// The center of the circle.
+        center: Pos2,
+
+        /// The radius of the circle.
+        radius: f32,
+    },
+
+    /// A filled rectangle, with rounded corners.
+    RoundedRect {
+        /// The rectangle, before the corners are rounded.
+        rect: Rect,
+
+        /// The radius of each corner, clamped to half the shortest side.
+        corner_radius: CornerRadiusF32,
+    },
+
+    /// A convex polygon, with the points in either winding order.
+    ///
+    /// A polygon without area (e.g. all points on a line) only contains the points on its edges.
+    ConvexPolygon(Vec<Pos2>),
+
+    /// Everything within `tolerance` of the line through the points,
+    /// e.g. a connection in a node graph.
+    Polyline {
+        /// The points of the line, in order.
+        points: Vec<Pos2>,
+
+        /// How far from the line a position can be and still be within the shape.
+        tolerance: f32,
+    },
+}
+
+impl HitShape {
+    /// A filled circle, e.g. for a round knob.
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::Circle { center, radius }
+    }
+
+    /// A rectangle with rounded corners, e.g. for a pill-shaped button.
+    pub fn rounded_rect(rect: Rect, corner_radius: impl Into<CornerRadiusF32>) -> Self {
+        Self::RoundedRect {
+            rect,
+            corner_radius: corner_radius.into(),
+        }
+    }
+
+    /// A convex polygon, e.g. for a rotated rectangle or a triangular handle.
+    ///
+    /// The points can be in either winding order.
+    pub fn convex_polygon(points: Vec<Pos2>) -> Self {
+        Self::ConvexPolygon(points)
+    }
+
+    /// Everything within `tolerance` of the line through the points,
+    /// e.g. a connection in a node graph.
+    pub fn polyline(points: Vec<Pos2>, tolerance: f32) -> Self {
+        Self::Polyline { points, tolerance }
+    }
+
+    /// The smallest rectangle containing the shape.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::Circle { center, radius } => {
+                Rect::from_center_size(*center, Vec2::splat(2.0 * radius))
+            }
+            Self::RoundedRect { rect, .. } => *rect,
+            Self::ConvexPolygon(points) => Rect::from_points(points),
+            Self::Polyline { points, tolerance } => Rect::from_points(points).expand(*tolerance),
+        }
+    }
+
+    /// Is the position inside (or on the edge of) the shape?
+    pub fn contains(&self, pos: Pos2) -> bool {
+        self.distance_sq_to_pos(pos) <= 0.0
+    }
+
+    /// The squared distance from the position to the shape, or zero if it is inside.
+    pub fn distance_sq_to_pos(&self, pos: Pos2) -> f32 {
+        let distance = match self {
+            Self::Circle { center, radius } => center.distance(pos) - radius,
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => distance_to_rounded_rect(*rect, *corner_radius, pos),
+            Self::ConvexPolygon(points) => distance_to_convex_polygon(points, pos),
+            Self::Polyline { points, tolerance } => {
+                let distance = if let [point] = points.as_slice() {
+                    point.distance(pos)
+                } else {
+                    points
+                        .windows(2)
+                        .map(|segment| distance_to_segment(segment[0], segment[1], pos))
+                        .fold(f32::INFINITY, f32::min)
+                };
+                distance - tolerance
+            }
+        };
+        let distance = distance.max(0.0);
+        distance * distance
+    }
+
+    /// Transform the shape, e.g. from layer coordinates to global coordinates.
+    pub fn transform(&self, transform: TSTransform) -> Self {
+        match self {
+            Self::Circle { center, radius } => Self::Circle {
+                center: transform * *center,
+                radius: transform.scaling * radius,
+            },
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => Self::RoundedRect {
+                rect: transform * *rect,
+                corner_radius: *corner_radius * transform.scaling,
+            },
+            Self::ConvexPolygon(points) => {
+                Self::ConvexPolygon(points.iter().map(|p| transform * *p).collect())
+            }
+            Self::Polyline { points, tolerance } => Self::Polyline {
+                points: points.iter().map(|p| transform * *p).collect(),
+                tolerance: transform.scaling * tolerance,
+            },
+        }
+    }
+}
+
+/// Signed distance: negative inside.
+fn distance_to_rounded_rect(rect: Rect, corner_radius: CornerRadiusF32, pos: Pos2) -> f32 {
+    let center = rect.center();
+    let radius = match (pos.x < center.x, pos.y < center.y) {
+        (true, true) => corner_radius.nw,
+        (false, true) => corner_radius.ne,
+        (true, false) => corner_radius.sw,
+        (false, false) => corner_radius.se,
+    }
+    .clamp(0.0, 0.5 * rect.width().min(rect.height()));
+
+    let q = (pos - center).abs() - 0.5 * rect.size() + Vec2::splat(radius);
+    q.max(Vec2::ZERO).length() + q.x.max(q.y).min(0.0) - radius
+}
+
+/// Unsigned distance: zero inside.
+fn distance_to_convex_polygon(points: &[Pos2], pos: Pos2) -> f32 {
+    if points.len() < 3 {
+        return match points {
+            [a, b] => distance_to_segment(*a, *b, pos),
+            [a] => a.distance(pos),
+            _ => f32::INFINITY,
+        };
+    }
+
+    let edges = || {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(a, b)| (*a, *b))
+    };
+    let distance_to_edges = || {
+        edges()
+            .map(|(a, b)| distance_to_segment(a, b, pos))
+            .fold(f32::INFINITY, f32::min)
+    };
+
+    let twice_area: f32 = edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+    if twice_area == 0.0 {
+        // Every position on the line through a degenerate polygon is on the same side of all edges:
+        return distance_to_edges();
+    }
+
+    let (mut any_left, mut any_right) = (false, false);
+    for (a, b) in edges() {
+        let cross = (b - a).x * (pos - a).y - (b - a).y * (pos - a).x;
+        any_left |= cross > 0.0;
+        any_right |= cross < 0.0;
+    }
+    if !(any_left && any_right) {
+        return 0.0; // inside
+    }
+
+    distance_to_edges()
+}
+
+fn distance_to_segment(a: Pos2, b: Pos2, pos: Pos2) -> f32 {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    let t = if length_sq > 0.0 {
+        ((pos - a).dot(ab) / length_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (a + t * ab).distance(pos)
+}
+
 /// Used to store each widget's [Id], [Rect] and [Sense] each frame.
 ///
 /// Used to check which widget gets input when a user clicks somewhere.
@@ -80,6 +288,9 @@ pub struct WidgetRects {
     /// Only filled in if the widget is interacted with,
     /// or if this is a debug build.
     infos: IdMap<WidgetInfo>,
+
+    /// The few widgets that are not interactive in their whole rectangle.
+    hit_shapes: IdMap<HitShape>,
 }
 
 impl PartialEq for WidgetRects {
@@ -127,6 +338,7 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos,
+            hit_shapes,
         } = self;
 
         #[expect(clippy::iter_over_hash_type)]
@@ -137,6 +349,8 @@ impl WidgetRects {
         by_id.clear();
 
         infos.clear();
+
+        hit_shapes.clear();
     }
 
     /// Insert the given widget rect in the given layer.
@@ -145,6 +359,7 @@ impl WidgetRects {
             by_layer,
             by_id,
             infos: _,
+            hit_shapes: _,
         } = self;
 
         let layer_widgets = by_layer.entry(layer_id).or_default();
@@ -189,4 +404,16 @@ impl WidgetRects {
     pub fn info(&self, id: Id) -> Option<&WidgetInfo> {
         self.infos.get(&id)
     }
+
+    /// Use this shape instead of the [`WidgetRect::interact_rect`] for hit testing and hovering.
+    ///
+    /// The interact rectangle still limits where the widget can be hit.
+    pub fn set_hit_shape(&mut self, id: Id, shape: HitShape) {
+        self.hit_shapes.insert(id, shape);
+    }
+
+    /// The shape set with [`Self::set_hit_shape`], if any.
+    pub fn hit_shape(&self, id: Id) -> Option<&HitShape> {
+        self.hit_shapes.get(&id)
+    }
 }
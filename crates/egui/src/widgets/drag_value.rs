@@ -4,9 +4,11 @@ use std::{cmp::Ordering, ops::RangeInclusive};
 
 use crate::{
     Button, CursorIcon, Id, Key, MINUS_CHAR_STR, Modifiers, NumExt as _, Response, RichText, Sense,
-    TextEdit, TextWrapMode, Ui, Widget, WidgetInfo, emath, text,
+    TextEdit, TextWrapMode, Ui, Units, Widget, WidgetInfo, emath, text,
 };
 
+use super::number_expression::NumberParser;
+
 // ----------------------------------------------------------------------------
 
 type NumFormatter<'a> = Box<dyn 'a + Fn(f64, RangeInclusive<usize>) -> String>;
@@ -47,6 +49,8 @@ pub struct DragValue<'a> {
     custom_formatter: Option<NumFormatter<'a>>,
     custom_parser: Option<NumParser<'a>>,
     update_while_editing: bool,
+    expressions: bool,
+    units: Option<Units>,
 }
 
 impl<'a> DragValue<'a> {
@@ -78,6 +82,8 @@ impl<'a> DragValue<'a> {
             custom_formatter: None,
             custom_parser: None,
             update_while_editing: true,
+            expressions: false,
+            units: None,
         }
     }
 
@@ -424,6 +430,48 @@ impl<'a> DragValue<'a> {
         self.update_while_editing = update;
         self
     }
+
+    /// Evaluate simple math when the value is typed in.
+    ///
+    /// This supports `+ - * / ^`, parentheses, the constants `pi`, `tau` and `e`,
+    /// percentages like `10 + 5%`, and edits relative to the value before editing,
+    /// like `+=10` or `*=2`.
+    ///
+    /// Ignored if a [`Self::custom_parser`] is set.
+    ///
+    /// Default: `false`.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut angle: f64 = 0.0;
+    /// ui.add(egui::DragValue::new(&mut angle).expressions(true)); // Try typing "2*pi/3"
+    /// # });
+    /// ```
+    #[inline]
+    pub fn expressions(mut self, expressions: bool) -> Self {
+        self.expressions = expressions;
+        self
+    }
+
+    /// Display the value with a unit, and accept typed values with units.
+    ///
+    /// The value is stored in the base unit of the [`Units`].
+    /// Values typed without a unit are in the unit currently displayed.
+    ///
+    /// Ignored for display if a [`Self::custom_formatter`] is set,
+    /// and for input if a [`Self::custom_parser`] is set.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut num_bytes: u64 = 1536;
+    /// ui.add(egui::DragValue::new(&mut num_bytes).units(egui::Units::bytes())); // "1.5 KiB"
+    /// # });
+    /// ```
+    #[inline]
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = Some(units);
+        self
+    }
 }
 
 impl Widget for DragValue<'_> {
@@ -440,6 +488,8 @@ impl Widget for DragValue<'_> {
             custom_formatter,
             custom_parser,
             update_while_editing,
+            expressions,
+            units,
         } = self;
 
         let shift = ui.input(|i| i.modifiers.shift_only());
@@ -457,11 +507,22 @@ impl Widget for DragValue<'_> {
                 mem.has_focus(id)
             });
 
+        let old_value = get(&mut get_set_value);
+
+        // Relative edits (`+=10`) apply to the value from before editing started:
+        let edit_base_id = id.with("__edit_base");
         if ui.memory_mut(|mem| mem.gained_focus(id)) {
-            ui.data_mut(|data| data.remove::<String>(id));
+            ui.data_mut(|data| {
+                data.remove::<String>(id);
+                data.insert_temp(edit_base_id, old_value);
+            });
         }
-
-        let old_value = get(&mut get_set_value);
+        let edit_base = ui.data(|data| data.get_temp(edit_base_id).unwrap_or(old_value));
+        let parser = NumberParser {
+            expressions,
+            units: units.as_ref(),
+            current_value: edit_base,
+        };
         let mut value = old_value;
         let aim_rad = ui.input(|i| i.aim_radius() as f64);
 
@@ -519,9 +580,15 @@ impl Widget for DragValue<'_> {
             ui.data_mut(|data| data.remove::<String>(id));
         }
 
-        let value_text = match custom_formatter {
-            Some(custom_formatter) => custom_formatter(value, auto_decimals..=max_decimals),
-            None => ui
+        let value_text = match (custom_formatter, &units) {
+            (Some(custom_formatter), _) => custom_formatter(value, auto_decimals..=max_decimals),
+            (None, Some(units)) => {
+                let number_formatter = &ui.style().number_formatter;
+                units.format(value, auto_decimals..=max_decimals, |value, decimals| {
+                    number_formatter.format(value, decimals)
+                })
+            }
+            (None, None) => ui
                 .style()
                 .number_formatter
                 .format(value, auto_decimals..=max_decimals),
@@ -529,18 +596,23 @@ impl Widget for DragValue<'_> {
 
         let text_style = ui.style().drag_value_text_style.clone();
 
-        if ui.memory(|mem| mem.lost_focus(id)) && !ui.input(|i| i.key_pressed(Key::Escape)) {
-            let value_text = ui.data_mut(|data| data.remove_temp::<String>(id));
-            if let Some(value_text) = value_text {
-                // We were editing the value as text last frame, but lost focus.
-                // Make sure we applied the last text value:
-                let parsed_value = parse(&custom_parser, &value_text);
-                if let Some(mut parsed_value) = parsed_value {
-                    // User edits always clamps:
-                    parsed_value = clamp_value_to_range(parsed_value, range.clone());
-                    set(&mut get_set_value, parsed_value);
+        if ui.memory(|mem| mem.lost_focus(id)) {
+            if !ui.input(|i| i.key_pressed(Key::Escape)) {
+                let value_text = ui.data_mut(|data| data.remove_temp::<String>(id));
+                if let Some(value_text) = value_text {
+                    // We were editing the value as text last frame, but lost focus.
+                    // Make sure we applied the last text value:
+                    let parsed_value = parse(&custom_parser, &parser, &value_text);
+                    if let Some(mut parsed_value) = parsed_value {
+                        // User edits always clamps:
+                        parsed_value = clamp_value_to_range(parsed_value, range.clone());
+                        set(&mut get_set_value, parsed_value);
+                    }
                 }
             }
+
+            // Done editing, whether the edit was committed or cancelled:
+            ui.data_mut(|data| data.remove::<f64>(edit_base_id));
         }
 
         // some clones below are redundant if AccessKit is disabled
@@ -576,7 +648,7 @@ impl Widget for DragValue<'_> {
                 response.lost_focus() && !ui.input(|i| i.key_pressed(Key::Escape))
             };
             if update {
-                let parsed_value = parse(&custom_parser, &value_text);
+                let parsed_value = parse(&custom_parser, &parser, &value_text);
                 if let Some(mut parsed_value) = parsed_value {
                     // User edits always clamps:
                     parsed_value = clamp_value_to_range(parsed_value, range.clone());
@@ -713,9 +785,14 @@ impl Widget for DragValue<'_> {
     }
 }
 
-fn parse(custom_parser: &Option<NumParser<'_>>, value_text: &str) -> Option<f64> {
+fn parse(
+    custom_parser: &Option<NumParser<'_>>,
+    parser: &NumberParser<'_>,
+    value_text: &str,
+) -> Option<f64> {
     match &custom_parser {
-        Some(parser) => parser(value_text),
+        Some(custom_parser) => custom_parser(value_text),
+        None if parser.expressions || parser.units.is_some() => parser.parse(value_text),
         None => default_parser(value_text),
     }
 }
@@ -14,6 +14,7 @@ mod hyperlink;
 mod image;
 mod image_button;
 mod label;
+mod number_expression;
 mod progress_bar;
 mod radio_button;
 mod selected_label;
@@ -36,10 +37,11 @@ pub use self::{
     },
     image_button::ImageButton,
     label::Label,
+    number_expression::{Unit, Units},
     progress_bar::ProgressBar,
     radio_button::RadioButton,
     separator::Separator,
-    slider::{Slider, SliderClamping, SliderOrientation},
+    slider::{Slider, SliderClamping, SliderOrientation, SliderTicks},
     spinner::Spinner,
     text_edit::{TextBuffer, TextEdit},
 };
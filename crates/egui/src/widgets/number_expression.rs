@@ -0,0 +1,637 @@
+//! Parsing of arithmetic expressions and units for [`crate::DragValue`].
+
+use std::ops::RangeInclusive;
+
+/// A unit a [`Units`] set understands, e.g. `mm`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    /// What the user types and sees, e.g. `"mm"`.
+    pub symbol: String,
+
+    /// How many of the base unit this is, e.g. `0.001` for millimeters if the base unit is meters.
+    pub factor: f64,
+
+    /// Can this unit be picked when displaying values, or is it only understood when typed?
+    pub display: bool,
+}
+
+/// A set of units a [`crate::DragValue`] understands and can display values in.
+///
+/// The value itself is always stored in the base unit (with factor `1.0`).
+/// Values typed without a unit are interpreted in the unit currently displayed.
+/// Adding numbers with and without a unit, like `1 m + 20`, is rejected as ambiguous.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut meters: f64 = 0.012;
+/// ui.add(
+///     egui::DragValue::new(&mut meters)
+///         .speed(0.001)
+///         .units(egui::Units::meters().display_unit("mm")),
+/// ); // Shows "12 mm", and understands "1.5 cm", "2 in", …
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Units {
+    units: Vec<Unit>,
+    display_unit: Option<String>,
+}
+
+impl Units {
+    /// A set of units with the given base unit, which has a factor of `1.0`.
+    pub fn new(base_symbol: impl Into<String>) -> Self {
+        Self {
+            units: Vec::new(),
+            display_unit: None,
+        }
+        .with(base_symbol, 1.0)
+    }
+
+    /// Lengths, stored in meters: `nm`, `µm`, `mm`, `cm`, `m`, `km`, plus `in`, `ft`, `yd` and `mi` for input.
+    pub fn meters() -> Self {
+        Self::new("m")
+            .with("nm", 1e-9)
+            .with("µm", 1e-6)
+            .alias("um", 1e-6)
+            .with("mm", 1e-3)
+            .with("cm", 1e-2)
+            .with("km", 1e3)
+            .alias("in", 0.0254)
+            .alias("\"", 0.0254)
+            .alias("ft", 0.3048)
+            .alias("'", 0.3048)
+            .alias("yd", 0.9144)
+            .alias("mi", 1609.344)
+    }
+
+    /// Sizes in bytes, displayed in binary units (`KiB`, `MiB`, …).
+    ///
+    /// Decimal units (`kB`, `MB`, …) are understood when typed.
+    pub fn bytes() -> Self {
+        let mut units = Self::new("B");
+        for (i, (binary, decimal)) in [("KiB", "kB"), ("MiB", "MB"), ("GiB", "GB"), ("TiB", "TB")]
+            .into_iter()
+            .enumerate()
+        {
+            let exponent = i as i32 + 1;
+            units = units
+                .with(binary, 1024_f64.powi(exponent))
+                .alias(decimal, 1000_f64.powi(exponent));
+        }
+        units.alias("KB", 1000.0)
+    }
+
+    /// Angles, stored in radians, displayed in degrees.
+    pub fn radians() -> Self {
+        Self::new("rad")
+            .with("°", std::f64::consts::PI / 180.0)
+            .alias("deg", std::f64::consts::PI / 180.0)
+            .alias("turn", std::f64::consts::TAU)
+            .display_unit("°")
+    }
+
+    /// Add a unit that values can be displayed in.
+    #[inline]
+    pub fn with(mut self, symbol: impl Into<String>, factor: f64) -> Self {
+        self.units.push(Unit {
+            symbol: symbol.into(),
+            factor,
+            display: true,
+        });
+        self
+    }
+
+    /// Add a unit that is understood when typed, but never used for display.
+    #[inline]
+    pub fn alias(mut self, symbol: impl Into<String>, factor: f64) -> Self {
+        self.units.push(Unit {
+            symbol: symbol.into(),
+            factor,
+            display: false,
+        });
+        self
+    }
+
+    /// Always display values in this unit.
+    ///
+    /// By default the largest unit smaller than the value is picked,
+    /// e.g. `1.5 KiB` rather than `1536 B`.
+    #[inline]
+    pub fn display_unit(mut self, symbol: impl Into<String>) -> Self {
+        self.display_unit = Some(symbol.into());
+        self
+    }
+
+    /// All the units in this set.
+    pub fn units(&self) -> &[Unit] {
+        &self.units
+    }
+
+    /// The unit to display the given value (in base units) in.
+    pub fn unit_for(&self, value: f64) -> Option<&Unit> {
+        if let Some(display_unit) = &self.display_unit {
+            return self.units.iter().find(|unit| &unit.symbol == display_unit);
+        }
+
+        let magnitude = value.abs();
+        let base = self.units.iter().find(|unit| unit.factor == 1.0);
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return base.or_else(|| self.units.first());
+        }
+
+        self.units
+            .iter()
+            .filter(|unit| unit.display && unit.factor <= magnitude)
+            .max_by(|a, b| a.factor.total_cmp(&b.factor))
+            .or_else(|| {
+                // Smaller than all units:
+                self.units
+                    .iter()
+                    .filter(|unit| unit.display)
+                    .min_by(|a, b| a.factor.total_cmp(&b.factor))
+            })
+    }
+
+    /// Format the value (in base units) in the unit it should be displayed in.
+    ///
+    /// `decimals` is the range of decimals to use for the base unit,
+    /// and is adjusted to the displayed unit.
+    pub(crate) fn format(
+        &self,
+        value: f64,
+        decimals: RangeInclusive<usize>,
+        format_number: impl Fn(f64, RangeInclusive<usize>) -> String,
+    ) -> String {
+        let Some(unit) = self.unit_for(value) else {
+            return format_number(value, decimals);
+        };
+        // Keep the same precision in base units, but don't show trailing zeros for larger units:
+        let shift = unit.factor.log10().round() as i64;
+        let shift_decimals =
+            |decimals: usize, shift: i64| (decimals as i64 + shift).clamp(0, 15) as usize;
+        let decimals = shift_decimals(*decimals.start(), shift.min(0))
+            ..=shift_decimals(*decimals.end(), shift);
+        format!(
+            "{} {}",
+            format_number(value / unit.factor, decimals),
+            unit.symbol
+        )
+    }
+
+    /// The longest unit symbol that `text` starts with.
+    fn match_prefix(&self, text: &str) -> Option<&Unit> {
+        self.units
+            .iter()
+            .filter(|unit| {
+                text.starts_with(&unit.symbol)
+                    && !text[unit.symbol.len()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric)
+            })
+            .max_by_key(|unit| unit.symbol.len())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// How to interpret text typed into a [`crate::DragValue`].
+pub(crate) struct NumberParser<'a> {
+    /// Allow arithmetic, constants, percentages and relative edits.
+    pub expressions: bool,
+
+    pub units: Option<&'a Units>,
+
+    /// The value (in base units) before editing started, used for relative edits like `+=10`.
+    pub current_value: f64,
+}
+
+impl NumberParser<'_> {
+    /// Parse the text into a value in base units.
+    pub fn parse(&self, text: &str) -> Option<f64> {
+        // Replace special minus character with normal minus (hyphen):
+        let text: String = text.trim().replace('−', "-");
+
+        // Bare numbers are in the displayed unit:
+        let default_factor = self
+            .units
+            .and_then(|units| units.unit_for(self.current_value))
+            .map_or(1.0, |unit| unit.factor);
+
+        if self.expressions {
+            for (op, apply) in RELATIVE_OPS {
+                if let Some(rest) = text.strip_prefix(op) {
+                    // The right hand side of `*=` and `/=` is a plain factor:
+                    let default_factor = if matches!(*op, "*=" | "/=") {
+                        1.0
+                    } else {
+                        default_factor
+                    };
+                    let rhs = self.parse_expression(rest, default_factor)?;
+                    return Some(apply(self.current_value, rhs)).filter(|v| !v.is_nan());
+                }
+            }
+        }
+
+        self.parse_expression(&text, default_factor)
+    }
+
+    fn parse_expression(&self, text: &str, default_factor: f64) -> Option<f64> {
+        let mut expr = Expression {
+            text,
+            pos: 0,
+            units: self.units,
+            arithmetic: self.expressions,
+        };
+        let term = expr.sum()?;
+        expr.skip_whitespace();
+        if expr.pos != text.len() {
+            return None;
+        }
+        let value = if term.has_unit {
+            term.fraction()
+        } else {
+            term.fraction() * default_factor
+        };
+        Some(value).filter(|v| !v.is_nan())
+    }
+}
+
+type RelativeOp = (&'static str, fn(f64, f64) -> f64);
+
+const RELATIVE_OPS: &[RelativeOp] = &[
+    ("+=", |a, b| a + b),
+    ("-=", |a, b| a - b),
+    ("*=", |a, b| a * b),
+    ("/=", |a, b| a / b),
+];
+
+/// A value produced while evaluating an [`Expression`].
+#[derive(Clone, Copy)]
+struct Term {
+    value: f64,
+
+    /// Is this a percentage?
+    ///
+    /// `a + b%` is `a` increased by `b` percent, like on a calculator.
+    /// Anywhere else a percentage is divided by 100.
+    is_percent: bool,
+
+    /// Was the value given with an explicit unit, and is thus already in base units?
+    has_unit: bool,
+}
+
+impl Term {
+    fn number(value: f64) -> Self {
+        Self {
+            value,
+            is_percent: false,
+            has_unit: false,
+        }
+    }
+
+    /// The value, with any percentage divided by 100.
+    fn fraction(self) -> f64 {
+        if self.is_percent {
+            self.value / 100.0
+        } else {
+            self.value
+        }
+    }
+}
+
+/// A recursive descent parser and evaluator.
+///
+/// ```text
+/// sum     = product (("+" | "-") product)*
+/// product = unary (("*" | "/") unary)*
+/// unary   = ("-" | "+") unary | power
+/// power   = postfix ("^" unary)?
+/// postfix = primary unit? "%"?
+/// primary = number | constant | "(" sum ")"
+/// ```
+///
+/// So `-2^2` is `-4`, and `2^-1` is `0.5`.
+///
+/// Numbers with and without a unit can be multiplied (`2 * 3 cm`), but not added (`1 m + 20`),
+/// since it is unclear which unit the bare number is in.
+///
+/// Without `arithmetic`, only a single (optionally negative) number with an optional unit is accepted.
+struct Expression<'a> {
+    text: &'a str,
+    pos: usize,
+    units: Option<&'a Units>,
+    arithmetic: bool,
+}
+
+impl Expression<'_> {
+    fn rest(&self) -> &str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn sum(&mut self) -> Option<Term> {
+        let mut lhs = self.product()?;
+        if !self.arithmetic {
+            return Some(lhs);
+        }
+        loop {
+            let sign = if self.eat("+") {
+                1.0
+            } else if self.eat("-") {
+                -1.0
+            } else {
+                return Some(lhs);
+            };
+            let rhs = self.product()?;
+            let value = lhs.fraction();
+            lhs = if rhs.is_percent {
+                Term {
+                    value: value + sign * value * rhs.value / 100.0,
+                    is_percent: false,
+                    has_unit: lhs.has_unit,
+                }
+            } else if lhs.has_unit == rhs.has_unit {
+                Term {
+                    value: value + sign * rhs.value,
+                    is_percent: false,
+                    has_unit: lhs.has_unit,
+                }
+            } else {
+                return None; // e.g. `1 m + 20`
+            };
+        }
+    }
+
+    fn product(&mut self) -> Option<Term> {
+        let mut lhs = self.unary()?;
+        if !self.arithmetic {
+            return Some(lhs);
+        }
+        loop {
+            let divide = if self.eat("*") || self.eat("×") {
+                false
+            } else if self.eat("/") || self.eat("÷") {
+                true
+            } else {
+                return Some(lhs);
+            };
+            let rhs = self.unary()?;
+            if divide {
+                lhs.value /= rhs.fraction();
+            } else {
+                lhs.value *= rhs.fraction();
+            }
+            lhs.has_unit |= rhs.has_unit;
+            // A percentage scaled by a number is still a percentage.
+        }
+    }
+
+    fn unary(&mut self) -> Option<Term> {
+        if self.eat("-") {
+            let term = self.unary()?;
+            Some(Term {
+                value: -term.value,
+                ..term
+            })
+        } else if self.arithmetic && self.eat("+") {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    fn power(&mut self) -> Option<Term> {
+        let base = self.postfix()?;
+        if self.arithmetic && self.eat("^") {
+            let exponent = self.unary()?;
+            Some(Term {
+                value: base.fraction().powf(exponent.fraction()),
+                is_percent: false,
+                has_unit: base.has_unit,
+            })
+        } else {
+            Some(base)
+        }
+    }
+
+    fn postfix(&mut self) -> Option<Term> {
+        let mut term = self.primary()?;
+
+        if let Some(units) = self.units {
+            self.skip_whitespace();
+            if let Some(unit) = units.match_prefix(self.rest()) {
+                self.pos += unit.symbol.len();
+                term.has_unit = true;
+                term.value *= unit.factor;
+            }
+        }
+
+        term.is_percent = self.arithmetic && self.eat("%");
+        Some(term)
+    }
+
+    fn primary(&mut self) -> Option<Term> {
+        self.skip_whitespace();
+
+        if self.arithmetic {
+            if self.eat("(") {
+                let term = self.sum()?;
+                if !self.eat(")") {
+                    return None;
+                }
+                return Some(Term {
+                    value: term.fraction(),
+                    is_percent: false,
+                    has_unit: term.has_unit,
+                });
+            }
+
+            for (name, value) in CONSTANTS {
+                let rest = self.rest();
+                if rest.starts_with(name)
+                    && !rest[name.len()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric)
+                {
+                    self.pos += name.len();
+                    return Some(Term::number(*value));
+                }
+            }
+        }
+
+        self.number().map(Term::number)
+    }
+
+    /// A number, ignoring whitespace between digits (used as thousands separators).
+    fn number(&mut self) -> Option<f64> {
+        let rest = self.rest();
+        let mut number = String::new();
+        let mut len = 0;
+        let mut chars = rest.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let is_exponent_sign = (c == '-' || c == '+')
+                && number
+                    .chars()
+                    .last()
+                    .is_some_and(|last| last == 'e' || last == 'E');
+            let is_exponent = (c == 'e' || c == 'E')
+                && !number.is_empty()
+                && chars
+                    .peek()
+                    .is_some_and(|(_, next)| next.is_ascii_digit() || *next == '-' || *next == '+');
+            if c.is_ascii_digit() || c == '.' || is_exponent_sign || is_exponent {
+                number.push(c);
+                len = i + c.len_utf8();
+            } else if c.is_whitespace()
+                && !number.is_empty()
+                && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit())
+            {
+                // Thousands separator
+            } else {
+                break;
+            }
+        }
+
+        let value = number.parse().ok()?;
+        self.pos += len;
+        Some(value)
+    }
+}
+
+const CONSTANTS: &[(&str, f64)] = &[
+    ("pi", std::f64::consts::PI),
+    ("π", std::f64::consts::PI),
+    ("tau", std::f64::consts::TAU),
+    ("τ", std::f64::consts::TAU),
+    ("e", std::f64::consts::E),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberParser, Units};
+
+    fn eval(text: &str) -> Option<f64> {
+        NumberParser {
+            expressions: true,
+            units: None,
+            current_value: 0.0,
+        }
+        .parse(text)
+    }
+
+    fn assert_approx(value: Option<f64>, expected: f64) {
+        let value = value.unwrap_or(f64::NAN);
+        assert!(
+            (value - expected).abs() < 1e-9,
+            "Expected {expected}, got {value}"
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_approx(eval("1 + 2 * 3"), 7.0);
+        assert_approx(eval("(1 + 2) * 3"), 9.0);
+        assert_approx(eval("2^3^2"), 512.0);
+        assert_approx(eval("-2^2"), -4.0);
+        assert_approx(eval("(-2)^2"), 4.0);
+        assert_approx(eval("2^-1"), 0.5);
+        assert_approx(eval("-2*3"), -6.0);
+        assert_approx(eval("2*pi/3"), std::f64::consts::TAU / 3.0);
+        assert_approx(eval("1e3 - 1"), 999.0);
+        assert_approx(eval("1 234 + 1"), 1235.0);
+        assert_approx(eval("−1.5"), -1.5);
+        assert_eq!(eval("1 +"), None);
+        assert_eq!(eval("(1"), None);
+        assert_eq!(eval("hello"), None);
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_approx(eval("10+5%"), 10.5);
+        assert_approx(eval("200 - 10%"), 180.0);
+        assert_approx(eval("50%"), 0.5);
+        assert_approx(eval("50% * 4"), 2.0);
+    }
+
+    #[test]
+    fn test_relative() {
+        let parser = NumberParser {
+            expressions: true,
+            units: None,
+            current_value: 5.0,
+        };
+        assert_approx(parser.parse("+=10"), 15.0);
+        assert_approx(parser.parse("-= 2*2"), 1.0);
+        assert_approx(parser.parse("*=3"), 15.0);
+        assert_approx(parser.parse("/=2"), 2.5);
+
+        let parser = NumberParser {
+            expressions: false,
+            ..parser
+        };
+        assert_eq!(parser.parse("+=10"), None);
+        assert_eq!(parser.parse("1+1"), None);
+        assert_approx(parser.parse("-3"), -3.0);
+    }
+
+    #[test]
+    fn test_units() {
+        let meters = Units::meters().display_unit("mm");
+        let parser = NumberParser {
+            expressions: true,
+            units: Some(&meters),
+            current_value: 0.012,
+        };
+        assert_approx(parser.parse("12 mm"), 0.012);
+        assert_approx(parser.parse("12"), 0.012);
+        assert_approx(parser.parse("1 m + 20 cm"), 1.2);
+        assert_approx(parser.parse("2 * 3 cm"), 0.06);
+        assert_approx(parser.parse("10 + 2"), 0.012);
+        assert_eq!(parser.parse("1 m + 20"), None, "ambiguous unit");
+        assert_approx(parser.parse("2in"), 0.0508);
+        assert_approx(parser.parse("+= 3"), 0.015);
+        assert_approx(parser.parse("*= 2"), 0.024);
+
+        let bytes = Units::bytes();
+        let parser = NumberParser {
+            expressions: false,
+            units: Some(&bytes),
+            current_value: 0.0,
+        };
+        assert_approx(parser.parse("1.5 KiB"), 1536.0);
+        assert_approx(parser.parse("2MB"), 2e6);
+        assert_eq!(parser.parse("2 parsecs"), None);
+    }
+
+    #[test]
+    fn test_unit_display() {
+        let bytes = Units::bytes();
+        let format = |value: f64| {
+            bytes.format(value, 0..=0, |v, d| {
+                emath::format_with_decimals_in_range(v, d)
+            })
+        };
+        assert_eq!(format(1536.0), "1.5 KiB");
+        assert_eq!(format(100.0), "100 B");
+        assert_eq!(format(3.0 * 1024.0 * 1024.0), "3 MiB");
+
+        let meters = Units::meters().display_unit("mm");
+        assert_eq!(meters.unit_for(1.0).map(|u| u.symbol.as_str()), Some("mm"));
+    }
+}
@@ -1,11 +1,11 @@
 #![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
 
-use std::ops::RangeInclusive;
+use std::{ops::RangeInclusive, sync::Arc};
 
 use crate::{
-    Color32, DragValue, EventFilter, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef, Rect,
-    Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, emath,
-    epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
+    Color32, DragValue, EventFilter, Galley, Key, Label, MINUS_CHAR_STR, NumExt as _, Pos2, Rangef,
+    Rect, Response, Sense, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText,
+    WidgetType, emath, epaint, lerp, pos2, remap, remap_clamp, style, style::HandleShape, vec2,
 };
 
 use super::drag_value::clamp_value_to_range;
@@ -75,6 +75,57 @@ pub enum SliderClamping {
     Always,
 }
 
+/// Where to put tick marks along the rail of a [`Slider`].
+///
+/// The ticks are placed with the same mapping as the slider itself,
+/// so they respect [`Slider::logarithmic`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SliderTicks {
+    /// This many ticks, evenly spaced along the rail (including both ends).
+    ///
+    /// For a logarithmic slider this means evenly spaced on a logarithmic scale.
+    Count(usize),
+
+    /// A tick every `step` units, starting at the start of the range.
+    Step(f64),
+
+    /// Ticks at exactly these values. Values outside of the range are ignored.
+    Values(Vec<f64>),
+}
+
+/// Never show more ticks than this.
+const MAX_TICKS: usize = 1000;
+
+/// Length of a tick mark, in points.
+const TICK_LENGTH: f32 = 4.0;
+
+/// One of the two handles of a range [`Slider`].
+///
+/// A normal slider only has a [`Thumb::Start`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+enum Thumb {
+    Start = 0,
+    End = 1,
+}
+
+impl Thumb {
+    fn other(self) -> Self {
+        match self {
+            Self::Start => Self::End,
+            Self::End => Self::Start,
+        }
+    }
+}
+
+/// What is being dragged when the rail of a range [`Slider`] is dragged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum RangeGrab {
+    Thumb(Thumb),
+
+    /// The segment between the two handles, moving both.
+    Middle,
+}
+
 /// Control a number with a slider.
 ///
 /// The slider range defines the values you get when pulling the slider to the far edges.
@@ -94,9 +145,16 @@ pub enum SliderClamping {
 /// ```
 ///
 /// The default [`Slider`] size is set by [`crate::style::Spacing::slider_width`].
+///
+/// Use [`Slider::new_range`] to select a range with two handles,
+/// and [`Slider::ticks`] to show tick marks along the rail.
 #[must_use = "You should put this widget in a ui with `ui.add(widget);`"]
 pub struct Slider<'a> {
     get_set_value: GetSetValue<'a>,
+
+    /// The end of the range, for range sliders.
+    get_set_end: Option<GetSetValue<'a>>,
+
     range: RangeInclusive<f64>,
     spec: SliderSpec,
     clamping: SliderClamping,
@@ -118,6 +176,8 @@ pub struct Slider<'a> {
     trailing_fill: Option<bool>,
     handle_shape: Option<HandleShape>,
     update_while_editing: bool,
+    ticks: Option<SliderTicks>,
+    tick_labels: bool,
 }
 
 impl<'a> Slider<'a> {
@@ -143,6 +203,7 @@ impl<'a> Slider<'a> {
     ) -> Self {
         Self {
             get_set_value: Box::new(get_set_value),
+            get_set_end: None,
             range,
             spec: SliderSpec {
                 logarithmic: false,
@@ -165,9 +226,61 @@ impl<'a> Slider<'a> {
             trailing_fill: None,
             handle_shape: None,
             update_while_editing: true,
+            ticks: None,
+            tick_labels: false,
         }
     }
 
+    /// Creates a new horizontal range slider, with one handle for each end of the selected range.
+    ///
+    /// The handles can't cross each other.
+    /// Dragging the rail between the handles moves the whole range.
+    ///
+    /// Both values will be clamped to the `range`,
+    /// unless you change this behavior with [`Self::clamping`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (mut low, mut high): (f32, f32) = (20.0, 80.0);
+    /// ui.add(egui::Slider::new_range(&mut low, &mut high, 0.0..=100.0).text("Range"));
+    /// # });
+    /// ```
+    pub fn new_range<Num: emath::Numeric>(
+        start: &'a mut Num,
+        end: &'a mut Num,
+        range: RangeInclusive<Num>,
+    ) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set_range(
+            range_f64,
+            move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *start = Num::from_f64(v);
+                }
+                start.to_f64()
+            },
+            move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *end = Num::from_f64(v);
+                }
+                end.to_f64()
+            },
+        );
+
+        if Num::INTEGRAL { slf.integer() } else { slf }
+    }
+
+    /// Like [`Self::new_range`], but with a getter/setter for each end of the range.
+    pub fn from_get_set_range(
+        range: RangeInclusive<f64>,
+        get_set_start: impl 'a + FnMut(Option<f64>) -> f64,
+        get_set_end: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        let mut slf = Self::from_get_set(range, get_set_start);
+        slf.get_set_end = Some(Box::new(get_set_end));
+        slf
+    }
+
     /// Control whether or not the slider shows the current value.
     /// Default: `true`.
     #[inline]
@@ -385,6 +498,39 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Show tick marks along the rail.
+    ///
+    /// Default: no ticks.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut my_f32: f32 = 1.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut my_f32, 1.0..=1000.0)
+    ///         .logarithmic(true)
+    ///         .ticks(egui::SliderTicks::Values(vec![1.0, 10.0, 100.0, 1000.0]))
+    ///         .tick_labels(true),
+    /// );
+    /// # });
+    /// ```
+    #[inline]
+    pub fn ticks(mut self, ticks: SliderTicks) -> Self {
+        self.ticks = Some(ticks);
+        self
+    }
+
+    /// Show the value of each tick below (or next to) it.
+    ///
+    /// The labels are formatted with [`Self::custom_formatter`] if set,
+    /// else with [`crate::Style::number_formatter`].
+    ///
+    /// Has no effect unless [`Self::ticks`] is set. Default: `false`.
+    #[inline]
+    pub fn tick_labels(mut self, tick_labels: bool) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
     /// Change the shape of the slider handle
     ///
     /// This setting can be enabled globally for all sliders with [`crate::Visuals::handle_shape`].
@@ -602,7 +748,14 @@ impl<'a> Slider<'a> {
     }
 
     fn get_value(&mut self) -> f64 {
-        let value = get(&mut self.get_set_value);
+        self.get_thumb_value(Thumb::Start)
+    }
+
+    fn get_thumb_value(&mut self, thumb: Thumb) -> f64 {
+        let value = match (thumb, &mut self.get_set_end) {
+            (Thumb::End, Some(get_set_end)) => get(get_set_end),
+            _ => get(&mut self.get_set_value),
+        };
         if self.clamping == SliderClamping::Always {
             clamp_value_to_range(value, self.range.clone())
         } else {
@@ -610,7 +763,11 @@ impl<'a> Slider<'a> {
         }
     }
 
-    fn set_value(&mut self, mut value: f64) {
+    fn set_value(&mut self, value: f64) {
+        self.set_thumb_value(Thumb::Start, value);
+    }
+
+    fn set_thumb_value(&mut self, thumb: Thumb, mut value: f64) {
         if self.clamping != SliderClamping::Never {
             value = clamp_value_to_range(value, self.range.clone());
         }
@@ -622,7 +779,25 @@ impl<'a> Slider<'a> {
         if let Some(max_decimals) = self.max_decimals {
             value = emath::round_to_decimals(value, max_decimals);
         }
-        set(&mut self.get_set_value, value);
+
+        if self.is_range() {
+            // The handles of a range slider can't cross each other:
+            let other = self.get_thumb_value(thumb.other());
+            let normalized = normalized_from_value(value, self.range(), &self.spec);
+            let other_normalized = normalized_from_value(other, self.range(), &self.spec);
+            let crosses = match thumb {
+                Thumb::Start => other_normalized < normalized,
+                Thumb::End => normalized < other_normalized,
+            };
+            if crosses {
+                value = other;
+            }
+        }
+
+        match (thumb, &mut self.get_set_end) {
+            (Thumb::End, Some(get_set_end)) => set(get_set_end, value),
+            _ => set(&mut self.get_set_value, value),
+        }
     }
 
     fn range(&self) -> RangeInclusive<f64> {
@@ -652,37 +827,238 @@ impl<'a> Slider<'a> {
 }
 
 impl Slider<'_> {
+    fn is_range(&self) -> bool {
+        self.get_set_end.is_some()
+    }
+
     /// Just the slider, no text
     fn allocate_slider_space(&self, ui: &mut Ui, thickness: f32) -> Response {
         let desired_size = match self.orientation {
             SliderOrientation::Horizontal => vec2(ui.spacing().slider_width, thickness),
             SliderOrientation::Vertical => vec2(thickness, ui.spacing().slider_width),
         };
-        ui.allocate_response(desired_size, Sense::drag())
+        let sense = if self.is_range() {
+            // The handles of a range slider are focused individually.
+            Sense::CLICK | Sense::DRAG
+        } else {
+            Sense::drag()
+        };
+        ui.allocate_response(desired_size, sense)
+    }
+
+    /// The part of the allocated space used by the rail and handles, i.e. excluding any ticks.
+    fn slider_rect(&self, rect: Rect, tick_space: f32) -> Rect {
+        let mut rect = rect;
+        match self.orientation {
+            SliderOrientation::Horizontal => rect.max.y -= tick_space,
+            SliderOrientation::Vertical => rect.max.x -= tick_space,
+        }
+        rect
+    }
+
+    /// The value the user is pointing at, using smart aim if enabled.
+    fn value_from_pointer(&self, ui: &Ui, position: f32, position_range: Rangef) -> f64 {
+        if self.smart_aim {
+            let aim_radius = ui.input(|i| i.aim_radius());
+            emath::smart_aim::best_in_range_f64(
+                self.value_from_position(position - aim_radius, position_range),
+                self.value_from_position(position + aim_radius, position_range),
+            )
+        } else {
+            self.value_from_position(position, position_range)
+        }
     }
 
     /// Just the slider, no text
-    fn slider_ui(&mut self, ui: &Ui, response: &Response) {
-        let rect = &response.rect;
+    fn slider_ui(&mut self, ui: &Ui, response: &Response, rect: Rect) {
         let handle_shape = self
             .handle_shape
             .unwrap_or_else(|| ui.style().visuals.handle_shape);
-        let position_range = self.position_range(rect, &handle_shape);
+        let position_range = self.position_range(&rect, &handle_shape);
 
         if let Some(pointer_position_2d) = response.interact_pointer_pos() {
             let position = self.pointer_position(pointer_position_2d);
-            let new_value = if self.smart_aim {
-                let aim_radius = ui.input(|i| i.aim_radius());
-                emath::smart_aim::best_in_range_f64(
-                    self.value_from_position(position - aim_radius, position_range),
-                    self.value_from_position(position + aim_radius, position_range),
-                )
-            } else {
-                self.value_from_position(position, position_range)
-            };
+            let new_value = self.value_from_pointer(ui, position, position_range);
             self.set_value(new_value);
         }
 
+        self.keyboard_ui(ui, response, Thumb::Start, position_range);
+
+        // Paint it:
+        if ui.is_rect_visible(response.rect) {
+            let value = self.get_value();
+
+            let visuals = ui.style().interact(response);
+            let rail_rect = self.paint_rail(ui, &rect);
+
+            let position_1d = self.position_from_value(value, position_range);
+            let center = self.marker_center(position_1d, &rail_rect);
+
+            // Decide if we should add trailing fill.
+            let trailing_fill = self
+                .trailing_fill
+                .unwrap_or_else(|| ui.visuals().slider_trailing_fill);
+
+            // Paint trailing fill.
+            if trailing_fill {
+                let start = match self.orientation {
+                    SliderOrientation::Horizontal => rail_rect.left_center(),
+                    SliderOrientation::Vertical => rail_rect.center_bottom(),
+                };
+                self.paint_fill(ui, &rail_rect, start, center);
+            }
+
+            self.paint_handle(ui, &rect, center, visuals);
+            self.paint_ticks(ui, response.rect, &rail_rect, position_range);
+        }
+    }
+
+    /// The slider part of a range slider, returning the responses of the two handles.
+    fn range_slider_ui(&mut self, ui: &Ui, response: &Response, rect: Rect) -> [Response; 2] {
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        let position_range = self.position_range(&rect, &handle_shape);
+        let handle_half_size = self.handle_half_size(&rect, &handle_shape);
+        let rail_rect = self.rail_rect(&rect, 0.0);
+
+        let positions = [Thumb::Start, Thumb::End].map(|thumb| {
+            let value = self.get_thumb_value(thumb);
+            self.position_from_value(value, position_range)
+        });
+
+        // When the handles overlap, the one that can move away from the edge should be on top:
+        let start_on_top = 0.5
+            < remap(
+                0.5 * (positions[0] + positions[1]),
+                position_range,
+                0.0..=1.0,
+            );
+        let interact_order = if start_on_top {
+            [Thumb::End, Thumb::Start]
+        } else {
+            [Thumb::Start, Thumb::End]
+        };
+        let mut thumb_responses = interact_order.map(|thumb| {
+            let center = self.marker_center(positions[thumb as usize], &rail_rect);
+            let thumb_rect = Rect::from_center_size(center, 2.0 * handle_half_size);
+            ui.interact(thumb_rect, response.id.with(thumb), Sense::drag())
+        });
+        if start_on_top {
+            thumb_responses.reverse();
+        }
+
+        // Dragging the rail moves the closest handle, or the whole range if grabbed in the middle:
+        let grab_id = response.id.with("grab");
+        if let Some(pointer_position_2d) = response.interact_pointer_pos() {
+            let position = self.pointer_position(pointer_position_2d);
+            let candidate = self.grab_at(position, positions, handle_half_size);
+            let grab = ui.data_mut(|d| *d.get_temp_mut_or_insert_with(grab_id, || candidate));
+            match grab {
+                RangeGrab::Thumb(thumb) => {
+                    let new_value = self.value_from_pointer(ui, position, position_range);
+                    self.set_thumb_value(thumb, new_value);
+                }
+                RangeGrab::Middle => {
+                    let delta = self.pointer_position(ui.input(|i| i.pointer.delta()).to_pos2());
+                    self.shift_range(delta, position_range);
+                }
+            }
+        } else {
+            ui.data_mut(|d| d.remove::<RangeGrab>(grab_id));
+        }
+
+        for (thumb, thumb_response) in [Thumb::Start, Thumb::End].into_iter().zip(&thumb_responses)
+        {
+            if let Some(pointer_position_2d) = thumb_response.interact_pointer_pos() {
+                let position = self.pointer_position(pointer_position_2d);
+                let new_value = self.value_from_pointer(ui, position, position_range);
+                self.set_thumb_value(thumb, new_value);
+            }
+            self.keyboard_ui(ui, thumb_response, thumb, position_range);
+        }
+
+        // Paint it:
+        if ui.is_rect_visible(response.rect) {
+            let rail_rect = self.paint_rail(ui, &rect);
+
+            let centers = [Thumb::Start, Thumb::End].map(|thumb| {
+                let value = self.get_thumb_value(thumb);
+                self.marker_center(self.position_from_value(value, position_range), &rail_rect)
+            });
+            self.paint_fill(ui, &rail_rect, centers[0], centers[1]);
+
+            for (center, thumb_response) in centers.into_iter().zip(&thumb_responses) {
+                let visuals = ui.style().interact(&thumb_response.union(response.clone()));
+                self.paint_handle(ui, &rect, center, visuals);
+            }
+
+            self.paint_ticks(ui, response.rect, &rail_rect, position_range);
+        }
+
+        thumb_responses
+    }
+
+    /// What is grabbed when pressing the rail of a range slider at `position`?
+    fn grab_at(&self, position: f32, positions: [f32; 2], handle_half_size: Vec2) -> RangeGrab {
+        let handle_radius = match self.orientation {
+            SliderOrientation::Horizontal => handle_half_size.x,
+            SliderOrientation::Vertical => handle_half_size.y,
+        };
+        let [start, end] = positions;
+        let distance_to_start = (position - start).abs();
+        let distance_to_end = (position - end).abs();
+        let between = start.min(end) < position && position < start.max(end);
+
+        if between && handle_radius < distance_to_start && handle_radius < distance_to_end {
+            RangeGrab::Middle
+        } else if distance_to_start < distance_to_end {
+            RangeGrab::Thumb(Thumb::Start)
+        } else if distance_to_end < distance_to_start {
+            RangeGrab::Thumb(Thumb::End)
+        } else if (position - start) * (end - start).signum() < 0.0 {
+            // The handles are on top of each other, so pick the one on the side of the pointer:
+            RangeGrab::Thumb(Thumb::Start)
+        } else {
+            RangeGrab::Thumb(Thumb::End)
+        }
+    }
+
+    /// Move both ends of a range slider by `delta_position` points, keeping the size of the range.
+    fn shift_range(&mut self, delta_position: f32, position_range: Rangef) {
+        let span = position_range.max - position_range.min;
+        if span == 0.0 || delta_position == 0.0 {
+            return;
+        }
+
+        let range = self.range();
+        let start = normalized_from_value(
+            self.get_thumb_value(Thumb::Start),
+            range.clone(),
+            &self.spec,
+        );
+        let end =
+            normalized_from_value(self.get_thumb_value(Thumb::End), range.clone(), &self.spec);
+        let delta = ((delta_position / span) as f64).clamp(-start.min(end), 1.0 - start.max(end));
+        if delta == 0.0 {
+            return;
+        }
+
+        let new_start = value_from_normalized(start + delta, range.clone(), &self.spec);
+        let new_end = value_from_normalized(end + delta, range, &self.spec);
+
+        // Move the leading handle first, so the handles never cross:
+        if 0.0 < delta {
+            self.set_thumb_value(Thumb::End, new_end);
+            self.set_thumb_value(Thumb::Start, new_start);
+        } else {
+            self.set_thumb_value(Thumb::Start, new_start);
+            self.set_thumb_value(Thumb::End, new_end);
+        }
+    }
+
+    /// Keyboard and accessibility actions for the handle with the given `response`.
+    fn keyboard_ui(&mut self, ui: &Ui, response: &Response, thumb: Thumb, position_range: Rangef) {
         let mut decrement = 0usize;
         let mut increment = 0usize;
 
@@ -726,7 +1102,7 @@ impl Slider<'_> {
 
         if kb_step != 0.0 {
             let ui_point_per_step = 1.0; // move this many ui points for each kb_step
-            let prev_value = self.get_value();
+            let prev_value = self.get_thumb_value(thumb);
             let prev_position = self.position_from_value(prev_value, position_range);
             let new_position = prev_position + ui_point_per_step * kb_step;
             let mut new_value = match self.step {
@@ -753,90 +1129,210 @@ impl Slider<'_> {
                     new_value
                 };
             }
-            self.set_value(new_value);
+            self.set_thumb_value(thumb, new_value);
         }
 
         ui.input(|input| {
             use accesskit::{Action, ActionData};
             for request in input.accesskit_action_requests(response.id, Action::SetValue) {
                 if let Some(ActionData::NumericValue(new_value)) = request.data {
-                    self.set_value(new_value);
+                    self.set_thumb_value(thumb, new_value);
                 }
             }
         });
+    }
 
-        // Paint it:
-        if ui.is_rect_visible(response.rect) {
-            let value = self.get_value();
-
-            let visuals = ui.style().interact(response);
-            let widget_visuals = &ui.visuals().widgets;
-            let spacing = &ui.style().spacing;
+    /// Paint the rail, returning its rectangle.
+    fn paint_rail(&self, ui: &Ui, rect: &Rect) -> Rect {
+        let widget_visuals = &ui.visuals().widgets;
+        let rail_radius = (ui.spacing().slider_rail_height / 2.0).at_least(0.0);
+        let rail_rect = self.rail_rect(rect, rail_radius);
+        ui.painter().rect_filled(
+            rail_rect,
+            widget_visuals.inactive.corner_radius,
+            widget_visuals.inactive.bg_fill,
+        );
+        rail_rect
+    }
 
-            let rail_radius = (spacing.slider_rail_height / 2.0).at_least(0.0);
-            let rail_rect = self.rail_rect(rect, rail_radius);
-            let corner_radius = widget_visuals.inactive.corner_radius;
+    /// Fill the part of the rail between the two given handle centers.
+    fn paint_fill(&self, ui: &Ui, rail_rect: &Rect, from: Pos2, to: Pos2) {
+        let corner_radius = ui.visuals().widgets.inactive.corner_radius;
+        let mut fill_rect = *rail_rect;
 
-            ui.painter()
-                .rect_filled(rail_rect, corner_radius, widget_visuals.inactive.bg_fill);
+        // The filled rect has to be drawn differently depending on the orientation.
+        match self.orientation {
+            SliderOrientation::Horizontal => {
+                fill_rect.min.x = from.x.min(to.x) - corner_radius.nw as f32;
+                fill_rect.max.x = from.x.max(to.x) + corner_radius.nw as f32;
+            }
+            SliderOrientation::Vertical => {
+                fill_rect.min.y = from.y.min(to.y) - corner_radius.se as f32;
+                fill_rect.max.y = from.y.max(to.y) + corner_radius.se as f32;
+            }
+        }
+        let fill_rect = fill_rect.intersect(*rail_rect);
 
-            let position_1d = self.position_from_value(value, position_range);
-            let center = self.marker_center(position_1d, &rail_rect);
+        ui.painter()
+            .rect_filled(fill_rect, corner_radius, ui.visuals().selection.bg_fill);
+    }
 
-            // Decide if we should add trailing fill.
-            let trailing_fill = self
-                .trailing_fill
-                .unwrap_or_else(|| ui.visuals().slider_trailing_fill);
+    fn paint_handle(&self, ui: &Ui, rect: &Rect, center: Pos2, visuals: &style::WidgetVisuals) {
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        match handle_shape {
+            style::HandleShape::Circle => {
+                ui.painter().add(epaint::CircleShape {
+                    center,
+                    radius: self.handle_radius(rect) + visuals.expansion,
+                    fill: visuals.bg_fill,
+                    stroke: visuals.fg_stroke,
+                });
+            }
+            style::HandleShape::Rect { .. } => {
+                let v = self.handle_half_size(rect, &handle_shape) + Vec2::splat(visuals.expansion);
+                let rect = Rect::from_center_size(center, 2.0 * v);
+                ui.painter().rect(
+                    rect,
+                    visuals.corner_radius,
+                    visuals.bg_fill,
+                    visuals.fg_stroke,
+                    epaint::StrokeKind::Inside,
+                );
+            }
+        }
+    }
 
-            // Paint trailing fill.
-            if trailing_fill {
-                let mut trailing_rail_rect = rail_rect;
+    /// The values at which to put ticks, if any.
+    fn tick_values(&self) -> Vec<f64> {
+        let Some(ticks) = &self.ticks else {
+            return Vec::new();
+        };
 
-                // The trailing rect has to be drawn differently depending on the orientation.
-                match self.orientation {
-                    SliderOrientation::Horizontal => {
-                        trailing_rail_rect.max.x = center.x + corner_radius.nw as f32;
-                    }
-                    SliderOrientation::Vertical => {
-                        trailing_rail_rect.min.y = center.y - corner_radius.se as f32;
-                    }
+        let range = self.range();
+        let (start, end) = (*range.start(), *range.end());
+        let (min, max) = (start.min(end), start.max(end));
+
+        match ticks {
+            SliderTicks::Count(count) => {
+                let count = (*count).at_most(MAX_TICKS);
+                (0..count)
+                    .map(|i| {
+                        let normalized = if count <= 1 {
+                            0.0
+                        } else {
+                            i as f64 / (count - 1) as f64
+                        };
+                        value_from_normalized(normalized, range.clone(), &self.spec)
+                    })
+                    .collect()
+            }
+            SliderTicks::Step(step) => {
+                let step = step.abs();
+                if step == 0.0 || !step.is_finite() || !(max - min).is_finite() {
+                    return Vec::new();
                 }
-
-                ui.painter().rect_filled(
-                    trailing_rail_rect,
-                    corner_radius,
-                    ui.visuals().selection.bg_fill,
-                );
+                let num_steps = ((max - min) / step + 1e-9)
+                    .floor()
+                    .at_most(MAX_TICKS as f64) as usize;
+                let direction = if start <= end { 1.0 } else { -1.0 };
+                (0..=num_steps)
+                    .map(|i| start + direction * i as f64 * step)
+                    .collect()
             }
+            SliderTicks::Values(values) => values
+                .iter()
+                .copied()
+                .filter(|value| min <= *value && *value <= max)
+                .collect(),
+        }
+    }
 
-            let radius = self.handle_radius(rect);
-
-            let handle_shape = self
-                .handle_shape
-                .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            match handle_shape {
-                style::HandleShape::Circle => {
-                    ui.painter().add(epaint::CircleShape {
-                        center,
-                        radius: radius + visuals.expansion,
-                        fill: visuals.bg_fill,
-                        stroke: visuals.fg_stroke,
-                    });
+    fn format_tick_label(&self, ui: &Ui, value: f64) -> String {
+        let decimals = self.min_decimals
+            ..=self
+                .max_decimals
+                .unwrap_or_else(|| self.min_decimals.max(6));
+        match &self.custom_formatter {
+            Some(custom_formatter) => custom_formatter(value, decimals),
+            None => ui.style().number_formatter.format(value, decimals),
+        }
+    }
+
+    fn tick_label_galleys(&self, ui: &Ui) -> Vec<(f64, Option<Arc<Galley>>)> {
+        self.tick_values()
+            .into_iter()
+            .map(|value| {
+                let galley = self.tick_labels.then(|| {
+                    WidgetText::from(self.format_tick_label(ui, value)).into_galley(
+                        ui,
+                        Some(TextWrapMode::Extend),
+                        f32::INFINITY,
+                        TextStyle::Small,
+                    )
+                });
+                (value, galley)
+            })
+            .collect()
+    }
+
+    /// How much extra space the ticks and their labels need across the rail.
+    fn tick_space(&self, ui: &Ui) -> f32 {
+        if self.ticks.is_none() {
+            return 0.0;
+        }
+        let label_size = self
+            .tick_label_galleys(ui)
+            .iter()
+            .filter_map(|(_, galley)| galley.as_ref().map(|galley| galley.size()))
+            .fold(Vec2::ZERO, |a, b| a.max(b));
+        match self.orientation {
+            SliderOrientation::Horizontal => TICK_LENGTH + label_size.y,
+            SliderOrientation::Vertical => TICK_LENGTH + label_size.x,
+        }
+    }
+
+    fn paint_ticks(&self, ui: &Ui, full_rect: Rect, rail_rect: &Rect, position_range: Rangef) {
+        if self.ticks.is_none() {
+            return;
+        }
+
+        let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+        let text_color = ui.visuals().text_color();
+        let slider_rect = self.slider_rect(full_rect, self.tick_space(ui));
+
+        for (value, galley) in self.tick_label_galleys(ui) {
+            let position = self.position_from_value(value, position_range);
+            let center = self.marker_center(position, rail_rect);
+            match self.orientation {
+                SliderOrientation::Horizontal => {
+                    let top = slider_rect.bottom();
+                    ui.painter()
+                        .vline(center.x, Rangef::new(top, top + TICK_LENGTH), stroke);
+                    if let Some(galley) = galley {
+                        let half_width = 0.5 * galley.size().x;
+                        let x = center.x.clamp(
+                            full_rect.left() + half_width,
+                            full_rect.right() - half_width,
+                        );
+                        let pos = pos2(x - half_width, top + TICK_LENGTH);
+                        ui.painter().galley(pos, galley, text_color);
+                    }
                 }
-                style::HandleShape::Rect { aspect_ratio } => {
-                    let v = match self.orientation {
-                        SliderOrientation::Horizontal => Vec2::new(radius * aspect_ratio, radius),
-                        SliderOrientation::Vertical => Vec2::new(radius, radius * aspect_ratio),
-                    };
-                    let v = v + Vec2::splat(visuals.expansion);
-                    let rect = Rect::from_center_size(center, 2.0 * v);
-                    ui.painter().rect(
-                        rect,
-                        visuals.corner_radius,
-                        visuals.bg_fill,
-                        visuals.fg_stroke,
-                        epaint::StrokeKind::Inside,
-                    );
+                SliderOrientation::Vertical => {
+                    let left = slider_rect.right();
+                    ui.painter()
+                        .hline(Rangef::new(left, left + TICK_LENGTH), center.y, stroke);
+                    if let Some(galley) = galley {
+                        let half_height = 0.5 * galley.size().y;
+                        let y = center.y.clamp(
+                            full_rect.top() + half_height,
+                            full_rect.bottom() - half_height,
+                        );
+                        let pos = pos2(left + TICK_LENGTH, y - half_height);
+                        ui.painter().galley(pos, galley, text_color);
+                    }
                 }
             }
         }
@@ -891,7 +1387,19 @@ impl Slider<'_> {
         limit / 2.5
     }
 
-    fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef) -> Response {
+    /// Half the size of the handle, without any expansion.
+    fn handle_half_size(&self, rect: &Rect, handle_shape: &style::HandleShape) -> Vec2 {
+        let radius = self.handle_radius(rect);
+        match handle_shape {
+            style::HandleShape::Circle => Vec2::splat(radius),
+            style::HandleShape::Rect { aspect_ratio } => match self.orientation {
+                SliderOrientation::Horizontal => Vec2::new(radius * aspect_ratio, radius),
+                SliderOrientation::Vertical => Vec2::new(radius, radius * aspect_ratio),
+            },
+        }
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui, position_range: Rangef, thumb: Thumb) -> Response {
         // If [`DragValue`] is controlled from the keyboard and `step` is defined, set speed to `step`
         let change = ui.input(|input| {
             input.num_presses(Key::ArrowUp) as i32 + input.num_presses(Key::ArrowRight) as i32
@@ -905,10 +1413,10 @@ impl Slider<'_> {
             step
         } else {
             self.drag_value_speed
-                .unwrap_or_else(|| self.current_gradient(position_range))
+                .unwrap_or_else(|| self.current_gradient(position_range, thumb))
         };
 
-        let mut value = self.get_value();
+        let mut value = self.get_thumb_value(thumb);
         let response = ui.add({
             let mut dv = DragValue::new(&mut value)
                 .speed(speed)
@@ -936,16 +1444,16 @@ impl Slider<'_> {
             }
             dv
         });
-        if value != self.get_value() {
-            self.set_value(value);
+        if value != self.get_thumb_value(thumb) {
+            self.set_thumb_value(thumb, value);
         }
         response
     }
 
     /// delta(value) / delta(points)
-    fn current_gradient(&mut self, position_range: Rangef) -> f64 {
+    fn current_gradient(&mut self, position_range: Rangef, thumb: Thumb) -> f64 {
         // TODO(emilk): handle clamping
-        let value = self.get_value();
+        let value = self.get_thumb_value(thumb);
         let value_from_pos = |position: f32| self.value_from_position(position, position_range);
         let pos_from_value = |value: f64| self.position_from_value(value, position_range);
         let left_value = value_from_pos(pos_from_value(value) - 0.5);
@@ -953,26 +1461,8 @@ impl Slider<'_> {
         right_value - left_value
     }
 
-    fn add_contents(&mut self, ui: &mut Ui) -> Response {
-        let old_value = self.get_value();
-
-        if self.clamping == SliderClamping::Always {
-            self.set_value(old_value);
-        }
-
-        let thickness = ui
-            .text_style_height(&TextStyle::Body)
-            .at_least(ui.spacing().interact_size.y);
-        let mut response = self.allocate_slider_space(ui, thickness);
-        self.slider_ui(ui, &response);
-
-        let value = self.get_value();
-        if value != old_value {
-            response.mark_changed();
-        }
-        response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, self.text.text()));
-
-        ui.ctx().accesskit_node_builder(response.id, |builder| {
+    fn accesskit_ui(&self, ui: &Ui, id: crate::Id, value: f64) {
+        ui.ctx().accesskit_node_builder(id, |builder| {
             use accesskit::Action;
             builder.set_min_numeric_value(*self.range.start());
             builder.set_max_numeric_value(*self.range.end());
@@ -993,30 +1483,91 @@ impl Slider<'_> {
                 builder.add_action(Action::Decrement);
             }
         });
+    }
 
-        let slider_response = response.clone();
+    fn add_contents(&mut self, ui: &mut Ui) -> Response {
+        let old_values = [Thumb::Start, Thumb::End].map(|thumb| self.get_thumb_value(thumb));
 
-        let value_response = if self.show_value {
-            let handle_shape = self
-                .handle_shape
-                .unwrap_or_else(|| ui.style().visuals.handle_shape);
-            let position_range = self.position_range(&response.rect, &handle_shape);
-            let value_response = self.value_ui(ui, position_range);
-            if value_response.gained_focus()
-                || value_response.has_focus()
-                || value_response.lost_focus()
+        if self.clamping == SliderClamping::Always {
+            self.set_thumb_value(Thumb::Start, old_values[0]);
+            if self.is_range() {
+                self.set_thumb_value(Thumb::End, old_values[1]);
+            }
+        }
+
+        let thickness = ui
+            .text_style_height(&TextStyle::Body)
+            .at_least(ui.spacing().interact_size.y);
+        let tick_space = self.tick_space(ui);
+        let mut response = self.allocate_slider_space(ui, thickness + tick_space);
+        let slider_rect = self.slider_rect(response.rect, tick_space);
+
+        let handle_shape = self
+            .handle_shape
+            .unwrap_or_else(|| ui.style().visuals.handle_shape);
+        let position_range = self.position_range(&slider_rect, &handle_shape);
+
+        let mut thumb_responses = Vec::new();
+        if self.is_range() {
+            thumb_responses = self.range_slider_ui(ui, &response, slider_rect).to_vec();
+
+            let text = self.text.text().to_owned();
+            for (thumb, thumb_response) in
+                [Thumb::Start, Thumb::End].into_iter().zip(&thumb_responses)
             {
-                // Use the [`DragValue`] id as the id of the whole widget,
-                // so that the focus events work as expected.
-                response = value_response.union(response);
-            } else {
-                // Use the slider id as the id for the whole widget
-                response = response.union(value_response.clone());
+                let value = self.get_thumb_value(thumb);
+                let label = match thumb {
+                    Thumb::Start => format!("{text} start"),
+                    Thumb::End => format!("{text} end"),
+                };
+                thumb_response
+                    .widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, label.trim()));
+                self.accesskit_ui(ui, thumb_response.id, value);
+            }
+            response.widget_info(|| {
+                WidgetInfo::labeled(WidgetType::Other, ui.is_enabled(), self.text.text())
+            });
+            for thumb_response in &thumb_responses {
+                response = response.union(thumb_response.clone());
             }
-            Some(value_response)
         } else {
-            None
-        };
+            self.slider_ui(ui, &response, slider_rect);
+
+            let value = self.get_value();
+            response.widget_info(|| WidgetInfo::slider(ui.is_enabled(), value, self.text.text()));
+            self.accesskit_ui(ui, response.id, value);
+        }
+
+        let new_values = [Thumb::Start, Thumb::End].map(|thumb| self.get_thumb_value(thumb));
+        if new_values != old_values {
+            response.mark_changed();
+        }
+
+        let slider_response = response.clone();
+
+        let mut value_responses = Vec::new();
+        if self.show_value {
+            let thumbs: &[Thumb] = if self.is_range() {
+                &[Thumb::Start, Thumb::End]
+            } else {
+                &[Thumb::Start]
+            };
+            for &thumb in thumbs {
+                let value_response = self.value_ui(ui, position_range, thumb);
+                if value_response.gained_focus()
+                    || value_response.has_focus()
+                    || value_response.lost_focus()
+                {
+                    // Use the [`DragValue`] id as the id of the whole widget,
+                    // so that the focus events work as expected.
+                    response = value_response.union(response);
+                } else {
+                    // Use the slider id as the id for the whole widget
+                    response = response.union(value_response.clone());
+                }
+                value_responses.push(value_response);
+            }
+        }
 
         if !self.text.is_empty() {
             let label_response =
@@ -1026,8 +1577,8 @@ impl Slider<'_> {
             // that a piece of text is a label for another widget,
             // e.g. so the text itself can be excluded from navigation.
             slider_response.labelled_by(label_response.id);
-            if let Some(value_response) = value_response {
-                value_response.labelled_by(label_response.id);
+            for other_response in thumb_responses.into_iter().chain(value_responses) {
+                other_response.labelled_by(label_response.id);
             }
         }
 
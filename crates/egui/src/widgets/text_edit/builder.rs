@@ -8,8 +8,9 @@ use epaint::{
 
 use crate::{
     Align, Align2, Color32, Context, CursorIcon, Event, EventFilter, FontSelection, Id, ImeEvent,
-    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, Response, Sense, Shape, TextBuffer,
-    TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState, epaint,
+    Key, KeyboardShortcut, Margin, Modifiers, NumExt as _, PasteFormat, Response, Sense, Shape,
+    TextBuffer, TextStyle, TextWrapMode, Ui, Vec2, Widget, WidgetInfo, WidgetText, WidgetWithState,
+    epaint,
     os::OperatingSystem,
     output::OutputEvent,
     response, text_selection,
@@ -89,6 +90,7 @@ pub struct TextEdit<'t> {
     char_limit: usize,
     return_key: Option<KeyboardShortcut>,
     background_color: Option<Color32>,
+    paste_formats: Vec<PasteFormat>,
 }
 
 impl WidgetWithState for TextEdit<'_> {
@@ -148,6 +150,7 @@ impl<'t> TextEdit<'t> {
             char_limit: usize::MAX,
             return_key: Some(KeyboardShortcut::new(Modifiers::NONE, Key::Enter)),
             background_color: None,
+            paste_formats: Vec::new(),
         }
     }
 
@@ -396,6 +399,19 @@ impl<'t> TextEdit<'t> {
         self.return_key = return_key.into();
         self
     }
+
+    /// While focused, also accept pasting this format, e.g. [`PasteFormat::Image`].
+    ///
+    /// Plain text is always pasted into the text.
+    /// Anything else that is pasted is returned in [`TextEditOutput::pasted`],
+    /// e.g. so a chat input can attach pasted images.
+    ///
+    /// See also [`Context::request_paste_format`].
+    #[inline]
+    pub fn paste_format(mut self, format: PasteFormat) -> Self {
+        self.paste_formats.push(format);
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -493,6 +509,7 @@ impl TextEdit<'_> {
             char_limit,
             return_key,
             background_color: _,
+            paste_formats,
         } = self;
 
         let text_color = text_color
@@ -614,10 +631,28 @@ impl TextEdit<'_> {
         }
 
         let mut cursor_range = None;
+        let mut pasted = Vec::new();
         let prev_cursor_range = state.cursor.range(&galley);
         if interactive && ui.memory(|mem| mem.has_focus(id)) {
             ui.memory_mut(|mem| mem.set_focus_lock_filter(id, event_filter));
 
+            for format in &paste_formats {
+                ui.ctx().request_paste_format(format.clone());
+            }
+            if !paste_formats.is_empty() {
+                pasted = ui.input(|i| {
+                    i.filtered_events(&event_filter)
+                        .into_iter()
+                        .filter_map(|event| match event {
+                            Event::PasteData(data) if paste_formats.contains(&data.format()) => {
+                                Some(data)
+                            }
+                            _ => None,
+                        })
+                        .collect()
+                });
+            }
+
             let default_cursor_range = if cursor_at_end {
                 CCursorRange::one(galley.end())
             } else {
@@ -870,6 +905,7 @@ impl TextEdit<'_> {
             text_clip_rect,
             state,
             cursor_range,
+            pasted,
         }
     }
 }
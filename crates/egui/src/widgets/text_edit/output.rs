@@ -21,6 +21,10 @@ pub struct TextEditOutput {
 
     /// Where the text cursor is.
     pub cursor_range: Option<CCursorRange>,
+
+    /// Anything other than plain text that was pasted this frame,
+    /// in one of the formats given to [`TextEdit::paste_format`](crate::TextEdit::paste_format).
+    pub pasted: Vec<crate::PastedData>,
 }
 
 // TODO(emilk): add `output.paint` and `output.store` and split out that code from `TextEdit::show`.
@@ -1,10 +1,17 @@
-use egui::{Color32, Context, Frame, Pos2, Rect, Sense, Stroke, Ui, Window, emath, vec2};
+use egui::{
+    Color32, Context, Frame, Pos2, Rect, Sense, Stroke, Ui, Window, emath,
+    epaint::VariableStrokeShape, vec2,
+};
 
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[cfg_attr(feature = "serde", serde(default))]
 pub struct Painting {
     /// in 0-1 normalized coordinates
     lines: Vec<Vec<Pos2>>,
+
+    /// The pen pressure at each point of [`Self::lines`], or 1.0 for mice and fingers.
+    pressures: Vec<Vec<f32>>,
+
     stroke: Stroke,
 }
 
@@ -12,6 +19,7 @@ impl Default for Painting {
     fn default() -> Self {
         Self {
             lines: Default::default(),
+            pressures: Default::default(),
             stroke: Stroke::new(1.0, Color32::from_rgb(25, 200, 100)),
         }
     }
@@ -25,6 +33,7 @@ impl Painting {
             ui.separator();
             if ui.button("Clear Painting").clicked() {
                 self.lines.clear();
+                self.pressures.clear();
             }
         })
         .response
@@ -43,27 +52,40 @@ impl Painting {
         if self.lines.is_empty() {
             self.lines.push(vec![]);
         }
+        self.pressures.resize_with(self.lines.len(), Vec::new);
 
         let current_line = self.lines.last_mut().unwrap();
+        let current_pressures = self.pressures.last_mut().unwrap();
 
         if let Some(pointer_pos) = response.interact_pointer_pos() {
             let canvas_pos = from_screen * pointer_pos;
             if current_line.last() != Some(&canvas_pos) {
+                let pressure = ui.input(|i| i.pointer.pen_pressure()).unwrap_or(1.0);
                 current_line.push(canvas_pos);
+                current_pressures.resize(current_line.len() - 1, 1.0);
+                current_pressures.push(pressure);
                 response.mark_changed();
             }
         } else if !current_line.is_empty() {
             self.lines.push(vec![]);
+            self.pressures.push(vec![]);
             response.mark_changed();
         }
 
         let shapes = self
             .lines
             .iter()
-            .filter(|line| line.len() >= 2)
-            .map(|line| {
+            .zip(&self.pressures)
+            .filter(|(line, _)| line.len() >= 2)
+            .map(|(line, pressures)| {
                 let points: Vec<Pos2> = line.iter().map(|p| to_screen * *p).collect();
-                egui::Shape::line(points, self.stroke)
+                if pressures.iter().all(|pressure| *pressure == 1.0) {
+                    egui::Shape::line(points, self.stroke)
+                } else {
+                    // Drawn with a pen:
+                    let line = egui::epaint::PathShape::line(points, self.stroke);
+                    VariableStrokeShape::from_path(&line, pressures.clone()).into()
+                }
             });
 
         painter.extend(shapes);
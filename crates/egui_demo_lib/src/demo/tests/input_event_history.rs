@@ -93,6 +93,7 @@ impl crate::View for InputEventHistory {
                         egui::Event::PointerMoved { .. }
                             | egui::Event::MouseMoved { .. }
                             | egui::Event::Touch { .. }
+                            | egui::Event::Pen(_)
                     )
                 {
                     continue;
@@ -127,6 +128,7 @@ fn event_summary(event: &egui::Event) -> String {
         egui::Event::MouseMoved { .. } => "MouseMoved { .. }".to_owned(),
         egui::Event::Zoom { .. } => "Zoom { .. }".to_owned(),
         egui::Event::Touch { phase, .. } => format!("Touch {{ phase: {phase:?}, .. }}"),
+        egui::Event::Pen(pen) => format!("Pen({:?}, ..)", pen.phase),
         egui::Event::MouseWheel { unit, .. } => format!("MouseWheel {{ unit: {unit:?}, .. }}"),
 
         _ => format!("{event:?}"),
@@ -21,6 +21,8 @@ pub mod loaders;
 mod sizing;
 mod strip;
 mod table;
+#[cfg(feature = "svg")]
+mod vector_svg;
 
 #[cfg(feature = "chrono")]
 pub use crate::datepicker::DatePickerButton;
@@ -29,6 +31,8 @@ pub(crate) use crate::layout::StripLayout;
 pub use crate::sizing::Size;
 pub use crate::strip::*;
 pub use crate::table::*;
+#[cfg(feature = "svg")]
+pub use crate::vector_svg::VectorSvg;
 
 pub use loaders::install_image_loaders;
 
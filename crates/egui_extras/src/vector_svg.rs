@@ -0,0 +1,756 @@
+use std::sync::Arc;
+
+use ahash::HashMap;
+use egui::{
+    Color32, Mesh, Painter, Pos2, Rect, Shape, Vec2,
+    epaint::{
+        ColorMode, CubicBezierShape, FillRule, LineCap, LineJoin, PathShape, PathStroke,
+        PolygonShape, QuadraticBezierShape, StrokeKind, Tessellator, Vertex,
+    },
+    mutex::Mutex,
+    pos2,
+};
+use resvg::usvg;
+
+/// How many sizes of each [`VectorSvg`] we keep tessellated meshes for.
+const MAX_CACHED_MESHES: usize = 8;
+
+/// Gradient colors are calculated per vertex,
+/// so we split up triangles with edges longer than this (in points).
+const MAX_GRADIENT_EDGE_LENGTH: f32 = 8.0;
+
+/// An SVG document as vector [`Shape`]s, which stays sharp at any size and can be tinted.
+///
+/// Unlike the `svg` image loader, which rasterizes the SVG into a texture,
+/// this converts the paths, fills, strokes, transforms and gradients of the SVG into shapes.
+///
+/// Not everything is supported: images, patterns, filters, clip paths and masks are ignored,
+/// and radial gradients ignore their focal point.
+/// Text is only included if it was converted to paths by [`usvg`], which requires the `svg_text` feature
+/// and fonts in the [`usvg::Options`].
+///
+/// Requires the "svg" feature.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let svg = egui_extras::VectorSvg::from_bytes(
+///     br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+///         <circle cx="8" cy="8" r="6" fill="white"/>
+///     </svg>"#,
+/// )
+/// .unwrap();
+/// let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
+/// svg.paint_at(ui.painter(), rect, ui.visuals().text_color());
+/// # });
+/// ```
+pub struct VectorSvg {
+    /// The size of the document, in SVG units.
+    size: Vec2,
+
+    /// In paint order, in document coordinates.
+    paths: Vec<SvgPath>,
+
+    /// Tessellated meshes, most recently used first.
+    meshes: Mutex<Vec<(MeshKey, Arc<Mesh>)>>,
+}
+
+/// The size and pixels-per-point of a tessellated mesh.
+type MeshKey = [u32; 3];
+
+impl VectorSvg {
+    /// Parse an SVG document using the default [`usvg::Options`].
+    ///
+    /// # Errors
+    /// On invalid SVG data.
+    pub fn from_bytes(svg_bytes: &[u8]) -> Result<Self, String> {
+        Self::from_bytes_with_options(svg_bytes, &usvg::Options::default())
+    }
+
+    /// Parse an SVG document.
+    ///
+    /// # Errors
+    /// On invalid SVG data.
+    pub fn from_bytes_with_options(
+        svg_bytes: &[u8],
+        options: &usvg::Options<'_>,
+    ) -> Result<Self, String> {
+        let tree = usvg::Tree::from_data(svg_bytes, options).map_err(|err| err.to_string())?;
+        Ok(Self::from_tree(&tree))
+    }
+
+    /// Convert an already parsed SVG document.
+    pub fn from_tree(tree: &usvg::Tree) -> Self {
+        let mut paths = vec![];
+        add_group(&mut paths, tree.root(), 1.0);
+        Self {
+            size: Vec2::new(tree.size().width(), tree.size().height()),
+            paths,
+            meshes: Default::default(),
+        }
+    }
+
+    /// The size of the document, in SVG units (usually pixels).
+    pub fn size(&self) -> Vec2 {
+        self.size
+    }
+
+    /// The SVG as shapes, stretched to fill `rect`.
+    ///
+    /// Curves are flattened to be accurate at the given `pixels_per_point`.
+    /// Gradient fills become [`Shape::Mesh`]es.
+    pub fn shapes(&self, rect: Rect, pixels_per_point: f32) -> Vec<Shape> {
+        let placement = Placement::new(self.size, rect);
+        let tolerance = 0.25 / pixels_per_point;
+        let mut tessellator =
+            Tessellator::new(pixels_per_point, Default::default(), [1, 1], vec![]);
+
+        let mut shapes = vec![];
+        for path in &self.paths {
+            let polylines: Vec<(Vec<Pos2>, bool)> = path
+                .subpaths
+                .iter()
+                .map(|subpath| (subpath.flatten(&placement, tolerance), subpath.closed))
+                .collect();
+
+            if path.stroke_first {
+                add_stroke(&mut shapes, path, &polylines, &placement);
+                add_fill(&mut shapes, path, &polylines, &placement, &mut tessellator);
+            } else {
+                add_fill(&mut shapes, path, &polylines, &placement, &mut tessellator);
+                add_stroke(&mut shapes, path, &polylines, &placement);
+            }
+        }
+        shapes
+    }
+
+    /// The SVG tessellated at the given size, with its top left corner at the origin.
+    ///
+    /// The result is cached for the last few sizes.
+    pub fn mesh(&self, size: Vec2, pixels_per_point: f32) -> Arc<Mesh> {
+        let key = [
+            size.x.to_bits(),
+            size.y.to_bits(),
+            pixels_per_point.to_bits(),
+        ];
+
+        let mut meshes = self.meshes.lock();
+        if let Some(index) = meshes.iter().position(|(cached_key, _)| *cached_key == key) {
+            let entry = meshes.remove(index);
+            let mesh = Arc::clone(&entry.1);
+            meshes.insert(0, entry);
+            return mesh;
+        }
+
+        profiling::function_scope!();
+        let mut tessellator =
+            Tessellator::new(pixels_per_point, Default::default(), [1, 1], vec![]);
+        let mut mesh = Mesh::default();
+        for shape in self.shapes(Rect::from_min_size(Pos2::ZERO, size), pixels_per_point) {
+            tessellator.tessellate_shape(shape, &mut mesh);
+        }
+
+        let mesh = Arc::new(mesh);
+        meshes.insert(0, (key, Arc::clone(&mesh)));
+        meshes.truncate(MAX_CACHED_MESHES);
+        mesh
+    }
+
+    /// Paint the SVG stretched to fill `rect`, with all colors multiplied by `tint`.
+    ///
+    /// The tessellated mesh is cached per size,
+    /// and takes the zoom of the layer (e.g. in a [`egui::Scene`]) into account.
+    pub fn paint_at(&self, painter: &Painter, rect: Rect, tint: Color32) {
+        let zoom = painter
+            .ctx()
+            .layer_transform_to_global(painter.layer_id())
+            .map_or(1.0, |transform| transform.scaling);
+        let mesh = self.mesh(rect.size(), zoom * painter.pixels_per_point());
+
+        let mut mesh = Mesh::clone(&mesh);
+        mesh.translate(rect.min.to_vec2());
+        if tint != Color32::WHITE {
+            for vertex in &mut mesh.vertices {
+                vertex.color = vertex.color * tint;
+            }
+        }
+        painter.add(Shape::mesh(mesh));
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Maps document coordinates to the screen.
+#[derive(Clone, Copy)]
+struct Placement {
+    origin: Pos2,
+    scale: Vec2,
+}
+
+impl Placement {
+    fn new(document_size: Vec2, rect: Rect) -> Self {
+        let scale = rect.size() / document_size;
+        Self {
+            origin: rect.min,
+            scale: if scale.is_finite() { scale } else { Vec2::ZERO },
+        }
+    }
+
+    fn to_screen(self, pos: Pos2) -> Pos2 {
+        self.origin + pos.to_vec2() * self.scale
+    }
+
+    fn to_document(self, pos: Pos2) -> Pos2 {
+        ((pos - self.origin) / self.scale).to_pos2()
+    }
+
+    /// How much to scale stroke widths.
+    fn stroke_scale(self) -> f32 {
+        (self.scale.x * self.scale.y).abs().sqrt()
+    }
+}
+
+struct SvgPath {
+    subpaths: Vec<SubPath>,
+    fill: Option<(SvgPaint, FillRule)>,
+    stroke: Option<SvgStroke>,
+    stroke_first: bool,
+}
+
+struct SubPath {
+    start: Pos2,
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+/// The control points and end point of a segment, starting where the last one ended.
+enum Segment {
+    Line(Pos2),
+    Quadratic(Pos2, Pos2),
+    Cubic(Pos2, Pos2, Pos2),
+}
+
+impl SubPath {
+    fn flatten(&self, placement: &Placement, tolerance: f32) -> Vec<Pos2> {
+        let mut points = vec![placement.to_screen(self.start)];
+        for segment in &self.segments {
+            let last = *points.last().unwrap_or(&Pos2::ZERO);
+            match *segment {
+                Segment::Line(end) => points.push(placement.to_screen(end)),
+                Segment::Quadratic(control, end) => {
+                    let curve = QuadraticBezierShape::from_points_stroke(
+                        [last, placement.to_screen(control), placement.to_screen(end)],
+                        false,
+                        Color32::TRANSPARENT,
+                        PathStroke::NONE,
+                    );
+                    points.extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+                }
+                Segment::Cubic(control1, control2, end) => {
+                    let curve = CubicBezierShape::from_points_stroke(
+                        [
+                            last,
+                            placement.to_screen(control1),
+                            placement.to_screen(control2),
+                            placement.to_screen(end),
+                        ],
+                        false,
+                        Color32::TRANSPARENT,
+                        PathStroke::NONE,
+                    );
+                    points.extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+                }
+            }
+        }
+        points.dedup();
+        points
+    }
+}
+
+struct SvgStroke {
+    paint: SvgPaint,
+    width: f32,
+    join: LineJoin,
+    cap: LineCap,
+
+    /// Dash and gap lengths, and the offset into them.
+    dashes: Option<(Vec<f32>, f32)>,
+}
+
+enum SvgPaint {
+    Color(Color32),
+    Gradient(Arc<Gradient>),
+}
+
+struct Gradient {
+    kind: GradientKind,
+
+    /// Offsets and colors, sorted by offset.
+    stops: Vec<(f32, Color32)>,
+    spread: usvg::SpreadMethod,
+
+    /// From document coordinates to the coordinates of [`Self::kind`].
+    to_gradient: usvg::Transform,
+}
+
+enum GradientKind {
+    Linear { start: Pos2, end: Pos2 },
+    Radial { center: Pos2, radius: f32 },
+}
+
+impl Gradient {
+    fn color_at(&self, pos: Pos2) -> Color32 {
+        let mut point = usvg::tiny_skia_path::Point::from_xy(pos.x, pos.y);
+        self.to_gradient.map_point(&mut point);
+        let pos = pos2(point.x, point.y);
+
+        let t = match self.kind {
+            GradientKind::Linear { start, end } => {
+                let dir = end - start;
+                if dir == Vec2::ZERO {
+                    1.0
+                } else {
+                    (pos - start).dot(dir) / dir.length_sq()
+                }
+            }
+            GradientKind::Radial { center, radius } => pos.distance(center) / radius,
+        };
+        let t = match self.spread {
+            usvg::SpreadMethod::Pad => t.clamp(0.0, 1.0),
+            usvg::SpreadMethod::Repeat => t.rem_euclid(1.0),
+            usvg::SpreadMethod::Reflect => 1.0 - (t.rem_euclid(2.0) - 1.0).abs(),
+        };
+
+        match self.stops.iter().position(|&(offset, _)| t < offset) {
+            None => self
+                .stops
+                .last()
+                .map_or(Color32::TRANSPARENT, |&(_, color)| color),
+            Some(0) => self.stops[0].1,
+            Some(i) => {
+                let (from, from_color) = self.stops[i - 1];
+                let (to, to_color) = self.stops[i];
+                from_color.lerp_to_gamma(to_color, (t - from) / (to - from))
+            }
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Converting from usvg:
+
+fn add_group(paths: &mut Vec<SvgPath>, group: &usvg::Group, opacity: f32) {
+    let opacity = opacity * group.opacity().get();
+    for node in group.children() {
+        match node {
+            usvg::Node::Group(group) => add_group(paths, group, opacity),
+            usvg::Node::Path(path) => {
+                if path.is_visible() {
+                    paths.push(convert_path(path, opacity));
+                }
+            }
+            usvg::Node::Text(text) => add_group(paths, text.flattened(), opacity),
+            usvg::Node::Image(_) => {
+                log::debug!("Images in SVGs are not supported by VectorSvg");
+            }
+        }
+    }
+}
+
+fn convert_path(path: &usvg::Path, opacity: f32) -> SvgPath {
+    let transform = path.abs_transform();
+    let map = |mut point: usvg::tiny_skia_path::Point| {
+        transform.map_point(&mut point);
+        pos2(point.x, point.y)
+    };
+
+    let mut subpaths: Vec<SubPath> = vec![];
+    for segment in path.data().segments() {
+        use usvg::tiny_skia_path::PathSegment;
+
+        if let PathSegment::MoveTo(start) = segment {
+            subpaths.push(SubPath {
+                start: map(start),
+                segments: vec![],
+                closed: false,
+            });
+            continue;
+        }
+        let Some(subpath) = subpaths.last_mut() else {
+            continue;
+        };
+        let segment = match segment {
+            PathSegment::MoveTo(_) => continue,
+            PathSegment::LineTo(end) => Segment::Line(map(end)),
+            PathSegment::QuadTo(control, end) => Segment::Quadratic(map(control), map(end)),
+            PathSegment::CubicTo(control1, control2, end) => {
+                Segment::Cubic(map(control1), map(control2), map(end))
+            }
+            PathSegment::Close => {
+                subpath.closed = true;
+                continue;
+            }
+        };
+        subpath.segments.push(segment);
+    }
+
+    let fill = path.fill().and_then(|fill| {
+        let paint = convert_paint(fill.paint(), opacity * fill.opacity().get(), transform)?;
+        let fill_rule = match fill.rule() {
+            usvg::FillRule::NonZero => FillRule::NonZero,
+            usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+        };
+        Some((paint, fill_rule))
+    });
+
+    let stroke = path.stroke().and_then(|stroke| {
+        let paint = convert_paint(stroke.paint(), opacity * stroke.opacity().get(), transform)?;
+        let scale = (transform.sx * transform.sy - transform.kx * transform.ky)
+            .abs()
+            .sqrt();
+        let join = match stroke.linejoin() {
+            usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => LineJoin::Miter {
+                min_angle: miter_min_angle(stroke.miterlimit().get()),
+            },
+            usvg::LineJoin::Round => LineJoin::Round,
+            usvg::LineJoin::Bevel => LineJoin::Bevel,
+        };
+        let cap = match stroke.linecap() {
+            usvg::LineCap::Butt => LineCap::Butt,
+            usvg::LineCap::Round => LineCap::Round,
+            usvg::LineCap::Square => LineCap::Square,
+        };
+        let dashes = stroke.dasharray().map(|dashes| {
+            let mut pattern: Vec<f32> = dashes.iter().map(|length| length * scale).collect();
+            if pattern.len() % 2 == 1 {
+                // An odd number of lengths is repeated, so that dashes and gaps alternate:
+                pattern.extend_from_within(..);
+            }
+            (pattern, stroke.dashoffset() * scale)
+        });
+        Some(SvgStroke {
+            paint,
+            width: stroke.width().get() * scale,
+            join,
+            cap,
+            dashes,
+        })
+    });
+
+    SvgPath {
+        subpaths,
+        fill,
+        stroke,
+        stroke_first: path.paint_order() == usvg::PaintOrder::StrokeAndFill,
+    }
+}
+
+fn convert_paint(
+    paint: &usvg::Paint,
+    opacity: f32,
+    path_transform: usvg::Transform,
+) -> Option<SvgPaint> {
+    let color = |color: usvg::Color, opacity: f32| {
+        Color32::from_rgba_unmultiplied(
+            color.red,
+            color.green,
+            color.blue,
+            (255.0 * opacity).round() as u8,
+        )
+    };
+
+    let (kind, base): (GradientKind, &usvg::BaseGradient) = match paint {
+        usvg::Paint::Color(c) => return Some(SvgPaint::Color(color(*c, opacity))),
+        usvg::Paint::LinearGradient(gradient) => (
+            GradientKind::Linear {
+                start: pos2(gradient.x1(), gradient.y1()),
+                end: pos2(gradient.x2(), gradient.y2()),
+            },
+            gradient,
+        ),
+        usvg::Paint::RadialGradient(gradient) => (
+            GradientKind::Radial {
+                center: pos2(gradient.cx(), gradient.cy()),
+                radius: gradient.r().get(),
+            },
+            gradient,
+        ),
+        usvg::Paint::Pattern(_) => {
+            log::debug!("Patterns in SVGs are not supported by VectorSvg");
+            return None;
+        }
+    };
+
+    let to_gradient = path_transform.pre_concat(base.transform()).invert()?;
+    let stops = base
+        .stops()
+        .iter()
+        .map(|stop| {
+            let opacity = opacity * stop.opacity().get();
+            (stop.offset().get(), color(stop.color(), opacity))
+        })
+        .collect();
+    Some(SvgPaint::Gradient(Arc::new(Gradient {
+        kind,
+        stops,
+        spread: base.spread_method(),
+        to_gradient,
+    })))
+}
+
+/// Convert the SVG `stroke-miterlimit` (a ratio of lengths) to [`LineJoin::Miter::min_angle`].
+fn miter_min_angle(miter_limit: f32) -> u8 {
+    // The miter length is `1 / sin(angle / 2)` times the stroke width.
+    if miter_limit <= 1.0 {
+        180
+    } else {
+        (2.0 * (1.0 / miter_limit).asin()).to_degrees().ceil() as u8
+    }
+}
+
+// ----------------------------------------------------------------------------
+// Creating shapes:
+
+fn add_fill(
+    shapes: &mut Vec<Shape>,
+    path: &SvgPath,
+    polylines: &[(Vec<Pos2>, bool)],
+    placement: &Placement,
+    tessellator: &mut Tessellator,
+) {
+    let Some((paint, fill_rule)) = &path.fill else {
+        return;
+    };
+    let contours = polylines.iter().map(|(points, _)| points.clone()).collect();
+
+    match paint {
+        SvgPaint::Color(color) => {
+            shapes.push(
+                PolygonShape::new(contours, *color, PathStroke::NONE)
+                    .with_fill_rule(*fill_rule)
+                    .into(),
+            );
+        }
+        SvgPaint::Gradient(gradient) => {
+            let polygon = PolygonShape::new(contours, Color32::WHITE, PathStroke::NONE)
+                .with_fill_rule(*fill_rule);
+            let mut mesh = Mesh::default();
+            tessellator.tessellate_polygon(&polygon, &mut mesh);
+            subdivide(&mut mesh, MAX_GRADIENT_EDGE_LENGTH);
+            for vertex in &mut mesh.vertices {
+                let color = gradient.color_at(placement.to_document(vertex.pos));
+                vertex.color = color.gamma_multiply_u8(vertex.color.a());
+            }
+            shapes.push(Shape::mesh(mesh));
+        }
+    }
+}
+
+fn add_stroke(
+    shapes: &mut Vec<Shape>,
+    path: &SvgPath,
+    polylines: &[(Vec<Pos2>, bool)],
+    placement: &Placement,
+) {
+    let Some(stroke) = &path.stroke else {
+        return;
+    };
+
+    let color = match &stroke.paint {
+        SvgPaint::Color(color) => ColorMode::Solid(*color),
+        SvgPaint::Gradient(gradient) => {
+            let gradient = Arc::clone(gradient);
+            let placement = *placement;
+            ColorMode::UV(Arc::new(move |_bounds, pos| {
+                gradient.color_at(placement.to_document(pos))
+            }))
+        }
+    };
+    let scale = placement.stroke_scale();
+    let path_stroke = PathStroke {
+        width: stroke.width * scale,
+        color,
+        kind: StrokeKind::Middle,
+        join: stroke.join,
+        cap: stroke.cap,
+    };
+
+    for (points, closed) in polylines {
+        if let Some((pattern, offset)) = &stroke.dashes {
+            let pattern: Vec<f32> = pattern.iter().map(|length| length * scale).collect();
+            for dash in dashes(points, *closed, &pattern, offset * scale) {
+                shapes.push(PathShape::line(dash, path_stroke.clone()).into());
+            }
+        } else if *closed {
+            shapes.push(PathShape::closed_line(points.clone(), path_stroke.clone()).into());
+        } else if 2 <= points.len() {
+            shapes.push(PathShape::line(points.clone(), path_stroke.clone()).into());
+        }
+    }
+}
+
+/// Split a polyline into dashes, following an SVG `stroke-dasharray` and `stroke-dashoffset`.
+///
+/// `pattern` alternates between the lengths of dashes and gaps, and has an even length.
+fn dashes(points: &[Pos2], closed: bool, pattern: &[f32], offset: f32) -> Vec<Vec<Pos2>> {
+    let total: f32 = pattern.iter().sum();
+    if points.len() < 2 || pattern.is_empty() || !total.is_finite() || total <= 0.0 {
+        return vec![];
+    }
+
+    // Find where in the pattern we start:
+    let mut index = 0;
+    let mut remaining = pattern[0];
+    let mut skip = offset.rem_euclid(total);
+    while remaining <= skip {
+        skip -= remaining;
+        index = (index + 1) % pattern.len();
+        remaining = pattern[index];
+    }
+    remaining -= skip;
+
+    let is_dash = |index: usize| index % 2 == 0;
+    let mut dashes = vec![];
+    let mut current = is_dash(index).then(|| vec![points[0]]);
+
+    let closing = closed.then(|| [points[points.len() - 1], points[0]]);
+    let segments = points.windows(2).map(|w| [w[0], w[1]]).chain(closing);
+    for [mut from, to] in segments {
+        let mut length = from.distance(to);
+        while remaining < length {
+            let split = from.lerp(to, remaining / length);
+            if let Some(mut dash) = current.take() {
+                dash.push(split);
+                dashes.push(dash);
+            } else {
+                current = Some(vec![split]);
+            }
+            length -= remaining;
+            from = split;
+            index = (index + 1) % pattern.len();
+            remaining = pattern[index];
+        }
+        remaining -= length;
+        if let Some(dash) = &mut current {
+            dash.push(to);
+        }
+    }
+    dashes.extend(current.filter(|dash| 2 <= dash.len()));
+    dashes
+}
+
+/// Split triangles in half until no edge is longer than `max_length`.
+fn subdivide(mesh: &mut Mesh, max_length: f32) {
+    let mut midpoints: HashMap<(u32, u32), u32> = HashMap::default();
+    let mut triangles: Vec<[u32; 3]> = mesh.triangles().collect();
+    mesh.indices.clear();
+
+    while let Some(triangle) = triangles.pop() {
+        let pos = |i: u32| mesh.vertices[i as usize].pos;
+        let (longest, length) = (0..3)
+            .map(|i| (i, pos(triangle[i]).distance(pos(triangle[(i + 1) % 3]))))
+            .fold((0, 0.0), |a, b| if a.1 < b.1 { b } else { a });
+
+        if length <= max_length {
+            mesh.add_triangle(triangle[0], triangle[1], triangle[2]);
+            continue;
+        }
+
+        let (a, b, c) = (
+            triangle[longest],
+            triangle[(longest + 1) % 3],
+            triangle[(longest + 2) % 3],
+        );
+        let midpoint = *midpoints.entry((a.min(b), a.max(b))).or_insert_with(|| {
+            let (va, vb) = (mesh.vertices[a as usize], mesh.vertices[b as usize]);
+            mesh.vertices.push(Vertex {
+                pos: va.pos.lerp(vb.pos, 0.5),
+                uv: va.uv.lerp(vb.uv, 0.5),
+                color: va.color.lerp_to_gamma(vb.color, 0.5),
+            });
+            mesh.vertices.len() as u32 - 1
+        });
+        triangles.push([a, midpoint, c]);
+        triangles.push([midpoint, b, c]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use egui::vec2;
+
+    use super::*;
+
+    #[test]
+    fn fills_and_strokes() {
+        let svg = VectorSvg::from_bytes(
+            br#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="10">
+                <g transform="translate(2 1)">
+                    <rect width="10" height="5" fill="red" stroke="blue" stroke-width="2"/>
+                </g>
+            </svg>"#,
+        )
+        .unwrap();
+        assert_eq!(svg.size(), vec2(20.0, 10.0));
+
+        let rect = Rect::from_min_size(pos2(100.0, 100.0), vec2(40.0, 20.0));
+        let shapes = svg.shapes(rect, 1.0);
+        let [Shape::Polygon(fill), Shape::Path(stroke)] = shapes.as_slice() else {
+            panic!("Expected a fill and a stroke, got {shapes:#?}");
+        };
+        assert_eq!(fill.fill, Color32::RED);
+        assert_eq!(
+            Rect::from_points(&fill.contours[0]),
+            Rect::from_min_size(pos2(104.0, 102.0), vec2(20.0, 10.0))
+        );
+        assert!(stroke.closed);
+        assert_eq!(stroke.stroke.width, 4.0);
+    }
+
+    #[test]
+    fn gradient_fill() {
+        let svg = VectorSvg::from_bytes(
+            br#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="10">
+                <linearGradient id="g" x1="0" x2="100" y1="0" y2="0" gradientUnits="userSpaceOnUse">
+                    <stop offset="0" stop-color="black"/>
+                    <stop offset="1" stop-color="white"/>
+                </linearGradient>
+                <rect width="100" height="10" fill="url(#g)"/>
+            </svg>"#,
+        )
+        .unwrap();
+
+        let mesh = svg.mesh(vec2(100.0, 10.0), 1.0);
+        assert!(mesh.is_valid());
+        for vertex in &mesh.vertices {
+            if vertex.color.a() == 255 {
+                let expected = (255.0 * vertex.pos.x / 100.0).round();
+                assert!(
+                    (vertex.color.r() as f32 - expected).abs() <= 1.0,
+                    "{vertex:?}"
+                );
+            }
+        }
+
+        assert!(Arc::ptr_eq(&mesh, &svg.mesh(vec2(100.0, 10.0), 1.0)));
+        assert!(!Arc::ptr_eq(&mesh, &svg.mesh(vec2(200.0, 20.0), 1.0)));
+    }
+
+    #[test]
+    fn dash_pattern() {
+        let line = [pos2(0.0, 0.0), pos2(10.0, 0.0)];
+        let xs = |dashes: Vec<Vec<Pos2>>| -> Vec<Vec<f32>> {
+            dashes
+                .iter()
+                .map(|dash| dash.iter().map(|p| p.x).collect())
+                .collect()
+        };
+        assert_eq!(
+            xs(dashes(&line, false, &[3.0, 1.0], 0.0)),
+            [vec![0.0, 3.0], vec![4.0, 7.0], vec![8.0, 10.0]]
+        );
+        assert_eq!(
+            xs(dashes(&line, false, &[3.0, 1.0], 2.0)),
+            [vec![0.0, 1.0], vec![2.0, 5.0], vec![6.0, 9.0]]
+        );
+    }
+}
@@ -352,6 +352,25 @@ impl Painter {
         clear(&self.gl, screen_size_in_pixels, clear_color);
     }
 
+    /// Only clear within the given regions (in points).
+    ///
+    /// See [`Self::paint_primitives_in_regions`].
+    pub fn clear_regions(
+        &self,
+        screen_size_in_pixels: [u32; 2],
+        pixels_per_point: f32,
+        regions: &[Rect],
+        clear_color: [f32; 4],
+    ) {
+        clear_regions(
+            &self.gl,
+            screen_size_in_pixels,
+            pixels_per_point,
+            regions,
+            clear_color,
+        );
+    }
+
     /// You are expected to have cleared the color buffer before calling this.
     pub fn paint_and_update_textures(
         &mut self,
@@ -402,6 +421,48 @@ impl Painter {
         profiling::function_scope!();
         self.assert_not_destroyed();
 
+        self.paint_primitives_within(
+            screen_size_px,
+            pixels_per_point,
+            clipped_primitives,
+            Rect::EVERYTHING,
+        );
+    }
+
+    /// Like [`Self::paint_primitives`], but only paints within the given regions (in points),
+    /// e.g. [`egui::FullOutput::damage`].
+    ///
+    /// The regions must not overlap.
+    /// Everything outside of them is left as is, so the framebuffer needs to still contain
+    /// what was painted last frame.
+    /// Clear the regions first with [`Self::clear_regions`].
+    pub fn paint_primitives_in_regions(
+        &mut self,
+        screen_size_px: [u32; 2],
+        pixels_per_point: f32,
+        clipped_primitives: &[egui::ClippedPrimitive],
+        regions: &[Rect],
+    ) {
+        profiling::function_scope!();
+        self.assert_not_destroyed();
+
+        for region in regions {
+            self.paint_primitives_within(
+                screen_size_px,
+                pixels_per_point,
+                clipped_primitives,
+                *region,
+            );
+        }
+    }
+
+    fn paint_primitives_within(
+        &mut self,
+        screen_size_px: [u32; 2],
+        pixels_per_point: f32,
+        clipped_primitives: &[egui::ClippedPrimitive],
+        region: Rect,
+    ) {
         unsafe { self.prepare_painting(screen_size_px, pixels_per_point) };
 
         for egui::ClippedPrimitive {
@@ -409,7 +470,11 @@ impl Painter {
             primitive,
         } in clipped_primitives
         {
-            set_clip_rect(&self.gl, screen_size_px, pixels_per_point, *clip_rect);
+            let clip_rect = clip_rect.intersect(region);
+            if !clip_rect.is_positive() {
+                continue;
+            }
+            set_clip_rect(&self.gl, screen_size_px, pixels_per_point, clip_rect);
 
             match primitive {
                 Primitive::Mesh(mesh) => {
@@ -421,7 +486,7 @@ impl Painter {
 
                         let info = egui::PaintCallbackInfo {
                             viewport: callback.rect,
-                            clip_rect: *clip_rect,
+                            clip_rect,
                             pixels_per_point,
                             screen_size_px,
                         };
@@ -747,6 +812,39 @@ pub fn clear(gl: &glow::Context, screen_size_in_pixels: [u32; 2], clear_color: [
     }
 }
 
+/// Only clear within the given regions (in points), e.g. [`egui::FullOutput::damage`].
+pub fn clear_regions(
+    gl: &glow::Context,
+    screen_size_in_pixels: [u32; 2],
+    pixels_per_point: f32,
+    regions: &[Rect],
+    clear_color: [f32; 4],
+) {
+    profiling::function_scope!();
+    unsafe {
+        gl.enable(glow::SCISSOR_TEST);
+
+        gl.viewport(
+            0,
+            0,
+            screen_size_in_pixels[0] as i32,
+            screen_size_in_pixels[1] as i32,
+        );
+        gl.clear_color(
+            clear_color[0],
+            clear_color[1],
+            clear_color[2],
+            clear_color[3],
+        );
+        for region in regions {
+            set_clip_rect(gl, screen_size_in_pixels, pixels_per_point, *region);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+        }
+
+        gl.disable(glow::SCISSOR_TEST);
+    }
+}
+
 impl Drop for Painter {
     fn drop(&mut self) {
         if !self.destroyed {
@@ -73,6 +73,7 @@ impl EguiGlow {
             textures_delta,
             shapes,
             pixels_per_point,
+            damage: _, // We always repaint everything
             viewport_output,
         } = self.egui_ctx.run(raw_input, run_ui);
 
@@ -655,10 +655,10 @@ impl<'a, State> Harness<'a, State> {
     /// This will add a [`RectShape`] to the output shapes, for the current frame.
     /// Will be overwritten on the next call to [`Self::run`].
     pub fn mask(&mut self, rect: Rect) {
-        self.output.shapes.push(ClippedShape {
-            clip_rect: Rect::EVERYTHING,
-            shape: Shape::Rect(RectShape::filled(rect, 0.0, Color32::MAGENTA)),
-        });
+        self.output.shapes.push(ClippedShape::new(
+            Rect::EVERYTHING,
+            Shape::Rect(RectShape::filled(rect, 0.0, Color32::MAGENTA)),
+        ));
     }
 
     /// Render the last output to an image.
@@ -677,15 +677,14 @@ impl<'a, State> Harness<'a, State> {
                 mouse_pos + egui::vec2(8.0, 16.0),
             ];
 
-            output.shapes.push(ClippedShape {
-                clip_rect: self.ctx.content_rect(),
-                shape: egui::epaint::PathShape::convex_polygon(
+            output.shapes.push(ClippedShape::new(
+                self.ctx.content_rect(),
+                egui::epaint::PathShape::convex_polygon(
                     triangle,
                     Color32::WHITE,
                     egui::Stroke::new(1.0, Color32::BLACK),
-                )
-                .into(),
-            });
+                ),
+            ));
         }
 
         self.renderer.render(&self.ctx, &output)
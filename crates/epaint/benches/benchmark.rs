@@ -2,7 +2,8 @@ use criterion::{Criterion, criterion_group, criterion_main};
 
 use epaint::{
     AlphaFromCoverage, ClippedShape, Color32, Mesh, PathStroke, Pos2, Rect, Shape, Stroke,
-    TessellationOptions, Tessellator, TextureAtlas, Vec2, pos2, tessellator::Path,
+    TessellationCache, TessellationOptions, Tessellator, TextureAtlas, Vec2, pos2,
+    tessellator::Path,
 };
 
 use std::hint::black_box;
@@ -55,7 +56,7 @@ fn tessellate_circles(c: &mut Criterion) {
             for _ in 0..10_000 {
                 let clip_rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(1024.0));
                 let shape = Shape::circle_filled(Pos2::new(10.0, 10.0), r, Color32::WHITE);
-                clipped_shapes.push(ClippedShape { clip_rect, shape });
+                clipped_shapes.push(ClippedShape::new(clip_rect, shape));
             }
         }
         assert_eq!(
@@ -85,6 +86,48 @@ fn tessellate_circles(c: &mut Criterion) {
     });
 }
 
+fn tessellate_with_cache(c: &mut Criterion) {
+    let clip_rect = Rect::from_min_size(Pos2::ZERO, Vec2::splat(1024.0));
+    let paths: Vec<ClippedShape> = (0..1000)
+        .map(|i| {
+            let points = (0..32)
+                .map(|j| pos2(j as f32 * 10.0, (i + j) as f32 % 7.0 * 3.0))
+                .collect();
+            let shape = Shape::line(points, Stroke::new(1.5, Color32::RED));
+            ClippedShape::new(clip_rect, shape)
+        })
+        .collect();
+    let rects: Vec<ClippedShape> = (0..1000)
+        .map(|i| {
+            let rect = Rect::from_min_size(pos2(i as f32, 0.0), Vec2::splat(20.0));
+            let shape = Shape::rect_filled(rect, 4.0, Color32::WHITE);
+            ClippedShape::new(clip_rect, shape)
+        })
+        .collect();
+
+    let atlas = TextureAtlas::new([4096, 256], AlphaFromCoverage::default());
+
+    for (name, shapes) in [("paths", paths), ("rects", rects)] {
+        for cache_meshes in [false, true] {
+            let options = TessellationOptions {
+                cache_meshes,
+                ..Default::default()
+            };
+            let mut tessellator =
+                Tessellator::new(2.0, options, atlas.size(), atlas.prepared_discs());
+            let mut cache = TessellationCache::default();
+            let cached = if cache_meshes { "cached" } else { "uncached" };
+            c.bench_function(&format!("tessellate_1k_{name}_{cached}"), |b| {
+                b.iter(|| {
+                    let clipped_primitives =
+                        tessellator.tessellate_shapes_with_cache(shapes.clone(), &mut cache);
+                    black_box(clipped_primitives);
+                });
+            });
+        }
+    }
+}
+
 fn thick_line_solid(c: &mut Criterion) {
     c.bench_function("thick_solid_line", move |b| {
         let line = [pos2(0.0, 0.0), pos2(50.0, 0.0), pos2(100.0, 1.0)];
@@ -278,6 +321,7 @@ criterion_group!(
     single_dashed_lines,
     many_dashed_lines,
     tessellate_circles,
+    tessellate_with_cache,
     thick_line_solid,
     thick_large_line_solid,
     thin_line_solid,
@@ -0,0 +1,509 @@
+use emath::{Pos2, Rect, TSTransform, Vec2};
+
+use crate::{Color32, CornerRadiusF32, FillRule, Mesh, Vertex, polygon_fill, tessellator::path};
+
+/// A clip region that is not just a rectangle, e.g. a rectangle with rounded corners.
+///
+/// Set it on [`crate::ClippedShape::clip_mask`] to only show the part of the shape inside the mask.
+/// The clipping is done on the CPU by the [`crate::Tessellator`],
+/// so it works the same with any backend.
+///
+/// Convex masks get anti-aliased edges.
+/// Concave masks (and masks with several contours) are clipped without anti-aliasing.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ClipMask {
+    /// Only show what is inside this rectangle with rounded corners.
+    RoundedRect {
+        /// The outer edge of the mask.
+        rect: Rect,
+
+        /// How much to round each corner of `rect`.
+        corner_radius: CornerRadiusF32,
+    },
+
+    /// Only show what is inside these closed contours.
+    Path {
+        /// Each contour is implicitly closed.
+        contours: Vec<Vec<Pos2>>,
+
+        /// Decides what is inside the contours.
+        fill_rule: FillRule,
+    },
+}
+
+impl ClipMask {
+    /// Only show what is inside this rectangle with rounded corners.
+    #[inline]
+    pub fn rounded_rect(rect: Rect, corner_radius: impl Into<CornerRadiusF32>) -> Self {
+        Self::RoundedRect {
+            rect,
+            corner_radius: corner_radius.into(),
+        }
+    }
+
+    /// Only show what is inside this circle.
+    #[inline]
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::rounded_rect(
+            Rect::from_center_size(center, Vec2::splat(2.0 * radius)),
+            radius,
+        )
+    }
+
+    /// Only show what is inside this closed polygon.
+    #[inline]
+    pub fn polygon(points: Vec<Pos2>) -> Self {
+        Self::path(vec![points], FillRule::NonZero)
+    }
+
+    /// Only show what is inside these closed contours, according to the [`FillRule`].
+    #[inline]
+    pub fn path(contours: Vec<Vec<Pos2>>, fill_rule: FillRule) -> Self {
+        Self::Path {
+            contours,
+            fill_rule,
+        }
+    }
+
+    /// Nothing outside of this is visible.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::RoundedRect { rect, .. } => *rect,
+            Self::Path { contours, .. } => {
+                Rect::from_points(&contours.iter().flatten().copied().collect::<Vec<_>>())
+            }
+        }
+    }
+
+    /// Move and scale the mask.
+    pub fn transform(&mut self, transform: TSTransform) {
+        match self {
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => {
+                *rect = transform * *rect;
+                *corner_radius *= transform.scaling;
+            }
+            Self::Path { contours, .. } => {
+                for point in contours.iter_mut().flatten() {
+                    *point = transform * *point;
+                }
+            }
+        }
+    }
+
+    /// Append the parts of the triangles of `mesh` that are inside the mask to `out`.
+    ///
+    /// `feathering` is the width of the anti-aliased edge of convex masks, in points.
+    pub fn clip_mesh(&self, mesh: &Mesh, feathering: f32, out: &mut Mesh) {
+        if mesh.is_empty() {
+            return;
+        }
+        if out.is_empty() {
+            out.texture_id = mesh.texture_id;
+        } else {
+            debug_assert_eq!(
+                out.texture_id, mesh.texture_id,
+                "Can't merge Mesh using different textures"
+            );
+        }
+
+        if let Some(polygon) = self.convex_polygon() {
+            if let Some(planes) = HalfPlane::around(&polygon) {
+                clip_mesh_convex(mesh, &planes, feathering, out);
+            }
+        } else if let Self::Path {
+            contours,
+            fill_rule,
+        } = self
+        {
+            let mut fill = Mesh::default();
+            polygon_fill::fill(contours, *fill_rule, Color32::WHITE, &mut fill);
+            let pieces: Vec<([HalfPlane; 3], Rect)> = fill
+                .triangles()
+                .filter_map(|triangle| {
+                    let points = triangle.map(|i| fill.vertices[i as usize].pos);
+                    let planes = HalfPlane::around(&points)?;
+                    Some((planes.try_into().ok()?, Rect::from_points(&points)))
+                })
+                .collect();
+            clip_mesh_pieces(mesh, &pieces, out);
+        }
+    }
+
+    /// The mask as a single convex polygon, if it is one.
+    fn convex_polygon(&self) -> Option<Vec<Pos2>> {
+        match self {
+            Self::RoundedRect {
+                rect,
+                corner_radius,
+            } => {
+                let mut points = vec![];
+                path::rounded_rectangle(&mut points, *rect, *corner_radius);
+                Some(points)
+            }
+            Self::Path { contours, .. } => match contours.as_slice() {
+                [contour] if is_convex(contour) => Some(contour.clone()),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Does the closed contour go around its inside exactly once, always turning the same way?
+fn is_convex(contour: &[Pos2]) -> bool {
+    let n = contour.len();
+    if n < 3 {
+        return false;
+    }
+    let mut sign = 0.0;
+    let mut total_turn = 0.0;
+    for i in 0..n {
+        let a = contour[i];
+        let b = contour[(i + 1) % n];
+        let c = contour[(i + 2) % n];
+        let (ab, bc) = (b - a, c - b);
+        if ab == Vec2::ZERO || bc == Vec2::ZERO {
+            continue;
+        }
+        let cross = ab.x * bc.y - ab.y * bc.x;
+        if cross != 0.0 {
+            if sign * cross < 0.0 {
+                return false;
+            }
+            sign = cross.signum();
+        }
+        total_turn += cross.atan2(ab.dot(bc));
+    }
+    (total_turn.abs() - std::f32::consts::TAU).abs() < 0.1
+}
+
+/// The points `p` where `normal · p <= offset`.
+#[derive(Clone, Copy, Debug)]
+struct HalfPlane {
+    normal: Vec2,
+    offset: f32,
+}
+
+impl HalfPlane {
+    /// The half-planes that make up the inside of a convex polygon, going either way around.
+    ///
+    /// Returns `None` for degenerate polygons.
+    fn around(polygon: &[Pos2]) -> Option<Vec<Self>> {
+        let n = polygon.len();
+        let area: f32 = (0..n)
+            .map(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+                a.x * b.y - a.y * b.x
+            })
+            .sum();
+        if !area.is_normal() {
+            return None;
+        }
+        let planes = (0..n)
+            .filter_map(|i| {
+                let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+                // Compute from the same end no matter the direction,
+                // so neighboring triangles cut along exactly the same line:
+                let (from, to, outward) = if (a.x, a.y) < (b.x, b.y) {
+                    (a, b, 0.0 < area)
+                } else {
+                    (b, a, area < 0.0)
+                };
+                let dir = (to - from).normalized();
+                if !dir.is_finite() || dir == Vec2::ZERO {
+                    return None;
+                }
+                // `rot90` points to the left, which is the outside of a clockwise polygon.
+                let normal = if outward { dir.rot90() } else { -dir.rot90() };
+                Some(Self {
+                    normal,
+                    offset: normal.dot(from.to_vec2()),
+                })
+            })
+            .collect();
+        Some(planes)
+    }
+
+    #[inline]
+    fn distance(&self, pos: Pos2) -> f32 {
+        self.normal.dot(pos.to_vec2()) - self.offset
+    }
+}
+
+/// How far outside of the convex polygon is this point? Negative on the inside.
+fn signed_distance(planes: &[HalfPlane], pos: Pos2) -> f32 {
+    planes
+        .iter()
+        .map(|plane| plane.distance(pos))
+        .fold(f32::NEG_INFINITY, f32::max)
+}
+
+/// Clip against a convex mask, feathering its edge.
+fn clip_mesh_convex(mesh: &Mesh, planes: &[HalfPlane], feathering: f32, out: &mut Mesh) {
+    let half = 0.5 * feathering;
+    let coverage = |pos: Pos2| {
+        if feathering > 0.0 {
+            (0.5 - signed_distance(planes, pos) / feathering).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    };
+
+    let distances: Vec<f32> = mesh
+        .vertices
+        .iter()
+        .map(|vertex| signed_distance(planes, vertex.pos))
+        .collect();
+
+    let mut reused = ReusedVertices::new(mesh);
+    let mut polygon = vec![];
+    let mut inside = vec![];
+    let mut band = vec![];
+
+    for triangle in mesh.triangles() {
+        let [a, b, c] = triangle.map(|i| i as usize);
+
+        if distances[a] <= -half && distances[b] <= -half && distances[c] <= -half {
+            // Fully inside, even the feathering:
+            reused.add_triangle(triangle, out);
+            continue;
+        }
+
+        polygon.clear();
+        polygon.extend([a, b, c].map(|i| mesh.vertices[i]));
+        for plane in planes {
+            split(
+                &polygon,
+                |pos| plane.distance(pos) - half,
+                &mut inside,
+                None,
+            );
+            std::mem::swap(&mut polygon, &mut inside);
+        }
+
+        if 0.0 < feathering {
+            // Peel off the feathered band, one edge at a time:
+            for plane in planes {
+                split(
+                    &polygon,
+                    |pos| plane.distance(pos) + half,
+                    &mut inside,
+                    Some(&mut band),
+                );
+                for vertex in &mut band {
+                    vertex.color = vertex.color.gamma_multiply(coverage(vertex.pos));
+                }
+                add_convex_polygon(&band, out);
+                std::mem::swap(&mut polygon, &mut inside);
+            }
+        }
+
+        add_convex_polygon(&polygon, out);
+    }
+}
+
+/// Clip against a mask made up of convex triangles, without anti-aliasing.
+fn clip_mesh_pieces(mesh: &Mesh, pieces: &[([HalfPlane; 3], Rect)], out: &mut Mesh) {
+    let mut polygon = vec![];
+    let mut inside = vec![];
+
+    for triangle in mesh.triangles() {
+        let triangle = triangle.map(|i| mesh.vertices[i as usize]);
+        let bounds = Rect::from_points(&triangle.map(|vertex| vertex.pos));
+
+        for (planes, piece_bounds) in pieces {
+            if !bounds.intersects(*piece_bounds) {
+                continue;
+            }
+            polygon.clear();
+            polygon.extend(triangle);
+            for plane in planes {
+                split(&polygon, |pos| plane.distance(pos), &mut inside, None);
+                std::mem::swap(&mut polygon, &mut inside);
+            }
+            add_convex_polygon(&polygon, out);
+        }
+    }
+}
+
+/// Split a convex polygon where `distance` crosses zero,
+/// into the part where it is negative (`inside`) and the part where it is positive (`outside`).
+fn split(
+    polygon: &[Vertex],
+    distance: impl Fn(Pos2) -> f32,
+    inside: &mut Vec<Vertex>,
+    mut outside: Option<&mut Vec<Vertex>>,
+) {
+    inside.clear();
+    if let Some(outside) = outside.as_deref_mut() {
+        outside.clear();
+    }
+
+    let n = polygon.len();
+    for i in 0..n {
+        let (a, b) = (polygon[i], polygon[(i + 1) % n]);
+        let (da, db) = (distance(a.pos), distance(b.pos));
+
+        if da <= 0.0 {
+            inside.push(a);
+        }
+        if 0.0 <= da
+            && let Some(outside) = outside.as_deref_mut()
+        {
+            outside.push(a);
+        }
+
+        if (da < 0.0 && 0.0 < db) || (db < 0.0 && 0.0 < da) {
+            let crossing = lerp_vertex((a, da), (b, db));
+            inside.push(crossing);
+            if let Some(outside) = outside.as_deref_mut() {
+                outside.push(crossing);
+            }
+        }
+    }
+}
+
+/// The vertex where the distance is zero, on the edge between two vertices.
+fn lerp_vertex((a, da): (Vertex, f32), (b, db): (Vertex, f32)) -> Vertex {
+    // Interpolate from the same end no matter the direction,
+    // so neighboring triangles get exactly the same vertex:
+    let ((a, da), (b, db)) = if (a.pos.x, a.pos.y) < (b.pos.x, b.pos.y) {
+        ((a, da), (b, db))
+    } else {
+        ((b, db), (a, da))
+    };
+    let t = da / (da - db);
+    Vertex {
+        pos: a.pos.lerp(b.pos, t),
+        uv: a.uv.lerp(b.uv, t),
+        color: a.color.lerp_to_gamma(b.color, t),
+    }
+}
+
+fn add_convex_polygon(polygon: &[Vertex], out: &mut Mesh) {
+    if polygon.len() < 3 {
+        return;
+    }
+    let first = out.vertices.len() as u32;
+    out.vertices.extend_from_slice(polygon);
+    for i in 1..polygon.len() as u32 - 1 {
+        out.add_triangle(first, first + i, first + i + 1);
+    }
+}
+
+/// Copies vertices of unclipped triangles once, so they can still be shared.
+struct ReusedVertices<'a> {
+    mesh: &'a Mesh,
+    new_index: Vec<u32>,
+}
+
+impl<'a> ReusedVertices<'a> {
+    fn new(mesh: &'a Mesh) -> Self {
+        Self {
+            mesh,
+            new_index: vec![u32::MAX; mesh.vertices.len()],
+        }
+    }
+
+    fn add_triangle(&mut self, triangle: [u32; 3], out: &mut Mesh) {
+        let [a, b, c] = triangle.map(|i| {
+            let new_index = &mut self.new_index[i as usize];
+            if *new_index == u32::MAX {
+                *new_index = out.vertices.len() as u32;
+                out.vertices.push(self.mesh.vertices[i as usize]);
+            }
+            *new_index
+        });
+        out.add_triangle(a, b, c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use emath::{pos2, vec2};
+
+    use super::*;
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.map(|i| mesh.vertices[i as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum()
+    }
+
+    fn square(rect: Rect) -> Mesh {
+        let mut mesh = Mesh::default();
+        mesh.add_colored_rect(rect, Color32::WHITE);
+        mesh
+    }
+
+    #[test]
+    fn clip_to_rounded_rect() {
+        let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0));
+        let mask = ClipMask::rounded_rect(rect, 20.0);
+
+        let mut out = Mesh::default();
+        mask.clip_mesh(&square(rect.expand(10.0)), 0.0, &mut out);
+        assert!(out.is_valid());
+
+        let bounds = out.calc_bounds();
+        assert!((bounds.min - rect.min).length() < 1e-3, "{bounds:?}");
+        assert!((bounds.max - rect.max).length() < 1e-3, "{bounds:?}");
+
+        // The corners are cut off:
+        let expected_area = 100.0 * 100.0 - (4.0 - std::f32::consts::PI) * 20.0 * 20.0;
+        assert!((area(&out) - expected_area).abs() < 10.0, "{}", area(&out));
+    }
+
+    #[test]
+    fn clip_to_circle_with_feathering() {
+        let mask = ClipMask::circle(pos2(50.0, 50.0), 10.0);
+        let mut out = Mesh::default();
+        mask.clip_mesh(&square(mask.bounding_rect().expand(5.0)), 1.0, &mut out);
+        assert!(out.is_valid());
+
+        for vertex in &out.vertices {
+            let distance = vertex.pos.distance(pos2(50.0, 50.0));
+            assert!(distance < 10.55, "{vertex:?}");
+            if distance < 9.0 {
+                assert_eq!(vertex.color, Color32::WHITE);
+            }
+            if 10.45 < distance {
+                assert!(vertex.color.a() < 20, "{vertex:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn unclipped_triangles_share_vertices() {
+        let mask =
+            ClipMask::rounded_rect(Rect::from_min_size(pos2(0.0, 0.0), vec2(50.0, 50.0)), 5.0);
+        let mesh = square(Rect::from_min_size(pos2(10.0, 10.0), vec2(10.0, 10.0)));
+        let mut out = Mesh::default();
+        mask.clip_mesh(&mesh, 1.0, &mut out);
+        assert_eq!(out, mesh);
+    }
+
+    #[test]
+    fn clip_to_concave_path() {
+        // An L shape:
+        let mask = ClipMask::polygon(vec![
+            pos2(0.0, 0.0),
+            pos2(10.0, 0.0),
+            pos2(10.0, 5.0),
+            pos2(5.0, 5.0),
+            pos2(5.0, 10.0),
+            pos2(0.0, 10.0),
+        ]);
+        let mut out = Mesh::default();
+        let mesh = square(Rect::from_min_size(pos2(-5.0, -5.0), vec2(20.0, 20.0)));
+        mask.clip_mesh(&mesh, 1.0, &mut out);
+        assert!(out.is_valid());
+        assert!((area(&out) - 75.0).abs() < 1e-3, "{}", area(&out));
+    }
+}
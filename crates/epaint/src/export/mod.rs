@@ -0,0 +1,1461 @@
+//! Export painted shapes to vector formats, e.g. for documentation or print.
+//!
+//! The [`Exporter`] walks a list of [`ClippedShape`]:s, such as `egui::FullOutput::shapes`,
+//! and writes them as an SVG or PDF document.
+//!
+//! Rectangles, circles, ellipses, paths, polygons and Bézier curves become vector paths,
+//! and clip rectangles and [`crate::ClipMask`]:s become clip paths.
+//! Text becomes the outlines of its glyphs, so you need to give the exporter the fonts the text was laid out with.
+//! Textured meshes are drawn as embedded bitmaps cut out of their textures,
+//! so you also need to give the exporter the textures it should use:
+//!
+//! ```
+//! use epaint::{export::Exporter, *};
+//!
+//! let fonts = Fonts::new(1024, AlphaFromCoverage::default(), text::FontDefinitions::default());
+//! let shapes = vec![ClippedShape::new(
+//!     Rect::EVERYTHING,
+//!     Shape::circle_filled(pos2(50.0, 50.0), 20.0, Color32::RED),
+//! )];
+//!
+//! let exporter = Exporter::new(Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0)))
+//!     .with_fonts(&fonts.fonts)
+//!     .with_texture(TextureId::default(), fonts.image());
+//! let svg: String = exporter.to_svg(&shapes);
+//! let pdf: Vec<u8> = exporter.to_pdf(&shapes);
+//! ```
+//!
+//! Glyphs without an outline, or from fonts the exporter doesn't know about,
+//! are drawn as bitmaps cut out of the font atlas ([`TextureId::default`]) instead.
+//!
+//! SVG:s can only embed bitmaps with the `svg_images` feature, which encodes them as PNG:s.
+//! Without it, bitmaps are left out of SVG:s. PDF:s always contain them.
+//!
+//! SVG and PDF paths have a single color, so a triangle with different colors
+//! at its vertices (e.g. from [`Shape::VariableStroke`] or a [`Mesh`]) is painted with
+//! the average of them. Smooth gradients therefore become steps, one per triangle.
+//!
+//! [`Shape::Callback`] can't be exported, and is skipped.
+
+mod pdf;
+mod svg;
+
+use std::{f32::consts::FRAC_PI_2, sync::Arc};
+
+use ab_glyph::Font as _;
+use emath::{Pos2, Rect, Rot2, Vec2, pos2, vec2};
+
+use crate::{
+    ArcKind, ClipMask, ClippedShape, Color32, ColorImage, ColorMode, CornerRadiusF32, FillRule,
+    LineCap, LineJoin, Mesh, PathStroke, Shape, Stroke, StrokeKind, TessellationOptions,
+    Tessellator, TextPathShape, TextShape, TextureId, Vertex,
+    text::{FontFaceKey, FontsImpl, Glyph, Row},
+};
+
+/// Writes painted shapes as SVG or PDF documents.
+///
+/// See the [module level docs](self) for an example.
+#[derive(Clone, Debug)]
+pub struct Exporter {
+    rect: Rect,
+    fonts: ahash::HashMap<FontFaceKey, ab_glyph::FontArc>,
+    textures: ahash::HashMap<TextureId, Arc<ColorImage>>,
+}
+
+impl Exporter {
+    /// Export the shapes within this rectangle (in points), e.g. `egui::Context::content_rect`.
+    ///
+    /// One point becomes one unit in the SVG, and one PDF point (1/72 inch) in the PDF.
+    pub fn new(rect: Rect) -> Self {
+        Self {
+            rect,
+            fonts: Default::default(),
+            textures: Default::default(),
+        }
+    }
+
+    /// Use these fonts to export text as the outlines of its glyphs.
+    ///
+    /// This must be the fonts the text was laid out with,
+    /// e.g. `ctx.fonts(|fonts| exporter.with_fonts(fonts.fonts))` in egui.
+    #[inline]
+    pub fn with_fonts(mut self, fonts: &FontsImpl) -> Self {
+        self.fonts
+            .extend(fonts.font_faces().map(|(key, font)| (key, font.clone())));
+        self
+    }
+
+    /// Use this image for meshes and text using the given texture.
+    ///
+    /// Text uses the font atlas, which is [`TextureId::default`],
+    /// for glyphs that can't be exported as outlines.
+    /// Triangles using a texture that the exporter doesn't know about are skipped,
+    /// unless they only sample a single texel (like untextured shapes do).
+    #[inline]
+    pub fn with_texture(
+        mut self,
+        texture_id: TextureId,
+        image: impl Into<Arc<ColorImage>>,
+    ) -> Self {
+        self.textures.insert(texture_id, image.into());
+        self
+    }
+
+    /// Write the shapes as an SVG document.
+    pub fn to_svg(&self, shapes: &[ClippedShape]) -> String {
+        let mut canvas = svg::SvgCanvas::new(self.rect);
+        self.paint(shapes, &mut canvas);
+        canvas.finish()
+    }
+
+    /// Write the shapes as a single-page PDF document.
+    pub fn to_pdf(&self, shapes: &[ClippedShape]) -> Vec<u8> {
+        let mut canvas = pdf::PdfCanvas::new(self.rect);
+        self.paint(shapes, &mut canvas);
+        canvas.finish()
+    }
+
+    fn paint(&self, shapes: &[ClippedShape], canvas: &mut impl Canvas) {
+        profiling::function_scope!();
+
+        let mut painter = ShapePainter {
+            fonts: &self.fonts,
+            textures: &self.textures,
+            canvas,
+            bitmaps: Default::default(),
+        };
+
+        let mut current_clip = None;
+        for clipped_shape in shapes {
+            let ClippedShape {
+                clip_rect,
+                shape,
+                clip_mask,
+            } = clipped_shape;
+
+            if !clip_rect.intersects(self.rect) {
+                continue;
+            }
+
+            let clip = (*clip_rect, clip_mask.as_deref());
+            if current_clip != Some(clip) {
+                current_clip = Some(clip);
+
+                let mut clip_paths = vec![];
+                if !clip_rect.contains_rect(self.rect) {
+                    clip_paths.push((Path::rect(*clip_rect), FillRule::NonZero));
+                }
+                if let Some(clip_mask) = clip_mask {
+                    clip_paths.push(clip_mask_path(clip_mask));
+                }
+                painter.canvas.set_clip(&clip_paths);
+            }
+
+            painter.paint_shape(shape);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// What the exported formats need to be able to draw.
+trait Canvas {
+    /// Only draw inside all of these paths, until the next call.
+    ///
+    /// An empty slice turns off clipping.
+    fn set_clip(&mut self, clip_paths: &[(Path, FillRule)]);
+
+    fn fill(&mut self, path: &Path, fill_rule: FillRule, color: Color32);
+
+    fn stroke(&mut self, path: &Path, stroke: &SolidStroke);
+
+    /// Draw the bitmap, mapping its pixel coordinates with `transform`,
+    /// optionally clipped to a path.
+    fn image(&mut self, bitmap: &Arc<Bitmap>, transform: Affine, clip: Option<&Path>);
+}
+
+/// A 2D affine transform `[a, b, c, d, e, f]`, mapping `(x, y)` to `(a x + c y + e, b x + d y + f)`.
+///
+/// This is the same layout as SVG and PDF use.
+type Affine = [f32; 6];
+
+/// A stroke with a single color.
+struct SolidStroke {
+    width: f32,
+    color: Color32,
+    join: LineJoin,
+    cap: LineCap,
+}
+
+/// An image cut out of a texture, with the tint already applied.
+struct Bitmap {
+    /// Unique for each bitmap of an export.
+    id: usize,
+
+    size: [usize; 2],
+
+    /// sRGBA, not premultiplied.
+    rgba: Vec<u8>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PathElement {
+    MoveTo(Pos2),
+    LineTo(Pos2),
+    CubicTo(Pos2, Pos2, Pos2),
+    Close,
+}
+
+/// A path made up of straight lines and cubic Bézier curves.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Path(Vec<PathElement>);
+
+/// How far along the tangents to put the control points of a cubic Bézier approximating a quarter circle.
+const QUARTER_CIRCLE_KAPPA: f32 = 0.552_284_8;
+
+impl Path {
+    fn rect(rect: Rect) -> Self {
+        let mut path = Self::default();
+        path.add_polygon(
+            &[
+                rect.left_top(),
+                rect.right_top(),
+                rect.right_bottom(),
+                rect.left_bottom(),
+            ],
+            true,
+        );
+        path
+    }
+
+    fn add_polygon(&mut self, points: &[Pos2], closed: bool) {
+        let Some((first, rest)) = points.split_first() else {
+            return;
+        };
+        self.0.push(PathElement::MoveTo(*first));
+        self.0
+            .extend(rest.iter().map(|point| PathElement::LineTo(*point)));
+        if closed {
+            self.0.push(PathElement::Close);
+        }
+    }
+
+    /// A clockwise rounded rectangle.
+    fn add_rounded_rect(&mut self, rect: Rect, corner_radius: CornerRadiusF32) {
+        let CornerRadiusF32 { nw, ne, sw, se } = corner_radius
+            .at_most(0.5 * rect.size().min_elem())
+            .at_least(0.0);
+        let Rect { min, max } = rect;
+
+        self.0.push(PathElement::MoveTo(pos2(min.x + nw, min.y)));
+        self.line_to(pos2(max.x - ne, min.y));
+        self.add_corner(pos2(max.x, min.y), pos2(max.x, min.y + ne));
+        self.line_to(pos2(max.x, max.y - se));
+        self.add_corner(pos2(max.x, max.y), pos2(max.x - se, max.y));
+        self.line_to(pos2(min.x + sw, max.y));
+        self.add_corner(pos2(min.x, max.y), pos2(min.x, max.y - sw));
+        self.line_to(pos2(min.x, min.y + nw));
+        self.add_corner(pos2(min.x, min.y), pos2(min.x + nw, min.y));
+        self.0.push(PathElement::Close);
+    }
+
+    /// A clockwise ellipse.
+    fn add_ellipse(&mut self, center: Pos2, radius: Vec2) {
+        let Vec2 { x: rx, y: ry } = radius;
+        self.0.push(PathElement::MoveTo(center + vec2(rx, 0.0)));
+        self.add_corner(center + vec2(rx, ry), center + vec2(0.0, ry));
+        self.add_corner(center + vec2(-rx, ry), center + vec2(-rx, 0.0));
+        self.add_corner(center + vec2(-rx, -ry), center + vec2(0.0, -ry));
+        self.add_corner(center + vec2(rx, -ry), center + vec2(rx, 0.0));
+        self.0.push(PathElement::Close);
+    }
+
+    /// An elliptical arc, see [`crate::ArcShape`] for how the angles work.
+    ///
+    /// Continues the current sub-path with a straight line to the start of the arc, if there is one.
+    fn add_arc(&mut self, center: Pos2, radius: Vec2, start_angle: f32, sweep_angle: f32) {
+        let point_at = |angle: f32| center + vec2(radius.x * angle.cos(), radius.y * angle.sin());
+        let tangent_at = |angle: f32| vec2(-radius.x * angle.sin(), radius.y * angle.cos());
+
+        let start = point_at(start_angle);
+        if self.last_point().is_some() {
+            self.line_to(start);
+        } else {
+            self.0.push(PathElement::MoveTo(start));
+        }
+
+        // Each piece is at most a quarter turn:
+        let pieces = (sweep_angle.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+        let step = sweep_angle / pieces as f32;
+        let kappa = 4.0 / 3.0 * (step / 4.0).tan();
+        for i in 0..pieces {
+            let from = start_angle + step * i as f32;
+            let to = from + step;
+            self.0.push(PathElement::CubicTo(
+                point_at(from) + kappa * tangent_at(from),
+                point_at(to) - kappa * tangent_at(to),
+                point_at(to),
+            ));
+        }
+    }
+
+    fn line_to(&mut self, point: Pos2) {
+        if self.last_point() != Some(point) {
+            self.0.push(PathElement::LineTo(point));
+        }
+    }
+
+    /// A quarter ellipse from the current point to `to`, bending around `corner`.
+    fn add_corner(&mut self, corner: Pos2, to: Pos2) {
+        let Some(from) = self.last_point() else {
+            return;
+        };
+        if from == to {
+            return;
+        }
+        self.0.push(PathElement::CubicTo(
+            from + QUARTER_CIRCLE_KAPPA * (corner - from),
+            to + QUARTER_CIRCLE_KAPPA * (corner - to),
+            to,
+        ));
+    }
+
+    fn last_point(&self) -> Option<Pos2> {
+        match self.0.last()? {
+            PathElement::MoveTo(point)
+            | PathElement::LineTo(point)
+            | PathElement::CubicTo(_, _, point) => Some(*point),
+            PathElement::Close => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+fn clip_mask_path(clip_mask: &ClipMask) -> (Path, FillRule) {
+    let mut path = Path::default();
+    match clip_mask {
+        ClipMask::RoundedRect {
+            rect,
+            corner_radius,
+        } => {
+            path.add_rounded_rect(*rect, *corner_radius);
+            (path, FillRule::NonZero)
+        }
+        ClipMask::Path {
+            contours,
+            fill_rule,
+        } => {
+            for contour in contours {
+                path.add_polygon(contour, true);
+            }
+            (path, *fill_rule)
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Turns [`Shape`]:s into [`Canvas`] calls.
+struct ShapePainter<'a, C> {
+    fonts: &'a ahash::HashMap<FontFaceKey, ab_glyph::FontArc>,
+    textures: &'a ahash::HashMap<TextureId, Arc<ColorImage>>,
+    canvas: &'a mut C,
+
+    /// Cut-out texture regions: `(texture, [min_x, min_y, max_x, max_y], tint)`.
+    bitmaps: ahash::HashMap<(TextureId, [usize; 4], Color32), Arc<Bitmap>>,
+}
+
+impl<C: Canvas> ShapePainter<'_, C> {
+    fn paint_shape(&mut self, shape: &Shape) {
+        match shape {
+            Shape::Noop | Shape::Callback(_) => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    self.paint_shape(shape);
+                }
+            }
+            Shape::Circle(circle) => {
+                let radius = Vec2::splat(circle.radius);
+                self.paint_ellipse(circle.center, radius, circle.fill, circle.stroke);
+            }
+            Shape::Ellipse(ellipse) => {
+                self.paint_ellipse(ellipse.center, ellipse.radius, ellipse.fill, ellipse.stroke);
+            }
+            Shape::Arc(arc) => {
+                let full_turn = arc.is_full_turn();
+                let closed = arc.kind != ArcKind::Open || full_turn;
+                if needs_tessellation(&arc.stroke, closed) {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+                let (center, start, sweep) = (arc.center, arc.start_angle, arc.sweep_angle());
+                if arc.radius.min_elem() <= 0.0 || sweep == 0.0 {
+                    return;
+                }
+
+                let mut path = Path::default();
+                match arc.kind {
+                    ArcKind::Open => path.add_arc(center, arc.radius, start, sweep),
+                    ArcKind::Sector => {
+                        if !full_turn {
+                            path.0.push(PathElement::MoveTo(center));
+                        }
+                        path.add_arc(center, arc.radius, start, sweep);
+                    }
+                    ArcKind::AnnulusSegment { inner_radius } => {
+                        path.add_arc(center, arc.radius, start, sweep);
+                        if full_turn {
+                            // The hole is a separate sub-path:
+                            path.0.push(PathElement::Close);
+                        }
+                        path.add_arc(center, inner_radius, start + sweep, -sweep);
+                    }
+                }
+
+                if closed {
+                    path.0.push(PathElement::Close);
+                    self.fill(&path, FillRule::NonZero, arc.fill);
+                } else {
+                    // Fill up to the chord between the ends:
+                    let mut chord_path = path.clone();
+                    chord_path.0.push(PathElement::Close);
+                    self.fill(&chord_path, FillRule::NonZero, arc.fill);
+                }
+                self.stroke(&path, &arc.stroke);
+            }
+            Shape::LineSegment { points, stroke } => {
+                let mut path = Path::default();
+                path.add_polygon(points, false);
+                self.stroke(&path, &(*stroke).into());
+            }
+            Shape::Path(path_shape) => {
+                if needs_tessellation(&path_shape.stroke, path_shape.closed) {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+                let mut path = Path::default();
+                path.add_polygon(&path_shape.points, path_shape.closed);
+                if path_shape.closed {
+                    self.fill(&path, FillRule::NonZero, path_shape.fill);
+                }
+                self.stroke(&path, &path_shape.stroke);
+            }
+            Shape::Polygon(polygon) => {
+                if needs_tessellation(&polygon.stroke, true) {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+                let mut path = Path::default();
+                for contour in &polygon.contours {
+                    path.add_polygon(contour, true);
+                }
+                self.fill(&path, polygon.fill_rule, polygon.fill);
+                self.stroke(&path, &polygon.stroke);
+            }
+            Shape::Rect(rect_shape) => {
+                if rect_shape.brush.is_some() {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+
+                let rect = rect_shape.rect;
+                let corner_radius = CornerRadiusF32::from(rect_shape.corner_radius);
+
+                if rect_shape.blur_width > 0.0 {
+                    self.paint_blurred_rect(
+                        rect,
+                        corner_radius,
+                        rect_shape.blur_width,
+                        rect_shape.fill,
+                    );
+                } else {
+                    let mut path = Path::default();
+                    path.add_rounded_rect(rect, corner_radius);
+                    self.fill(&path, FillRule::NonZero, rect_shape.fill);
+                }
+
+                // Stroke along the middle of where the stroke is painted:
+                let Stroke { width, color, .. } = rect_shape.stroke;
+                let mut stroke_rect = rect;
+                let mut stroke_corner_radius = corner_radius;
+                match rect_shape.stroke_kind {
+                    StrokeKind::Inside => {
+                        stroke_rect = rect.shrink(0.5 * width);
+                        stroke_corner_radius -= 0.5 * width;
+                    }
+                    StrokeKind::Middle => {}
+                    StrokeKind::Outside => {
+                        stroke_rect = rect.expand(0.5 * width);
+                        stroke_corner_radius += 0.5 * width;
+                    }
+                }
+                let mut path = Path::default();
+                path.add_rounded_rect(stroke_rect, stroke_corner_radius);
+                self.stroke(&path, &Stroke::new(width, color).into());
+            }
+            Shape::QuadraticBezier(bezier) => {
+                if needs_tessellation(&bezier.stroke, bezier.closed) {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+                // A quadratic Bézier is a cubic one with the control points 2/3 of the way to the middle point:
+                let [from, control, to] = bezier.points;
+                let mut path = Path::default();
+                path.0.push(PathElement::MoveTo(from));
+                path.0.push(PathElement::CubicTo(
+                    from + 2.0 / 3.0 * (control - from),
+                    to + 2.0 / 3.0 * (control - to),
+                    to,
+                ));
+                self.paint_bezier(path, bezier.closed, bezier.fill, &bezier.stroke);
+            }
+            Shape::CubicBezier(bezier) => {
+                if needs_tessellation(&bezier.stroke, bezier.closed) {
+                    self.paint_tessellated(shape.clone());
+                    return;
+                }
+                let [from, control_1, control_2, to] = bezier.points;
+                let mut path = Path::default();
+                path.0.push(PathElement::MoveTo(from));
+                path.0.push(PathElement::CubicTo(control_1, control_2, to));
+                self.paint_bezier(path, bezier.closed, bezier.fill, &bezier.stroke);
+            }
+            Shape::VariableStroke(_) => {
+                // SVG and PDF strokes have a constant width, so this becomes triangles:
+                self.paint_tessellated(shape.clone());
+            }
+            Shape::Text(text_shape) => {
+                self.paint_text(text_shape);
+            }
+            Shape::TextPath(text_path_shape) => {
+                if text_path_shape.galley.is_empty() || text_path_shape.opacity_factor <= 0.0 {
+                    return;
+                }
+
+                let mut outlines = vec![];
+                text_path_shape.for_each_glyph(|placed_row, vertices, placement| {
+                    let Some((glyph_index, glyph)) = placed_row
+                        .glyphs
+                        .iter()
+                        .enumerate()
+                        .find(|(_, glyph)| glyph.first_vertex as usize == vertices.start)
+                    else {
+                        return;
+                    };
+                    let place = |pos: Pos2| placement.place(placed_row.pos + pos.to_vec2());
+                    if let Some(outline) = self.glyph_outline(placed_row, glyph, place) {
+                        let row_index = text_path_shape
+                            .galley
+                            .rows
+                            .iter()
+                            .position(|row| std::ptr::eq(row, placed_row));
+                        outlines.push((row_index, glyph_index, outline));
+                    }
+                });
+
+                let mut text_path_shape = TextPathShape::clone(text_path_shape);
+                let mut fills = vec![];
+                for (row_index, glyph_index, outline) in outlines {
+                    if let Some(row_index) = row_index {
+                        let glyph_color =
+                            hide_glyph(&mut text_path_shape.galley, row_index, glyph_index);
+                        let color = text_color(
+                            glyph_color,
+                            text_path_shape.override_text_color,
+                            text_path_shape.fallback_color,
+                            text_path_shape.opacity_factor,
+                        );
+                        fills.push((outline, color));
+                    }
+                }
+
+                let pixels_per_point = text_path_shape.galley.pixels_per_point;
+                self.paint_tessellated_with(
+                    Shape::TextPath(Arc::new(text_path_shape)),
+                    pixels_per_point,
+                );
+                for (outline, color) in fills {
+                    self.fill(&outline, FillRule::NonZero, color);
+                }
+            }
+            Shape::Mesh(mesh) => {
+                self.paint_mesh(mesh);
+            }
+        }
+    }
+
+    fn paint_ellipse(&mut self, center: Pos2, radius: Vec2, fill: Color32, stroke: Stroke) {
+        if radius.min_elem() <= 0.0 {
+            return;
+        }
+        let mut path = Path::default();
+        path.add_ellipse(center, radius);
+        self.fill(&path, FillRule::NonZero, fill);
+        self.stroke(&path, &stroke.into());
+    }
+
+    fn paint_bezier(&mut self, mut path: Path, closed: bool, fill: Color32, stroke: &PathStroke) {
+        if closed {
+            path.0.push(PathElement::Close);
+            self.fill(&path, FillRule::NonZero, fill);
+        }
+        self.stroke(&path, stroke);
+    }
+
+    /// Blurred rectangles (e.g. shadows) are approximated by a stack of increasingly smaller,
+    /// translucent rectangles.
+    fn paint_blurred_rect(
+        &mut self,
+        rect: Rect,
+        corner_radius: CornerRadiusF32,
+        blur_width: f32,
+        fill: Color32,
+    ) {
+        const LAYERS: usize = 8;
+
+        let alpha = fill.a() as f32 / 255.0;
+        if alpha <= 0.0 {
+            return;
+        }
+        // All layers stacked on top of each other should add up to the original opacity:
+        let layer_alpha = 1.0 - (1.0 - alpha.min(0.999)).powf(1.0 / LAYERS as f32);
+        let layer_fill = fill.gamma_multiply(layer_alpha / alpha);
+
+        for i in 0..LAYERS {
+            let expansion = blur_width * (0.5 - (i as f32 + 0.5) / LAYERS as f32);
+            let mut layer_corner_radius = corner_radius;
+            layer_corner_radius += expansion;
+            let mut path = Path::default();
+            path.add_rounded_rect(rect.expand(expansion), layer_corner_radius);
+            self.fill(&path, FillRule::NonZero, layer_fill);
+        }
+    }
+
+    fn fill(&mut self, path: &Path, fill_rule: FillRule, color: Color32) {
+        if color != Color32::TRANSPARENT && !path.is_empty() {
+            self.canvas.fill(path, fill_rule, color);
+        }
+    }
+
+    /// The stroke must have passed [`needs_tessellation`].
+    fn stroke(&mut self, path: &Path, stroke: &PathStroke) {
+        let ColorMode::Solid(color) = stroke.color else {
+            return;
+        };
+        if stroke.width <= 0.0 || color == Color32::TRANSPARENT || path.is_empty() {
+            return;
+        }
+        self.canvas.stroke(
+            path,
+            &SolidStroke {
+                width: stroke.width,
+                color,
+                join: stroke.join,
+                cap: stroke.cap,
+            },
+        );
+    }
+
+    /// Paint the glyphs of the text as outlines, and the rest (backgrounds, underlines, …)
+    /// as what the [`Tessellator`] makes of it.
+    fn paint_text(&mut self, text_shape: &TextShape) {
+        if text_shape.galley.is_empty() || text_shape.opacity_factor <= 0.0 {
+            return;
+        }
+
+        let rotation = Rot2::from_angle(text_shape.angle);
+        let mut outlines = vec![];
+        for (row_index, placed_row) in text_shape.galley.rows.iter().enumerate() {
+            let place =
+                |pos: Pos2| text_shape.pos + rotation * (placed_row.pos.to_vec2() + pos.to_vec2());
+            for (glyph_index, glyph) in placed_row.glyphs.iter().enumerate() {
+                if let Some(outline) = self.glyph_outline(placed_row, glyph, place) {
+                    outlines.push((row_index, glyph_index, outline));
+                }
+            }
+        }
+
+        let mut text_shape = text_shape.clone();
+        let mut fills = vec![];
+        for (row_index, glyph_index, outline) in outlines {
+            let glyph_color = hide_glyph(&mut text_shape.galley, row_index, glyph_index);
+            let color = text_color(
+                glyph_color,
+                text_shape.override_text_color,
+                text_shape.fallback_color,
+                text_shape.opacity_factor,
+            );
+            fills.push((outline, color));
+        }
+
+        let pixels_per_point = text_shape.galley.pixels_per_point;
+        self.paint_tessellated_with(Shape::Text(text_shape), pixels_per_point);
+        for (outline, color) in fills {
+            self.fill(&outline, FillRule::NonZero, color);
+        }
+    }
+
+    /// The outline of a glyph, if we have its font.
+    ///
+    /// `place` maps from the coordinates of the row to the screen.
+    fn glyph_outline(
+        &self,
+        row: &Row,
+        glyph: &Glyph,
+        place: impl Fn(Pos2) -> Pos2,
+    ) -> Option<Path> {
+        let face = glyph.face?;
+        if glyph.uv_rect.is_nothing() {
+            return None; // Nothing to draw, e.g. a space
+        }
+        let outline = self.fonts.get(&face.face)?.outline(face.id)?;
+
+        // Where the glyph quad goes tells us how to place the outline,
+        // including any scaling and italic slant.
+        // The quad covers the bitmap of the glyph, which is offset from where the glyph starts:
+        let first_vertex = glyph.first_vertex as usize;
+        let quad = row
+            .visuals
+            .mesh
+            .vertices
+            .get(first_vertex..first_vertex + 4)?;
+        let bitmap_rect = Rect::from_min_size(glyph.uv_rect.offset.to_pos2(), glyph.uv_rect.size);
+        let transform = uv_to_pos_transform(
+            [
+                bitmap_rect.left_top(),
+                bitmap_rect.left_bottom(),
+                bitmap_rect.right_bottom(),
+            ],
+            [quad[0].pos, quad[2].pos, quad[3].pos].map(place),
+        )?;
+
+        // Font units have y pointing up, from the baseline:
+        let to_screen = |point: ab_glyph::Point| {
+            let pos = pos2(0.0, face.y_offset) + face.scale * vec2(point.x, -point.y);
+            transform_pos(&transform, pos)
+        };
+
+        let mut path = Path::default();
+        let mut last_point = None;
+        for curve in &outline.curves {
+            let (from, to) = match *curve {
+                ab_glyph::OutlineCurve::Line(from, to)
+                | ab_glyph::OutlineCurve::Quad(from, _, to)
+                | ab_glyph::OutlineCurve::Cubic(from, _, _, to) => (from, to),
+            };
+            if last_point != Some(from) {
+                // A new contour:
+                if !path.is_empty() {
+                    path.0.push(PathElement::Close);
+                }
+                path.0.push(PathElement::MoveTo(to_screen(from)));
+            }
+            last_point = Some(to);
+
+            path.0.push(match *curve {
+                ab_glyph::OutlineCurve::Line(_, to) => PathElement::LineTo(to_screen(to)),
+                ab_glyph::OutlineCurve::Quad(from, control, to) => {
+                    let [from, control, to] = [from, control, to].map(to_screen);
+                    PathElement::CubicTo(
+                        from + 2.0 / 3.0 * (control - from),
+                        to + 2.0 / 3.0 * (control - to),
+                        to,
+                    )
+                }
+                ab_glyph::OutlineCurve::Cubic(_, control_1, control_2, to) => {
+                    PathElement::CubicTo(to_screen(control_1), to_screen(control_2), to_screen(to))
+                }
+            });
+        }
+        if path.is_empty() {
+            return None;
+        }
+        path.0.push(PathElement::Close);
+        Some(path)
+    }
+
+    /// Paint what the [`Tessellator`] makes of the shape.
+    fn paint_tessellated(&mut self, shape: Shape) {
+        self.paint_tessellated_with(shape, 1.0);
+    }
+
+    fn paint_tessellated_with(&mut self, shape: Shape, pixels_per_point: f32) {
+        let font_tex_size = self
+            .textures
+            .get(&TextureId::default())
+            .map_or([1, 1], |image| image.size);
+        let options = TessellationOptions {
+            // Feathered edges would turn into translucent triangles:
+            feathering: false,
+            coarse_tessellation_culling: false,
+            prerasterized_discs: false,
+            round_text_to_pixels: false,
+            round_line_segments_to_pixels: false,
+            round_rects_to_pixels: false,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(pixels_per_point, options, font_tex_size, vec![]);
+
+        let mut mesh = Mesh::with_texture(shape.texture_id());
+        tessellator.tessellate_shape(shape, &mut mesh);
+        self.paint_mesh(&mesh);
+    }
+
+    /// Paint the triangles of a mesh.
+    ///
+    /// Runs of triangles with the same color become a single filled path.
+    /// Runs of textured triangles sharing the same mapping from texture to screen
+    /// (like the two triangles of a glyph) become a single bitmap.
+    fn paint_mesh(&mut self, mesh: &Mesh) {
+        let texture = self.textures.get(&mesh.texture_id).cloned();
+        let texture_size = texture.as_ref().map_or(Vec2::ZERO, |image| {
+            vec2(image.size[0] as f32, image.size[1] as f32)
+        });
+
+        let mut run = MeshRun::None;
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| {
+                let Vertex { pos, uv, color } = mesh.vertices[triangle[i] as usize];
+                // uv in texels:
+                (pos, (uv.to_vec2() * texture_size).to_pos2(), color)
+            });
+            let positions = [a.0, b.0, c.0];
+            let uvs = [a.1, b.1, c.1];
+            let color = average_color([a.2, b.2, c.2]);
+
+            if color == Color32::TRANSPARENT || signed_area(positions) == 0.0 {
+                continue;
+            }
+
+            let uv_extent = (uvs[1] - uvs[0]).length().max((uvs[2] - uvs[0]).length());
+            let transform = if uv_extent < 0.5 {
+                None // Samples a single texel
+            } else {
+                uv_to_pos_transform(uvs, positions)
+            };
+
+            let Some(transform) = transform else {
+                let texel_color = match &texture {
+                    Some(texture) => texel(texture, uvs[0]),
+                    None if uv_extent < 0.5 => Color32::WHITE,
+                    None => continue,
+                };
+                let color = color * texel_color;
+                if color == Color32::TRANSPARENT {
+                    continue;
+                }
+
+                if !matches!(&run, MeshRun::Solid { color: run_color, .. } if *run_color == color) {
+                    self.flush_mesh_run(&mut run, mesh.texture_id);
+                    run = MeshRun::Solid {
+                        color,
+                        path: Path::default(),
+                    };
+                }
+                if let MeshRun::Solid { path, .. } = &mut run {
+                    path.add_polygon(&clockwise(positions), true);
+                }
+                continue;
+            };
+
+            if texture.is_none() {
+                continue;
+            }
+
+            // Neighboring glyphs can have the same transform, but should still be separate bitmaps:
+            let uv_bounds = Rect::from_points(&uvs);
+            let same_run = matches!(
+                &run,
+                MeshRun::Textured { transform: run_transform, color: run_color, uv_rect, .. }
+                    if *run_color == color
+                        && almost_same_transform(run_transform, &transform)
+                        && uv_rect.intersects(uv_bounds)
+            );
+            if !same_run {
+                self.flush_mesh_run(&mut run, mesh.texture_id);
+                run = MeshRun::Textured {
+                    transform,
+                    color,
+                    uv_rect: Rect::NOTHING,
+                    uv_area: 0.0,
+                    path: Path::default(),
+                };
+            }
+            if let MeshRun::Textured {
+                uv_rect,
+                uv_area,
+                path,
+                ..
+            } = &mut run
+            {
+                *uv_rect = uv_rect.union(uv_bounds);
+                *uv_area += signed_area(uvs).abs();
+                path.add_polygon(&clockwise(positions), true);
+            }
+        }
+
+        self.flush_mesh_run(&mut run, mesh.texture_id);
+    }
+
+    fn flush_mesh_run(&mut self, run: &mut MeshRun, texture_id: TextureId) {
+        match std::mem::replace(run, MeshRun::None) {
+            MeshRun::None => {}
+            MeshRun::Solid { color, path } => {
+                self.canvas.fill(&path, FillRule::NonZero, color);
+            }
+            MeshRun::Textured {
+                transform,
+                color,
+                uv_rect,
+                uv_area,
+                path,
+            } => {
+                let Some(texture) = self.textures.get(&texture_id) else {
+                    return;
+                };
+                let crop = [
+                    uv_rect.min.x.floor().max(0.0) as usize,
+                    uv_rect.min.y.floor().max(0.0) as usize,
+                    (uv_rect.max.x.ceil().max(0.0) as usize).min(texture.size[0]),
+                    (uv_rect.max.y.ceil().max(0.0) as usize).min(texture.size[1]),
+                ];
+                if crop[2] <= crop[0] || crop[3] <= crop[1] {
+                    return;
+                }
+
+                let next_id = self.bitmaps.len();
+                let bitmap = self
+                    .bitmaps
+                    .entry((texture_id, crop, color))
+                    .or_insert_with(|| Arc::new(cut_out_bitmap(next_id, texture, crop, color)))
+                    .clone();
+
+                // Map the pixels of the bitmap to the screen:
+                let [a, b, c, d, e, f] = transform;
+                let (x, y) = (crop[0] as f32, crop[1] as f32);
+                let transform = [a, b, c, d, a * x + c * y + e, b * x + d * y + f];
+
+                // No need to clip if the triangles cover exactly the cut-out pixels:
+                let crop_rect = Rect::from_min_max(
+                    pos2(crop[0] as f32, crop[1] as f32),
+                    pos2(crop[2] as f32, crop[3] as f32),
+                );
+                let covers_crop = uv_area >= 0.999 * crop_rect.area()
+                    && (uv_rect.min - crop_rect.min).length() < 1e-3
+                    && (uv_rect.max - crop_rect.max).length() < 1e-3;
+
+                self.canvas.image(
+                    &bitmap,
+                    transform,
+                    if covers_crop { None } else { Some(&path) },
+                );
+            }
+        }
+    }
+}
+
+/// Consecutive triangles of a mesh that are painted together.
+enum MeshRun {
+    None,
+
+    /// Triangles of the same color.
+    Solid {
+        color: Color32,
+        path: Path,
+    },
+
+    /// Textured triangles with the same texture transform and tint.
+    Textured {
+        /// From texels to points.
+        transform: Affine,
+        color: Color32,
+
+        /// The bounding rectangle of the triangles in the texture, in texels.
+        uv_rect: Rect,
+
+        /// The total area of the triangles in the texture, in texels.
+        uv_area: f32,
+
+        /// The outline of the triangles, in points.
+        path: Path,
+    },
+}
+
+/// Strokes that can't be expressed by a plain SVG or PDF stroke.
+fn needs_tessellation(stroke: &PathStroke, closed: bool) -> bool {
+    let has_stroke = stroke.width > 0.0 && stroke.color != ColorMode::TRANSPARENT;
+    has_stroke
+        && (matches!(stroke.color, ColorMode::UV(_))
+            || (closed && stroke.kind != StrokeKind::Middle))
+}
+
+/// Removes the glyph quad from the galley, so it isn't painted as a bitmap,
+/// and returns the color it had.
+fn hide_glyph(galley: &mut Arc<crate::Galley>, row_index: usize, glyph_index: usize) -> Color32 {
+    let row = Arc::make_mut(&mut Arc::make_mut(galley).rows[row_index].row);
+    let first_vertex = row.glyphs[glyph_index].first_vertex as usize;
+    let quad = &mut row.visuals.mesh.vertices[first_vertex..first_vertex + 4];
+
+    // Triangles without an area are skipped:
+    let pos = quad[0].pos;
+    for vertex in quad.iter_mut() {
+        vertex.pos = pos;
+    }
+    quad[0].color
+}
+
+/// The color of a glyph, like the [`Tessellator`] picks it.
+fn text_color(
+    color: Color32,
+    override_text_color: Option<Color32>,
+    fallback_color: Color32,
+    opacity_factor: f32,
+) -> Color32 {
+    let mut color = override_text_color.unwrap_or(color);
+    if color == Color32::PLACEHOLDER {
+        color = fallback_color;
+    }
+    if opacity_factor < 1.0 {
+        color = color.gamma_multiply(opacity_factor);
+    }
+    color
+}
+
+/// SVG and PDF paths have a single color, so a triangle with different colors at its
+/// vertices gets the average of them.
+fn average_color(colors: [Color32; 3]) -> Color32 {
+    if colors[0] == colors[1] && colors[0] == colors[2] {
+        return colors[0];
+    }
+    let channel = |i: usize| {
+        let sum: u32 = colors.iter().map(|color| color[i] as u32).sum();
+        ((sum + 1) / 3) as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
+
+/// Positive for clockwise triangles (on screen, where y points down).
+fn signed_area([a, b, c]: [Pos2; 3]) -> f32 {
+    0.5 * cross(b - a, c - a)
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+fn clockwise(points: [Pos2; 3]) -> [Pos2; 3] {
+    // Merged triangles must all wind the same way, or overlaps would cancel out:
+    if signed_area(points) < 0.0 {
+        [points[0], points[2], points[1]]
+    } else {
+        points
+    }
+}
+
+/// The affine transform mapping the texture coordinates of a triangle to its positions.
+fn uv_to_pos_transform(uvs: [Pos2; 3], positions: [Pos2; 3]) -> Option<Affine> {
+    let (du1, du2) = (uvs[1] - uvs[0], uvs[2] - uvs[0]);
+    let (dp1, dp2) = (positions[1] - positions[0], positions[2] - positions[0]);
+
+    let det = cross(du1, du2);
+    if det.abs() < 1e-6 {
+        return None;
+    }
+
+    let x_axis = (du2.y * dp1 - du1.y * dp2) / det;
+    let y_axis = (du1.x * dp2 - du2.x * dp1) / det;
+    let offset = positions[0] - uvs[0].x * x_axis - uvs[0].y * y_axis;
+    Some([x_axis.x, x_axis.y, y_axis.x, y_axis.y, offset.x, offset.y])
+}
+
+fn transform_pos(&[a, b, c, d, e, f]: &Affine, pos: Pos2) -> Pos2 {
+    pos2(a * pos.x + c * pos.y + e, b * pos.x + d * pos.y + f)
+}
+
+fn almost_same_transform(a: &Affine, b: &Affine) -> bool {
+    a.iter()
+        .zip(b)
+        .all(|(a, b)| (a - b).abs() <= 1e-3 * (1.0 + a.abs().max(b.abs())))
+}
+
+/// The texel at this position, in texels.
+fn texel(image: &ColorImage, uv: Pos2) -> Color32 {
+    let x = (uv.x.max(0.0) as usize).min(image.size[0].saturating_sub(1));
+    let y = (uv.y.max(0.0) as usize).min(image.size[1].saturating_sub(1));
+    image
+        .pixels
+        .get(y * image.size[0] + x)
+        .copied()
+        .unwrap_or_default()
+}
+
+fn cut_out_bitmap(id: usize, image: &ColorImage, crop: [usize; 4], tint: Color32) -> Bitmap {
+    let [min_x, min_y, max_x, max_y] = crop;
+    let mut rgba = Vec::with_capacity(4 * (max_x - min_x) * (max_y - min_y));
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let color = image.pixels[y * image.size[0] + x] * tint;
+            rgba.extend_from_slice(&color.to_srgba_unmultiplied());
+        }
+    }
+    Bitmap {
+        id,
+        size: [max_x - min_x, max_y - min_y],
+        rgba,
+    }
+}
+
+/// Formats a number compactly, with at most three decimals.
+struct Num(f32);
+
+impl std::fmt::Display for Num {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = if self.0.is_finite() { self.0 } else { 0.0 };
+        let text = format!("{value:.3}");
+        let text = text.trim_end_matches('0').trim_end_matches('.');
+        if text == "-0" {
+            f.write_str("0")
+        } else {
+            f.write_str(text)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AlphaFromCoverage, ArcShape, Fonts, PathShape, RectShape, text::FontDefinitions};
+
+    fn page() -> Rect {
+        Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0))
+    }
+
+    /// The PDF as text, keeping the byte offsets intact.
+    fn ascii(pdf: &[u8]) -> String {
+        pdf.iter()
+            .map(|&byte| if byte.is_ascii() { byte as char } else { '?' })
+            .collect()
+    }
+
+    #[test]
+    fn number_formatting() {
+        assert_eq!(Num(1.0).to_string(), "1");
+        assert_eq!(Num(0.5).to_string(), "0.5");
+        assert_eq!(Num(-0.0001).to_string(), "0");
+        assert_eq!(Num(1.23456).to_string(), "1.235");
+        assert_eq!(Num(f32::NAN).to_string(), "0");
+    }
+
+    #[test]
+    fn vector_shapes_to_svg() {
+        let shapes = vec![
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                RectShape::filled(
+                    Rect::from_min_size(pos2(10.0, 10.0), vec2(30.0, 20.0)),
+                    4,
+                    Color32::RED,
+                ),
+            ),
+            ClippedShape::new(
+                Rect::from_min_size(pos2(0.0, 0.0), vec2(50.0, 50.0)),
+                Shape::circle_stroke(pos2(50.0, 50.0), 10.0, (2.0, Color32::BLUE)),
+            ),
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                PathShape::line(
+                    vec![pos2(0.0, 0.0), pos2(10.0, 10.0), pos2(20.0, 0.0)],
+                    PathStroke::new(1.5, Color32::from_black_alpha(128)).with_cap(LineCap::Round),
+                ),
+            ),
+        ];
+
+        let svg = Exporter::new(page()).to_svg(&shapes);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        assert!(svg.contains(r##"fill="#ff0000""##));
+        assert!(svg.contains(r##"stroke="#0000ff" stroke-width="2""##));
+        assert!(svg.contains(r#"stroke-opacity="0.502""#));
+        assert!(svg.contains(r#"stroke-linecap="round""#));
+        assert_eq!(
+            svg.matches("<clipPath").count(),
+            1,
+            "Only the circle is clipped"
+        );
+    }
+
+    #[test]
+    fn arcs_become_curves() {
+        let quarter = std::f32::consts::FRAC_PI_2;
+        let shapes = vec![
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                ArcShape::sector(
+                    pos2(50.0, 50.0),
+                    Vec2::splat(10.0),
+                    0.0..=quarter,
+                    Color32::RED,
+                    Stroke::NONE,
+                ),
+            ),
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                ArcShape::annulus_segment(
+                    pos2(50.0, 50.0),
+                    Vec2::splat(20.0),
+                    Vec2::splat(10.0),
+                    0.0..=std::f32::consts::TAU,
+                    Color32::BLUE,
+                    Stroke::NONE,
+                ),
+            ),
+        ];
+
+        let svg = Exporter::new(page()).to_svg(&shapes);
+        assert!(
+            svg.contains(r#"d="M50 50L60 50C60 55.523 55.523 60 50 60Z""#),
+            "{svg}"
+        );
+        // A full ring is two closed sub-paths of four quarters each:
+        let ring = svg.lines().find(|line| line.contains("#0000ff")).unwrap();
+        assert_eq!(ring.matches('M').count(), 2, "{ring}");
+        assert_eq!(ring.matches('C').count(), 8, "{ring}");
+    }
+
+    #[test]
+    fn clip_mask_becomes_clip_path() {
+        let shape = ClippedShape::new(
+            Rect::EVERYTHING,
+            RectShape::filled(page(), 0, Color32::GREEN),
+        )
+        .with_clip_mask(ClipMask::path(
+            vec![vec![pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 100.0)]],
+            FillRule::EvenOdd,
+        ));
+
+        let svg = Exporter::new(page()).to_svg(&[shape]);
+        assert!(svg.contains(r#"clip-rule="evenodd""#));
+        assert!(svg.contains(r#"<path d="M0 0L100 0L0 100Z" clip-rule="evenodd"/>"#));
+    }
+
+    fn text_shapes(fonts: &mut Fonts) -> Vec<ClippedShape> {
+        let mut text = |y: f32, italics: bool| {
+            let mut job = crate::text::LayoutJob::default();
+            job.append(
+                "ab",
+                0.0,
+                crate::text::TextFormat {
+                    font_id: crate::FontId::proportional(14.0),
+                    color: Color32::BLACK,
+                    italics,
+                    ..Default::default()
+                },
+            );
+            let galley = fonts.with_pixels_per_point(1.0).layout_job(job);
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                Shape::galley(pos2(10.0, y), galley, Color32::BLACK),
+            )
+        };
+        vec![text(10.0, false), text(40.0, true)]
+    }
+
+    #[test]
+    fn text_becomes_glyph_outlines() {
+        let mut fonts = Fonts::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let shapes = text_shapes(&mut fonts);
+        let exporter = Exporter::new(page())
+            .with_fonts(&fonts.fonts)
+            .with_texture(TextureId::default(), fonts.image());
+
+        // The outlines should end up where the glyph bitmaps would be painted:
+        let assert_outlines_match_bitmaps = |shapes: &[ClippedShape]| {
+            let svg = exporter.to_svg(shapes);
+            assert!(!svg.contains("<image"), "{svg}");
+            let glyphs: Vec<&str> = svg
+                .lines()
+                .filter(|line| line.contains(r##"fill="#000000""##))
+                .collect();
+            assert_eq!(glyphs.len(), 2 * shapes.len(), "{svg}");
+
+            for (shape, glyphs) in shapes.iter().zip(glyphs.chunks(2)) {
+                let Shape::Text(text_shape) = &shape.shape else {
+                    panic!()
+                };
+                let bitmap_bounds = text_shape
+                    .galley
+                    .mesh_bounds
+                    .translate(text_shape.pos.to_vec2());
+                let mut outline_bounds = Rect::NOTHING;
+                for glyph in glyphs {
+                    let d = glyph.split('"').nth(1).unwrap();
+                    let numbers: Vec<f32> = d
+                        .split(|c: char| c.is_ascii_alphabetic() || c == ' ')
+                        .filter(|number| !number.is_empty())
+                        .map(|number| number.parse().unwrap())
+                        .collect();
+                    for point in numbers.chunks_exact(2) {
+                        outline_bounds.extend_with(pos2(point[0], point[1]));
+                    }
+                }
+                let tolerance = 1.5 * text_shape.galley.rect.height() / 14.0;
+                assert!(
+                    bitmap_bounds
+                        .expand(tolerance)
+                        .contains_rect(outline_bounds)
+                        && 0.6 * bitmap_bounds.area() < outline_bounds.area(),
+                    "{outline_bounds:?} vs {bitmap_bounds:?}"
+                );
+            }
+        };
+        assert_outlines_match_bitmaps(&shapes);
+
+        // Also when the text is transformed:
+        let mut scaled_shapes = shapes.clone();
+        for clipped_shape in &mut scaled_shapes {
+            clipped_shape
+                .shape
+                .transform(emath::TSTransform::new(vec2(-10.0, 5.0), 2.0));
+        }
+        assert_outlines_match_bitmaps(&scaled_shapes);
+
+        let pdf = ascii(&exporter.to_pdf(&shapes));
+        assert!(!pdf.contains("/Subtype /Image"));
+        assert_eq!(pdf.matches("\nf\n").count(), 4);
+    }
+
+    #[test]
+    fn text_without_fonts_becomes_glyph_bitmaps() {
+        let mut fonts = Fonts::new(
+            1024,
+            AlphaFromCoverage::default(),
+            FontDefinitions::default(),
+        );
+        let shapes = text_shapes(&mut fonts);
+        let exporter = Exporter::new(page()).with_texture(TextureId::default(), fonts.image());
+
+        #[cfg(feature = "svg_images")]
+        {
+            let svg = exporter.to_svg(&shapes);
+            assert_eq!(
+                svg.matches("<image ").count(),
+                2,
+                "Each glyph should be embedded once, even when slanted"
+            );
+            assert_eq!(svg.matches("<use ").count(), 4);
+            assert!(svg.contains("data:image/png;base64,"));
+        }
+        #[cfg(not(feature = "svg_images"))]
+        assert!(!exporter.to_svg(&shapes).contains("<image"));
+
+        let pdf = ascii(&exporter.to_pdf(&shapes));
+        assert_eq!(pdf.matches("/Subtype /Image").count(), 4, "Color + mask");
+        assert_eq!(pdf.matches("/Im0 Do").count(), 2);
+    }
+
+    #[test]
+    fn textured_mesh() {
+        let mut image = ColorImage::filled([4, 4], Color32::WHITE);
+        image.pixels[0] = Color32::RED;
+        let texture_id = TextureId::User(1);
+
+        let mut mesh = Mesh::with_texture(texture_id);
+        mesh.add_rect_with_uv(
+            Rect::from_min_size(pos2(10.0, 10.0), vec2(40.0, 40.0)),
+            Rect::from_min_max(pos2(0.0, 0.0), pos2(0.5, 0.5)),
+            Color32::WHITE,
+        );
+        let shapes = [ClippedShape::new(Rect::EVERYTHING, Shape::mesh(mesh))];
+
+        // Without the texture, there is nothing to draw:
+        let pdf = ascii(&Exporter::new(page()).to_pdf(&shapes));
+        assert!(!pdf.contains("/Subtype /Image"));
+
+        let exporter = Exporter::new(page()).with_texture(texture_id, image);
+        let pdf = ascii(&exporter.to_pdf(&shapes));
+        assert!(pdf.contains("/Subtype /Image /Width 2 /Height 2"));
+        assert!(pdf.contains("q\n/GA255 gs\n40 0 0 -40 10 50 cm /Im0 Do\nQ"));
+
+        #[cfg(feature = "svg_images")]
+        {
+            let svg = exporter.to_svg(&shapes);
+            assert!(svg.contains(r#"<image id="image0" width="2" height="2""#));
+            assert!(svg.contains(r#"transform="matrix(20 0 0 20 10 10)""#));
+            assert!(
+                !svg.contains("<clipPath"),
+                "The quad covers the whole bitmap"
+            );
+        }
+    }
+
+    #[test]
+    fn pdf_structure() {
+        let shapes = [
+            ClippedShape::new(
+                Rect::EVERYTHING,
+                Shape::circle_filled(pos2(50.0, 50.0), 20.0, Color32::from_white_alpha(64)),
+            ),
+            ClippedShape::new(
+                Rect::from_min_size(Pos2::ZERO, vec2(10.0, 10.0)),
+                Shape::line_segment([pos2(0.0, 0.0), pos2(10.0, 10.0)], (1.0, Color32::RED)),
+            ),
+        ];
+        let text = ascii(&Exporter::new(page()).to_pdf(&shapes));
+
+        assert!(text.starts_with("%PDF-1.4"));
+        assert!(text.trim_end().ends_with("%%EOF"));
+        assert!(text.contains("/MediaBox [0 0 100 100]"));
+        assert!(text.contains("/GA64 << /Type /ExtGState /ca 0.251 /CA 0.251 >>"));
+        assert!(text.contains("0 0 10 10 re W n"));
+
+        // The cross-reference table must point at each object in turn:
+        let startxref = text.rfind("startxref").unwrap();
+        let xref_offset: usize = text[startxref..].lines().nth(1).unwrap().parse().unwrap();
+        assert!(text[xref_offset..].starts_with("xref\n0 5\n"));
+        let offsets: Vec<usize> = text[xref_offset..]
+            .lines()
+            .skip(3)
+            .map_while(|line| line.strip_suffix(" 00000 n "))
+            .map(|offset| offset.parse().unwrap())
+            .collect();
+        assert_eq!(offsets.len(), 4, "Catalog, pages, page and content");
+        for (i, offset) in offsets.iter().enumerate() {
+            let object = format!("{} 0 obj\n", i + 1);
+            assert!(text[*offset..].starts_with(&object), "{object}");
+        }
+        assert!(text.contains("trailer\n<< /Size 5 /Root 1 0 R >>"));
+
+        // A single page:
+        assert_eq!(text.matches("/Type /Page ").count(), 1);
+        assert!(text.contains("<< /Type /Pages /Kids [3 0 R] /Count 1 >>"));
+        assert!(text.contains("/Parent 2 0 R"));
+        assert!(text.contains("/Contents 4 0 R"));
+
+        // The content stream has the right length, and paints the shapes:
+        let content = &text[offsets[3]..];
+        let length_start = content.find("/Length ").unwrap() + "/Length ".len();
+        let length: usize = content[length_start..]
+            .split_whitespace()
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap();
+        let stream_start = content.find("stream\n").unwrap() + "stream\n".len();
+        let stream = &content[stream_start..stream_start + length];
+        assert!(content[stream_start + length..].starts_with("\nendstream\nendobj\n"));
+        assert!(stream.starts_with("1 0 0 -1 0 100 cm\n"), "Flip the y axis");
+        assert!(stream.contains("/GA64 gs\n1 1 1 rg\n"), "{stream}");
+        assert!(stream.contains(" c\n"), "The circle is made of curves");
+        assert!(stream.contains("q\n0 0 10 10 re W n\n"), "{stream}");
+        assert!(stream.contains("0 0 m\n10 10 l\nS\n"), "{stream}");
+        assert!(stream.ends_with("Q\n"), "The clip is popped at the end");
+    }
+}
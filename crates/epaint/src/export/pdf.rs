@@ -0,0 +1,356 @@
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use emath::Rect;
+
+use super::{Affine, Bitmap, Canvas, Num, Path, PathElement, SolidStroke, svg::miter_limit};
+use crate::{Color32, FillRule, LineCap, LineJoin};
+
+/// Writes a single-page PDF document.
+pub(super) struct PdfCanvas {
+    rect: Rect,
+
+    /// The content stream of the page.
+    content: String,
+
+    /// Are we inside a `q` … `Q` pair for clipping?
+    is_clipping: bool,
+
+    /// Opacities used, each of which gets a graphics state `/GA<alpha>`.
+    alphas: std::collections::BTreeSet<u8>,
+
+    /// Bitmaps used, each of which becomes an image `/Im<id>`.
+    bitmaps: Vec<Arc<Bitmap>>,
+}
+
+impl PdfCanvas {
+    pub fn new(rect: Rect) -> Self {
+        let mut content = String::new();
+
+        // Flip the y axis, so we can use the coordinates of egui, where y points down:
+        writeln!(
+            content,
+            "1 0 0 -1 {} {} cm",
+            Num(-rect.min.x),
+            Num(rect.max.y),
+        )
+        .ok();
+
+        Self {
+            rect,
+            content,
+            is_clipping: false,
+            alphas: Default::default(),
+            bitmaps: vec![],
+        }
+    }
+
+    pub fn finish(mut self) -> Vec<u8> {
+        if self.is_clipping {
+            self.content.push_str("Q\n");
+        }
+
+        let mut pdf = PdfWriter::default();
+        let catalog = pdf.reserve();
+        let pages = pdf.reserve();
+        let page = pdf.reserve();
+        let content = pdf.reserve();
+
+        let mut ext_g_states = String::new();
+        for alpha in &self.alphas {
+            let alpha_f = Num(*alpha as f32 / 255.0);
+            write!(
+                ext_g_states,
+                " /GA{alpha} << /Type /ExtGState /ca {alpha_f} /CA {alpha_f} >>"
+            )
+            .ok();
+        }
+
+        let mut x_objects = String::new();
+        let mut images = vec![];
+        for bitmap in &self.bitmaps {
+            let image = pdf.reserve();
+            let mask = pdf.reserve();
+            write!(x_objects, " /Im{} {image} 0 R", bitmap.id).ok();
+            images.push((bitmap, image, mask));
+        }
+
+        pdf.object(catalog, &format!("<< /Type /Catalog /Pages {pages} 0 R >>"));
+        pdf.object(
+            pages,
+            &format!("<< /Type /Pages /Kids [{page} 0 R] /Count 1 >>"),
+        );
+        pdf.object(
+            page,
+            &format!(
+                "<< /Type /Page /Parent {pages} 0 R /MediaBox [0 0 {} {}] /Contents {content} 0 R \
+                 /Resources << /ExtGState <<{ext_g_states} >> /XObject <<{x_objects} >> >> >>",
+                Num(self.rect.width()),
+                Num(self.rect.height()),
+            ),
+        );
+        pdf.stream(content, "", self.content.as_bytes());
+
+        for (bitmap, image, mask) in images {
+            let [width, height] = bitmap.size;
+            let rgb: Vec<u8> = bitmap
+                .rgba
+                .chunks_exact(4)
+                .flat_map(|rgba| [rgba[0], rgba[1], rgba[2]])
+                .collect();
+            let alpha: Vec<u8> = bitmap.rgba.chunks_exact(4).map(|rgba| rgba[3]).collect();
+            pdf.stream(
+                image,
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {width} /Height {height} \
+                     /ColorSpace /DeviceRGB /BitsPerComponent 8 /SMask {mask} 0 R"
+                ),
+                &rgb,
+            );
+            pdf.stream(
+                mask,
+                &format!(
+                    "/Type /XObject /Subtype /Image /Width {width} /Height {height} \
+                     /ColorSpace /DeviceGray /BitsPerComponent 8"
+                ),
+                &alpha,
+            );
+        }
+
+        pdf.finish(catalog)
+    }
+
+    /// Set the opacity for the following painting operations.
+    fn set_alpha(&mut self, alpha: u8) {
+        self.alphas.insert(alpha);
+        writeln!(self.content, "/GA{alpha} gs").ok();
+    }
+
+    fn write_path(&mut self, path: &Path) {
+        for element in &path.0 {
+            match *element {
+                PathElement::MoveTo(p) => {
+                    writeln!(self.content, "{} {} m", Num(p.x), Num(p.y)).ok();
+                }
+                PathElement::LineTo(p) => {
+                    writeln!(self.content, "{} {} l", Num(p.x), Num(p.y)).ok();
+                }
+                PathElement::CubicTo(a, b, p) => {
+                    writeln!(
+                        self.content,
+                        "{} {} {} {} {} {} c",
+                        Num(a.x),
+                        Num(a.y),
+                        Num(b.x),
+                        Num(b.y),
+                        Num(p.x),
+                        Num(p.y)
+                    )
+                    .ok();
+                }
+                PathElement::Close => self.content.push_str("h\n"),
+            }
+        }
+    }
+
+    /// Writes a rectangle path with the `re` operator, if the path is a plain rectangle.
+    fn write_rect(&mut self, path: &Path) -> bool {
+        if let [
+            PathElement::MoveTo(a),
+            PathElement::LineTo(b),
+            PathElement::LineTo(c),
+            PathElement::LineTo(d),
+            PathElement::Close,
+        ] = path.0[..]
+            && a.y == b.y
+            && b.x == c.x
+            && c.y == d.y
+            && d.x == a.x
+        {
+            write!(
+                self.content,
+                "{} {} {} {} re",
+                Num(a.x),
+                Num(a.y),
+                Num(c.x - a.x),
+                Num(c.y - a.y)
+            )
+            .ok();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Intersect the current clip with this path.
+    fn clip(&mut self, path: &Path, fill_rule: FillRule) {
+        let operator = match fill_rule {
+            FillRule::NonZero => "W n",
+            FillRule::EvenOdd => "W* n",
+        };
+        if self.write_rect(path) {
+            writeln!(self.content, " {operator}").ok();
+        } else {
+            self.write_path(path);
+            writeln!(self.content, "{operator}").ok();
+        }
+    }
+}
+
+impl Canvas for PdfCanvas {
+    fn set_clip(&mut self, clip_paths: &[(Path, FillRule)]) {
+        if self.is_clipping {
+            self.content.push_str("Q\n");
+        }
+        self.is_clipping = !clip_paths.is_empty();
+        if self.is_clipping {
+            self.content.push_str("q\n");
+            for (path, fill_rule) in clip_paths {
+                self.clip(path, *fill_rule);
+            }
+        }
+    }
+
+    fn fill(&mut self, path: &Path, fill_rule: FillRule, color: Color32) {
+        let [r, g, b, a] = color.to_srgba_unmultiplied();
+        self.set_alpha(a);
+        writeln!(
+            self.content,
+            "{} {} {} rg",
+            Num(r as f32 / 255.0),
+            Num(g as f32 / 255.0),
+            Num(b as f32 / 255.0)
+        )
+        .ok();
+        self.write_path(path);
+        self.content.push_str(match fill_rule {
+            FillRule::NonZero => "f\n",
+            FillRule::EvenOdd => "f*\n",
+        });
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: &SolidStroke) {
+        let [r, g, b, a] = stroke.color.to_srgba_unmultiplied();
+        self.set_alpha(a);
+        let cap = match stroke.cap {
+            LineCap::Butt => 0,
+            LineCap::Round => 1,
+            LineCap::Square => 2,
+        };
+        let (join, miter_limit) = match stroke.join {
+            LineJoin::Miter { min_angle } => (0, miter_limit(min_angle)),
+            LineJoin::Round => (1, 10.0),
+            LineJoin::Bevel => (2, 10.0),
+        };
+        writeln!(
+            self.content,
+            "{} {} {} RG {} w {cap} J {join} j {} M",
+            Num(r as f32 / 255.0),
+            Num(g as f32 / 255.0),
+            Num(b as f32 / 255.0),
+            Num(stroke.width),
+            Num(miter_limit),
+        )
+        .ok();
+        self.write_path(path);
+        self.content.push_str("S\n");
+    }
+
+    fn image(&mut self, bitmap: &Arc<Bitmap>, transform: Affine, clip: Option<&Path>) {
+        if !self.bitmaps.iter().any(|known| known.id == bitmap.id) {
+            self.bitmaps.push(bitmap.clone());
+        }
+
+        self.content.push_str("q\n");
+        if let Some(clip) = clip {
+            self.clip(clip, FillRule::NonZero);
+        }
+        self.set_alpha(255);
+
+        // A PDF image covers the unit square, with its first row at the top (y = 1).
+        // Map that to the pixels of the bitmap, and then on to the page:
+        let [w, h] = bitmap.size.map(|size| size as f32);
+        let [a, b, c, d, e, f] = transform;
+        writeln!(
+            self.content,
+            "{} {} {} {} {} {} cm /Im{} Do",
+            Num(a * w),
+            Num(b * w),
+            Num(-c * h),
+            Num(-d * h),
+            Num(c * h + e),
+            Num(d * h + f),
+            bitmap.id,
+        )
+        .ok();
+        self.content.push_str("Q\n");
+    }
+}
+
+/// Writes the objects of a PDF file, and the cross-reference table pointing at them.
+#[derive(Default)]
+struct PdfWriter {
+    out: Vec<u8>,
+
+    /// The byte offset of each object, indexed by object number - 1.
+    offsets: Vec<Option<usize>>,
+}
+
+impl PdfWriter {
+    /// Reserve an object number.
+    fn reserve(&mut self) -> usize {
+        self.offsets.push(None);
+        self.offsets.len()
+    }
+
+    fn begin_object(&mut self, id: usize) {
+        if self.out.is_empty() {
+            // The binary comment tells tools that the file contains binary data.
+            self.out.extend_from_slice(b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n");
+        }
+        self.offsets[id - 1] = Some(self.out.len());
+        self.out
+            .extend_from_slice(format!("{id} 0 obj\n").as_bytes());
+    }
+
+    fn object(&mut self, id: usize, dictionary: &str) {
+        self.begin_object(id);
+        self.out.extend_from_slice(dictionary.as_bytes());
+        self.out.extend_from_slice(b"\nendobj\n");
+    }
+
+    fn stream(&mut self, id: usize, dictionary_entries: &str, data: &[u8]) {
+        self.begin_object(id);
+        let separator = if dictionary_entries.is_empty() {
+            ""
+        } else {
+            " "
+        };
+        self.out.extend_from_slice(
+            format!(
+                "<< {dictionary_entries}{separator}/Length {} >>\nstream\n",
+                data.len()
+            )
+            .as_bytes(),
+        );
+        self.out.extend_from_slice(data);
+        self.out.extend_from_slice(b"\nendstream\nendobj\n");
+    }
+
+    fn finish(mut self, root: usize) -> Vec<u8> {
+        let xref_offset = self.out.len();
+        let mut xref = format!("xref\n0 {}\n0000000000 65535 f \n", self.offsets.len() + 1);
+        for offset in &self.offsets {
+            debug_assert!(offset.is_some(), "Reserved PDF object was never written");
+            writeln!(xref, "{:010} 00000 n ", offset.unwrap_or_default()).ok();
+        }
+        write!(
+            xref,
+            "trailer\n<< /Size {} /Root {root} 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+            self.offsets.len() + 1
+        )
+        .ok();
+        self.out.extend_from_slice(xref.as_bytes());
+        self.out
+    }
+}
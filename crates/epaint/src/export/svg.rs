@@ -0,0 +1,289 @@
+use std::fmt::Write as _;
+use std::sync::Arc;
+
+use emath::Rect;
+
+use super::{Affine, Bitmap, Canvas, Num, Path, PathElement, SolidStroke};
+use crate::{Color32, FillRule, LineCap, LineJoin};
+
+/// Writes an SVG document.
+pub(super) struct SvgCanvas {
+    out: String,
+
+    /// Number of `<g>` elements we are inside of.
+    open_groups: usize,
+
+    next_clip_id: usize,
+
+    /// Has the bitmap with this id been defined, or could it not be?
+    defined_bitmaps: ahash::HashMap<usize, bool>,
+}
+
+impl SvgCanvas {
+    pub fn new(rect: Rect) -> Self {
+        let mut out = String::new();
+        writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" xmlns:xlink="http://www.w3.org/1999/xlink" version="1.1" width="{}" height="{}" viewBox="{} {} {} {}">"#,
+            Num(rect.width()),
+            Num(rect.height()),
+            Num(rect.min.x),
+            Num(rect.min.y),
+            Num(rect.width()),
+            Num(rect.height()),
+        )
+        .ok();
+        Self {
+            out,
+            open_groups: 0,
+            next_clip_id: 0,
+            defined_bitmaps: Default::default(),
+        }
+    }
+
+    pub fn finish(mut self) -> String {
+        self.close_groups();
+        self.out.push_str("</svg>\n");
+        self.out
+    }
+
+    fn close_groups(&mut self) {
+        for _ in 0..self.open_groups {
+            self.out.push_str("</g>\n");
+        }
+        self.open_groups = 0;
+    }
+
+    /// Define a clip path, and open a group using it.
+    fn open_clip_group(&mut self, path: &Path, fill_rule: FillRule) {
+        let id = self.next_clip_id;
+        self.next_clip_id += 1;
+        writeln!(
+            self.out,
+            r#"<clipPath id="clip{id}"><path d="{}" clip-rule="{}"/></clipPath>"#,
+            PathData(path),
+            fill_rule_name(fill_rule),
+        )
+        .ok();
+        writeln!(self.out, r#"<g clip-path="url(#clip{id})">"#).ok();
+        self.open_groups += 1;
+    }
+}
+
+impl Canvas for SvgCanvas {
+    fn set_clip(&mut self, clip_paths: &[(Path, FillRule)]) {
+        self.close_groups();
+        for (path, fill_rule) in clip_paths {
+            self.open_clip_group(path, *fill_rule);
+        }
+    }
+
+    fn fill(&mut self, path: &Path, fill_rule: FillRule, color: Color32) {
+        write!(
+            self.out,
+            r#"<path d="{}" fill="{}""#,
+            PathData(path),
+            Hex(color)
+        )
+        .ok();
+        write_opacity(&mut self.out, "fill-opacity", color);
+        if fill_rule == FillRule::EvenOdd {
+            self.out.push_str(r#" fill-rule="evenodd""#);
+        }
+        self.out.push_str("/>\n");
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: &SolidStroke) {
+        write!(
+            self.out,
+            r#"<path d="{}" fill="none" stroke="{}" stroke-width="{}""#,
+            PathData(path),
+            Hex(stroke.color),
+            Num(stroke.width),
+        )
+        .ok();
+        write_opacity(&mut self.out, "stroke-opacity", stroke.color);
+        match stroke.join {
+            LineJoin::Miter { min_angle } => {
+                write!(
+                    self.out,
+                    r#" stroke-miterlimit="{}""#,
+                    Num(miter_limit(min_angle))
+                )
+                .ok();
+            }
+            LineJoin::Round => self.out.push_str(r#" stroke-linejoin="round""#),
+            LineJoin::Bevel => self.out.push_str(r#" stroke-linejoin="bevel""#),
+        }
+        match stroke.cap {
+            LineCap::Butt => {}
+            LineCap::Round => self.out.push_str(r#" stroke-linecap="round""#),
+            LineCap::Square => self.out.push_str(r#" stroke-linecap="square""#),
+        }
+        self.out.push_str("/>\n");
+    }
+
+    fn image(&mut self, bitmap: &Arc<Bitmap>, transform: Affine, clip: Option<&Path>) {
+        let out = &mut self.out;
+        let is_defined = *self.defined_bitmaps.entry(bitmap.id).or_insert_with(|| {
+            let Some(png) = encode_png(bitmap) else {
+                return false;
+            };
+            writeln!(
+                out,
+                r#"<defs><image id="image{}" width="{}" height="{}" preserveAspectRatio="none" xlink:href="data:image/png;base64,{}"/></defs>"#,
+                bitmap.id,
+                bitmap.size[0],
+                bitmap.size[1],
+                Base64(&png),
+            )
+            .ok();
+            true
+        });
+        if !is_defined {
+            return;
+        }
+
+        let [a, b, c, d, e, f] = transform.map(Num);
+        let use_element = format!(
+            r##"<use xlink:href="#image{}" transform="matrix({a} {b} {c} {d} {e} {f})"/>"##,
+            bitmap.id
+        );
+
+        if let Some(clip) = clip {
+            let id = self.next_clip_id;
+            self.next_clip_id += 1;
+            writeln!(
+                self.out,
+                r#"<clipPath id="clip{id}"><path d="{}"/></clipPath>"#,
+                PathData(clip),
+            )
+            .ok();
+            writeln!(
+                self.out,
+                r#"<g clip-path="url(#clip{id})">{use_element}</g>"#
+            )
+            .ok();
+        } else {
+            self.out.push_str(&use_element);
+            self.out.push('\n');
+        }
+    }
+}
+
+#[cfg(feature = "svg_images")]
+fn encode_png(bitmap: &Bitmap) -> Option<Vec<u8>> {
+    use image::ImageEncoder as _;
+
+    let [width, height] = bitmap.size.map(|size| size as u32);
+    let mut png = vec![];
+    image::codecs::png::PngEncoder::new(&mut png)
+        .write_image(&bitmap.rgba, width, height, image::ExtendedColorType::Rgba8)
+        .map_err(|err| log::warn!("Failed to encode bitmap as PNG: {err}"))
+        .ok()?;
+    Some(png)
+}
+
+#[cfg(not(feature = "svg_images"))]
+fn encode_png(_bitmap: &Bitmap) -> Option<Vec<u8>> {
+    None // Needs the `svg_images` feature
+}
+
+fn fill_rule_name(fill_rule: FillRule) -> &'static str {
+    match fill_rule {
+        FillRule::NonZero => "nonzero",
+        FillRule::EvenOdd => "evenodd",
+    }
+}
+
+/// Convert [`LineJoin::Miter::min_angle`] to the ratio used by SVG and PDF.
+pub(super) fn miter_limit(min_angle: u8) -> f32 {
+    // The miter length is `1 / sin(angle / 2)` times the stroke width.
+    let half_angle = (min_angle as f32 / 2.0).to_radians();
+    if half_angle <= 0.0 {
+        1000.0
+    } else {
+        (1.0 / half_angle.sin()).min(1000.0)
+    }
+}
+
+fn write_opacity(out: &mut String, attribute: &str, color: Color32) {
+    let alpha = color.a();
+    if alpha < 255 {
+        write!(out, r#" {attribute}="{}""#, Num(alpha as f32 / 255.0)).ok();
+    }
+}
+
+/// The `d` attribute of an SVG path.
+struct PathData<'a>(&'a Path);
+
+impl std::fmt::Display for PathData<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for element in &self.0.0 {
+            match *element {
+                PathElement::MoveTo(p) => write!(f, "M{} {}", Num(p.x), Num(p.y))?,
+                PathElement::LineTo(p) => write!(f, "L{} {}", Num(p.x), Num(p.y))?,
+                PathElement::CubicTo(a, b, p) => write!(
+                    f,
+                    "C{} {} {} {} {} {}",
+                    Num(a.x),
+                    Num(a.y),
+                    Num(b.x),
+                    Num(b.y),
+                    Num(p.x),
+                    Num(p.y)
+                )?,
+                PathElement::Close => f.write_str("Z")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An opaque `#rrggbb` color.
+struct Hex(Color32);
+
+impl std::fmt::Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [r, g, b, _] = self.0.to_srgba_unmultiplied();
+        write!(f, "#{r:02x}{g:02x}{b:02x}")
+    }
+}
+
+struct Base64<'a>(&'a [u8]);
+
+impl std::fmt::Display for Base64<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write as _;
+
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        for chunk in self.0.chunks(3) {
+            let bytes = [
+                chunk[0],
+                chunk.get(1).copied().unwrap_or(0),
+                chunk.get(2).copied().unwrap_or(0),
+            ];
+            let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+            for i in 0..4 {
+                if i <= chunk.len() {
+                    let index = (bits >> (18 - 6 * i)) & 0x3f;
+                    f.write_char(ALPHABET[index as usize] as char)?;
+                } else {
+                    f.write_char('=')?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_base64() {
+    assert_eq!(Base64(b"").to_string(), "");
+    assert_eq!(Base64(b"f").to_string(), "Zg==");
+    assert_eq!(Base64(b"fo").to_string(), "Zm8=");
+    assert_eq!(Base64(b"foo").to_string(), "Zm9v");
+    assert_eq!(Base64(b"foobar").to_string(), "Zm9vYmFy");
+}
@@ -24,19 +24,23 @@
 #![allow(clippy::manual_range_contains)]
 
 mod brush;
+mod clip_mask;
 pub mod color;
 mod corner_radius;
 mod corner_radius_f32;
+pub mod export;
 pub mod image;
 mod margin;
 mod margin_f32;
 mod mesh;
 pub mod mutex;
+mod polygon_fill;
 mod shadow;
 pub mod shape_transform;
 mod shapes;
 pub mod stats;
 mod stroke;
+mod tessellation_cache;
 pub mod tessellator;
 pub mod text;
 mod texture_atlas;
@@ -47,6 +51,7 @@ mod viewport;
 
 pub use self::{
     brush::Brush,
+    clip_mask::ClipMask,
     color::ColorMode,
     corner_radius::CornerRadius,
     corner_radius_f32::CornerRadiusF32,
@@ -56,11 +61,13 @@ pub use self::{
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shapes::{
-        CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PaintCallbackInfo, PathShape,
-        QuadraticBezierShape, RectShape, Shape, TextShape,
+        ArcKind, ArcShape, CircleShape, CubicBezierShape, EllipseShape, FillRule, PaintCallback,
+        PaintCallbackInfo, PathShape, PolygonShape, QuadraticBezierShape, RectShape, Shape,
+        TextPathShape, TextShape, VariableStrokeShape, WidthProfile,
     },
     stats::PaintStats,
-    stroke::{PathStroke, Stroke, StrokeKind},
+    stroke::{LineCap, LineJoin, PathStroke, Stroke, StrokeKind},
+    tessellation_cache::TessellationCache,
     tessellator::{TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, FontsView, Galley},
     texture_atlas::TextureAtlas,
@@ -125,17 +132,61 @@ pub struct ClippedShape {
 
     /// The shape
     pub shape: Shape,
+
+    /// Optional clip mask, on top of [`Self::clip_rect`].
+    ///
+    /// This is applied on the CPU during tessellation, and is ignored by [`Shape::Callback`].
+    ///
+    /// Use [`Self::with_clip_mask`] to set it, so that adding more fields like this one
+    /// doesn't break code that creates a [`ClippedShape`].
+    pub(crate) clip_mask: Option<std::sync::Arc<ClipMask>>,
 }
 
 impl ClippedShape {
+    /// A [`Shape`] within a clip rectangle, without a clip mask.
+    #[inline]
+    pub fn new(clip_rect: emath::Rect, shape: impl Into<Shape>) -> Self {
+        Self {
+            clip_rect,
+            shape: shape.into(),
+            clip_mask: None,
+        }
+    }
+
+    /// Also clip the shape against this [`ClipMask`].
+    #[inline]
+    pub fn with_clip_mask(mut self, clip_mask: impl Into<std::sync::Arc<ClipMask>>) -> Self {
+        self.clip_mask = Some(clip_mask.into());
+        self
+    }
+
+    /// The clip mask applied on top of [`Self::clip_rect`], if any.
+    #[inline]
+    pub fn clip_mask(&self) -> Option<&ClipMask> {
+        self.clip_mask.as_deref()
+    }
+
+    /// Set or remove the clip mask applied on top of [`Self::clip_rect`].
+    #[inline]
+    pub fn set_clip_mask(&mut self, clip_mask: Option<std::sync::Arc<ClipMask>>) {
+        self.clip_mask = clip_mask;
+    }
+
     /// Transform (move/scale) the shape in-place.
     ///
     /// If using a [`PaintCallback`], note that only the rect is scaled as opposed
     /// to other shapes where the stroke is also scaled.
     pub fn transform(&mut self, transform: emath::TSTransform) {
-        let Self { clip_rect, shape } = self;
+        let Self {
+            clip_rect,
+            shape,
+            clip_mask,
+        } = self;
         *clip_rect = transform * *clip_rect;
         shape.transform(transform);
+        if let Some(clip_mask) = clip_mask {
+            std::sync::Arc::make_mut(clip_mask).transform(transform);
+        }
     }
 }
 
@@ -0,0 +1,802 @@
+//! Filling polygons that can be concave, self-intersecting, and have holes.
+//!
+//! [`fill`] cuts the polygon into horizontal slabs at every vertex and every crossing of two edges.
+//! Within a slab no edges cross, so we can walk the edges from left to right,
+//! keep track of the winding number, and add a trapezoid wherever it is inside.
+//!
+//! For anti-aliasing we need the outline of the filled region, to feather it.
+//! [`outline`] finds it by splitting the edges where they cross,
+//! and keeping those edges which have the inside on one side and the outside on the other.
+
+use ahash::HashMap;
+use emath::{Pos2, Vec2, pos2};
+
+use crate::{Color32, FillRule, Mesh, Vertex, WHITE_UV};
+
+/// Points closer than this to an edge are considered to be on it.
+const EPSILON: f32 = 1e-3;
+
+/// Fill the parts of `contours` that are inside according to `fill_rule`.
+///
+/// Each contour is implicitly closed.
+/// This does no anti-aliasing.
+pub fn fill(contours: &[Vec<Pos2>], fill_rule: FillRule, color: Color32, out: &mut Mesh) {
+    fill_where(contours, |winding| fill_rule.is_inside(winding), color, out);
+}
+
+/// Fill the parts of `contours` that have a winding number for which `is_inside` returns `true`.
+pub fn fill_where(
+    contours: &[Vec<Pos2>],
+    is_inside: impl Fn(i32) -> bool,
+    color: Color32,
+    out: &mut Mesh,
+) {
+    let mut edges = sloped_edges(contours);
+    if edges.len() < 2 {
+        return;
+    }
+    edges.sort_by(|a, b| a.top.y.total_cmp(&b.top.y));
+
+    let mut ys: Vec<f32> = edges.iter().flat_map(|e| [e.top.y, e.bottom.y]).collect();
+    ys.sort_by(f32::total_cmp);
+    ys.dedup();
+
+    let mut active: Vec<ActiveEdge> = vec![];
+    let mut next_edge = 0;
+
+    for slab in ys.windows(2) {
+        let (mut y0, y1) = (slab[0], slab[1]);
+
+        for a in &mut active {
+            if a.edge.bottom.y <= y0 {
+                a.close_span(y0, color, out);
+            }
+        }
+        active.retain(|a| y0 < a.edge.bottom.y);
+        while next_edge < edges.len() && edges[next_edge].top.y <= y0 {
+            active.push(ActiveEdge {
+                id: next_edge,
+                edge: edges[next_edge],
+                x0: 0.0,
+                x1: 0.0,
+                span: None,
+            });
+            next_edge += 1;
+        }
+
+        // Split the slab further wherever edges cross:
+        while y0 < y1 {
+            for a in &mut active {
+                a.x0 = a.edge.x_at(y0);
+                a.x1 = a.edge.x_at(y1);
+            }
+            active.sort_by(|a, b| a.x0.total_cmp(&b.x0).then(a.x1.total_cmp(&b.x1)));
+
+            // The first crossing is always between two edges that are neighbors at the top.
+            // Edges that cross right at the top (because of rounding errors) are swapped,
+            // since they have already passed each other.
+            let mut y_split = y1;
+            let mut i = 0;
+            while i + 1 < active.len() {
+                let (d0, d1) = (
+                    active[i + 1].x0 - active[i].x0,
+                    active[i + 1].x1 - active[i].x1,
+                );
+                if d1 < 0.0 {
+                    let y = y0 + (y1 - y0) * d0 / (d0 - d1);
+                    if y <= y0 {
+                        active.swap(i, i + 1);
+                        i = i.saturating_sub(1);
+                        continue;
+                    }
+                    y_split = y_split.min(y);
+                }
+                i += 1;
+            }
+
+            update_spans(&mut active, y0, &is_inside, color, out);
+            y0 = y_split;
+        }
+    }
+
+    if let Some(&y_last) = ys.last() {
+        for a in &mut active {
+            a.close_span(y_last, color, out);
+        }
+    }
+}
+
+/// The outline of the parts of `contours` that are inside according to `fill_rule`.
+///
+/// The returned loops do not cross each other, and have the inside on their right,
+/// i.e. outer loops go clockwise and holes go counter-clockwise.
+pub fn outline(contours: &[Vec<Pos2>], fill_rule: FillRule) -> Vec<Vec<Pos2>> {
+    let graph = PlanarGraph::from_contours(contours);
+    let boundary = graph.boundary_edges(fill_rule);
+    graph.chain_loops(&boundary)
+}
+
+// ----------------------------------------------------------------------------
+
+/// A non-horizontal edge.
+#[derive(Clone, Copy, Debug)]
+struct Edge {
+    top: Pos2,
+    bottom: Pos2,
+
+    /// How much the winding number increases when crossing this edge from left to right.
+    winding: i32,
+}
+
+impl Edge {
+    /// Exact at the end points.
+    fn x_at(&self, y: f32) -> f32 {
+        if y <= self.top.y {
+            self.top.x
+        } else if self.bottom.y <= y {
+            self.bottom.x
+        } else {
+            let t = (y - self.top.y) / (self.bottom.y - self.top.y);
+            self.top.x + t * (self.bottom.x - self.top.x)
+        }
+    }
+}
+
+/// All non-horizontal edges of the contours.
+fn sloped_edges(contours: &[Vec<Pos2>]) -> Vec<Edge> {
+    let mut edges = vec![];
+    for contour in contours {
+        for (i, &a) in contour.iter().enumerate() {
+            let b = contour[(i + 1) % contour.len()];
+            if a.y == b.y || !a.is_finite() || !b.is_finite() {
+                continue;
+            }
+            // A clockwise contour goes up on its left side,
+            // so that the inside gets a positive winding number.
+            edges.push(if a.y < b.y {
+                Edge {
+                    top: a,
+                    bottom: b,
+                    winding: -1,
+                }
+            } else {
+                Edge {
+                    top: b,
+                    bottom: a,
+                    winding: 1,
+                }
+            });
+        }
+    }
+    edges
+}
+
+struct ActiveEdge {
+    /// Unique for each edge.
+    id: usize,
+
+    edge: Edge,
+
+    /// The x coordinate at the top and bottom of the current slab.
+    x0: f32,
+    x1: f32,
+
+    /// The inside span that has this edge on its left, if any.
+    span: Option<Span>,
+}
+
+/// An inside region between two edges, which may go on for several slabs.
+struct Span {
+    right_id: usize,
+    right: Edge,
+
+    /// Where the span started.
+    top_y: f32,
+
+    /// Is this still a span in the current slab?
+    alive: bool,
+}
+
+impl ActiveEdge {
+    /// Add the trapezoid of the span starting at this edge, ending at `bottom_y`.
+    fn close_span(&mut self, bottom_y: f32, color: Color32, out: &mut Mesh) {
+        let Some(span) = self.span.take() else {
+            return;
+        };
+        let (left, right, top_y) = (self.edge, span.right, span.top_y);
+        if bottom_y <= top_y {
+            return;
+        }
+
+        let idx = out.vertices.len() as u32;
+        for pos in [
+            pos2(left.x_at(top_y), top_y),
+            pos2(right.x_at(top_y), top_y),
+            pos2(right.x_at(bottom_y), bottom_y),
+            pos2(left.x_at(bottom_y), bottom_y),
+        ] {
+            out.vertices.push(Vertex {
+                pos,
+                uv: WHITE_UV,
+                color,
+            });
+        }
+        out.add_triangle(idx, idx + 1, idx + 2);
+        out.add_triangle(idx, idx + 2, idx + 3);
+    }
+}
+
+/// Find the inside spans of the slab starting at `y`, which has no crossing edges.
+///
+/// Spans between the same two edges as in the slab above are continued.
+/// Other spans are closed, adding their trapezoids to `out`.
+///
+/// `active` must be sorted from left to right.
+fn update_spans(
+    active: &mut [ActiveEdge],
+    y: f32,
+    is_inside: &impl Fn(i32) -> bool,
+    color: Color32,
+    out: &mut Mesh,
+) {
+    for a in active.iter_mut() {
+        if let Some(span) = &mut a.span {
+            span.alive = false;
+        }
+    }
+
+    let mut winding = 0;
+    let mut span_start = None;
+    for i in 0..active.len() {
+        let was_inside = is_inside(winding);
+        winding += active[i].edge.winding;
+        let inside = is_inside(winding);
+
+        if !was_inside && inside {
+            span_start = Some(i);
+        } else if was_inside
+            && !inside
+            && let Some(start) = span_start.take()
+        {
+            let (right_id, right) = (active[i].id, active[i].edge);
+            let left = &mut active[start];
+            if let Some(span) = &mut left.span
+                && span.right_id == right_id
+            {
+                span.alive = true;
+            } else {
+                left.close_span(y, color, out);
+                left.span = Some(Span {
+                    right_id,
+                    right,
+                    top_y: y,
+                    alive: true,
+                });
+            }
+        }
+    }
+
+    for a in active.iter_mut() {
+        if a.span.as_ref().is_some_and(|span| !span.alive) {
+            a.close_span(y, color, out);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The contours of a polygon, with the edges split wherever they cross or touch,
+/// so that edges only meet at their end points.
+struct PlanarGraph {
+    vertices: Vec<Pos2>,
+
+    /// Each edge goes from the top vertex to the bottom vertex,
+    /// or from the left vertex to the right vertex if horizontal.
+    ///
+    /// The winding is how many contours go along the edge in that direction,
+    /// minus how many go the other way. It is never zero.
+    edges: Vec<(u32, u32, i32)>,
+}
+
+impl PlanarGraph {
+    fn from_contours(contours: &[Vec<Pos2>]) -> Self {
+        let mut vertices = SnappedVertices::default();
+
+        let mut segments: Vec<[u32; 2]> = vec![];
+        for contour in contours {
+            if contour.iter().any(|p| !p.is_finite()) {
+                continue;
+            }
+            let ids: Vec<u32> = contour.iter().map(|&p| vertices.get_or_insert(p)).collect();
+            for (i, &a) in ids.iter().enumerate() {
+                let b = ids[(i + 1) % ids.len()];
+                if a != b {
+                    segments.push([a, b]);
+                }
+            }
+        }
+
+        // Find where segments cross or touch, as (segment, t, vertex):
+        let mut splits: Vec<(usize, f32, u32)> = vec![];
+
+        let y_ranges: Vec<(f32, f32)> = segments
+            .iter()
+            .map(|&[a, b]| {
+                let (a, b) = (vertices.pos(a).y, vertices.pos(b).y);
+                (a.min(b), a.max(b))
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..segments.len()).collect();
+        order.sort_by(|&i, &j| y_ranges[i].0.total_cmp(&y_ranges[j].0));
+
+        let mut active: Vec<usize> = vec![];
+        for &i in &order {
+            let min_y = y_ranges[i].0;
+            active.retain(|&j| min_y - EPSILON <= y_ranges[j].1);
+            for &j in &active {
+                Self::intersect(&mut vertices, &segments, i, j, &mut splits);
+            }
+            active.push(i);
+        }
+
+        splits.sort_by(|a, b| a.0.cmp(&b.0).then(a.1.total_cmp(&b.1)));
+
+        // Split the segments, and merge the ones going between the same two vertices:
+        let mut windings: HashMap<(u32, u32), i32> = HashMap::default();
+        let mut add_edge = |a: u32, b: u32| {
+            if a == b {
+                return;
+            }
+            let (pa, pb) = (vertices.pos(a), vertices.pos(b));
+            if (pa.y, pa.x) < (pb.y, pb.x) {
+                *windings.entry((a, b)).or_default() += 1;
+            } else {
+                *windings.entry((b, a)).or_default() -= 1;
+            }
+        };
+        let mut splits = splits.iter().peekable();
+        for (i, &[a, b]) in segments.iter().enumerate() {
+            let mut prev = a;
+            while let Some(&(_, _, vertex)) = splits.next_if(|(segment, _, _)| *segment == i) {
+                add_edge(prev, vertex);
+                prev = vertex;
+            }
+            add_edge(prev, b);
+        }
+
+        let mut edges: Vec<(u32, u32, i32)> = windings
+            .into_iter()
+            .filter(|&(_, winding)| winding != 0)
+            .map(|((a, b), winding)| (a, b, winding))
+            .collect();
+        edges.sort_unstable_by_key(|&(a, b, _)| (a, b));
+
+        Self {
+            vertices: vertices.positions,
+            edges,
+        }
+    }
+
+    /// Record where segment `i` and `j` cross or touch each other.
+    fn intersect(
+        vertices: &mut SnappedVertices,
+        segments: &[[u32; 2]],
+        i: usize,
+        j: usize,
+        splits: &mut Vec<(usize, f32, u32)>,
+    ) {
+        let ([a0, a1], [b0, b1]) = (segments[i], segments[j]);
+        let (pa0, pa1) = (vertices.pos(a0), vertices.pos(a1));
+        let (pb0, pb1) = (vertices.pos(b0), vertices.pos(b1));
+
+        let a_rect = emath::Rect::from_two_pos(pa0, pa1).expand(EPSILON);
+        let b_rect = emath::Rect::from_two_pos(pb0, pb1).expand(EPSILON);
+        if !a_rect.intersects(b_rect) {
+            return;
+        }
+
+        // An end point lying on the other segment (including overlapping segments):
+        let mut touching = false;
+        for (segment, (p0, p1), others) in [(i, (pa0, pa1), [b0, b1]), (j, (pb0, pb1), [a0, a1])] {
+            for vertex in others {
+                if let Some(t) = point_on_segment(vertices.pos(vertex), p0, p1) {
+                    splits.push((segment, t, vertex));
+                    touching = true;
+                }
+            }
+        }
+
+        let shares_vertex = a0 == b0 || a0 == b1 || a1 == b0 || a1 == b1;
+        if touching || shares_vertex {
+            return;
+        }
+
+        // A proper crossing:
+        let (r, s) = (pa1 - pa0, pb1 - pb0);
+        let denom = cross(r, s);
+        if denom == 0.0 {
+            return;
+        }
+        let q = pb0 - pa0;
+        let t = cross(q, s) / denom;
+        let u = cross(q, r) / denom;
+        if 0.0 < t && t < 1.0 && 0.0 < u && u < 1.0 {
+            let vertex = vertices.get_or_insert(pa0 + t * r);
+            splits.push((i, t, vertex));
+            splits.push((j, u, vertex));
+        }
+    }
+
+    /// The edges that have the inside on one side and the outside on the other,
+    /// directed so that the inside is on their right.
+    fn boundary_edges(&self, fill_rule: FillRule) -> Vec<(u32, u32)> {
+        let pos = |v: u32| self.vertices[v as usize];
+
+        let (mut horizontal, sloped): (Vec<_>, Vec<_>) = self
+            .edges
+            .iter()
+            .partition(|&&(a, b, _)| pos(a).y == pos(b).y);
+        let mut sloped: Vec<(Edge, u32, u32)> = sloped
+            .into_iter()
+            .map(|&(a, b, winding)| {
+                let edge = Edge {
+                    top: pos(a),
+                    bottom: pos(b),
+                    // A clockwise contour goes up on its left side,
+                    // so that the inside gets a positive winding number.
+                    winding: -winding,
+                };
+                (edge, a, b)
+            })
+            .collect();
+        sloped.sort_by(|a, b| a.0.top.y.total_cmp(&b.0.top.y));
+        horizontal.sort_by(|a, b| pos(a.0).y.total_cmp(&pos(b.0).y));
+
+        let mut ys: Vec<f32> = self
+            .edges
+            .iter()
+            .flat_map(|&(a, b, _)| [pos(a).y, pos(b).y])
+            .collect();
+        ys.sort_by(f32::total_cmp);
+        ys.dedup();
+
+        let mut boundary = vec![];
+        // `inside` is whether the right and left side are inside, going from `first` to `second`.
+        let mut add_boundary = |first: u32, second: u32, inside: (bool, bool)| match inside {
+            (true, false) => boundary.push((first, second)),
+            (false, true) => boundary.push((second, first)),
+            _ => {}
+        };
+
+        let mut active: Vec<(Edge, u32, u32)> = vec![];
+        let mut prefix_windings: Vec<i32> = vec![];
+        let mut next_sloped = 0;
+        let mut next_horizontal = 0;
+
+        for (s, &y) in ys.iter().enumerate() {
+            active.retain(|(edge, _, _)| y < edge.bottom.y);
+            while next_sloped < sloped.len() && sloped[next_sloped].0.top.y <= y {
+                active.push(sloped[next_sloped]);
+                next_sloped += 1;
+            }
+
+            prefix_windings.clear();
+            prefix_windings.push(0);
+            if let Some(&y_next) = ys.get(s + 1) {
+                // The edges don't cross, so we can order them by their middle in the slab.
+                // We avoid computing the middle y, since it may round to the top or bottom
+                // if the slab is very thin. In a very thin slab, edges starting (or ending)
+                // at the same point may still get the same middle, so then we look at their slopes.
+                let sort_key = |edge: &Edge| {
+                    let slope = (edge.bottom.x - edge.top.x) / (edge.bottom.y - edge.top.y);
+                    let slope = if edge.top.y == y {
+                        slope
+                    } else if edge.bottom.y == y_next {
+                        -slope
+                    } else {
+                        0.0
+                    };
+                    (edge.x_at(y) + edge.x_at(y_next), slope)
+                };
+                active.sort_by(|a, b| {
+                    let (a, b) = (sort_key(&a.0), sort_key(&b.0));
+                    a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+                });
+
+                let mut winding = 0;
+                for (edge, top, bottom) in &active {
+                    let left = winding;
+                    winding += edge.winding;
+                    prefix_windings.push(winding);
+
+                    // Only classify each edge once, in the first slab it is in:
+                    if edge.top.y == y {
+                        let inside = (fill_rule.is_inside(left), fill_rule.is_inside(winding));
+                        // Going down, the right side is the left side.
+                        add_boundary(*top, *bottom, inside);
+                    }
+                }
+            }
+
+            while next_horizontal < horizontal.len() && pos(horizontal[next_horizontal].0).y <= y {
+                let &(left, right, winding) = horizontal[next_horizontal];
+                next_horizontal += 1;
+
+                let mid_x = 0.5 * (pos(left).x + pos(right).x);
+                let below = if ys.get(s + 1).is_some() {
+                    let k = active.partition_point(|(edge, _, _)| edge.x_at(y) < mid_x);
+                    prefix_windings[k]
+                } else {
+                    0
+                };
+                // A clockwise contour goes right along its top side.
+                let above = below - winding;
+                let inside = (fill_rule.is_inside(below), fill_rule.is_inside(above));
+                // Going right, the right side is below.
+                add_boundary(left, right, inside);
+            }
+        }
+
+        boundary
+    }
+
+    /// Join the boundary edges into closed loops.
+    fn chain_loops(&self, boundary: &[(u32, u32)]) -> Vec<Vec<Pos2>> {
+        let pos = |v: u32| self.vertices[v as usize];
+
+        let mut boundary = boundary.to_vec();
+        boundary.sort_unstable();
+        let outgoing = |v: u32| {
+            let start = boundary.partition_point(|&(from, _)| from < v);
+            let end = boundary.partition_point(|&(from, _)| from <= v);
+            start..end
+        };
+
+        let mut used = vec![false; boundary.len()];
+        let mut loops = vec![];
+
+        for start in 0..boundary.len() {
+            if used[start] {
+                continue;
+            }
+            let start_vertex = boundary[start].0;
+            let mut points = vec![];
+            let mut e = start;
+            loop {
+                used[e] = true;
+                let (from, to) = boundary[e];
+                points.push(pos(from));
+                if to == start_vertex {
+                    break;
+                }
+
+                // Where several loops touch, take the sharpest turn to the right,
+                // so the loops stay separate:
+                let incoming = pos(to) - pos(from);
+                let next = outgoing(to).filter(|&n| !used[n]).max_by(|&n0, &n1| {
+                    let turn = |n: usize| {
+                        let outgoing = pos(boundary[n].1) - pos(to);
+                        cross(incoming, outgoing).atan2(incoming.dot(outgoing))
+                    };
+                    turn(n0).total_cmp(&turn(n1))
+                });
+                match next {
+                    Some(next) => e = next,
+                    None => break, // Should only happen due to rounding errors
+                }
+            }
+            if 3 <= points.len() {
+                loops.push(points);
+            }
+        }
+
+        loops
+    }
+}
+
+/// Vertices of a [`PlanarGraph`], where points closer than [`EPSILON`] to each other are merged.
+///
+/// Otherwise several edges crossing at almost the same point would create a tiny mess of edges.
+#[derive(Default)]
+struct SnappedVertices {
+    positions: Vec<Pos2>,
+
+    /// Vertices by grid cell, with the cell size being [`EPSILON`].
+    grid: HashMap<(i64, i64), Vec<u32>>,
+}
+
+impl SnappedVertices {
+    fn pos(&self, vertex: u32) -> Pos2 {
+        self.positions[vertex as usize]
+    }
+
+    fn get_or_insert(&mut self, pos: Pos2) -> u32 {
+        let cell_x = (pos.x / EPSILON).floor() as i64;
+        let cell_y = (pos.y / EPSILON).floor() as i64;
+        for x in cell_x - 1..=cell_x + 1 {
+            for y in cell_y - 1..=cell_y + 1 {
+                for &vertex in self.grid.get(&(x, y)).into_iter().flatten() {
+                    if self.pos(vertex).distance_sq(pos) <= EPSILON * EPSILON {
+                        return vertex;
+                    }
+                }
+            }
+        }
+        let vertex = self.positions.len() as u32;
+        self.positions.push(pos);
+        self.grid.entry((cell_x, cell_y)).or_default().push(vertex);
+        vertex
+    }
+}
+
+/// If `p` is on the segment between `a` and `b` (but not at its ends), how far along is it?
+fn point_on_segment(p: Pos2, a: Pos2, b: Pos2) -> Option<f32> {
+    let ab = b - a;
+    let length_sq = ab.length_sq();
+    if length_sq == 0.0 || p == a || p == b {
+        return None;
+    }
+    let t = (p - a).dot(ab) / length_sq;
+    (0.0 < t && t < 1.0 && (a + t * ab).distance_sq(p) <= EPSILON * EPSILON).then_some(t)
+}
+
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(min: f32, max: f32) -> Vec<Pos2> {
+        vec![
+            pos2(min, min),
+            pos2(max, min),
+            pos2(max, max),
+            pos2(min, max),
+        ]
+    }
+
+    fn area(mesh: &Mesh) -> f32 {
+        mesh.indices
+            .chunks_exact(3)
+            .map(|t| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[t[i] as usize].pos);
+                0.5 * cross(b - a, c - a).abs()
+            })
+            .sum()
+    }
+
+    fn loop_area(points: &[Pos2]) -> f32 {
+        let mut area = 0.0;
+        for (i, &a) in points.iter().enumerate() {
+            let b = points[(i + 1) % points.len()];
+            area += 0.5 * cross(a.to_vec2(), b.to_vec2());
+        }
+        area
+    }
+
+    #[test]
+    fn fill_square_with_hole() {
+        let mut hole = square(3.0, 7.0);
+        hole.reverse();
+        let contours = [square(0.0, 10.0), hole];
+
+        let mut mesh = Mesh::default();
+        fill(&contours, FillRule::NonZero, Color32::WHITE, &mut mesh);
+        assert_eq!(area(&mesh), 100.0 - 16.0);
+
+        // With the even-odd rule, the direction of the hole doesn't matter:
+        let contours = [square(0.0, 10.0), square(3.0, 7.0)];
+        let mut mesh = Mesh::default();
+        fill(&contours, FillRule::EvenOdd, Color32::WHITE, &mut mesh);
+        assert_eq!(area(&mesh), 100.0 - 16.0);
+
+        let mut mesh = Mesh::default();
+        fill(&contours, FillRule::NonZero, Color32::WHITE, &mut mesh);
+        assert_eq!(area(&mesh), 100.0);
+    }
+
+    #[test]
+    fn fill_self_intersecting() {
+        // A bow-tie, crossing itself at (5, 5):
+        let bow_tie = vec![
+            pos2(0.0, 0.0),
+            pos2(10.0, 10.0),
+            pos2(10.0, 0.0),
+            pos2(0.0, 10.0),
+        ];
+        let mut mesh = Mesh::default();
+        fill(
+            &[bow_tie.clone()],
+            FillRule::NonZero,
+            Color32::WHITE,
+            &mut mesh,
+        );
+        assert!((area(&mesh) - 50.0).abs() < 1e-3, "{}", area(&mesh));
+
+        let loops = outline(&[bow_tie], FillRule::NonZero);
+        assert_eq!(loops.len(), 2);
+        for points in &loops {
+            assert_eq!(points.len(), 3);
+            assert!((loop_area(points) - 25.0).abs() < 1e-3, "{points:?}");
+        }
+    }
+
+    #[test]
+    fn outline_of_overlapping_squares() {
+        // Two overlapping squares going in the same direction:
+        let contours = [square(0.0, 10.0), square(5.0, 15.0)];
+
+        let union = outline(&contours, FillRule::NonZero);
+        assert_eq!(union.len(), 1);
+        assert_eq!(union[0].len(), 8);
+        assert_eq!(loop_area(&union[0]), 200.0 - 25.0);
+
+        // Even-odd leaves the overlap as a hole, and the loops touch at two corners:
+        let xor = outline(&contours, FillRule::EvenOdd);
+        let total_area: f32 = xor.iter().map(|points| loop_area(points)).sum();
+        assert_eq!(total_area, 200.0 - 2.0 * 25.0);
+    }
+
+    #[test]
+    fn outline_of_squares_sharing_an_edge() {
+        // The shared edge is not part of the outline:
+        let contours = [
+            square(0.0, 10.0),
+            vec![
+                pos2(10.0, 0.0),
+                pos2(20.0, 0.0),
+                pos2(20.0, 10.0),
+                pos2(10.0, 10.0),
+            ],
+        ];
+        let union = outline(&contours, FillRule::NonZero);
+        assert_eq!(union.len(), 1);
+        assert_eq!(loop_area(&union[0]), 200.0);
+        assert_eq!(union[0].len(), 6, "{:?}", union[0]);
+    }
+
+    #[test]
+    fn outline_with_many_crossings() {
+        // Many edges crossing close to each other, which once created wrong outlines
+        // because of rounding errors:
+        let cases = [
+            vec![
+                vec![pos2(15.0, 6.0), pos2(9.0, 15.0), pos2(9.0, 2.0)],
+                vec![
+                    pos2(12.0, 18.0),
+                    pos2(12.0, 13.0),
+                    pos2(2.0, 19.0),
+                    pos2(17.0, 11.0),
+                ],
+                vec![pos2(16.0, 12.0), pos2(5.0, 15.0), pos2(2.0, 4.0)],
+            ],
+            vec![
+                vec![pos2(18.0, 4.0), pos2(8.0, 11.0), pos2(17.0, 4.0)],
+                vec![
+                    pos2(14.0, 3.0),
+                    pos2(0.0, 11.0),
+                    pos2(19.0, 13.0),
+                    pos2(6.0, 5.0),
+                    pos2(14.0, 12.0),
+                ],
+                vec![pos2(11.0, 7.0), pos2(17.0, 11.0), pos2(15.0, 17.0)],
+            ],
+        ];
+        for contours in cases {
+            for fill_rule in [FillRule::NonZero, FillRule::EvenOdd] {
+                let mut mesh = Mesh::default();
+                fill(&contours, fill_rule, Color32::WHITE, &mut mesh);
+                let fill_area = area(&mesh);
+
+                let loops = outline(&contours, fill_rule);
+                let outline_area: f32 = loops.iter().map(|points| loop_area(points)).sum();
+                assert!(
+                    (fill_area - outline_area).abs() < 0.01,
+                    "{fill_rule:?}: {fill_area} vs {outline_area}"
+                );
+            }
+        }
+    }
+}
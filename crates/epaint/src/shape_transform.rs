@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use crate::{
-    CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Mesh, PathShape,
-    QuadraticBezierShape, RectShape, Shape, TextShape, color,
+    ArcShape, CircleShape, Color32, ColorMode, CubicBezierShape, EllipseShape, Galley, Mesh,
+    PathShape, PolygonShape, QuadraticBezierShape, RectShape, Shape, TextPathShape, TextShape,
+    color,
 };
 
 /// Remember to handle [`Color32::PLACEHOLDER`] specially!
@@ -30,6 +31,12 @@ pub fn adjust_colors(
             fill,
             stroke,
         })
+        | Shape::Polygon(PolygonShape {
+            contours: _,
+            fill_rule: _,
+            fill,
+            stroke,
+        })
         | Shape::QuadraticBezier(QuadraticBezierShape {
             points: _,
             closed: _,
@@ -46,6 +53,24 @@ pub fn adjust_colors(
             adjust_color_mode(&mut stroke.color, adjust_color);
         }
 
+        Shape::Arc(arc_shape) => {
+            let ArcShape {
+                center: _,
+                radius: _,
+                start_angle: _,
+                end_angle: _,
+                kind: _,
+                fill,
+                stroke,
+            } = Arc::make_mut(arc_shape);
+            adjust_color(fill);
+            adjust_color_mode(&mut stroke.color, adjust_color);
+        }
+
+        Shape::VariableStroke(stroke) => {
+            adjust_color_mode(&mut Arc::make_mut(stroke).color, adjust_color);
+        }
+
         Shape::Circle(CircleShape {
             center: _,
             radius: _,
@@ -87,15 +112,26 @@ pub fn adjust_colors(
                 adjust_color(override_text_color);
             }
 
-            if !galley.is_empty() {
-                let galley = Arc::make_mut(galley);
-                for placed_row in &mut galley.rows {
-                    let row = Arc::make_mut(&mut placed_row.row);
-                    for vertex in &mut row.visuals.mesh.vertices {
-                        adjust_color(&mut vertex.color);
-                    }
-                }
+            adjust_galley_colors(galley, adjust_color);
+        }
+
+        Shape::TextPath(text_path_shape) => {
+            let TextPathShape {
+                path: _,
+                closed: _,
+                galley,
+                align: _,
+                offset: _,
+                fallback_color,
+                override_text_color,
+                opacity_factor: _,
+            } = Arc::make_mut(text_path_shape);
+
+            adjust_color(fallback_color);
+            if let Some(override_text_color) = override_text_color {
+                adjust_color(override_text_color);
             }
+            adjust_galley_colors(galley, adjust_color);
         }
 
         Shape::Mesh(mesh) => {
@@ -132,3 +168,18 @@ fn adjust_color_mode(
         }
     }
 }
+
+fn adjust_galley_colors(
+    galley: &mut Arc<Galley>,
+    adjust_color: impl Fn(&mut Color32) + Send + Sync + Copy + 'static,
+) {
+    if !galley.is_empty() {
+        let galley = Arc::make_mut(galley);
+        for placed_row in &mut galley.rows {
+            let row = Arc::make_mut(&mut placed_row.row);
+            for vertex in &mut row.visuals.mesh.vertices {
+                adjust_color(&mut vertex.color);
+            }
+        }
+    }
+}
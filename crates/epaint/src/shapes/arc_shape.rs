@@ -0,0 +1,315 @@
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    ops::RangeInclusive,
+};
+
+use crate::*;
+
+/// What part of an ellipse an [`ArcShape`] covers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ArcKind {
+    /// Just the arc.
+    ///
+    /// The fill covers the area between the arc and the straight line (chord) between its ends.
+    #[default]
+    Open,
+
+    /// A pie slice, with straight lines from the ends of the arc to the center.
+    Sector,
+
+    /// A slice of a ring ("donut"),
+    /// between the arc and a smaller arc with the same angles.
+    AnnulusSegment {
+        /// The radii of the inner arc.
+        inner_radius: Vec2,
+    },
+}
+
+/// An elliptical arc, a pie slice or a slice of a ring.
+///
+/// Angles are in radians, with 0 pointing right (+X) and increasing clockwise on screen
+/// (towards +Y, which points down).
+/// For an ellipse the angles are those of a circle that has been squashed into the ellipse,
+/// so that e.g. the slices of an elliptical pie chart keep their proportions.
+///
+/// ```
+/// # use epaint::*;
+/// // The top half of a ring:
+/// let shape = ArcShape::annulus_segment(
+///     pos2(50.0, 50.0),
+///     Vec2::splat(40.0),
+///     Vec2::splat(30.0),
+///     std::f32::consts::PI..=std::f32::consts::TAU,
+///     Color32::BLUE,
+///     Stroke::NONE,
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ArcShape {
+    pub center: Pos2,
+
+    /// The radii (a, b) of the ellipse, which is 2a wide and 2b high.
+    pub radius: Vec2,
+
+    /// Where the arc starts, in radians.
+    pub start_angle: f32,
+
+    /// Where the arc ends, in radians.
+    ///
+    /// This can be smaller than [`Self::start_angle`] to go counter-clockwise.
+    /// The arc goes at most one full turn.
+    pub end_angle: f32,
+
+    pub kind: ArcKind,
+
+    pub fill: Color32,
+
+    pub stroke: PathStroke,
+}
+
+impl ArcShape {
+    /// An elliptical arc, without any fill.
+    #[inline]
+    pub fn arc(
+        center: Pos2,
+        radius: Vec2,
+        angles: RangeInclusive<f32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            start_angle: *angles.start(),
+            end_angle: *angles.end(),
+            kind: ArcKind::Open,
+            fill: Color32::TRANSPARENT,
+            stroke: stroke.into(),
+        }
+    }
+
+    /// A pie slice.
+    #[inline]
+    pub fn sector(
+        center: Pos2,
+        radius: Vec2,
+        angles: RangeInclusive<f32>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            kind: ArcKind::Sector,
+            fill: fill.into(),
+            ..Self::arc(center, radius, angles, stroke)
+        }
+    }
+
+    /// A slice of a ring, between the `outer_radius` and `inner_radius`.
+    #[inline]
+    pub fn annulus_segment(
+        center: Pos2,
+        outer_radius: Vec2,
+        inner_radius: Vec2,
+        angles: RangeInclusive<f32>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            kind: ArcKind::AnnulusSegment { inner_radius },
+            fill: fill.into(),
+            ..Self::arc(center, outer_radius, angles, stroke)
+        }
+    }
+
+    /// How far the arc goes, in radians, clamped to at most one full turn.
+    ///
+    /// Positive is clockwise.
+    #[inline]
+    pub fn sweep_angle(&self) -> f32 {
+        (self.end_angle - self.start_angle).clamp(-TAU, TAU)
+    }
+
+    /// Does the arc go all the way around?
+    #[inline]
+    pub fn is_full_turn(&self) -> bool {
+        TAU <= self.sweep_angle().abs()
+    }
+
+    /// The point at `angle` on the ellipse with the given radii.
+    #[inline]
+    fn point_at(&self, radius: Vec2, angle: f32) -> Pos2 {
+        let (sin, cos) = angle.sin_cos();
+        self.center + vec2(radius.x * cos, radius.y * sin)
+    }
+
+    /// Points along the arc with the given radii, from the start angle to the end angle.
+    ///
+    /// For a full turn, the last point (which is the same as the first) is left out.
+    fn flatten_arc(&self, radius: Vec2, tolerance: f32) -> Vec<Pos2> {
+        let sweep = self.sweep_angle();
+        let max_radius = radius.max_elem();
+        let max_angle_per_segment = if tolerance < max_radius {
+            2.0 * (1.0 - tolerance / max_radius).acos()
+        } else {
+            FRAC_PI_2
+        };
+        let segments = ((sweep.abs() / max_angle_per_segment).ceil() as usize).clamp(1, 1024);
+        let num_points = if self.is_full_turn() {
+            segments
+        } else {
+            segments + 1
+        };
+        (0..num_points)
+            .map(|i| {
+                let angle = self.start_angle + sweep * i as f32 / segments as f32;
+                self.point_at(radius, angle)
+            })
+            .collect()
+    }
+
+    /// Approximate the shape with straight lines.
+    ///
+    /// The `tolerance` is the maximum distance between the true arc and the lines.
+    /// By default it is a thousandth of the radius.
+    ///
+    /// For [`ArcKind::Open`] this returns the arc itself, which is only closed when filled
+    /// (or when it goes all the way around).
+    /// Otherwise this returns the closed outline of the shape, in the form taken by
+    /// [`PolygonShape::contours`]: a full ring has its hole as a second contour.
+    pub fn flatten(&self, tolerance: Option<f32>) -> Vec<Vec<Pos2>> {
+        let tolerance = tolerance.unwrap_or_else(|| self.radius.max_elem() * 0.001);
+        let outer = self.flatten_arc(self.radius, tolerance);
+
+        match self.kind {
+            ArcKind::Open => vec![outer],
+            ArcKind::Sector => {
+                if self.is_full_turn() {
+                    vec![outer]
+                } else {
+                    let mut contour = Vec::with_capacity(outer.len() + 1);
+                    contour.push(self.center);
+                    contour.extend(outer);
+                    vec![contour]
+                }
+            }
+            ArcKind::AnnulusSegment { inner_radius } => {
+                let mut inner = self.flatten_arc(inner_radius, tolerance);
+                inner.reverse();
+                if self.is_full_turn() {
+                    // The hole goes the other way around:
+                    vec![outer, inner]
+                } else {
+                    let mut contour = outer;
+                    contour.extend(inner);
+                    vec![contour]
+                }
+            }
+        }
+    }
+
+    /// The tight bounding box of the arc with the given radii.
+    fn arc_bounding_rect(&self, radius: Vec2) -> Rect {
+        let sweep = self.sweep_angle();
+        let (from, to) = if sweep < 0.0 {
+            (self.start_angle + sweep, self.start_angle)
+        } else {
+            (self.start_angle, self.start_angle + sweep)
+        };
+
+        let mut rect = Rect::from_two_pos(self.point_at(radius, from), self.point_at(radius, to));
+
+        // Include the extreme points of the ellipse that the arc passes:
+        let first_quarter = (from / FRAC_PI_2).ceil() as i32;
+        let last_quarter = (to / FRAC_PI_2).floor() as i32;
+        for quarter in first_quarter..=last_quarter.min(first_quarter + 3) {
+            rect.extend_with(self.point_at(radius, quarter as f32 * FRAC_PI_2));
+        }
+        rect
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            return Rect::NOTHING;
+        }
+        let mut rect = self.arc_bounding_rect(self.radius);
+        match self.kind {
+            ArcKind::Open => {}
+            ArcKind::Sector => rect.extend_with(self.center),
+            ArcKind::AnnulusSegment { inner_radius } => {
+                rect = rect.union(self.arc_bounding_rect(inner_radius));
+            }
+        }
+        rect.expand(self.stroke.width / 2.0)
+    }
+}
+
+impl From<ArcShape> for Shape {
+    #[inline(always)]
+    fn from(shape: ArcShape) -> Self {
+        Self::Arc(shape.into())
+    }
+}
+
+#[test]
+fn arc_bounding_rect() {
+    use std::f32::consts::PI;
+
+    let center = pos2(100.0, 100.0);
+    let radius = vec2(20.0, 10.0);
+
+    // The bottom half, passing the lowest point:
+    let arc = ArcShape::arc(center, radius, 0.0..=PI, Stroke::new(2.0, Color32::RED));
+    let rect = arc.visual_bounding_rect();
+    assert!((rect.min - pos2(79.0, 99.0)).length() < 1e-3, "{rect:?}");
+    assert!((rect.max - pos2(121.0, 111.0)).length() < 1e-3, "{rect:?}");
+
+    // Counter-clockwise from the right to the top, including the center:
+    let sector = ArcShape::sector(center, radius, 0.0..=-FRAC_PI_2, Color32::RED, Stroke::NONE);
+    let rect = sector.visual_bounding_rect();
+    assert!((rect.min - pos2(100.0, 90.0)).length() < 1e-3, "{rect:?}");
+    assert!((rect.max - pos2(120.0, 100.0)).length() < 1e-3, "{rect:?}");
+}
+
+#[test]
+fn arc_flatten() {
+    let center = pos2(0.0, 0.0);
+    let radius = Vec2::splat(100.0);
+    let tolerance = 0.1;
+
+    let arc = ArcShape::arc(center, radius, 0.0..=FRAC_PI_2, Stroke::NONE);
+    let [points] = &arc.flatten(Some(tolerance))[..] else {
+        panic!("Expected a single contour");
+    };
+    assert_eq!(points.first(), Some(&pos2(100.0, 0.0)));
+    assert!((*points.last().unwrap() - pos2(0.0, 100.0)).length() < 1e-3);
+    for pair in points.windows(2) {
+        let middle = pair[0].lerp(pair[1], 0.5);
+        assert!(100.0 - middle.to_vec2().length() <= tolerance + 1e-3);
+    }
+
+    // A full ring is an outer contour and a hole going the other way:
+    let ring = ArcShape::annulus_segment(
+        center,
+        radius,
+        Vec2::splat(50.0),
+        0.0..=TAU,
+        Color32::RED,
+        Stroke::NONE,
+    );
+    let contours = ring.flatten(Some(tolerance));
+    assert_eq!(contours.len(), 2);
+    let signed_area = |contour: &[Pos2]| -> f32 {
+        let n = contour.len();
+        (0..n)
+            .map(|i| {
+                let (a, b) = (contour[i], contour[(i + 1) % n]);
+                a.x * b.y - b.x * a.y
+            })
+            .sum::<f32>()
+            / 2.0
+    };
+    assert!(0.0 < signed_area(&contours[0]));
+    assert!(signed_area(&contours[1]) < 0.0);
+}
@@ -1,19 +1,27 @@
+mod arc_shape;
 mod bezier_shape;
 mod circle_shape;
 mod ellipse_shape;
 mod paint_callback;
 mod path_shape;
+mod polygon_shape;
 mod rect_shape;
 mod shape;
+mod text_path_shape;
 mod text_shape;
+mod variable_stroke_shape;
 
 pub use self::{
+    arc_shape::{ArcKind, ArcShape},
     bezier_shape::{CubicBezierShape, QuadraticBezierShape},
     circle_shape::CircleShape,
     ellipse_shape::EllipseShape,
     paint_callback::{PaintCallback, PaintCallbackInfo},
     path_shape::PathShape,
+    polygon_shape::{FillRule, PolygonShape},
     rect_shape::RectShape,
     shape::Shape,
+    text_path_shape::TextPathShape,
     text_shape::TextShape,
+    variable_stroke_shape::{VariableStrokeShape, WidthProfile},
 };
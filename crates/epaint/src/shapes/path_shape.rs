@@ -12,6 +12,7 @@ pub struct PathShape {
     pub closed: bool,
 
     /// Fill is only supported for convex polygons.
+    /// Use a [`PolygonShape`] for concave polygons, or polygons with holes.
     pub fill: Color32,
 
     /// Color and thickness of the line.
@@ -68,7 +69,13 @@ impl PathShape {
         if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
             Rect::NOTHING
         } else {
-            Rect::from_points(&self.points).expand(self.stroke.width / 2.0)
+            // Square caps stick out diagonally:
+            let cap_factor = if !self.closed && self.stroke.cap == LineCap::Square {
+                std::f32::consts::SQRT_2
+            } else {
+                1.0
+            };
+            Rect::from_points(&self.points).expand(cap_factor * self.stroke.width / 2.0)
         }
     }
 }
@@ -0,0 +1,101 @@
+use crate::*;
+
+/// How to decide which parts of a [`PolygonShape`] are inside,
+/// when its contours overlap each other or themselves.
+///
+/// Both rules look at the _winding number_ of a point:
+/// how many times the contours go clockwise around it,
+/// minus how many times they go counter-clockwise around it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum FillRule {
+    /// A point is inside if the winding number is not zero.
+    ///
+    /// To cut a hole, let the contour of the hole go the opposite direction of the outer contour.
+    #[default]
+    NonZero,
+
+    /// A point is inside if the winding number is odd.
+    ///
+    /// Any contour inside another one cuts a hole, no matter its direction.
+    EvenOdd,
+}
+
+impl FillRule {
+    /// Is a point with the given winding number inside?
+    #[inline]
+    pub fn is_inside(self, winding_number: i32) -> bool {
+        match self {
+            Self::NonZero => winding_number != 0,
+            Self::EvenOdd => winding_number % 2 != 0,
+        }
+    }
+}
+
+/// A filled polygon made up of one or more closed contours, with an optional outline.
+///
+/// Unlike the fill of a [`PathShape`], the contours can be concave and can intersect themselves
+/// and each other. The [`FillRule`] decides what is inside, which is also how you cut holes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PolygonShape {
+    /// The closed contours of the polygon.
+    ///
+    /// Each contour is implicitly closed, i.e. the last point connects back to the first one.
+    pub contours: Vec<Vec<Pos2>>,
+
+    /// Decides which parts of the polygon are filled.
+    pub fill_rule: FillRule,
+
+    /// The color of the inside of the polygon, as decided by the [`Self::fill_rule`].
+    pub fill: Color32,
+
+    /// The outline of each contour.
+    pub stroke: PathStroke,
+}
+
+impl PolygonShape {
+    /// A filled polygon using [`FillRule::NonZero`].
+    #[inline]
+    pub fn new(
+        contours: Vec<Vec<Pos2>>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self {
+            contours,
+            fill_rule: FillRule::NonZero,
+            fill: fill.into(),
+            stroke: stroke.into(),
+        }
+    }
+
+    /// Use this [`FillRule`] to decide what is inside.
+    #[inline]
+    pub fn with_fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = fill_rule;
+        self
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.fill == Color32::TRANSPARENT && self.stroke.is_empty() {
+            Rect::NOTHING
+        } else {
+            let mut rect = Rect::NOTHING;
+            for contour in &self.contours {
+                for &point in contour {
+                    rect.extend_with(point);
+                }
+            }
+            rect.expand(self.stroke.width / 2.0)
+        }
+    }
+}
+
+impl From<PolygonShape> for Shape {
+    #[inline(always)]
+    fn from(shape: PolygonShape) -> Self {
+        Self::Polygon(shape)
+    }
+}
@@ -6,13 +6,13 @@ use emath::{Align2, Pos2, Rangef, Rect, TSTransform, Vec2, pos2};
 
 use crate::{
     Color32, CornerRadius, Mesh, Stroke, StrokeKind, TextureId,
-    stroke::PathStroke,
+    stroke::{LineCap, PathStroke},
     text::{FontId, FontsView, Galley},
 };
 
 use super::{
-    CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape, QuadraticBezierShape,
-    RectShape, TextShape,
+    ArcKind, ArcShape, CircleShape, CubicBezierShape, EllipseShape, PaintCallback, PathShape,
+    PolygonShape, QuadraticBezierShape, RectShape, TextPathShape, TextShape, VariableStrokeShape,
 };
 
 /// A paint primitive such as a circle or a piece of text.
@@ -38,6 +38,11 @@ pub enum Shape {
     /// Ellipse with optional outline and fill.
     Ellipse(EllipseShape),
 
+    /// An elliptical arc, pie slice or slice of a ring, with optional outline and fill.
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    Arc(Arc<ArcShape>),
+
     /// A line between two points.
     LineSegment { points: [Pos2; 2], stroke: Stroke },
 
@@ -45,6 +50,10 @@ pub enum Shape {
     /// The path can have a stroke and/or fill (if closed).
     Path(PathShape),
 
+    /// A filled polygon with any number of contours, which may be concave,
+    /// self-intersecting, or cut holes into each other.
+    Polygon(PolygonShape),
+
     /// Rectangle with optional outline and fill.
     Rect(RectShape),
 
@@ -53,6 +62,13 @@ pub enum Shape {
     /// This needs to be recreated if `pixels_per_point` (dpi scale) changes.
     Text(TextShape),
 
+    /// Text that follows a path.
+    ///
+    /// This needs to be recreated if `pixels_per_point` (dpi scale) changes.
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    TextPath(Arc<TextPathShape>),
+
     /// A general triangle mesh.
     ///
     /// Can be used to display images.
@@ -66,6 +82,11 @@ pub enum Shape {
     /// A cubic [Bézier Curve](https://en.wikipedia.org/wiki/B%C3%A9zier_curve).
     CubicBezier(CubicBezierShape),
 
+    /// A stroke whose width varies along a path, e.g. for pressure-sensitive drawing.
+    ///
+    /// Wrapped in an [`Arc`] to minimize the size of [`Shape`].
+    VariableStroke(Arc<VariableStrokeShape>),
+
     /// Backend-specific painting.
     Callback(PaintCallback),
 }
@@ -167,6 +188,9 @@ impl Shape {
     }
 
     /// Turn a line into dashes.
+    ///
+    /// Each dash gets the [`Stroke::cap`] at both ends,
+    /// and dashes that go around a corner of `path` use the [`Stroke::join`].
     pub fn dashed_line(
         path: &[Pos2],
         stroke: impl Into<Stroke>,
@@ -256,6 +280,20 @@ impl Shape {
         Self::Path(PathShape::convex_polygon(points, fill, stroke))
     }
 
+    /// A polygon with one or more contours, which may be concave or intersect each other.
+    ///
+    /// Uses [`crate::FillRule::NonZero`], so to cut a hole,
+    /// let its contour go the opposite direction of the outer contour.
+    /// See [`PolygonShape`] for more options.
+    #[inline]
+    pub fn polygon(
+        contours: Vec<Vec<Pos2>>,
+        fill: impl Into<Color32>,
+        stroke: impl Into<PathStroke>,
+    ) -> Self {
+        Self::Polygon(PolygonShape::new(contours, fill, stroke))
+    }
+
     #[inline]
     pub fn circle_filled(center: Pos2, radius: f32, fill_color: impl Into<Color32>) -> Self {
         Self::Circle(CircleShape::filled(center, radius, fill_color))
@@ -369,19 +407,29 @@ impl Shape {
             }
             Self::Circle(circle_shape) => circle_shape.visual_bounding_rect(),
             Self::Ellipse(ellipse_shape) => ellipse_shape.visual_bounding_rect(),
+            Self::Arc(arc_shape) => arc_shape.visual_bounding_rect(),
             Self::LineSegment { points, stroke } => {
                 if stroke.is_empty() {
                     Rect::NOTHING
                 } else {
-                    Rect::from_two_pos(points[0], points[1]).expand(stroke.width / 2.0)
+                    // Square caps stick out diagonally:
+                    let cap_factor = if stroke.cap == LineCap::Square {
+                        std::f32::consts::SQRT_2
+                    } else {
+                        1.0
+                    };
+                    Rect::from_two_pos(points[0], points[1]).expand(cap_factor * stroke.width / 2.0)
                 }
             }
             Self::Path(path_shape) => path_shape.visual_bounding_rect(),
+            Self::Polygon(polygon_shape) => polygon_shape.visual_bounding_rect(),
             Self::Rect(rect_shape) => rect_shape.visual_bounding_rect(),
             Self::Text(text_shape) => text_shape.visual_bounding_rect(),
+            Self::TextPath(text_path_shape) => text_path_shape.visual_bounding_rect(),
             Self::Mesh(mesh) => mesh.calc_bounds(),
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
+            Self::VariableStroke(stroke) => stroke.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
         }
     }
@@ -438,6 +486,15 @@ impl Shape {
                 ellipse_shape.radius *= transform.scaling;
                 ellipse_shape.stroke.width *= transform.scaling;
             }
+            Self::Arc(arc_shape) => {
+                let arc_shape = Arc::make_mut(arc_shape);
+                arc_shape.center = transform * arc_shape.center;
+                arc_shape.radius *= transform.scaling;
+                if let ArcKind::AnnulusSegment { inner_radius } = &mut arc_shape.kind {
+                    *inner_radius *= transform.scaling;
+                }
+                arc_shape.stroke.width *= transform.scaling;
+            }
             Self::LineSegment { points, stroke } => {
                 for p in points {
                     *p = transform * *p;
@@ -450,6 +507,14 @@ impl Shape {
                 }
                 path_shape.stroke.width *= transform.scaling;
             }
+            Self::Polygon(polygon_shape) => {
+                for contour in &mut polygon_shape.contours {
+                    for p in contour {
+                        *p = transform * *p;
+                    }
+                }
+                polygon_shape.stroke.width *= transform.scaling;
+            }
             Self::Rect(rect_shape) => {
                 rect_shape.rect = transform * rect_shape.rect;
                 rect_shape.corner_radius *= transform.scaling;
@@ -459,6 +524,9 @@ impl Shape {
             Self::Text(text_shape) => {
                 text_shape.transform(transform);
             }
+            Self::TextPath(text_path_shape) => {
+                Arc::make_mut(text_path_shape).transform(transform);
+            }
             Self::Mesh(mesh) => {
                 Arc::make_mut(mesh).transform(transform);
             }
@@ -474,6 +542,13 @@ impl Shape {
                 }
                 bezier.stroke.width *= transform.scaling;
             }
+            Self::VariableStroke(stroke) => {
+                let stroke = Arc::make_mut(stroke);
+                for p in &mut stroke.points {
+                    *p = transform * *p;
+                }
+                stroke.width *= transform.scaling;
+            }
             Self::Callback(shape) => {
                 shape.rect = transform * shape.rect;
             }
@@ -525,17 +600,26 @@ fn dashes_from_line(
     let mut drawing_dash = false;
     let mut step = 0;
     let steps = dash_lengths.len();
+
+    // The points of the current dash, which may go around corners of the path.
+    let mut dash = Vec::new();
+    let add_point = |dash: &mut Vec<Pos2>, point: Pos2| {
+        if dash.last() != Some(&point) {
+            dash.push(point);
+        }
+    };
+
     for window in path.windows(2) {
         let (start, end) = (window[0], window[1]);
         let vector = end - start;
         let segment_length = vector.length();
 
-        let mut start_point = start;
         while position_on_segment < segment_length {
             let new_point = start + vector * (position_on_segment / segment_length);
+            add_point(&mut dash, new_point);
             if drawing_dash {
                 // This is the end point.
-                shapes.push(Shape::line_segment([start_point, new_point], stroke));
+                push_dash(&mut dash, stroke, shapes);
                 position_on_segment += gap_lengths[step];
                 // Increment step counter
                 step += 1;
@@ -544,17 +628,35 @@ fn dashes_from_line(
                 }
             } else {
                 // Start a new dash.
-                start_point = new_point;
                 position_on_segment += dash_lengths[step];
             }
             drawing_dash = !drawing_dash;
         }
 
-        // If the segment ends and the dash is not finished, add the segment's end point.
+        // If the segment ends and the dash is not finished, continue it on the next segment.
         if drawing_dash {
-            shapes.push(Shape::line_segment([start_point, end], stroke));
+            add_point(&mut dash, end);
         }
 
         position_on_segment -= segment_length;
     }
+
+    if drawing_dash {
+        push_dash(&mut dash, stroke, shapes);
+    }
+}
+
+/// Add the dash made up of `points` to `shapes`, and clear `points`.
+fn push_dash(points: &mut Vec<Pos2>, stroke: Stroke, shapes: &mut Vec<Shape>) {
+    match points.as_slice() {
+        [] | [_] => {}
+        &[a, b] => {
+            // Line segments are cheaper, and are rounded to pixels when possible.
+            shapes.push(Shape::line_segment([a, b], stroke));
+        }
+        _ => {
+            shapes.push(Shape::line(points.clone(), stroke));
+        }
+    }
+    points.clear();
 }
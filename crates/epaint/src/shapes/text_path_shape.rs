@@ -0,0 +1,454 @@
+use std::{ops::Range, sync::Arc};
+
+use emath::{Align, Rot2};
+
+use crate::*;
+
+/// Text that follows a path, e.g. the name of a curved road or a label around a gauge.
+///
+/// Each glyph of the [`Galley`] is placed on the path at the distance along it
+/// that matches where the glyph is in the galley, and rotated to follow the path.
+/// The baseline of the first row of text goes along the path,
+/// and any further rows go below it (to the right, when following the path).
+///
+/// Only the glyphs are painted:
+/// backgrounds, underlines and strikethrough of the galley are left out.
+///
+/// Any curve can be used by flattening it first,
+/// e.g. with [`CubicBezierShape::flatten`] or [`ArcShape::flatten`].
+///
+/// This needs to be recreated if `pixels_per_point` (dpi scale) changes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct TextPathShape {
+    /// The path that the text follows.
+    pub path: Vec<Pos2>,
+
+    /// If true, the path goes back to its first point, and text can continue around past the end.
+    pub closed: bool,
+
+    /// The laid out text, from [`FontsView::layout_job`].
+    pub galley: Arc<Galley>,
+
+    /// Where along the path the text goes: at its start, in the middle, or at its end.
+    pub align: Align,
+
+    /// Move the text this many points further along the path, after aligning it.
+    pub offset: f32,
+
+    /// Any [`Color32::PLACEHOLDER`] in the galley will be replaced by the given color.
+    pub fallback_color: Color32,
+
+    /// If set, the text color in the galley will be ignored and replaced
+    /// with the given color.
+    pub override_text_color: Option<Color32>,
+
+    /// If set, the text will be rendered with the given opacity in gamma space.
+    pub opacity_factor: f32,
+}
+
+impl TextPathShape {
+    /// Text at the start of an open path.
+    ///
+    /// The given fallback color will be used for any uncolored part of the galley (using [`Color32::PLACEHOLDER`]).
+    #[inline]
+    pub fn new(path: Vec<Pos2>, galley: Arc<Galley>, fallback_color: Color32) -> Self {
+        Self {
+            path,
+            closed: false,
+            galley,
+            align: Align::Min,
+            offset: 0.0,
+            fallback_color,
+            override_text_color: None,
+            opacity_factor: 1.0,
+        }
+    }
+
+    /// Connect the last point of the path back to the first one.
+    #[inline]
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Put the text at the start, middle or end of the path.
+    #[inline]
+    pub fn with_align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Move the text this many points further along the path.
+    #[inline]
+    pub fn with_offset(mut self, offset: f32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Use the given color for the text, regardless of what color is already in the galley.
+    #[inline]
+    pub fn with_override_text_color(mut self, override_text_color: Color32) -> Self {
+        self.override_text_color = Some(override_text_color);
+        self
+    }
+
+    /// Render text with this opacity in gamma space
+    #[inline]
+    pub fn with_opacity_factor(mut self, opacity_factor: f32) -> Self {
+        self.opacity_factor = opacity_factor;
+        self
+    }
+
+    /// Move the shape by this many points, in-place.
+    pub fn transform(&mut self, transform: emath::TSTransform) {
+        for point in &mut self.path {
+            *point = transform * *point;
+        }
+        self.offset *= transform.scaling;
+        scale_galley(&mut self.galley, transform.scaling);
+    }
+
+    /// The visual bounding rectangle
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let mut rect = Rect::NOTHING;
+        self.for_each_glyph(|row, vertices, placement| {
+            for vertex in &row.visuals.mesh.vertices[vertices] {
+                rect.extend_with(placement.place(row.pos + vertex.pos.to_vec2()));
+            }
+        });
+        rect
+    }
+
+    /// Calls `f` with each visible glyph: the row it is in, the range of its vertices in the mesh
+    /// of the row, and where it goes.
+    pub(crate) fn for_each_glyph(
+        &self,
+        mut f: impl FnMut(&text::PlacedRow, Range<usize>, GlyphPlacement),
+    ) {
+        let path = PathSampler::new(&self.path, self.closed);
+        let galley_rect = self.galley.rect;
+        let start = match self.align {
+            Align::Min => 0.0,
+            Align::Center => 0.5 * (path.length() - galley_rect.width()),
+            Align::Max => path.length() - galley_rect.width(),
+        } + self.offset;
+
+        // The baseline of the first row goes along the path:
+        let baseline = self
+            .galley
+            .rows
+            .iter()
+            .find_map(|row| row.glyphs.first().map(|glyph| row.pos.y + glyph.pos.y))
+            .unwrap_or_default();
+
+        for row in &self.galley.rows {
+            let glyph_vertices = &row.visuals.glyph_vertex_range;
+            for (i, glyph) in row.glyphs.iter().enumerate() {
+                let first_vertex = glyph.first_vertex as usize;
+                let end_vertex = row
+                    .glyphs
+                    .get(i + 1)
+                    .map_or(glyph_vertices.end, |next| next.first_vertex as usize);
+                if end_vertex <= first_vertex {
+                    continue; // e.g. a space
+                }
+
+                // The middle of the glyph goes on the path:
+                let center_x = row.pos.x + glyph.pos.x + 0.5 * glyph.advance_width;
+                let (pos, direction) = path.at(start + center_x - galley_rect.min.x);
+                f(
+                    row,
+                    first_vertex..end_vertex,
+                    GlyphPlacement {
+                        pivot: pos2(center_x, baseline),
+                        pos,
+                        rot: Rot2::from_angle(direction.angle()),
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Scale the contents of the galley, including the glyphs, since that is where they are placed from.
+fn scale_galley(galley: &mut Arc<Galley>, scaling: f32) {
+    let Galley {
+        job: _,
+        rows,
+        elided: _,
+        rect,
+        mesh_bounds,
+        num_vertices: _,
+        num_indices: _,
+        pixels_per_point: _,
+        intrinsic_size,
+    } = Arc::make_mut(galley);
+
+    *rect = scaling * *rect;
+    *mesh_bounds = scaling * *mesh_bounds;
+    *intrinsic_size = scaling * *intrinsic_size;
+
+    for text::PlacedRow {
+        pos,
+        row,
+        ends_with_newline: _,
+    } in rows
+    {
+        *pos *= scaling;
+
+        let text::Row {
+            section_index_at_start: _,
+            glyphs,
+            size,
+            visuals,
+        } = Arc::make_mut(row);
+
+        *size *= scaling;
+
+        for glyph in glyphs {
+            let text::Glyph {
+                chr: _,
+                pos,
+                advance_width,
+                line_height,
+                font_ascent,
+                font_height,
+                font_impl_ascent,
+                font_impl_height,
+                uv_rect: _,
+                section_index: _,
+                first_vertex: _,
+                face: _,
+            } = glyph;
+
+            *pos *= scaling;
+            *advance_width *= scaling;
+            *line_height *= scaling;
+            *font_ascent *= scaling;
+            *font_height *= scaling;
+            *font_impl_ascent *= scaling;
+            *font_impl_height *= scaling;
+        }
+
+        let text::RowVisuals {
+            mesh,
+            mesh_bounds,
+            glyph_index_start: _,
+            glyph_vertex_range: _,
+        } = visuals;
+
+        *mesh_bounds = scaling * *mesh_bounds;
+
+        for v in &mut mesh.vertices {
+            v.pos *= scaling;
+        }
+    }
+}
+
+/// Where a glyph of a [`TextPathShape`] goes.
+#[derive(Clone, Copy)]
+pub(crate) struct GlyphPlacement {
+    /// The point of the galley that goes on the path.
+    pivot: Pos2,
+
+    /// Where on screen the pivot goes.
+    pos: Pos2,
+
+    /// The direction of the path there.
+    rot: Rot2,
+}
+
+impl GlyphPlacement {
+    /// From galley coordinates to screen coordinates.
+    #[inline]
+    pub fn place(&self, galley_pos: Pos2) -> Pos2 {
+        self.pos + self.rot * (galley_pos - self.pivot)
+    }
+}
+
+/// Finds the point at some distance along a path.
+struct PathSampler {
+    /// Without repeated points. A closed path ends with its first point again.
+    points: Vec<Pos2>,
+
+    /// The distance along the path to each point.
+    distances: Vec<f32>,
+
+    closed: bool,
+}
+
+impl PathSampler {
+    fn new(path: &[Pos2], closed: bool) -> Self {
+        let mut points: Vec<Pos2> = Vec::with_capacity(path.len() + 1);
+        for &point in path {
+            if points.last() != Some(&point) {
+                points.push(point);
+            }
+        }
+        if closed && 2 < points.len() && points.first() != points.last() {
+            points.push(points[0]);
+        }
+
+        let mut distances = Vec::with_capacity(points.len());
+        let mut distance = 0.0;
+        for (i, point) in points.iter().enumerate() {
+            if 0 < i {
+                distance += points[i - 1].distance(*point);
+            }
+            distances.push(distance);
+        }
+
+        Self {
+            points,
+            distances,
+            closed,
+        }
+    }
+
+    fn length(&self) -> f32 {
+        self.distances.last().copied().unwrap_or_default()
+    }
+
+    /// The point at the given distance along the path, and the direction of the path there.
+    ///
+    /// Open paths are extended in a straight line past their ends.
+    fn at(&self, mut distance: f32) -> (Pos2, Vec2) {
+        match self.points[..] {
+            [] => (pos2(distance, 0.0), Vec2::X),
+            [point] => (point + distance * Vec2::X, Vec2::X),
+            _ => {
+                if self.closed {
+                    distance = distance.rem_euclid(self.length());
+                }
+                let num_segments = self.points.len() - 1;
+                let segment = self
+                    .distances
+                    .partition_point(|d| *d <= distance)
+                    .saturating_sub(1)
+                    .min(num_segments - 1);
+                let from = self.points[segment];
+                let direction = (self.points[segment + 1] - from).normalized();
+                (
+                    from + (distance - self.distances[segment]) * direction,
+                    direction,
+                )
+            }
+        }
+    }
+}
+
+impl From<TextPathShape> for Shape {
+    #[inline(always)]
+    fn from(shape: TextPathShape) -> Self {
+        Self::TextPath(shape.into())
+    }
+}
+
+#[test]
+fn path_sampler() {
+    let path = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+
+    let open = PathSampler::new(&path, false);
+    assert_eq!(open.length(), 20.0);
+    assert_eq!(open.at(5.0), (pos2(5.0, 0.0), Vec2::X));
+    assert_eq!(open.at(15.0), (pos2(10.0, 5.0), Vec2::Y));
+    // Past the ends:
+    assert_eq!(open.at(-5.0), (pos2(-5.0, 0.0), Vec2::X));
+    assert_eq!(open.at(25.0), (pos2(10.0, 15.0), Vec2::Y));
+
+    let closed = PathSampler::new(&path, true);
+    let (pos, _) = closed.at(closed.length() + 5.0);
+    assert!((pos - pos2(5.0, 0.0)).length() < 1e-4, "{pos:?}");
+}
+
+#[test]
+fn text_along_vertical_path() {
+    use crate::text::FontDefinitions;
+
+    let mut fonts = Fonts::new(
+        1024,
+        AlphaFromCoverage::default(),
+        FontDefinitions::default(),
+    );
+    let galley = fonts.with_pixels_per_point(1.0).layout_no_wrap(
+        "Hello".to_owned(),
+        FontId::proportional(20.0),
+        Color32::WHITE,
+    );
+    let text_width = galley.rect.width();
+
+    // Going down, so the text is rotated a quarter turn clockwise:
+    let shape = TextPathShape::new(
+        vec![pos2(50.0, 0.0), pos2(50.0, 100.0)],
+        galley,
+        Color32::WHITE,
+    );
+
+    let mut tessellator = Tessellator::new(
+        1.0,
+        TessellationOptions::default(),
+        fonts.font_image_size(),
+        vec![],
+    );
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_text_path(&shape, &mut mesh);
+    assert!(mesh.is_valid(), "Invalid mesh");
+    assert_eq!(mesh.vertices.len(), 4 * 5, "Expected a quad per glyph");
+
+    let bounds = mesh.calc_bounds();
+    assert_eq!(bounds, shape.visual_bounding_rect());
+    assert!(
+        0.0 <= bounds.min.y && bounds.max.y <= text_width,
+        "{bounds:?}"
+    );
+    // The glyphs are above the baseline, which is to the right when going down:
+    assert!(
+        48.0 < bounds.min.x && bounds.max.x < 50.0 + 20.0,
+        "{bounds:?}"
+    );
+
+    // Glyphs that are outside the clip rect are culled:
+    tessellator.set_clip_rect(Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 10.0)));
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_text_path(&shape, &mut mesh);
+    assert!(!mesh.is_empty() && mesh.vertices.len() < 4 * 5);
+}
+
+#[test]
+fn transform_scales_glyphs() {
+    use crate::text::FontDefinitions;
+
+    let mut fonts = Fonts::new(
+        1024,
+        AlphaFromCoverage::default(),
+        FontDefinitions::default(),
+    );
+    let galley = fonts.with_pixels_per_point(1.0).layout_no_wrap(
+        "Hello".to_owned(),
+        FontId::proportional(20.0),
+        Color32::WHITE,
+    );
+    let shape = TextPathShape::new(
+        vec![pos2(0.0, 50.0), pos2(100.0, 50.0)],
+        galley,
+        Color32::WHITE,
+    );
+    let bounds = shape.visual_bounding_rect();
+
+    let mut scaled = shape.clone();
+    scaled.transform(emath::TSTransform::from_scaling(2.0));
+    let scaled_bounds = scaled.visual_bounding_rect();
+
+    // The glyphs are spaced out along the path, not just made bigger:
+    assert!(
+        (scaled_bounds.min - 2.0 * bounds.min).length() < 1e-3
+            && (scaled_bounds.max - 2.0 * bounds.max).length() < 1e-3,
+        "{scaled_bounds:?} vs {bounds:?}"
+    );
+    let glyph_pos = scaled.galley.rows[0].glyphs[1].pos;
+    assert_eq!(
+        glyph_pos.to_vec2(),
+        2.0 * shape.galley.rows[0].glyphs[1].pos.to_vec2()
+    );
+}
@@ -0,0 +1,450 @@
+use std::{fmt::Debug, sync::Arc};
+
+use crate::*;
+
+/// How the width of a [`VariableStrokeShape`] varies along its path.
+///
+/// The values are factors, multiplied with [`VariableStrokeShape::width`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum WidthProfile {
+    /// A width factor for each point, e.g. the pressure of a pen.
+    ///
+    /// If there are not as many factors as points,
+    /// the factors are instead spread out evenly along the length of the path.
+    /// For instance, `vec![0.0, 1.0, 0.0]` makes a stroke that is widest in the middle.
+    PerPoint(Vec<f32>),
+
+    /// A callback from how far along the path a point is to the width factor there.
+    ///
+    /// The distance along the path is normalized, going from 0.0 at the start to 1.0 at the end.
+    ///
+    /// **This cannot be serialized**
+    #[cfg_attr(feature = "serde", serde(skip))]
+    AlongPath(Arc<dyn Fn(f32) -> f32 + Send + Sync>),
+}
+
+impl Default for WidthProfile {
+    /// The same width everywhere.
+    fn default() -> Self {
+        Self::PerPoint(vec![1.0])
+    }
+}
+
+impl Debug for WidthProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PerPoint(factors) => f.debug_tuple("PerPoint").field(factors).finish(),
+            Self::AlongPath(_) => f.debug_tuple("AlongPath").field(&"<closure>").finish(),
+        }
+    }
+}
+
+impl PartialEq for WidthProfile {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::PerPoint(l), Self::PerPoint(r)) => l == r,
+            _ => false,
+        }
+    }
+}
+
+impl From<Vec<f32>> for WidthProfile {
+    #[inline]
+    fn from(factors: Vec<f32>) -> Self {
+        Self::PerPoint(factors)
+    }
+}
+
+/// A stroke along a path, with a width that varies along it.
+///
+/// Use this for pressure-sensitive or tapered strokes.
+/// Unlike [`PathStroke`], the stroke is always centered on the path, and there is no fill.
+///
+/// ```
+/// # use epaint::*;
+/// // A stroke that tapers off at both ends:
+/// let points = vec![pos2(0.0, 0.0), pos2(50.0, 20.0), pos2(100.0, 0.0)];
+/// let shape = VariableStrokeShape::new(points, 8.0, Color32::BLACK)
+///     .with_profile(WidthProfile::AlongPath(std::sync::Arc::new(|t| (std::f32::consts::PI * t).sin())));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct VariableStrokeShape {
+    /// The path to stroke.
+    pub points: Vec<Pos2>,
+
+    /// If true, connect the first and last of the points together.
+    pub closed: bool,
+
+    /// The width of the stroke where the [`WidthProfile`] is 1.0.
+    pub width: f32,
+
+    /// How the width varies along the path.
+    pub profile: WidthProfile,
+
+    pub color: ColorMode,
+
+    /// How corners are drawn.
+    pub join: LineJoin,
+
+    /// How the ends of open paths are drawn.
+    pub cap: LineCap,
+}
+
+impl VariableStrokeShape {
+    /// An open stroke with round joins and caps,
+    /// and the same width everywhere until you set a [`WidthProfile`].
+    #[inline]
+    pub fn new(points: Vec<Pos2>, width: f32, color: impl Into<Color32>) -> Self {
+        Self {
+            points,
+            closed: false,
+            width,
+            profile: WidthProfile::default(),
+            color: ColorMode::Solid(color.into()),
+            join: LineJoin::Round,
+            cap: LineCap::Round,
+        }
+    }
+
+    /// Stroke the path of a [`PathShape`], using the width, color, join and cap of its stroke.
+    ///
+    /// The fill of the path is not included.
+    pub fn from_path(path: &PathShape, profile: impl Into<WidthProfile>) -> Self {
+        Self::from_points_and_stroke(path.points.clone(), path.closed, &path.stroke, profile)
+    }
+
+    /// Stroke a [`QuadraticBezierShape`], using the width, color, join and cap of its stroke.
+    ///
+    /// The curve is flattened into line segments using the given `tolerance`,
+    /// see [`QuadraticBezierShape::flatten`].
+    /// The fill of the curve is not included.
+    pub fn from_quadratic_bezier(
+        bezier: &QuadraticBezierShape,
+        tolerance: Option<f32>,
+        profile: impl Into<WidthProfile>,
+    ) -> Self {
+        let points = bezier.flatten(tolerance);
+        Self::from_points_and_stroke(points, bezier.closed, &bezier.stroke, profile)
+    }
+
+    /// Stroke a [`CubicBezierShape`], using the width, color, join and cap of its stroke.
+    ///
+    /// The curve is flattened into line segments using the given `tolerance`,
+    /// see [`CubicBezierShape::flatten`].
+    /// The fill of the curve is not included.
+    pub fn from_cubic_bezier(
+        bezier: &CubicBezierShape,
+        tolerance: Option<f32>,
+        profile: impl Into<WidthProfile>,
+    ) -> Self {
+        let points = bezier.flatten(tolerance);
+        Self::from_points_and_stroke(points, bezier.closed, &bezier.stroke, profile)
+    }
+
+    fn from_points_and_stroke(
+        points: Vec<Pos2>,
+        closed: bool,
+        stroke: &PathStroke,
+        profile: impl Into<WidthProfile>,
+    ) -> Self {
+        Self {
+            points,
+            closed,
+            width: stroke.width,
+            profile: profile.into(),
+            color: stroke.color.clone(),
+            join: stroke.join,
+            cap: stroke.cap,
+        }
+    }
+
+    /// Connect the last point back to the first one.
+    #[inline]
+    pub fn closed(mut self) -> Self {
+        self.closed = true;
+        self
+    }
+
+    /// Set how the width varies along the path.
+    #[inline]
+    pub fn with_profile(mut self, profile: impl Into<WidthProfile>) -> Self {
+        self.profile = profile.into();
+        self
+    }
+
+    /// Set how the corners are drawn.
+    #[inline]
+    pub fn with_join(mut self, join: LineJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Set how the ends of open paths are drawn.
+    #[inline]
+    pub fn with_cap(mut self, cap: LineCap) -> Self {
+        self.cap = cap;
+        self
+    }
+
+    /// The points to stroke, with the width of the stroke at each of them.
+    ///
+    /// Between the points, the width changes linearly.
+    /// So unless the [`WidthProfile`] has a factor for each point, the segments are subdivided
+    /// where the profile isn't linear, e.g. along the curve of a [`WidthProfile::AlongPath`].
+    pub fn points_and_widths(&self) -> (Vec<Pos2>, Vec<f32>) {
+        let n = self.points.len();
+        let width = |factor: f32| (self.width * factor).max(0.0);
+
+        if let WidthProfile::PerPoint(factors) = &self.profile
+            && factors.len() == n
+        {
+            return (
+                self.points.clone(),
+                factors.iter().copied().map(width).collect(),
+            );
+        }
+        if n == 0 {
+            return Default::default();
+        }
+
+        let mut path = self.points.clone();
+        if self.closed {
+            path.push(path[0]);
+        }
+
+        // How far along the path is each point?
+        let mut distances = Vec::with_capacity(path.len());
+        let mut distance = 0.0;
+        for (i, point) in path.iter().enumerate() {
+            if 0 < i {
+                distance += path[i - 1].distance(*point);
+            }
+            distances.push(distance);
+        }
+        let length = if 0.0 < distance { distance } else { 1.0 };
+
+        let width_at = |t: f32| {
+            width(match &self.profile {
+                WidthProfile::PerPoint(factors) => sample_evenly(factors, t),
+                WidthProfile::AlongPath(callback) => callback(t),
+            })
+        };
+
+        // Where the width can bend, so we need a point there:
+        let knots: Vec<f32> = match &self.profile {
+            WidthProfile::PerPoint(factors) => {
+                let last = factors.len().saturating_sub(1);
+                (1..last).map(|i| i as f32 / last as f32).collect()
+            }
+            WidthProfile::AlongPath(_) => (1..MIN_SAMPLES_ALONG_PATH)
+                .map(|i| i as f32 / MIN_SAMPLES_ALONG_PATH as f32)
+                .collect(),
+        };
+        let mut knots = knots.into_iter().peekable();
+
+        let mut points = vec![path[0]];
+        let mut widths = vec![width_at(0.0)];
+        for (segment, distance) in path.windows(2).zip(distances.windows(2)) {
+            let [t0, t1] = [distance[0] / length, distance[1] / length];
+            let sample = |t: f32| {
+                let along = if t0 < t1 { (t - t0) / (t1 - t0) } else { 0.0 };
+                (segment[0].lerp(segment[1], along), width_at(t))
+            };
+
+            let mut start = (t0, *widths.last().unwrap_or(&0.0));
+            while let Some(&t) = knots.peek()
+                && t < t1
+            {
+                knots.next();
+                if t0 < t {
+                    let (point, width) = sample(t);
+                    subdivide(&sample, start, (t, width), &mut points, &mut widths);
+                    points.push(point);
+                    widths.push(width);
+                    start = (t, width);
+                }
+            }
+            let end = (t1, width_at(t1));
+            subdivide(&sample, start, end, &mut points, &mut widths);
+            points.push(segment[1]);
+            widths.push(end.1);
+        }
+
+        if self.closed {
+            // The path ends where it started:
+            points.pop();
+            widths.pop();
+        }
+
+        (points, widths)
+    }
+
+    /// The visual bounding rectangle (includes stroke width)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        if self.color == ColorMode::TRANSPARENT {
+            return Rect::NOTHING;
+        }
+        let (points, widths) = self.points_and_widths();
+        let max_width = widths.into_iter().fold(0.0, f32::max);
+        if max_width <= 0.0 {
+            Rect::NOTHING
+        } else {
+            Rect::from_points(&points).expand(max_width / 2.0)
+        }
+    }
+}
+
+/// A [`WidthProfile::AlongPath`] is sampled at least this many times along the path,
+/// so that we don't miss any bumps in it.
+const MIN_SAMPLES_ALONG_PATH: usize = 32;
+
+/// Subdivide until the width is linear to within this many points.
+const WIDTH_TOLERANCE: f32 = 0.1;
+
+/// Add the points between `start` and `end` (exclusive) needed to follow the width closely.
+///
+/// `start` and `end` are the normalized distances along the path, and the widths there.
+fn subdivide(
+    sample: &dyn Fn(f32) -> (Pos2, f32),
+    start: (f32, f32),
+    end: (f32, f32),
+    points: &mut Vec<Pos2>,
+    widths: &mut Vec<f32>,
+) {
+    fn subdivide_to_depth(
+        sample: &dyn Fn(f32) -> (Pos2, f32),
+        (t0, w0): (f32, f32),
+        (t1, w1): (f32, f32),
+        depth: u32,
+        points: &mut Vec<Pos2>,
+        widths: &mut Vec<f32>,
+    ) {
+        if depth == 0 || t1 <= t0 {
+            return;
+        }
+        let t = 0.5 * (t0 + t1);
+        let (point, width) = sample(t);
+        if (width - 0.5 * (w0 + w1)).abs() <= WIDTH_TOLERANCE {
+            return;
+        }
+        subdivide_to_depth(sample, (t0, w0), (t, width), depth - 1, points, widths);
+        points.push(point);
+        widths.push(width);
+        subdivide_to_depth(sample, (t, width), (t1, w1), depth - 1, points, widths);
+    }
+
+    const MAX_DEPTH: u32 = 6;
+    subdivide_to_depth(sample, start, end, MAX_DEPTH, points, widths);
+}
+
+/// Linearly interpolate `values` spread out evenly over `0.0..=1.0`.
+fn sample_evenly(values: &[f32], t: f32) -> f32 {
+    match values {
+        [] => 1.0,
+        [value] => *value,
+        _ => {
+            let x = t.clamp(0.0, 1.0) * (values.len() - 1) as f32;
+            let i = (x.floor() as usize).min(values.len() - 2);
+            emath::lerp(values[i]..=values[i + 1], x - i as f32)
+        }
+    }
+}
+
+impl From<VariableStrokeShape> for Shape {
+    #[inline(always)]
+    fn from(shape: VariableStrokeShape) -> Self {
+        Self::VariableStroke(shape.into())
+    }
+}
+
+#[test]
+fn points_and_widths() {
+    let points = vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(40.0, 0.0)];
+    let shape = VariableStrokeShape::new(points.clone(), 2.0, Color32::WHITE);
+    assert_eq!(
+        shape.points_and_widths(),
+        (points.clone(), vec![2.0, 2.0, 2.0])
+    );
+
+    let shape = shape.with_profile(vec![1.0, 2.0, 3.0]);
+    assert_eq!(
+        shape.points_and_widths(),
+        (points.clone(), vec![2.0, 4.0, 6.0])
+    );
+
+    // Spread out along the path, linear between the ends:
+    let shape = shape.with_profile(vec![0.0, 2.0]);
+    assert_eq!(
+        shape.points_and_widths(),
+        (points.clone(), vec![0.0, 1.0, 4.0])
+    );
+
+    // Each factor gets a point of its own:
+    let shape = VariableStrokeShape::new(
+        vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(30.0, 0.0)],
+        2.0,
+        Color32::WHITE,
+    )
+    .with_profile(vec![0.0, 2.0, 2.0, 0.0]);
+    let (sampled_points, widths) = shape.points_and_widths();
+    let expected_points = [0.0, 10.0, 20.0, 30.0].map(|x| pos2(x, 0.0));
+    assert_eq!(sampled_points.len(), expected_points.len());
+    for (point, expected) in sampled_points.iter().zip(expected_points) {
+        assert!(point.distance(expected) < 1e-4, "{point:?} vs {expected:?}");
+    }
+    for (width, expected) in widths.iter().zip([0.0, 4.0, 4.0, 0.0]) {
+        assert!((width - expected).abs() < 1e-4, "{width} vs {expected}");
+    }
+
+    // A callback is followed between the points:
+    let shape = VariableStrokeShape::new(points.clone(), 2.0, Color32::WHITE)
+        .with_profile(WidthProfile::AlongPath(Arc::new(|t| 1.0 - t)));
+    let (sampled_points, widths) = shape.points_and_widths();
+    assert_eq!(sampled_points.len(), widths.len());
+    for point in &points {
+        assert!(sampled_points.contains(point));
+    }
+    for (point, width) in sampled_points.iter().zip(widths) {
+        assert!((width - 2.0 * (1.0 - point.x / 40.0)).abs() < 1e-5);
+    }
+}
+
+#[test]
+fn along_path_is_followed_between_points() {
+    // A single long segment, with a bump in the middle of its width:
+    let points = vec![pos2(0.0, 0.0), pos2(100.0, 0.0)];
+    let shape = VariableStrokeShape::new(points, 10.0, Color32::WHITE).with_profile(
+        WidthProfile::AlongPath(Arc::new(|t| (std::f32::consts::TAU * t).sin().abs())),
+    );
+    let (points, widths) = shape.points_and_widths();
+    assert!(MIN_SAMPLES_ALONG_PATH < points.len());
+    assert_eq!(points.first(), Some(&pos2(0.0, 0.0)));
+    assert_eq!(points.last(), Some(&pos2(100.0, 0.0)));
+
+    // Linear interpolation between the samples should be close to the profile everywhere:
+    for x in 0..=1000 {
+        let x = x as f32 / 10.0;
+        let i = points
+            .partition_point(|point| point.x <= x)
+            .clamp(1, points.len() - 1);
+        let along = (x - points[i - 1].x) / (points[i].x - points[i - 1].x);
+        let width = emath::lerp(widths[i - 1]..=widths[i], along);
+        let expected = 10.0 * (std::f32::consts::TAU * x / 100.0).sin().abs();
+        assert!((width - expected).abs() < 0.2, "{x}: {width} vs {expected}");
+    }
+
+    // The bumps are within the bounding rectangle:
+    assert_eq!(shape.visual_bounding_rect().height(), 10.0);
+
+    // A closed path doesn't repeat its first point:
+    let points = vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+    let shape = VariableStrokeShape::new(points, 10.0, Color32::WHITE)
+        .closed()
+        .with_profile(WidthProfile::AlongPath(Arc::new(|t| t)));
+    let (points, widths) = shape.points_and_widths();
+    assert_eq!(points.first(), Some(&pos2(0.0, 0.0)));
+    assert_ne!(points.last(), Some(&pos2(0.0, 0.0)));
+    assert!(widths.last().is_some_and(|width| *width > 9.0));
+}
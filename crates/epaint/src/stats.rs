@@ -1,6 +1,6 @@
 //! Collect statistics about what is being painted.
 
-use crate::{ClippedShape, Galley, Mesh, Primitive, Shape};
+use crate::{ClippedShape, Galley, Mesh, Primitive, Shape, TessellationCache};
 
 /// Size of the elements in a vector/array.
 #[derive(Clone, Copy, Default, PartialEq)]
@@ -172,6 +172,15 @@ pub struct PaintStats {
     pub clipped_primitives: AllocInfo,
     pub vertices: AllocInfo,
     pub indices: AllocInfo,
+
+    /// Shapes whose meshes were reused from the [`TessellationCache`].
+    pub cache_hits: usize,
+
+    /// Shapes that were tessellated and added to the [`TessellationCache`].
+    pub cache_misses: usize,
+
+    pub cached_vertices: AllocInfo,
+    pub cached_indices: AllocInfo,
 }
 
 impl PaintStats {
@@ -200,6 +209,7 @@ impl PaintStats {
             Shape::Noop
             | Shape::Circle { .. }
             | Shape::Ellipse { .. }
+            | Shape::Arc(_)
             | Shape::LineSegment { .. }
             | Shape::Rect { .. }
             | Shape::CubicBezier(_)
@@ -207,6 +217,14 @@ impl PaintStats {
             Shape::Path(path_shape) => {
                 self.shape_path += AllocInfo::from_slice(&path_shape.points);
             }
+            Shape::VariableStroke(stroke) => {
+                self.shape_path += AllocInfo::from_slice(&stroke.points);
+            }
+            Shape::Polygon(polygon_shape) => {
+                for contour in &polygon_shape.contours {
+                    self.shape_path += AllocInfo::from_slice(contour);
+                }
+            }
             Shape::Text(text_shape) => {
                 self.shape_text += AllocInfo::from_galley(&text_shape.galley);
 
@@ -215,6 +233,10 @@ impl PaintStats {
                     self.text_shape_vertices += AllocInfo::from_slice(&row.visuals.mesh.vertices);
                 }
             }
+            Shape::TextPath(text_path_shape) => {
+                self.shape_text += AllocInfo::from_galley(&text_path_shape.galley);
+                self.shape_path += AllocInfo::from_slice(&text_path_shape.path);
+            }
             Shape::Mesh(mesh) => {
                 self.shape_mesh += AllocInfo::from_mesh(mesh);
             }
@@ -237,6 +259,16 @@ impl PaintStats {
         }
         self
     }
+
+    pub fn with_tessellation_cache(mut self, cache: &TessellationCache) -> Self {
+        self.cache_hits = cache.num_hits();
+        self.cache_misses = cache.num_misses();
+        for mesh in cache.meshes() {
+            self.cached_vertices += AllocInfo::from_slice(&mesh.vertices);
+            self.cached_indices += AllocInfo::from_slice(&mesh.indices);
+        }
+        self
+    }
 }
 
 fn megabytes(size: usize) -> String {
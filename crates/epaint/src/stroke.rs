@@ -6,14 +6,69 @@ use emath::GuiRounding as _;
 
 use super::{Color32, ColorMode, Pos2, Rect, emath};
 
+/// How the corners of a stroked path are drawn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineJoin {
+    /// Extend the outer edges of the two segments until they meet in a sharp point.
+    ///
+    /// The sharper the corner, the longer the point.
+    /// Corners sharper than `min_angle` (in degrees, measured between the two segments)
+    /// are instead cut off flat at half the stroke width.
+    /// A `min_angle` of zero never cuts off any corners.
+    ///
+    /// The limit is expressed as an angle rather than a length to keep [`PathStroke`] small.
+    Miter { min_angle: u8 },
+
+    /// Round the outer edge of the corner.
+    Round,
+
+    /// Connect the outer edges of the two segments with a straight line.
+    Bevel,
+}
+
+impl LineJoin {
+    /// The default: a miter join, with corners sharper than a right angle cut off.
+    pub const DEFAULT: Self = Self::Miter { min_angle: 90 };
+}
+
+impl Default for LineJoin {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// How the ends of an open stroked path are drawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LineCap {
+    /// The stroke ends exactly at the end points.
+    #[default]
+    Butt,
+
+    /// The stroke ends in a half-circle around each end point.
+    Round,
+
+    /// The stroke extends half the stroke width past each end point.
+    Square,
+}
+
 /// Describes the width and color of a line.
 ///
 /// The default stroke is the same as [`Stroke::NONE`].
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Stroke {
     pub width: f32,
     pub color: Color32,
+
+    /// How corners are drawn.
+    pub join: LineJoin,
+
+    /// How the ends of open lines are drawn.
+    pub cap: LineCap,
 }
 
 impl Stroke {
@@ -21,6 +76,8 @@ impl Stroke {
     pub const NONE: Self = Self {
         width: 0.0,
         color: Color32::TRANSPARENT,
+        join: LineJoin::DEFAULT,
+        cap: LineCap::Butt,
     };
 
     #[inline]
@@ -28,9 +85,22 @@ impl Stroke {
         Self {
             width: width.into(),
             color: color.into(),
+            ..Self::NONE
         }
     }
 
+    /// Set how the corners are drawn.
+    #[inline]
+    pub fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
+    /// Set how the ends of open lines are drawn.
+    #[inline]
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
     /// True if width is zero or color is transparent
     #[inline]
     pub fn is_empty(&self) -> bool {
@@ -91,9 +161,16 @@ where
 impl std::hash::Hash for Stroke {
     #[inline(always)]
     fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
-        let Self { width, color } = *self;
+        let Self {
+            width,
+            color,
+            join,
+            cap,
+        } = *self;
         emath::OrderedFloat(width).hash(state);
         color.hash(state);
+        join.hash(state);
+        cap.hash(state);
     }
 }
 
@@ -116,10 +193,20 @@ pub enum StrokeKind {
 /// The default stroke is the same as [`Stroke::NONE`].
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct PathStroke {
     pub width: f32,
     pub color: ColorMode,
     pub kind: StrokeKind,
+
+    /// How corners are drawn.
+    ///
+    /// Closed paths that are also filled always have mitered corners,
+    /// so that the fill and the stroke line up.
+    pub join: LineJoin,
+
+    /// How the ends of open paths are drawn.
+    pub cap: LineCap,
 }
 
 impl Default for PathStroke {
@@ -135,6 +222,8 @@ impl PathStroke {
         width: 0.0,
         color: ColorMode::TRANSPARENT,
         kind: StrokeKind::Middle,
+        join: LineJoin::DEFAULT,
+        cap: LineCap::Butt,
     };
 
     #[inline]
@@ -142,7 +231,7 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::Solid(color.into()),
-            kind: StrokeKind::Middle,
+            ..Self::NONE
         }
     }
 
@@ -157,7 +246,7 @@ impl PathStroke {
         Self {
             width: width.into(),
             color: ColorMode::UV(Arc::new(callback)),
-            kind: StrokeKind::Middle,
+            ..Self::NONE
         }
     }
 
@@ -166,6 +255,18 @@ impl PathStroke {
         Self { kind, ..self }
     }
 
+    /// Set how the corners are drawn.
+    #[inline]
+    pub fn with_join(self, join: LineJoin) -> Self {
+        Self { join, ..self }
+    }
+
+    /// Set how the ends of open paths are drawn.
+    #[inline]
+    pub fn with_cap(self, cap: LineCap) -> Self {
+        Self { cap, ..self }
+    }
+
     /// Set the stroke to be painted right on the edge of the shape, half inside and half outside.
     #[inline]
     pub fn middle(self) -> Self {
@@ -219,7 +320,9 @@ impl From<Stroke> for PathStroke {
             Self {
                 width: value.width,
                 color: ColorMode::Solid(value.color),
-                kind: StrokeKind::Middle,
+                join: value.join,
+                cap: value.cap,
+                ..Self::NONE
             }
         }
     }
@@ -0,0 +1,588 @@
+//! Reuse the tessellation of shapes that haven't changed since the previous frame.
+
+use std::{
+    hash::{BuildHasher as _, Hash as _, Hasher},
+    mem::discriminant,
+    sync::Arc,
+};
+
+use emath::Rect;
+
+use crate::{
+    ArcKind, ArcShape, CircleShape, ClippedShape, ColorMode, CubicBezierShape, EllipseShape, Mesh,
+    PathShape, PathStroke, PolygonShape, Pos2, QuadraticBezierShape, RectShape, Shape,
+    TessellationOptions, Tessellator, TextPathShape, VariableStrokeShape, Vec2, WidthProfile,
+    texture_atlas::PreparedDisc,
+};
+
+/// Remembers the [`Mesh`]es of shapes from one frame to the next,
+/// so that shapes that haven't changed don't have to be tessellated again.
+///
+/// Only shapes that are slow to tessellate are cached:
+/// paths, polygons, curves, ellipses, arcs, rectangles and text along paths,
+/// as well as [`Shape::Vec`]s of them.
+/// Each shape is looked up by a hash of its contents,
+/// and only reused if it is equal to the shape that was cached.
+/// Shapes with color or width callbacks can't be compared, so they are never cached.
+///
+/// The meshes of shapes that were not painted in the last frame are forgotten,
+/// and so is everything else if the tessellation settings or `pixels_per_point` change.
+///
+/// Use the same cache every frame with [`Tessellator::tessellate_shapes_with_cache`].
+#[derive(Default)]
+pub struct TessellationCache {
+    /// What the cached meshes were tessellated with.
+    settings: Option<Settings>,
+
+    entries: nohash_hasher::IntMap<u64, CachedMesh>,
+
+    /// Increased by one each frame.
+    generation: u64,
+
+    /// How many shapes were found in the cache last frame.
+    num_hits: usize,
+
+    /// How many shapes were tessellated and added to the cache last frame.
+    num_misses: usize,
+}
+
+#[derive(PartialEq)]
+struct Settings {
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+    prepared_discs: Vec<PreparedDisc>,
+}
+
+struct CachedMesh {
+    /// The shape the mesh was tessellated from,
+    /// compared against on a hit in case two shapes have the same hash.
+    shape: Shape,
+
+    mesh: Arc<Mesh>,
+
+    /// The [`TessellationCache::generation`] when this was last painted.
+    last_used: u64,
+}
+
+impl TessellationCache {
+    /// How many shapes were reused from the cache in the last frame.
+    #[inline]
+    pub fn num_hits(&self) -> usize {
+        self.num_hits
+    }
+
+    /// How many shapes were tessellated and added to the cache in the last frame.
+    #[inline]
+    pub fn num_misses(&self) -> usize {
+        self.num_misses
+    }
+
+    /// The meshes kept in the cache.
+    pub fn meshes(&self) -> impl Iterator<Item = &Mesh> {
+        self.entries.values().map(|entry| &*entry.mesh)
+    }
+
+    /// Forget all cached meshes.
+    pub fn clear(&mut self) {
+        self.settings = None;
+        self.entries.clear();
+        self.num_hits = 0;
+        self.num_misses = 0;
+    }
+
+    /// Replace every shape that is worth caching with a [`Shape::Mesh`],
+    /// either from the cache or tessellated by the given tessellator.
+    ///
+    /// The clip rectangles and clip masks are applied later, so the meshes don't depend on them.
+    pub(crate) fn replace_with_meshes(
+        &mut self,
+        tessellator: &Tessellator,
+        shapes: &mut [ClippedShape],
+    ) {
+        profiling::function_scope!();
+
+        let settings = Settings {
+            pixels_per_point: tessellator.pixels_per_point,
+            options: tessellator.options,
+            font_tex_size: tessellator.font_tex_size,
+            prepared_discs: tessellator.prepared_discs.clone(),
+        };
+        if self.settings.as_ref() != Some(&settings) {
+            self.entries.clear();
+            self.settings = Some(settings);
+        }
+
+        self.generation += 1;
+        let generation = self.generation;
+
+        let mut misses: Vec<(usize, u64)> = vec![];
+        self.num_hits = 0;
+        for (index, clipped_shape) in shapes.iter_mut().enumerate() {
+            let Some(key) = shape_key(&clipped_shape.shape) else {
+                continue;
+            };
+            match self.entries.get_mut(&key) {
+                Some(entry) if entry.shape == clipped_shape.shape => {
+                    entry.last_used = generation;
+                    clipped_shape.shape = Shape::Mesh(entry.mesh.clone());
+                    self.num_hits += 1;
+                }
+                _ => misses.push((index, key)),
+            }
+        }
+        self.num_misses = misses.len();
+
+        let meshes = tessellate_misses(tessellator, shapes, &misses);
+        for ((index, key), mesh) in misses.into_iter().zip(meshes) {
+            let mesh = Arc::new(mesh);
+            let shape = std::mem::replace(&mut shapes[index].shape, Shape::Mesh(mesh.clone()));
+            self.entries.insert(
+                key,
+                CachedMesh {
+                    shape,
+                    mesh,
+                    last_used: generation,
+                },
+            );
+        }
+
+        self.entries
+            .retain(|_, entry| entry.last_used == generation);
+    }
+}
+
+/// Tessellate each of the shapes at the given indices into its own mesh, without any culling.
+fn tessellate_misses(
+    tessellator: &Tessellator,
+    shapes: &[ClippedShape],
+    misses: &[(usize, u64)],
+) -> Vec<Mesh> {
+    let tessellate = |tessellator: &mut Tessellator, index: usize| {
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_shape(shapes[index].shape.clone(), &mut mesh);
+        mesh
+    };
+
+    let mut tessellator = tessellator.clone();
+    tessellator.set_clip_rect(Rect::EVERYTHING);
+
+    #[cfg(feature = "rayon")]
+    if tessellator.options.parallel_tessellation && 1 < misses.len() {
+        use rayon::prelude::*;
+
+        return misses
+            .par_iter()
+            .map(|&(index, _)| tessellate(&mut tessellator.clone(), index))
+            .collect();
+    }
+
+    misses
+        .iter()
+        .map(|&(index, _)| tessellate(&mut tessellator, index))
+        .collect()
+}
+
+/// The hash of a shape that is worth caching.
+fn shape_key(shape: &Shape) -> Option<u64> {
+    let worth_caching = match shape {
+        Shape::Vec(shapes) => !shapes.is_empty(),
+        Shape::Ellipse(_)
+        | Shape::Arc(_)
+        | Shape::Path(_)
+        | Shape::Polygon(_)
+        | Shape::VariableStroke(_)
+        | Shape::Rect(_)
+        | Shape::TextPath(_)
+        | Shape::QuadraticBezier(_)
+        | Shape::CubicBezier(_) => true,
+
+        // These are about as fast to tessellate as to look up:
+        Shape::Noop
+        | Shape::Circle(_)
+        | Shape::LineSegment { .. }
+        | Shape::Text(_)
+        | Shape::Mesh(_)
+        | Shape::Callback(_) => false,
+    };
+    if !worth_caching {
+        return None;
+    }
+
+    let mut hasher = ahash::RandomState::with_seeds(1, 2, 3, 4).build_hasher();
+    hash_shape(&mut hasher, shape).then(|| hasher.finish())
+}
+
+/// Returns `false` if the shape can't be cached.
+fn hash_shape(h: &mut impl Hasher, shape: &Shape) -> bool {
+    discriminant(shape).hash(h);
+    match shape {
+        Shape::Noop => {}
+        Shape::Vec(shapes) => {
+            h.write_usize(shapes.len());
+            return shapes.iter().all(|shape| hash_shape(h, shape));
+        }
+        Shape::Circle(circle) => {
+            let CircleShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            } = circle;
+            hash_pos2(h, *center);
+            hash_f32(h, *radius);
+            fill.hash(h);
+            stroke.hash(h);
+        }
+        Shape::Ellipse(ellipse) => {
+            let EllipseShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            } = ellipse;
+            hash_pos2(h, *center);
+            hash_vec2(h, *radius);
+            fill.hash(h);
+            stroke.hash(h);
+        }
+        Shape::Arc(arc) => {
+            let ArcShape {
+                center,
+                radius,
+                start_angle,
+                end_angle,
+                kind,
+                fill,
+                stroke,
+            } = &**arc;
+            hash_pos2(h, *center);
+            hash_vec2(h, *radius);
+            hash_f32(h, *start_angle);
+            hash_f32(h, *end_angle);
+            discriminant(kind).hash(h);
+            if let ArcKind::AnnulusSegment { inner_radius } = kind {
+                hash_vec2(h, *inner_radius);
+            }
+            fill.hash(h);
+            return hash_path_stroke(h, stroke);
+        }
+        Shape::LineSegment { points, stroke } => {
+            hash_points(h, points);
+            stroke.hash(h);
+        }
+        Shape::Path(path) => {
+            let PathShape {
+                points,
+                closed,
+                fill,
+                stroke,
+            } = path;
+            hash_points(h, points);
+            closed.hash(h);
+            fill.hash(h);
+            return hash_path_stroke(h, stroke);
+        }
+        Shape::Polygon(polygon) => {
+            let PolygonShape {
+                contours,
+                fill_rule,
+                fill,
+                stroke,
+            } = polygon;
+            h.write_usize(contours.len());
+            for contour in contours {
+                hash_points(h, contour);
+            }
+            fill_rule.hash(h);
+            fill.hash(h);
+            return hash_path_stroke(h, stroke);
+        }
+        Shape::VariableStroke(variable_stroke) => {
+            let VariableStrokeShape {
+                points,
+                closed,
+                width,
+                profile,
+                color,
+                join,
+                cap,
+            } = &**variable_stroke;
+            hash_points(h, points);
+            closed.hash(h);
+            hash_f32(h, *width);
+            discriminant(profile).hash(h);
+            match profile {
+                WidthProfile::PerPoint(factors) => {
+                    h.write_usize(factors.len());
+                    for factor in factors {
+                        hash_f32(h, *factor);
+                    }
+                }
+                WidthProfile::AlongPath(_) => return false,
+            }
+            if !hash_color_mode(h, color) {
+                return false;
+            }
+            join.hash(h);
+            cap.hash(h);
+        }
+        Shape::Rect(rect_shape) => {
+            let RectShape {
+                rect,
+                corner_radius,
+                fill,
+                stroke,
+                stroke_kind,
+                round_to_pixels,
+                blur_width,
+                brush,
+            } = rect_shape;
+            if brush.is_some() {
+                return false; // textured, so it can't share a mesh with other shapes
+            }
+            hash_rect(h, *rect);
+            corner_radius.hash(h);
+            fill.hash(h);
+            stroke.hash(h);
+            discriminant(stroke_kind).hash(h);
+            round_to_pixels.hash(h);
+            hash_f32(h, *blur_width);
+        }
+        Shape::TextPath(text_path) => {
+            let TextPathShape {
+                path,
+                closed,
+                galley,
+                align,
+                offset,
+                fallback_color,
+                override_text_color,
+                opacity_factor,
+            } = &**text_path;
+            hash_points(h, path);
+            closed.hash(h);
+            Arc::as_ptr(galley).hash(h);
+            align.hash(h);
+            hash_f32(h, *offset);
+            fallback_color.hash(h);
+            override_text_color.hash(h);
+            hash_f32(h, *opacity_factor);
+        }
+        Shape::QuadraticBezier(bezier) => {
+            let QuadraticBezierShape {
+                points,
+                closed,
+                fill,
+                stroke,
+            } = bezier;
+            hash_points(h, points);
+            closed.hash(h);
+            fill.hash(h);
+            return hash_path_stroke(h, stroke);
+        }
+        Shape::CubicBezier(bezier) => {
+            let CubicBezierShape {
+                points,
+                closed,
+                fill,
+                stroke,
+            } = bezier;
+            hash_points(h, points);
+            closed.hash(h);
+            fill.hash(h);
+            return hash_path_stroke(h, stroke);
+        }
+        Shape::Text(_) | Shape::Mesh(_) | Shape::Callback(_) => return false,
+    }
+    true
+}
+
+/// Returns `false` if the stroke can't be cached.
+fn hash_path_stroke(h: &mut impl Hasher, stroke: &PathStroke) -> bool {
+    let PathStroke {
+        width,
+        color,
+        kind,
+        join,
+        cap,
+    } = stroke;
+    hash_f32(h, *width);
+    discriminant(kind).hash(h);
+    join.hash(h);
+    cap.hash(h);
+    hash_color_mode(h, color)
+}
+
+/// Returns `false` for [`ColorMode::UV`], which isn't equal to anything, so it can't be cached.
+fn hash_color_mode(h: &mut impl Hasher, color: &ColorMode) -> bool {
+    match color {
+        ColorMode::Solid(color) => {
+            color.hash(h);
+            true
+        }
+        ColorMode::UV(_) => false,
+    }
+}
+
+#[inline]
+fn hash_f32(h: &mut impl Hasher, value: f32) {
+    h.write_u32(value.to_bits());
+}
+
+#[inline]
+fn hash_vec2(h: &mut impl Hasher, value: Vec2) {
+    hash_f32(h, value.x);
+    hash_f32(h, value.y);
+}
+
+#[inline]
+fn hash_pos2(h: &mut impl Hasher, value: Pos2) {
+    hash_vec2(h, value.to_vec2());
+}
+
+#[inline]
+fn hash_rect(h: &mut impl Hasher, rect: Rect) {
+    hash_pos2(h, rect.min);
+    hash_pos2(h, rect.max);
+}
+
+fn hash_points(h: &mut impl Hasher, points: &[Pos2]) {
+    h.write_usize(points.len());
+    for point in points {
+        hash_pos2(h, *point);
+    }
+}
+
+#[test]
+fn reuse_unchanged_shapes() {
+    use crate::{ClippedPrimitive, Color32, PathStroke, Primitive, pos2};
+
+    let path = |y: f32| {
+        ClippedShape::new(
+            Rect::EVERYTHING,
+            PathShape::line(
+                vec![pos2(0.0, y), pos2(50.0, y + 20.0), pos2(100.0, y)],
+                PathStroke::new(2.0, Color32::RED),
+            ),
+        )
+    };
+    let segment = ClippedShape::new(
+        Rect::EVERYTHING,
+        Shape::line_segment([pos2(0.0, 0.0), pos2(10.0, 0.0)], (1.0, Color32::RED)),
+    );
+
+    let meshes = |primitives: Vec<ClippedPrimitive>| -> Vec<Mesh> {
+        primitives
+            .into_iter()
+            .map(|clipped_primitive| match clipped_primitive.primitive {
+                Primitive::Mesh(mesh) => mesh,
+                Primitive::Callback(_) => panic!("Expected only meshes"),
+            })
+            .collect()
+    };
+
+    let options = TessellationOptions {
+        cache_meshes: true,
+        ..Default::default()
+    };
+    let mut cache = TessellationCache::default();
+    let tessellate = |cache: &mut TessellationCache, shapes: Vec<ClippedShape>| {
+        meshes(
+            Tessellator::new(1.0, options, [1, 1], vec![])
+                .tessellate_shapes_with_cache(shapes, cache),
+        )
+    };
+
+    let uncached = meshes(
+        Tessellator::new(1.0, Default::default(), [1, 1], vec![]).tessellate_shapes(vec![
+            path(0.0),
+            path(10.0),
+            segment.clone(),
+        ]),
+    );
+
+    let first = tessellate(&mut cache, vec![path(0.0), path(10.0), segment.clone()]);
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 2));
+    assert_eq!(first, uncached);
+
+    let second = tessellate(&mut cache, vec![path(0.0), path(10.0), segment.clone()]);
+    assert_eq!((cache.num_hits(), cache.num_misses()), (2, 0));
+    assert_eq!(second, uncached);
+
+    // One changed path, and one that is gone:
+    tessellate(&mut cache, vec![path(20.0), segment]);
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 1));
+    assert_eq!(
+        cache.meshes().count(),
+        1,
+        "Unused meshes should be forgotten"
+    );
+
+    // New settings:
+    Tessellator::new(2.0, options, [1, 1], vec![])
+        .tessellate_shapes_with_cache(vec![path(20.0)], &mut cache);
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 1));
+}
+
+#[test]
+fn hash_collisions_are_tessellated() {
+    use crate::{Color32, PathStroke, Primitive, pos2};
+
+    let path = |y: f32| {
+        ClippedShape::new(
+            Rect::EVERYTHING,
+            PathShape::line(
+                vec![pos2(0.0, y), pos2(50.0, y + 20.0), pos2(100.0, y)],
+                PathStroke::new(2.0, Color32::RED),
+            ),
+        )
+    };
+    let tessellator = Tessellator::new(
+        1.0,
+        TessellationOptions {
+            cache_meshes: true,
+            ..Default::default()
+        },
+        [1, 1],
+        vec![],
+    );
+
+    let mut cache = TessellationCache::default();
+    tessellator
+        .clone()
+        .tessellate_shapes_with_cache(vec![path(0.0)], &mut cache);
+
+    // Pretend that another path has the same hash as the cached one:
+    let (_, entry) = cache.entries.drain().next().unwrap();
+    let key = shape_key(&path(10.0).shape).unwrap();
+    cache.entries.insert(key, entry);
+
+    let primitives = tessellator
+        .clone()
+        .tessellate_shapes_with_cache(vec![path(10.0)], &mut cache);
+    assert_eq!((cache.num_hits(), cache.num_misses()), (0, 1));
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    let uncached = tessellator.clone().tessellate_shapes(vec![path(10.0)]);
+    let Primitive::Mesh(uncached) = &uncached[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert_eq!(mesh, uncached);
+}
+
+#[test]
+fn callbacks_are_not_cached() {
+    use crate::{Color32, PathStroke, pos2};
+
+    let points = vec![pos2(0.0, 0.0), pos2(50.0, 20.0), pos2(100.0, 0.0)];
+    let uv_stroke = PathStroke::new_uv(2.0, |_, _| Color32::RED);
+    assert_eq!(
+        shape_key(&PathShape::line(points.clone(), uv_stroke).into()),
+        None
+    );
+    assert!(
+        shape_key(&PathShape::line(points, PathStroke::new(2.0, Color32::RED)).into()).is_some()
+    );
+}
@@ -8,10 +8,11 @@
 use emath::{GuiRounding as _, NumExt as _, Pos2, Rect, Rot2, Vec2, pos2, remap, vec2};
 
 use crate::{
-    CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32, CubicBezierShape,
-    EllipseShape, Mesh, PathShape, Primitive, QuadraticBezierShape, RectShape, Shape, Stroke,
-    StrokeKind, TextShape, TextureId, Vertex, WHITE_UV, color::ColorMode, emath,
-    stroke::PathStroke, texture_atlas::PreparedDisc,
+    ArcKind, ArcShape, CircleShape, ClippedPrimitive, ClippedShape, Color32, CornerRadiusF32,
+    CubicBezierShape, EllipseShape, LineCap, LineJoin, Mesh, PathShape, PolygonShape, Primitive,
+    QuadraticBezierShape, RectShape, Shape, Stroke, StrokeKind, TessellationCache, TextPathShape,
+    TextShape, TextureId, VariableStrokeShape, Vertex, WHITE_UV, color::ColorMode, emath,
+    polygon_fill, stroke::PathStroke, texture_atlas::PreparedDisc,
 };
 
 // ----------------------------------------------------------------------------
@@ -380,7 +381,25 @@ impl Path {
         self.add_point(points[1], normal);
     }
 
+    /// Add an open path, with the default [`LineJoin`].
     pub fn add_open_points(&mut self, points: &[Pos2]) {
+        self.add_open_points_with_join(points, LineJoin::default(), 0.0, 0.0);
+    }
+
+    /// Add an open path that will be stroked with `stroke`,
+    /// with corners as given by [`PathStroke::join`].
+    pub fn add_stroked_open_points(&mut self, points: &[Pos2], stroke: &PathStroke) {
+        let (half_width, offset) = join_width_and_offset(stroke);
+        self.add_open_points_with_join(points, stroke.join, half_width, offset);
+    }
+
+    fn add_open_points_with_join(
+        &mut self,
+        points: &[Pos2],
+        join: LineJoin,
+        half_width: f32,
+        offset: f32,
+    ) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
 
@@ -401,21 +420,7 @@ impl Path {
                     n1 = n0;
                 }
 
-                let normal = (n0 + n1) / 2.0;
-                let length_sq = normal.length_sq();
-                let right_angle_length_sq = 0.5;
-                let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-                if sharper_than_a_right_angle {
-                    // cut off the sharp corner
-                    let center_normal = normal.normalized();
-                    let n0c = (n0 + center_normal) / 2.0;
-                    let n1c = (n1 + center_normal) / 2.0;
-                    self.add_point(points[i], n0c / n0c.length_sq());
-                    self.add_point(points[i], n1c / n1c.length_sq());
-                } else {
-                    // miter join
-                    self.add_point(points[i], normal / length_sq);
-                }
+                self.add_join(points[i], n0, n1, join, half_width, offset);
 
                 n0 = n1;
             }
@@ -426,7 +431,49 @@ impl Path {
         }
     }
 
+    /// Add a closed path.
+    ///
+    /// All corners are mitered, no matter how sharp,
+    /// so that the path can be filled.
     pub fn add_line_loop(&mut self, points: &[Pos2]) {
+        // We can't just cut off corners for filled shapes,
+        // because the feather will both expand and contract the corner along the provided normals
+        // to make sure it doesn't grow, and the shrinking will make the inner points cross each other.
+        //
+        // A better approach is to shrink the vertices in by half the feather-width here
+        // and then only expand during feathering.
+        //
+        // See https://github.com/emilk/egui/issues/1226
+        self.add_line_loop_with_join(points, LineJoin::Miter { min_angle: 0 }, 0.0, 0.0);
+    }
+
+    /// Add a closed path that will be stroked with `stroke`, but not filled,
+    /// with corners as given by [`PathStroke::join`].
+    ///
+    /// Use [`Self::add_line_loop`] for paths that are filled.
+    pub fn add_stroked_line_loop(&mut self, points: &[Pos2], stroke: &PathStroke) {
+        let (half_width, offset) = join_width_and_offset(stroke);
+        self.add_line_loop_with_join(points, stroke.join, half_width, offset);
+    }
+
+    /// Add a closed path for a shape with the given fill and stroke.
+    ///
+    /// Filled shapes are always mitered (see [`Self::add_line_loop`]).
+    fn add_line_loop_for(&mut self, points: &[Pos2], fill: Color32, stroke: &PathStroke) {
+        if fill == Color32::TRANSPARENT && !stroke.is_empty() {
+            self.add_stroked_line_loop(points, stroke);
+        } else {
+            self.add_line_loop(points);
+        }
+    }
+
+    fn add_line_loop_with_join(
+        &mut self,
+        points: &[Pos2],
+        join: LineJoin,
+        half_width: f32,
+        offset: f32,
+    ) {
         let n = points.len();
         assert!(n >= 2, "A path needs at least two points, but got {n}");
         self.reserve(n);
@@ -444,34 +491,100 @@ impl Path {
                 n1 = n0;
             }
 
-            let normal = (n0 + n1) / 2.0;
-            let length_sq = normal.length_sq();
+            self.add_join(points[i], n0, n1, join, half_width, offset);
+
+            n0 = n1;
+        }
+    }
+
+    /// Add the corner at `pos` between a segment with normal `n0` and a segment with normal `n1`.
+    ///
+    /// The stroke is `2 * half_width` wide, and its middle is `offset` along the normal from `pos`.
+    ///
+    /// Sharp corners are made up of several points, one for each normal on the outside of the corner.
+    /// Unless the corner is sharper than 60°, all of them share the same (mitered) vertex
+    /// on the inside of the corner, so that the stroke doesn't overlap itself there.
+    fn add_join(
+        &mut self,
+        pos: Pos2,
+        n0: Vec2,
+        n1: Vec2,
+        join: LineJoin,
+        half_width: f32,
+        offset: f32,
+    ) {
+        let normal = (n0 + n1) / 2.0;
+        let length_sq = normal.length_sq();
+        let almost_straight = 0.9999 < length_sq;
+
+        // For a corner with angle `a` between the two segments, `length_sq = sin²(a/2)`.
+        // We leave some slack for rounding errors, so that right angles are not cut off
+        // with the default limit of 90°.
+        let sharper_than = |min_angle: u8| {
+            let half_angle = 0.5 * f32::from(min_angle).to_radians();
+            length_sq < half_angle.sin().powi(2) - 1e-5
+        };
+
+        // Which side of the stroke (along the normal) is on the inside of the corner:
+        let inner_sign = if n0.x * n1.y - n0.y * n1.x < 0.0 {
+            1.0
+        } else {
+            -1.0
+        };
+        let inner_offset = offset + inner_sign * half_width;
+        let outer_offset = offset - inner_sign * half_width;
+        let inner = pos + inner_offset * normal / length_sq;
 
-            // We can't just cut off corners for filled shapes like this,
-            // because the feather will both expand and contract the corner along the provided normals
-            // to make sure it doesn't grow, and the shrinking will make the inner points cross each other.
-            //
-            // A better approach is to shrink the vertices in by half the feather-width here
-            // and then only expand during feathering.
-            //
-            // See https://github.com/emilk/egui/issues/1226
-            const CUT_OFF_SHARP_CORNERS: bool = false;
+        // Sharing the inner vertex stretches the feathering by up to the length of the miter,
+        // so we only do it for corners of at least 60°, where the miter is at most twice the width:
+        let share_inner = 0.0 < half_width && 0.25 <= length_sq;
 
-            let right_angle_length_sq = 0.5;
-            let sharper_than_a_right_angle = length_sq < right_angle_length_sq;
-            if CUT_OFF_SHARP_CORNERS && sharper_than_a_right_angle {
-                // cut off the sharp corner
+        let corner_point = |outer_normal: Vec2| {
+            if !share_inner {
+                return (pos, outer_normal);
+            }
+            let outer = pos + outer_offset * outer_normal;
+            // The stroke goes from `middle - half_width * normal` to `middle + half_width * normal`:
+            let (plus, minus) = if 0.0 < inner_sign {
+                (inner, outer)
+            } else {
+                (outer, inner)
+            };
+            let middle = plus + 0.5 * (minus - plus);
+            let normal = (plus - minus) / (2.0 * half_width);
+            // The stroke moves the path by `offset` along the normal later:
+            (middle - offset * normal, normal)
+        };
+        let mut add_corner_point = |outer_normal: Vec2| {
+            let (pos, normal) = corner_point(outer_normal);
+            self.add_point(pos, normal);
+        };
+
+        match join {
+            LineJoin::Miter { min_angle } if sharper_than(min_angle) => {
+                // The miter is too long: cut off the sharp corner
                 let center_normal = normal.normalized();
                 let n0c = (n0 + center_normal) / 2.0;
                 let n1c = (n1 + center_normal) / 2.0;
-                self.add_point(points[i], n0c / n0c.length_sq());
-                self.add_point(points[i], n1c / n1c.length_sq());
-            } else {
+                add_corner_point(n0c / n0c.length_sq());
+                add_corner_point(n1c / n1c.length_sq());
+            }
+            LineJoin::Bevel if !almost_straight => {
+                add_corner_point(n0);
+                add_corner_point(n1);
+            }
+            LineJoin::Round if !almost_straight => {
+                let angle = (n0.x * n1.y - n0.y * n1.x).atan2(n0.dot(n1));
+                let segments = arc_segments(outer_offset.abs(), angle.abs());
+                for i in 0..=segments {
+                    let t = i as f32 / segments as f32;
+                    add_corner_point(Rot2::from_angle(t * angle) * n0);
+                }
+            }
+            _ => {
                 // miter join
-                self.add_point(points[i], normal / length_sq);
+                self.add_point(pos, normal / length_sq);
             }
-
-            n0 = n1;
         }
     }
 
@@ -707,6 +820,8 @@ pub struct TessellationOptions {
     pub debug_ignore_clip_rects: bool,
 
     /// The maximum distance between the original curve and the flattened curve.
+    ///
+    /// Used for Bézier curves and arcs.
     pub bezier_tolerance: f32,
 
     /// The default value will be 1.0e-5, it will be used during float compare.
@@ -715,6 +830,16 @@ pub struct TessellationOptions {
     /// If `rayon` feature is activated, should we parallelize tessellation?
     pub parallel_tessellation: bool,
 
+    /// If `true`, [`Tessellator::tessellate_shapes_with_cache`] reuses
+    /// the meshes of shapes that haven't changed since the previous frame.
+    ///
+    /// This only pays off if there are many slow shapes (long paths, curves, polygons, …)
+    /// that stay the same from frame to frame, since each of them has to be hashed and compared.
+    /// Default: `false`.
+    ///
+    /// See [`crate::TessellationCache`].
+    pub cache_meshes: bool,
+
     /// If `true`, invalid meshes will be silently ignored.
     /// If `false`, invalid meshes will cause a panic.
     ///
@@ -738,6 +863,7 @@ impl Default for TessellationOptions {
             bezier_tolerance: 0.1,
             epsilon: 1.0e-5,
             parallel_tessellation: true,
+            cache_meshes: false,
             validate_meshes: false,
         }
     }
@@ -820,6 +946,50 @@ fn fill_closed_path(feathering: f32, path: &mut [PathPoint], fill_color: Color32
     }
 }
 
+/// Add the feathering around a closed path that goes clockwise around the filled region.
+///
+/// Returns the inner edge of the feathering, which the caller needs to fill.
+fn feather_closed_path(
+    feathering: f32,
+    path: &[PathPoint],
+    fill_color: Color32,
+    out: &mut Mesh,
+) -> Vec<Pos2> {
+    let n = path.len() as u32;
+    out.reserve_triangles(2 * n as usize);
+    out.reserve_vertices(2 * n as usize);
+    let idx_inner = out.vertices.len() as u32;
+    let idx_outer = idx_inner + 1;
+
+    // The mitered normal of a sharp corner is long, and would make a spike of feathering.
+    // Polygons can have concave corners as sharp as you like, so we limit it:
+    const MAX_NORMAL_LENGTH: f32 = 2.0;
+
+    let mut inner = Vec::with_capacity(path.len());
+    let mut i0 = n - 1;
+    for i1 in 0..n {
+        let p1 = &path[i1 as usize];
+        let length = p1.normal.length();
+        let normal = if MAX_NORMAL_LENGTH < length {
+            p1.normal * (MAX_NORMAL_LENGTH / length)
+        } else {
+            p1.normal
+        };
+        let dm = 0.5 * feathering * normal;
+
+        let pos_inner = p1.pos - dm;
+        let pos_outer = p1.pos + dm;
+
+        out.colored_vertex(pos_inner, fill_color);
+        out.colored_vertex(pos_outer, Color32::TRANSPARENT);
+        out.add_triangle(idx_inner + i1 * 2, idx_inner + i0 * 2, idx_outer + 2 * i0);
+        out.add_triangle(idx_outer + i0 * 2, idx_outer + i1 * 2, idx_inner + 2 * i1);
+        inner.push(pos_inner);
+        i0 = i1;
+    }
+    inner
+}
+
 /// Like [`fill_closed_path`] but with texturing.
 ///
 /// The `uv_from_pos` is called for each vertex position.
@@ -986,6 +1156,16 @@ fn stroke_and_fill_path(
         }
     }
 
+    let round_cap = path_type == PathType::Open && stroke.cap == LineCap::Round;
+    if path_type == PathType::Open && stroke.cap == LineCap::Square {
+        // Extend the ends by half the stroke width:
+        let half_width = 0.5 * stroke.width;
+        let first = &mut path[0];
+        first.pos += first.normal.rot90() * half_width;
+        let last = &mut path[n as usize - 1];
+        last.pos -= last.normal.rot90() * half_width;
+    }
+
     // Expand the bounding box to include the thickness of the path
     let uv_bbox = if matches!(stroke.color, ColorMode::UV(_)) {
         Rect::from_points(&path.iter().map(|p| p.pos).collect::<Vec<Pos2>>())
@@ -1133,11 +1313,10 @@ fn stroke_and_fill_path(
                     //   |    |  que  |    |
                     //   |    |       |    |
 
-                    // (in the future it would be great with an option to add a circular end instead)
+                    // With round caps we don't extrude, but add a half-disk to each end instead.
 
                     // TODO(emilk): we should probably shrink before adding the line caps,
                     // so that we don't add to the area of the line.
-                    // TODO(emilk): make line caps optional.
 
                     out.reserve_triangles(6 * n as usize + 4);
                     out.reserve_vertices(4 * n as usize);
@@ -1146,7 +1325,11 @@ fn stroke_and_fill_path(
                         let end = path[0];
                         let p = end.pos;
                         let n = end.normal;
-                        let back_extrude = n.rot90() * feathering;
+                        let back_extrude = if round_cap {
+                            Vec2::ZERO
+                        } else {
+                            n.rot90() * feathering
+                        };
                         out.colored_vertex(p + n * outer_rad + back_extrude, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
@@ -1158,8 +1341,10 @@ fn stroke_and_fill_path(
                         );
                         out.colored_vertex(p - n * outer_rad + back_extrude, color_outer);
 
-                        out.add_triangle(idx + 0, idx + 1, idx + 2);
-                        out.add_triangle(idx + 0, idx + 2, idx + 3);
+                        if !round_cap {
+                            out.add_triangle(idx + 0, idx + 1, idx + 2);
+                            out.add_triangle(idx + 0, idx + 2, idx + 3);
+                        }
                     }
 
                     let mut i0 = 0;
@@ -1195,7 +1380,11 @@ fn stroke_and_fill_path(
                         let end = path[i1 as usize];
                         let p = end.pos;
                         let n = end.normal;
-                        let back_extrude = -n.rot90() * feathering;
+                        let back_extrude = if round_cap {
+                            Vec2::ZERO
+                        } else {
+                            -n.rot90() * feathering
+                        };
                         out.colored_vertex(p + n * outer_rad + back_extrude, color_outer);
                         out.colored_vertex(
                             p + n * inner_rad,
@@ -1216,9 +1405,34 @@ fn stroke_and_fill_path(
                         out.add_triangle(idx + 4 * i0 + 2, idx + 4 * i0 + 3, idx + 4 * i1 + 2);
                         out.add_triangle(idx + 4 * i0 + 3, idx + 4 * i1 + 2, idx + 4 * i1 + 3);
 
-                        // The extension:
-                        out.add_triangle(idx + 4 * i1 + 0, idx + 4 * i1 + 1, idx + 4 * i1 + 2);
-                        out.add_triangle(idx + 4 * i1 + 0, idx + 4 * i1 + 2, idx + 4 * i1 + 3);
+                        if !round_cap {
+                            // The extension:
+                            out.add_triangle(idx + 4 * i1 + 0, idx + 4 * i1 + 1, idx + 4 * i1 + 2);
+                            out.add_triangle(idx + 4 * i1 + 0, idx + 4 * i1 + 2, idx + 4 * i1 + 3);
+                        }
+                    }
+
+                    if round_cap {
+                        let color = |pos| get_color(color_middle, pos);
+                        let [first, last] = [path[0], path[n as usize - 1]];
+                        add_round_cap(
+                            out,
+                            first.pos,
+                            first.normal,
+                            first.normal.rot90(),
+                            inner_rad,
+                            outer_rad,
+                            color,
+                        );
+                        add_round_cap(
+                            out,
+                            last.pos,
+                            last.normal,
+                            -last.normal.rot90(),
+                            inner_rad,
+                            outer_rad,
+                            color,
+                        );
                     }
                 }
             }
@@ -1273,6 +1487,15 @@ fn stroke_and_fill_path(
                     get_color(&stroke.color, p.pos - radius * p.normal),
                 );
             }
+
+            if round_cap {
+                let color = |pos| get_color(&stroke.color, pos);
+                let [first, last] = [path[0], path[n as usize - 1]];
+                let (normal, outward) = (first.normal, first.normal.rot90());
+                add_round_cap(out, first.pos, normal, outward, radius, radius, color);
+                let (normal, outward) = (last.normal, -last.normal.rot90());
+                add_round_cap(out, last.pos, normal, outward, radius, radius, color);
+            }
         }
 
         if color_fill != Color32::TRANSPARENT {
@@ -1289,6 +1512,224 @@ fn stroke_and_fill_path(
     }
 }
 
+/// Tessellate the given path as a stroke with a different width at each point.
+///
+/// `widths` has the width of the stroke at each point of the `path`.
+fn stroke_path_with_widths(
+    feathering: f32,
+    path: &mut [PathPoint],
+    widths: &[f32],
+    path_type: PathType,
+    color: &ColorMode,
+    cap: LineCap,
+    out: &mut Mesh,
+) {
+    debug_assert_eq!(path.len(), widths.len(), "Expected one width per point");
+    let n = path.len() as u32;
+    if n < 2 {
+        return;
+    }
+
+    let open = path_type == PathType::Open;
+    let round_cap = open && cap == LineCap::Round;
+    if open && cap == LineCap::Square {
+        // Extend the ends by half the stroke width there:
+        let first = &mut path[0];
+        first.pos += first.normal.rot90() * (0.5 * widths[0]);
+        let last = &mut path[n as usize - 1];
+        last.pos -= last.normal.rot90() * (0.5 * widths[n as usize - 1]);
+    }
+
+    let max_width = widths.iter().copied().fold(0.0, f32::max);
+    let uv_bbox = if matches!(color, ColorMode::UV(_)) {
+        Rect::from_points(&path.iter().map(|p| p.pos).collect::<Vec<Pos2>>())
+            .expand((max_width / 2.0) + feathering)
+    } else {
+        Rect::NAN
+    };
+    let get_color = |pos: Pos2| match color {
+        ColorMode::Solid(col) => *col,
+        ColorMode::UV(fun) => fun(uv_bbox, pos),
+    };
+
+    let idx = out.vertices.len() as u32;
+
+    if 0.0 < feathering {
+        // Like the thick anti-aliased lines of `stroke_and_fill_path`, with four edges per point:
+        // outer, inner, inner, outer.
+        // Where the stroke is thinner than a pixel, the inner edges meet in the middle,
+        // and we lessen the opacity instead of making it thinner.
+        let radii = |width: f32| {
+            if feathering <= width {
+                (0.5 * (width - feathering), 0.5 * (width + feathering), 1.0)
+            } else {
+                (0.0, feathering, width / feathering)
+            }
+        };
+
+        out.reserve_triangles(6 * n as usize + 4);
+        out.reserve_vertices(4 * n as usize);
+
+        for (i, point) in path.iter().enumerate() {
+            let (inner_rad, outer_rad, opacity) = radii(widths[i]);
+            let p = point.pos;
+            let normal = point.normal;
+            let back_extrude = if !open || round_cap {
+                Vec2::ZERO
+            } else if i == 0 {
+                normal.rot90() * feathering
+            } else if i + 1 == n as usize {
+                -normal.rot90() * feathering
+            } else {
+                Vec2::ZERO
+            };
+            let inner_color = |pos| mul_color(get_color(pos), opacity);
+            out.colored_vertex(p + normal * outer_rad + back_extrude, Color32::TRANSPARENT);
+            out.colored_vertex(p + normal * inner_rad, inner_color(p + normal * inner_rad));
+            out.colored_vertex(p - normal * inner_rad, inner_color(p - normal * inner_rad));
+            out.colored_vertex(p - normal * outer_rad + back_extrude, Color32::TRANSPARENT);
+        }
+
+        let num_segments = if open { n - 1 } else { n };
+        for i0 in 0..num_segments {
+            let i1 = (i0 + 1) % n;
+            for edge in 0..3 {
+                out.add_triangle(
+                    idx + 4 * i0 + edge,
+                    idx + 4 * i0 + edge + 1,
+                    idx + 4 * i1 + edge,
+                );
+                out.add_triangle(
+                    idx + 4 * i0 + edge + 1,
+                    idx + 4 * i1 + edge,
+                    idx + 4 * i1 + edge + 1,
+                );
+            }
+        }
+
+        if open {
+            let [first, last] = [path[0], path[n as usize - 1]];
+            if round_cap {
+                for (end, outward, width) in [
+                    (first, first.normal.rot90(), widths[0]),
+                    (last, -last.normal.rot90(), widths[n as usize - 1]),
+                ] {
+                    let (inner_rad, outer_rad, opacity) = radii(width);
+                    let color = |pos| mul_color(get_color(pos), opacity);
+                    add_round_cap(
+                        out, end.pos, end.normal, outward, inner_rad, outer_rad, color,
+                    );
+                }
+            } else {
+                // Close off the extruded ends:
+                for i in [0, n - 1] {
+                    out.add_triangle(idx + 4 * i + 0, idx + 4 * i + 1, idx + 4 * i + 2);
+                    out.add_triangle(idx + 4 * i + 0, idx + 4 * i + 2, idx + 4 * i + 3);
+                }
+            }
+        }
+    } else {
+        // not anti-aliased:
+        out.reserve_triangles(2 * n as usize);
+        out.reserve_vertices(2 * n as usize);
+
+        for (point, width) in path.iter().zip(widths) {
+            let radius = width / 2.0;
+            let [left, right] = [
+                point.pos + radius * point.normal,
+                point.pos - radius * point.normal,
+            ];
+            out.colored_vertex(left, get_color(left));
+            out.colored_vertex(right, get_color(right));
+        }
+
+        let num_segments = if open { n - 1 } else { n };
+        for i0 in 0..num_segments {
+            let i1 = (i0 + 1) % n;
+            out.add_triangle(idx + 2 * i0, idx + 2 * i0 + 1, idx + 2 * i1);
+            out.add_triangle(idx + 2 * i1, idx + 2 * i0 + 1, idx + 2 * i1 + 1);
+        }
+
+        if round_cap {
+            let [first, last] = [path[0], path[n as usize - 1]];
+            let radius = widths[0] / 2.0;
+            let (normal, outward) = (first.normal, first.normal.rot90());
+            add_round_cap(out, first.pos, normal, outward, radius, radius, get_color);
+            let radius = widths[n as usize - 1] / 2.0;
+            let (normal, outward) = (last.normal, -last.normal.rot90());
+            add_round_cap(out, last.pos, normal, outward, radius, radius, get_color);
+        }
+    }
+}
+
+/// How many segments to use for a circular arc, so that it is within a tenth of a point of a true circle.
+fn arc_segments(radius: f32, angle: f32) -> usize {
+    const TOLERANCE: f32 = 0.1;
+    const MAX_SEGMENTS: usize = 64;
+    if radius <= TOLERANCE {
+        return 1;
+    }
+    let max_angle_per_segment = 2.0 * (1.0 - TOLERANCE / radius).acos();
+    ((angle / max_angle_per_segment).ceil() as usize).clamp(1, MAX_SEGMENTS)
+}
+
+/// The half width of the stroke, and how far its middle is from the path, for [`Path::add_join`].
+fn join_width_and_offset(stroke: &PathStroke) -> (f32, f32) {
+    let half_width = 0.5 * stroke.width;
+    let offset = match stroke.kind {
+        StrokeKind::Inside => -half_width,
+        StrokeKind::Middle => 0.0,
+        StrokeKind::Outside => half_width,
+    };
+    (half_width, offset)
+}
+
+/// Add a half-disk at the end of an open stroke, for [`LineCap::Round`].
+///
+/// The cap goes from `center + normal * radius` around to `center - normal * radius`,
+/// bulging towards `outward`.
+/// It is opaque out to `inner_rad`, and then fades out to `outer_rad` for anti-aliasing.
+fn add_round_cap(
+    out: &mut Mesh,
+    center: Pos2,
+    normal: Vec2,
+    outward: Vec2,
+    inner_rad: f32,
+    outer_rad: f32,
+    get_color: impl Fn(Pos2) -> Color32,
+) {
+    let feathered = inner_rad < outer_rad;
+    let stride = if feathered { 2 } else { 1 };
+    let mut segments = arc_segments(outer_rad, std::f32::consts::PI);
+    segments += segments % 2; // So that there is a vertex at the very tip
+
+    out.reserve_vertices(1 + stride * (segments + 1));
+    out.reserve_triangles((1 + 2 * (stride - 1)) * segments);
+
+    let idx = out.vertices.len() as u32;
+    out.colored_vertex(center, get_color(center));
+    for i in 0..=segments {
+        let angle = std::f32::consts::PI * i as f32 / segments as f32;
+        let dir = normal * angle.cos() + outward * angle.sin();
+        let inner = center + dir * inner_rad;
+        out.colored_vertex(inner, get_color(inner));
+        if feathered {
+            out.colored_vertex(center + dir * outer_rad, Color32::TRANSPARENT);
+        }
+    }
+
+    let stride = stride as u32;
+    for i in 0..segments as u32 {
+        let a = idx + 1 + stride * i;
+        let b = a + stride;
+        out.add_triangle(idx, a, b);
+        if feathered {
+            out.add_triangle(a, a + 1, b);
+            out.add_triangle(a + 1, b, b + 1);
+        }
+    }
+}
+
 fn mul_color(color: Color32, factor: f32) -> Color32 {
     // The fast gamma-space multiply also happens to be perceptually better.
     // Win-win!
@@ -1302,12 +1743,12 @@ fn mul_color(color: Color32, factor: f32) -> Color32 {
 /// For performance reasons it is smart to reuse the same [`Tessellator`].
 #[derive(Clone)]
 pub struct Tessellator {
-    pixels_per_point: f32,
-    options: TessellationOptions,
-    font_tex_size: [usize; 2],
+    pub(crate) pixels_per_point: f32,
+    pub(crate) options: TessellationOptions,
+    pub(crate) font_tex_size: [usize; 2],
 
     /// See [`crate::TextureAtlas::prepared_discs`].
-    prepared_discs: Vec<PreparedDisc>,
+    pub(crate) prepared_discs: Vec<PreparedDisc>,
 
     /// size of feathering in points. normally the size of a physical pixel. 0.0 if disabled
     feathering: f32,
@@ -1362,7 +1803,15 @@ impl Tessellator {
         clipped_shape: ClippedShape,
         out_primitives: &mut Vec<ClippedPrimitive>,
     ) {
-        let ClippedShape { clip_rect, shape } = clipped_shape;
+        let ClippedShape {
+            mut clip_rect,
+            shape,
+            clip_mask,
+        } = clipped_shape;
+
+        if let Some(clip_mask) = &clip_mask {
+            clip_rect = clip_rect.intersect(clip_mask.bounding_rect().expand(self.feathering));
+        }
 
         if !clip_rect.is_positive() {
             return; // skip empty clip rectangles
@@ -1370,7 +1819,14 @@ impl Tessellator {
 
         if let Shape::Vec(shapes) = shape {
             for shape in shapes {
-                self.tessellate_clipped_shape(ClippedShape { clip_rect, shape }, out_primitives);
+                self.tessellate_clipped_shape(
+                    ClippedShape {
+                        clip_rect,
+                        shape,
+                        clip_mask: clip_mask.clone(),
+                    },
+                    out_primitives,
+                );
             }
             return;
         }
@@ -1407,7 +1863,13 @@ impl Tessellator {
 
         if let Primitive::Mesh(out_mesh) = &mut out.primitive {
             self.clip_rect = clip_rect;
-            self.tessellate_shape(shape, out_mesh);
+            if let Some(clip_mask) = clip_mask {
+                let mut mesh = Mesh::default();
+                self.tessellate_shape(shape, &mut mesh);
+                clip_mask.clip_mesh(&mesh, self.feathering, out_mesh);
+            } else {
+                self.tessellate_shape(shape, out_mesh);
+            }
         } else {
             unreachable!();
         }
@@ -1433,6 +1895,9 @@ impl Tessellator {
             Shape::Ellipse(ellipse) => {
                 self.tessellate_ellipse(ellipse, out);
             }
+            Shape::Arc(arc) => {
+                self.tessellate_arc(&arc, out);
+            }
             Shape::Mesh(mesh) => {
                 profiling::scope!("mesh");
 
@@ -1456,6 +1921,9 @@ impl Tessellator {
             Shape::Path(path_shape) => {
                 self.tessellate_path(&path_shape, out);
             }
+            Shape::Polygon(polygon_shape) => {
+                self.tessellate_polygon(&polygon_shape, out);
+            }
             Shape::Rect(rect_shape) => {
                 self.tessellate_rect(&rect_shape, out);
             }
@@ -1469,10 +1937,14 @@ impl Tessellator {
                 }
                 self.tessellate_text(&text_shape, out);
             }
+            Shape::TextPath(text_path_shape) => {
+                self.tessellate_text_path(&text_path_shape, out);
+            }
             Shape::QuadraticBezier(quadratic_shape) => {
                 self.tessellate_quadratic_bezier(&quadratic_shape, out);
             }
             Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(&cubic_shape, out),
+            Shape::VariableStroke(stroke) => self.tessellate_variable_stroke(&stroke, out),
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
@@ -1535,6 +2007,84 @@ impl Tessellator {
             .fill_and_stroke(self.feathering, fill, &path_stroke, out);
     }
 
+    /// Tessellate a single [`ArcShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the arc to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_arc(&mut self, shape: &ArcShape, out: &mut Mesh) {
+        if shape.radius.x <= 0.0 || shape.radius.y <= 0.0 || shape.sweep_angle() == 0.0 {
+            return;
+        }
+
+        if self.options.coarse_tessellation_culling
+            && !shape.visual_bounding_rect().intersects(self.clip_rect)
+        {
+            return;
+        }
+
+        profiling::function_scope!();
+
+        let ArcShape {
+            kind, fill, stroke, ..
+        } = shape;
+
+        let mut contours = shape.flatten(Some(self.options.bezier_tolerance));
+
+        // Everything but rings and pie slices of more than half a turn are convex:
+        let convex = match kind {
+            ArcKind::Open => true,
+            ArcKind::Sector => {
+                shape.is_full_turn() || shape.sweep_angle().abs() <= std::f32::consts::PI
+            }
+            ArcKind::AnnulusSegment { .. } => false,
+        };
+
+        if !convex {
+            let polygon = PolygonShape::new(contours, *fill, stroke.clone());
+            self.tessellate_polygon(&polygon, out);
+            return;
+        }
+
+        let points = contours.swap_remove(0);
+        if points.len() < 2 {
+            return;
+        }
+
+        self.scratchpad_path.clear();
+        if shape.is_full_turn() {
+            // No corners, so no joins:
+            self.scratchpad_path.add_line_loop(&points);
+            self.scratchpad_path
+                .fill_and_stroke(self.feathering, *fill, stroke, out);
+        } else if *kind != ArcKind::Open {
+            // Fill and stroke separately, so that the stroke can have other joins than miters,
+            // like for polygons:
+            if *fill != Color32::TRANSPARENT {
+                self.scratchpad_path.add_line_loop(&points);
+                self.scratchpad_path.fill(self.feathering, *fill, out);
+                self.scratchpad_path.clear();
+            }
+            if !stroke.is_empty() {
+                self.scratchpad_path.add_stroked_line_loop(&points, stroke);
+                self.scratchpad_path
+                    .stroke_closed(self.feathering, stroke, out);
+            }
+        } else {
+            if *fill != Color32::TRANSPARENT {
+                // Fill up to the chord between the ends:
+                self.scratchpad_path.add_line_loop(&points);
+                self.scratchpad_path.fill(self.feathering, *fill, out);
+                self.scratchpad_path.clear();
+            }
+            if !stroke.is_empty() {
+                self.scratchpad_path
+                    .add_stroked_open_points(&points, stroke);
+                self.scratchpad_path
+                    .stroke(self.feathering, PathType::Open, stroke, out);
+            }
+        }
+    }
+
     /// Tessellate a single [`EllipseShape`] into a [`Mesh`].
     ///
     /// * `shape`: the ellipse to tessellate.
@@ -1733,7 +2283,8 @@ impl Tessellator {
         self.scratchpad_path.clear();
 
         if *closed {
-            self.scratchpad_path.add_line_loop(points);
+            self.scratchpad_path
+                .add_line_loop_for(points, *fill, stroke);
 
             self.scratchpad_path
                 .fill_and_stroke(self.feathering, *fill, stroke, out);
@@ -1744,13 +2295,69 @@ impl Tessellator {
                 "You asked to fill a path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            self.scratchpad_path.add_stroked_open_points(points, stroke);
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
         }
     }
 
+    /// Tessellate a single [`PolygonShape`] into a [`Mesh`].
+    ///
+    /// * `polygon_shape`: the polygon to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_polygon(&mut self, polygon_shape: &PolygonShape, out: &mut Mesh) {
+        if self.options.coarse_tessellation_culling
+            && !polygon_shape
+                .visual_bounding_rect()
+                .intersects(self.clip_rect)
+        {
+            return;
+        }
+
+        profiling::function_scope!();
+
+        let PolygonShape {
+            contours,
+            fill_rule,
+            fill,
+            stroke,
+        } = polygon_shape;
+
+        if *fill != Color32::TRANSPARENT {
+            if 0.0 < self.feathering {
+                // Feather the outline of the filled region, like `fill_closed_path` does,
+                // and fill what is inside the inner edge of the feathering.
+                let outline = polygon_fill::outline(contours, *fill_rule);
+                let mut inner_contours = Vec::with_capacity(outline.len());
+                for contour in &outline {
+                    self.scratchpad_path.clear();
+                    self.scratchpad_path.add_line_loop(contour);
+                    inner_contours.push(feather_closed_path(
+                        self.feathering,
+                        &self.scratchpad_path.0,
+                        *fill,
+                        out,
+                    ));
+                }
+                // The outline goes clockwise around the inside, giving it a positive winding number.
+                // Where the feathering makes the outline turn inside out, the winding becomes negative.
+                polygon_fill::fill_where(&inner_contours, |winding| 0 < winding, *fill, out);
+            } else {
+                polygon_fill::fill(contours, *fill_rule, *fill, out);
+            }
+        }
+
+        if !stroke.is_empty() {
+            for contour in contours.iter().filter(|contour| 2 <= contour.len()) {
+                self.scratchpad_path.clear();
+                self.scratchpad_path.add_stroked_line_loop(contour, stroke);
+                self.scratchpad_path
+                    .stroke_closed(self.feathering, stroke, out);
+            }
+        }
+    }
+
     /// Tessellate a single [`Rect`] into a [`Mesh`].
     ///
     /// * `rect`: the rectangle to tessellate.
@@ -2101,6 +2708,76 @@ impl Tessellator {
         }
     }
 
+    /// Tessellate a single [`TextPathShape`] into a [`Mesh`].
+    ///
+    /// * `text_path_shape`: the text to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_text_path(&self, text_path_shape: &TextPathShape, out: &mut Mesh) {
+        let TextPathShape {
+            galley,
+            fallback_color,
+            override_text_color,
+            opacity_factor,
+            ..
+        } = text_path_shape;
+
+        if galley.is_empty() || *opacity_factor <= 0.0 {
+            return;
+        }
+
+        if galley.pixels_per_point != self.pixels_per_point {
+            log::warn!(
+                "epaint: WARNING: pixels_per_point (dpi scale) have changed between text layout and tessellation. \
+                       You must recreate your text shapes if pixels_per_point changes."
+            );
+        }
+
+        profiling::function_scope!();
+
+        let uv_normalizer = vec2(
+            1.0 / self.font_tex_size[0] as f32,
+            1.0 / self.font_tex_size[1] as f32,
+        );
+        let clip_rect = self.clip_rect;
+        let culling = self.options.coarse_tessellation_culling;
+
+        text_path_shape.for_each_glyph(|row, vertices, placement| {
+            let glyph_vertices = &row.visuals.mesh.vertices[vertices];
+            let positions: Vec<Pos2> = glyph_vertices
+                .iter()
+                .map(|vertex| placement.place(row.pos + vertex.pos.to_vec2()))
+                .collect();
+
+            // Each glyph is placed separately, so we cull them separately:
+            if culling && !Rect::from_points(&positions).intersects(clip_rect) {
+                return;
+            }
+
+            let idx = out.vertices.len() as u32;
+            for (vertex, pos) in glyph_vertices.iter().zip(positions) {
+                let mut color = override_text_color.unwrap_or(vertex.color);
+                if color == Color32::PLACEHOLDER {
+                    color = *fallback_color;
+                }
+                if *opacity_factor < 1.0 {
+                    color = color.gamma_multiply(*opacity_factor);
+                }
+                out.vertices.push(Vertex {
+                    pos,
+                    uv: (vertex.uv.to_vec2() * uv_normalizer).to_pos2(),
+                    color,
+                });
+            }
+
+            // Each glyph is a quad, like `Mesh::add_rect_with_uv` makes them:
+            for quad in 0..glyph_vertices.len() as u32 / 4 {
+                let i = idx + 4 * quad;
+                out.add_triangle(i, i + 1, i + 2);
+                out.add_triangle(i + 2, i + 1, i + 3);
+            }
+        });
+    }
+
     /// Tessellate a single [`QuadraticBezierShape`] into a [`Mesh`].
     ///
     /// * `quadratic_shape`: the shape to tessellate.
@@ -2157,6 +2834,93 @@ impl Tessellator {
         }
     }
 
+    /// Tessellate a single [`VariableStrokeShape`] into a [`Mesh`].
+    ///
+    /// * `shape`: the stroke to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_variable_stroke(&mut self, shape: &VariableStrokeShape, out: &mut Mesh) {
+        if shape.points.len() < 2 || shape.color == ColorMode::TRANSPARENT {
+            return;
+        }
+
+        profiling::function_scope!();
+
+        let (sampled_points, sampled_widths) = shape.points_and_widths();
+
+        if self.options.coarse_tessellation_culling {
+            let max_width = sampled_widths.iter().copied().fold(0.0, f32::max);
+            let bounding_rect = Rect::from_points(&sampled_points).expand(max_width / 2.0);
+            if !bounding_rect.intersects(self.clip_rect) {
+                return;
+            }
+        }
+
+        // Skip repeated points, so that every segment has a direction:
+        let mut points: Vec<Pos2> = Vec::with_capacity(sampled_points.len());
+        let mut point_widths = Vec::with_capacity(sampled_points.len());
+        for (point, width) in sampled_points.into_iter().zip(sampled_widths) {
+            if points.last() != Some(&point) {
+                points.push(point);
+                point_widths.push(width);
+            }
+        }
+        let mut closed = shape.closed;
+        if closed && 2 < points.len() && points.first() == points.last() {
+            points.pop();
+            point_widths.pop();
+        }
+        closed &= 3 <= points.len();
+
+        let n = points.len();
+        if n < 2 || point_widths.iter().all(|width| *width <= 0.0) {
+            return;
+        }
+
+        // The normal of the segment from point `i` to the next one:
+        let segment_normal = |i: usize| (points[(i + 1) % n] - points[i]).normalized().rot90();
+
+        self.scratchpad_path.clear();
+        self.scratchpad_path.reserve(n);
+        let mut path_widths = Vec::with_capacity(n);
+        for i in 0..n {
+            let (n0, n1) = if closed {
+                (segment_normal((i + n - 1) % n), segment_normal(i))
+            } else {
+                // The ends only have one segment each:
+                (
+                    segment_normal(i.saturating_sub(1)),
+                    segment_normal(i.min(n - 2)),
+                )
+            };
+            self.scratchpad_path.add_join(
+                points[i],
+                n0,
+                n1,
+                shape.join,
+                0.5 * point_widths[i],
+                0.0,
+            );
+
+            // Joins can add several points, all with the same width:
+            path_widths.resize(self.scratchpad_path.0.len(), point_widths[i]);
+        }
+
+        let path_type = if closed {
+            PathType::Closed
+        } else {
+            PathType::Open
+        };
+        stroke_path_with_widths(
+            self.feathering,
+            &mut self.scratchpad_path.0,
+            &path_widths,
+            path_type,
+            &shape.color,
+            shape.cap,
+            out,
+        );
+    }
+
     fn tessellate_bezier_complete(
         &mut self,
         points: &[Pos2],
@@ -2171,7 +2935,7 @@ impl Tessellator {
 
         self.scratchpad_path.clear();
         if closed {
-            self.scratchpad_path.add_line_loop(points);
+            self.scratchpad_path.add_line_loop_for(points, fill, stroke);
 
             self.scratchpad_path
                 .fill_and_stroke(self.feathering, fill, stroke, out);
@@ -2182,7 +2946,7 @@ impl Tessellator {
                 "You asked to fill a bezier path that is not closed. That makes no sense."
             );
 
-            self.scratchpad_path.add_open_points(points);
+            self.scratchpad_path.add_stroked_open_points(points, stroke);
 
             self.scratchpad_path
                 .stroke(self.feathering, PathType::Open, stroke, out);
@@ -2251,6 +3015,24 @@ impl Tessellator {
         clipped_primitives
     }
 
+    /// Like [`Self::tessellate_shapes`], but reuses the meshes of shapes
+    /// that haven't changed since the last time the same cache was used.
+    ///
+    /// Does not use the cache if [`TessellationOptions::cache_meshes`] is off.
+    /// See [`TessellationCache`] for what is cached.
+    pub fn tessellate_shapes_with_cache(
+        &mut self,
+        mut shapes: Vec<ClippedShape>,
+        cache: &mut TessellationCache,
+    ) -> Vec<ClippedPrimitive> {
+        if self.options.cache_meshes {
+            cache.replace_with_meshes(self, &mut shapes);
+        } else {
+            cache.clear();
+        }
+        self.tessellate_shapes(shapes)
+    }
+
     /// Find large shapes and throw them on the rayon thread pool,
     /// then replace the original shape with their tessellated meshes.
     #[cfg(feature = "rayon")]
@@ -2268,10 +3050,20 @@ impl Tessellator {
 
                 Shape::Path(path_shape) => 32 < path_shape.points.len(),
 
-                Shape::QuadraticBezier(_) | Shape::CubicBezier(_) | Shape::Ellipse(_) => true,
+                Shape::VariableStroke(stroke) => 32 < stroke.points.len(),
+
+                Shape::Polygon(polygon_shape) => {
+                    32 < polygon_shape.contours.iter().map(Vec::len).sum::<usize>()
+                }
+
+                Shape::QuadraticBezier(_)
+                | Shape::CubicBezier(_)
+                | Shape::Ellipse(_)
+                | Shape::Arc(_) => true,
 
                 Shape::Noop
                 | Shape::Text(_)
+                | Shape::TextPath(_)
                 | Shape::Circle(_)
                 | Shape::Mesh(_)
                 | Shape::LineSegment { .. }
@@ -2351,10 +3143,7 @@ fn test_tessellator() {
     shapes.push(Shape::mesh(mesh));
 
     let shape = Shape::Vec(shapes);
-    let clipped_shapes = vec![ClippedShape {
-        clip_rect: rect,
-        shape,
-    }];
+    let clipped_shapes = vec![ClippedShape::new(rect, shape)];
 
     let font_tex_size = [1024, 1024]; // unused
     let prepared_discs = vec![]; // unused
@@ -2409,3 +3198,433 @@ fn path_bounding_box() {
         );
     }
 }
+
+#[test]
+fn line_caps() {
+    use crate::*;
+
+    let stroke_bounds = |cap: LineCap, feathering: f32| {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        path.add_open_points(&[pos2(0.0, 0.0), pos2(100.0, 0.0)]);
+        path.stroke_open(
+            feathering,
+            &PathStroke::new(10.0, Color32::WHITE).with_cap(cap),
+            &mut mesh,
+        );
+        mesh.calc_bounds()
+    };
+
+    for feathering in [0.0, 1.0] {
+        let butt = stroke_bounds(LineCap::Butt, feathering);
+        assert!(butt.min.x <= 0.0 && -1.0 <= butt.min.x, "{butt:?}");
+
+        // Extends half the stroke width past the butt end:
+        let square = stroke_bounds(LineCap::Square, feathering);
+        assert!(
+            (square.min.x - (butt.min.x - 5.0)).abs() < 0.01,
+            "{square:?}"
+        );
+        assert!(
+            (square.max.x - (butt.max.x + 5.0)).abs() < 0.01,
+            "{square:?}"
+        );
+        assert_eq!(square.y_range(), butt.y_range());
+
+        // A half-circle with the radius of the stroke (plus feathering):
+        let round = stroke_bounds(LineCap::Round, feathering);
+        let radius = 5.0 + 0.5 * feathering;
+        assert!((round.min.x + radius).abs() < 0.01, "{round:?}");
+        assert!((round.max.x - 100.0 - radius).abs() < 0.01, "{round:?}");
+        assert_eq!(round.y_range(), butt.y_range());
+    }
+}
+
+#[test]
+fn line_segment_caps() {
+    use crate::*;
+
+    let mut tessellator = Tessellator::new(1.0, TessellationOptions::default(), [1, 1], vec![]);
+    let mut segment_bounds = |cap: LineCap| {
+        let mut mesh = Mesh::default();
+        let stroke = Stroke::new(10.0, Color32::WHITE).with_cap(cap);
+        tessellator.tessellate_line_segment([pos2(0.0, 0.5), pos2(100.0, 0.5)], stroke, &mut mesh);
+        mesh.calc_bounds()
+    };
+
+    let butt = segment_bounds(LineCap::Butt);
+    assert!(butt.width() < 102.0, "{butt:?}");
+
+    // A half-circle with the radius of the stroke at each end:
+    let round = segment_bounds(LineCap::Round);
+    assert!((round.width() - 110.0).abs() < 1.0, "{round:?}");
+}
+
+#[test]
+fn line_joins() {
+    use crate::*;
+
+    // A very sharp corner at (100, 0):
+    let points = [pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 10.0)];
+
+    let stroke_right_edge = |join: LineJoin| {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        let stroke = PathStroke::new(10.0, Color32::WHITE).with_join(join);
+        path.add_stroked_open_points(&points, &stroke);
+        path.stroke_open(0.0, &stroke, &mut mesh);
+        mesh.calc_bounds().max.x
+    };
+
+    let miter = stroke_right_edge(LineJoin::Miter { min_angle: 0 });
+    assert!(
+        150.0 < miter,
+        "The miter should make a long spike, got {miter}"
+    );
+
+    // Cut off at half the stroke width (perpendicular to the corner, which is not quite along x):
+    let clipped = stroke_right_edge(LineJoin::default());
+    assert!(105.0 < clipped && clipped < 106.0, "{clipped}");
+
+    // A round join reaches half the stroke width in all directions:
+    let round = stroke_right_edge(LineJoin::Round);
+    assert!((round - 105.0).abs() < 0.01, "{round}");
+
+    // A bevel is a bit shorter than that:
+    let bevel = stroke_right_edge(LineJoin::Bevel);
+    assert!(100.0 < bevel && bevel < 101.0, "{bevel}");
+}
+
+#[test]
+fn line_joins_do_not_overlap_on_the_inside() {
+    use crate::*;
+
+    // A right angle, turning clockwise at (100, 0):
+    let points = [pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(100.0, 100.0)];
+
+    let covered_area = |join: LineJoin, kind: StrokeKind| {
+        let mut mesh = Mesh::default();
+        let mut path = Path::default();
+        let stroke = PathStroke::new(10.0, Color32::WHITE)
+            .with_join(join)
+            .with_kind(kind);
+        path.add_stroked_open_points(&points, &stroke);
+        path.stroke_open(0.0, &stroke, &mut mesh);
+        mesh.indices
+            .chunks_exact(3)
+            .map(|triangle| {
+                let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+                0.5 * ((b - a).x * (c - a).y - (b - a).y * (c - a).x).abs()
+            })
+            .sum::<f32>()
+    };
+
+    // Two 100x10 rectangles overlapping in a 5x5 square, plus the outer corner.
+    let bevel = covered_area(LineJoin::Bevel, StrokeKind::Middle);
+    assert!((bevel - (1975.0 + 12.5)).abs() < 0.1, "{bevel}");
+
+    let round = covered_area(LineJoin::Round, StrokeKind::Middle);
+    let quarter_disc = 0.25 * std::f32::consts::PI * 25.0;
+    assert!(
+        1987.5 < round && round < 1975.0 + quarter_disc + 0.1,
+        "{round}"
+    );
+
+    // Outside the path (to the left), the stroke is on the outside of the corner,
+    // and the inner side of the stroke follows the path:
+    let outside = covered_area(LineJoin::Bevel, StrokeKind::Outside);
+    assert!((outside - (2000.0 + 50.0)).abs() < 0.1, "{outside}");
+}
+
+#[test]
+fn filled_shapes_keep_mitered_corners() {
+    use crate::*;
+
+    // A very sharp corner at (100, 0):
+    let points = vec![pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(0.0, 10.0)];
+    let stroke = PathStroke::new(2.0, Color32::WHITE);
+
+    let right_edge = |fill: Color32| {
+        let mut mesh = Mesh::default();
+        let mut tessellator = Tessellator::new(1.0, TessellationOptions::default(), [1, 1], vec![]);
+        let shape = PathShape::convex_polygon(points.clone(), fill, stroke.clone());
+        tessellator.tessellate_path(&shape, &mut mesh);
+        mesh.calc_bounds().max.x
+    };
+
+    assert!(
+        110.0 < right_edge(Color32::RED),
+        "The filled corner should be mitered"
+    );
+    assert!(
+        right_edge(Color32::TRANSPARENT) < 102.0,
+        "The stroked corner should be cut off"
+    );
+}
+
+#[test]
+fn dashes_around_corners() {
+    let path = [pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 10.0)];
+
+    // Straight dashes are plain line segments:
+    let stroke = Stroke::new(2.0, Color32::WHITE).with_cap(LineCap::Round);
+    let dashes = Shape::dashed_line(&path, stroke, 5.0, 2.0);
+    assert_eq!(dashes.len(), 3);
+    assert_eq!(
+        dashes[0],
+        Shape::line_segment([pos2(0.0, 0.0), pos2(5.0, 0.0)], stroke)
+    );
+
+    // The dash from 7 to 12 along the path goes around the corner:
+    let Shape::Path(corner_dash) = &dashes[1] else {
+        panic!("Expected a path, got {:?}", dashes[1]);
+    };
+    assert_eq!(
+        corner_dash.points,
+        [pos2(7.0, 0.0), pos2(10.0, 0.0), pos2(10.0, 2.0)]
+    );
+    assert_eq!(corner_dash.stroke.cap, LineCap::Round);
+}
+
+#[test]
+fn concave_polygon_with_hole() {
+    use crate::*;
+
+    // A "C" shape, open to the right, with a square hole in its back:
+    let c_shape = vec![
+        pos2(0.0, 0.0),
+        pos2(30.0, 0.0),
+        pos2(30.0, 10.0),
+        pos2(10.0, 10.0),
+        pos2(10.0, 20.0),
+        pos2(30.0, 20.0),
+        pos2(30.0, 30.0),
+        pos2(0.0, 30.0),
+    ];
+    let hole = vec![
+        pos2(2.0, 12.0),
+        pos2(2.0, 18.0),
+        pos2(8.0, 18.0),
+        pos2(8.0, 12.0),
+    ];
+    let polygon = PolygonShape::new(vec![c_shape, hole], Color32::WHITE, Stroke::NONE);
+
+    let covered = |mesh: &Mesh, p: Pos2| {
+        mesh.indices.chunks_exact(3).any(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| mesh.vertices[triangle[i] as usize].pos);
+            let side = |a: Pos2, b: Pos2| (b - a).x * (p - a).y - (b - a).y * (p - a).x;
+            let sides = [side(a, b), side(b, c), side(c, a)];
+            sides.iter().all(|&s| 0.0 < s) || sides.iter().all(|&s| s < 0.0)
+        })
+    };
+
+    for feathering in [false, true] {
+        let options = TessellationOptions {
+            feathering,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_polygon(&polygon, &mut mesh);
+        assert!(mesh.is_valid());
+
+        assert!(covered(&mesh, pos2(20.0, 5.0)));
+        assert!(covered(&mesh, pos2(5.0, 25.0)));
+        assert!(
+            !covered(&mesh, pos2(20.0, 15.0)),
+            "The notch should be empty"
+        );
+        assert!(!covered(&mesh, pos2(5.0, 15.0)), "The hole should be empty");
+
+        let bounds = mesh.calc_bounds();
+        let margin = if feathering { 0.5 } else { 0.0 };
+        assert_eq!(bounds, polygon.visual_bounding_rect().expand(margin));
+    }
+}
+
+#[test]
+fn polygon_feathering_has_no_spikes() {
+    use crate::*;
+
+    // A square with a very sharp notch cut into its top:
+    let contour = vec![
+        pos2(0.0, 0.0),
+        pos2(49.0, 0.0),
+        pos2(50.0, 90.0),
+        pos2(51.0, 0.0),
+        pos2(100.0, 0.0),
+        pos2(100.0, 100.0),
+        pos2(0.0, 100.0),
+    ];
+    let polygon = PolygonShape::new(vec![contour.clone()], Color32::WHITE, Stroke::NONE);
+
+    let feathering = 1.0;
+    let mut tessellator = Tessellator::new(1.0, TessellationOptions::default(), [1, 1], vec![]);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_polygon(&polygon, &mut mesh);
+    assert!(mesh.is_valid());
+
+    // The outer edge of the feathering should stay close to the outline:
+    let outer_vertices = mesh
+        .vertices
+        .iter()
+        .filter(|vertex| vertex.color == Color32::TRANSPARENT);
+    for vertex in outer_vertices {
+        let distance = contour
+            .iter()
+            .map(|point| point.distance(vertex.pos))
+            .fold(f32::INFINITY, f32::min);
+        assert!(
+            distance <= feathering + 1e-3,
+            "{:?} is {distance} from the outline",
+            vertex.pos
+        );
+    }
+}
+
+#[test]
+fn clip_mask_is_applied() {
+    use crate::*;
+
+    let mask = ClipMask::circle(pos2(50.0, 50.0), 20.0);
+    let shapes = vec![
+        ClippedShape::new(
+            Rect::EVERYTHING,
+            Shape::Vec(vec![
+                Shape::rect_filled(
+                    Rect::from_min_size(pos2(0.0, 0.0), vec2(100.0, 100.0)),
+                    0.0,
+                    Color32::RED,
+                ),
+                Shape::circle_filled(pos2(10.0, 10.0), 5.0, Color32::RED),
+            ]),
+        )
+        .with_clip_mask(mask.clone()),
+    ];
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let primitives = tessellator.tessellate_shapes(shapes);
+    assert_eq!(primitives.len(), 1);
+    assert_eq!(primitives[0].clip_rect, mask.bounding_rect().expand(1.0));
+
+    let Primitive::Mesh(mesh) = &primitives[0].primitive else {
+        panic!("Expected a mesh");
+    };
+    assert!(mesh.is_valid());
+    assert!(mesh.calc_bounds().max.x <= 70.6);
+    for vertex in &mesh.vertices {
+        assert!(vertex.pos.distance(pos2(50.0, 50.0)) < 20.6, "{vertex:?}");
+    }
+}
+
+#[test]
+fn variable_stroke_widths() {
+    use crate::*;
+
+    for feathering in [0.0, 1.0] {
+        let mut tessellator = Tessellator::new(
+            1.0 / feathering.at_least(0.5),
+            TessellationOptions {
+                feathering: 0.0 < feathering,
+                ..Default::default()
+            },
+            [1, 1],
+            vec![],
+        );
+
+        // Tapering from 2 to 20 wide:
+        let shape = VariableStrokeShape::new(
+            vec![pos2(0.0, 0.0), pos2(50.0, 0.0), pos2(100.0, 0.0)],
+            10.0,
+            Color32::WHITE,
+        )
+        .with_cap(LineCap::Butt)
+        .with_profile(vec![0.2, 2.0]);
+
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_variable_stroke(&shape, &mut mesh);
+        assert!(mesh.is_valid(), "Invalid mesh");
+
+        let bounds = mesh.calc_bounds();
+        let outer_rad = 10.0 + 0.5 * feathering;
+        assert!((bounds.max.y - outer_rad).abs() < 0.01, "{bounds:?}");
+        assert!((bounds.min.y + outer_rad).abs() < 0.01, "{bounds:?}");
+
+        // Narrow at the start:
+        for vertex in &mesh.vertices {
+            if vertex.pos.x < 1.0 {
+                assert!(vertex.pos.y.abs() <= 1.0 + feathering, "{vertex:?}");
+            }
+        }
+
+        // Joins of a closed path:
+        let shape = VariableStrokeShape::new(
+            vec![pos2(0.0, 0.0), pos2(100.0, 0.0), pos2(50.0, 80.0)],
+            8.0,
+            Color32::WHITE,
+        )
+        .closed()
+        .with_profile(vec![1.0, 0.0, 1.0]);
+        for join in [LineJoin::Round, LineJoin::Bevel, LineJoin::default()] {
+            let mut mesh = Mesh::default();
+            tessellator.tessellate_variable_stroke(&shape.clone().with_join(join), &mut mesh);
+            assert!(mesh.is_valid(), "Invalid mesh for {join:?}");
+            assert!(!mesh.is_empty(), "Empty mesh for {join:?}");
+        }
+    }
+}
+
+#[test]
+fn arcs_sectors_and_rings() {
+    use crate::*;
+    use std::f32::consts::{PI, TAU};
+
+    let center = pos2(100.0, 100.0);
+    let radius = vec2(40.0, 20.0);
+    // Round joins, so that the corners stay within the visual bounding rect:
+    let stroke = PathStroke::new(2.0, Color32::WHITE).with_join(LineJoin::Round);
+    let shapes = [
+        ArcShape::arc(center, radius, 0.0..=PI, stroke.clone()),
+        ArcShape::arc(center, radius, -0.5..=TAU, stroke.clone()),
+        ArcShape::sector(center, radius, 0.0..=1.0, Color32::RED, stroke.clone()),
+        ArcShape::sector(center, radius, 0.0..=-5.0, Color32::RED, stroke.clone()),
+        ArcShape::annulus_segment(
+            center,
+            radius,
+            0.5 * radius,
+            1.0..=3.0,
+            Color32::RED,
+            stroke.clone(),
+        ),
+        ArcShape::annulus_segment(
+            center,
+            radius,
+            0.5 * radius,
+            0.0..=TAU,
+            Color32::RED,
+            stroke,
+        ),
+    ];
+
+    for feathering in [false, true] {
+        let options = TessellationOptions {
+            feathering,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1, 1], vec![]);
+        for shape in &shapes {
+            let mut mesh = Mesh::default();
+            tessellator.tessellate_arc(shape, &mut mesh);
+            assert!(mesh.is_valid(), "Invalid mesh for {shape:?}");
+            assert!(!mesh.is_empty(), "Empty mesh for {shape:?}");
+
+            let bounds = mesh.calc_bounds();
+            let expected = shape.visual_bounding_rect();
+            let slack = if feathering { 1.0 } else { 0.1 };
+            assert!(
+                expected.expand(slack).contains_rect(bounds),
+                "{shape:?}: {bounds:?} is outside {expected:?}"
+            );
+        }
+    }
+}
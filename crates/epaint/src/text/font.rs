@@ -133,6 +133,22 @@ pub struct GlyphAllocation {
     pub uv_rect: UvRect,
 }
 
+/// Which glyph of which font face a [`super::Glyph`] shows, and at what size.
+///
+/// Used to look up the outline of the glyph, e.g. when exporting text as vector paths.
+/// This is the size the glyph was laid out with, before any transform of the galley.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct GlyphFace {
+    pub face: FontFaceKey,
+    pub id: ab_glyph::GlyphId,
+
+    /// From font units to points.
+    pub scale: f32,
+
+    /// How far below the baseline of the glyph the outline goes, in points.
+    pub y_offset: f32,
+}
+
 #[derive(Hash, PartialEq, Eq)]
 struct GlyphCacheKey(u64);
 
@@ -221,6 +237,11 @@ impl FontImpl {
         )
     }
 
+    #[inline]
+    pub(super) fn ab_glyph_font(&self) -> &ab_glyph::FontArc {
+        &self.ab_glyph_font
+    }
+
     /// An un-ordered iterator over all supported characters.
     fn characters(&self) -> impl Iterator<Item = char> + '_ {
         self.ab_glyph_font
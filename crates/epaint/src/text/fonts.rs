@@ -800,6 +800,15 @@ impl FontsImpl {
         }
     }
 
+    /// All the font faces, for looking up glyph outlines.
+    pub(crate) fn font_faces(
+        &self,
+    ) -> impl Iterator<Item = (FontFaceKey, &ab_glyph::FontArc)> + '_ {
+        self.fonts_by_id
+            .iter()
+            .map(|(key, font_impl)| (*key, font_impl.ab_glyph_font()))
+    }
+
     /// Get the right font implementation from [`FontFamily`].
     pub fn font(&mut self, family: &FontFamily) -> Font<'_> {
         let cached_family = self.family_cache.entry(family.clone()).or_insert_with(|| {
@@ -6,7 +6,7 @@ use crate::{
     Color32, Mesh, Stroke, Vertex,
     stroke::PathStroke,
     text::{
-        font::{ScaledMetrics, is_cjk, is_cjk_break_allowed},
+        font::{GlyphFace, ScaledMetrics, is_cjk, is_cjk_break_allowed},
         fonts::FontFaceKey,
     },
 };
@@ -231,6 +231,7 @@ fn layout_section(
                 uv_rect: glyph_alloc.uv_rect,
                 section_index,
                 first_vertex: 0, // filled in later
+                face: glyph_face(font_id, glyph_info.id, &current_font_impl_metrics),
             });
 
             paragraph.cursor_x_px += glyph_alloc.advance_width_px;
@@ -239,6 +240,19 @@ fn layout_section(
     }
 }
 
+fn glyph_face(
+    face: FontFaceKey,
+    id: Option<ab_glyph::GlyphId>,
+    metrics: &ScaledMetrics,
+) -> Option<GlyphFace> {
+    Some(GlyphFace {
+        face,
+        id: id?,
+        scale: metrics.px_scale_factor / metrics.pixels_per_point,
+        y_offset: metrics.y_offset_in_points,
+    })
+}
+
 /// Calculate the intrinsic size of the text.
 ///
 /// The result is eventually passed to `Response::intrinsic_size`.
@@ -533,6 +547,7 @@ fn replace_last_glyph_with_overflow_character(
                 uv_rect: replacement_glyph_alloc.uv_rect,
                 section_index,
                 first_vertex: 0, // filled in later
+                face: glyph_face(font_id, glyph_info.id, &font_impl_metrics),
             });
             return;
         }
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use super::{
     cursor::{CCursor, LayoutCursor},
-    font::UvRect,
+    font::{GlyphFace, UvRect},
 };
 use crate::{Color32, FontId, Mesh, Stroke, text::FontsView};
 use emath::{Align, GuiRounding as _, NumExt as _, OrderedFloat, Pos2, Rect, Vec2, pos2, vec2};
@@ -704,6 +704,10 @@ pub struct Glyph {
 
     /// Which is our first vertex in [`RowVisuals::mesh`].
     pub first_vertex: u32,
+
+    /// The font face and glyph this is drawn with, if any.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) face: Option<GlyphFace>,
 }
 
 impl Glyph {
@@ -0,0 +1,40 @@
+//! Helpers shared by the integration tests.
+
+#![allow(dead_code)] // not every test uses every helper
+
+use egui::Pos2;
+use egui_kittest::Harness;
+
+/// How many frames [`drag`] spends moving the pointer from start to end.
+///
+/// Spreading the drag over several frames catches bugs in drag thresholds
+/// and in state that accumulates the pointer delta over the drag.
+pub const DRAG_STEPS: usize = 10;
+
+/// Press the primary button at `from`, move to `to` over [`DRAG_STEPS`] frames, and release.
+///
+/// Only a single frame is run after the release, so any animation that follows can be inspected.
+pub fn drag<State>(harness: &mut Harness<'_, State>, from: Pos2, to: Pos2) {
+    drag_and_hold(harness, from, to, 0);
+}
+
+/// Like [`drag`], but keeps the pointer still at `to` for `frames_still_before_release` frames
+/// before releasing it.
+pub fn drag_and_hold<State>(
+    harness: &mut Harness<'_, State>,
+    from: Pos2,
+    to: Pos2,
+    frames_still_before_release: usize,
+) {
+    harness.hover_at(from);
+    harness.step();
+    harness.drag_at(from);
+    harness.step();
+    for i in 1..=DRAG_STEPS {
+        harness.hover_at(from.lerp(to, i as f32 / DRAG_STEPS as f32));
+        harness.step();
+    }
+    harness.run_steps(frames_still_before_release);
+    harness.drop_at(to);
+    harness.step();
+}
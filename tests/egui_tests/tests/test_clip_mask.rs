@@ -0,0 +1,108 @@
+use egui::{ClipMask, Color32, Frame, Rect, Shape, pos2, vec2};
+use egui_kittest::Harness;
+
+#[test]
+fn painter_clip_mask() {
+    let mask = ClipMask::circle(pos2(50.0, 50.0), 20.0);
+
+    let mut harness = Harness::new_ui(|ui| {
+        let rect = Rect::from_min_size(pos2(30.0, 30.0), vec2(40.0, 40.0));
+        ui.painter().rect_filled(rect, 0.0, Color32::RED);
+        ui.painter()
+            .with_clip_mask(ClipMask::circle(pos2(50.0, 50.0), 20.0))
+            .rect_filled(rect, 0.0, Color32::BLUE);
+    });
+    harness.run();
+
+    let masks: Vec<_> = harness
+        .output()
+        .shapes
+        .iter()
+        .filter_map(|clipped| match &clipped.shape {
+            Shape::Rect(rect) => Some((rect.fill, clipped.clip_mask())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        masks[masks.len() - 2..],
+        [(Color32::RED, None), (Color32::BLUE, Some(&mask))]
+    );
+}
+
+#[test]
+fn frame_clip_content() {
+    let mut harness = Harness::new_ui(|ui| {
+        let mut frame = Frame::NONE
+            .fill(Color32::GRAY)
+            .corner_radius(10.0)
+            .inner_margin(4.0)
+            .begin(ui);
+        frame.content_ui.label("Clipped");
+        frame.clip_content(ui);
+        frame.end(ui);
+
+        ui.label("Not clipped");
+    });
+    harness.run();
+
+    let shapes = &harness.output().shapes;
+    let frame_index = shapes
+        .iter()
+        .position(
+            |clipped| matches!(&clipped.shape, Shape::Rect(rect) if rect.fill == Color32::GRAY),
+        )
+        .unwrap();
+
+    // The frame itself is not clipped, but the label inside it is:
+    assert!(shapes[frame_index].clip_mask().is_none());
+    let Some(ClipMask::RoundedRect {
+        rect,
+        corner_radius,
+    }) = shapes[frame_index + 1].clip_mask()
+    else {
+        panic!("Expected the frame contents to have a clip mask");
+    };
+    assert_eq!(corner_radius.nw, 10.0);
+    assert!(rect.contains_rect(shapes[frame_index + 1].shape.visual_bounding_rect()));
+    assert!(shapes[frame_index + 2].clip_mask().is_none());
+}
+
+#[test]
+fn frame_show_clip_content() {
+    let mut harness = Harness::new_ui(|ui| {
+        Frame::NONE
+            .fill(Color32::GRAY)
+            .corner_radius(10.0)
+            .inner_margin(4.0)
+            .clip_content(true)
+            .show(ui, |ui| {
+                ui.label("Clipped");
+            });
+
+        Frame::NONE
+            .fill(Color32::DARK_GRAY)
+            .corner_radius(10.0)
+            .show(ui, |ui| {
+                ui.label("Not clipped");
+            });
+    });
+    harness.run();
+
+    let shapes = &harness.output().shapes;
+    let frame_index = |fill| {
+        shapes
+            .iter()
+            .position(|clipped| matches!(&clipped.shape, Shape::Rect(rect) if rect.fill == fill))
+            .unwrap()
+    };
+
+    let clipped = frame_index(Color32::GRAY);
+    assert!(shapes[clipped].clip_mask().is_none());
+    assert!(matches!(
+        shapes[clipped + 1].clip_mask(),
+        Some(ClipMask::RoundedRect { .. })
+    ));
+
+    let not_clipped = frame_index(Color32::DARK_GRAY);
+    assert!(shapes[not_clipped + 1].clip_mask().is_none());
+}
@@ -0,0 +1,111 @@
+use std::cell::Cell;
+
+use egui::{ComboBox, Key, Vec2, accesskit::Role};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+const FRUITS: [&str; 5] = ["Apple", "Banana", "Blueberry", "Cherry", "Grape"];
+
+#[test]
+fn filterable_combo_box_selects_with_keyboard() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, selected: &mut usize| {
+                ComboBox::from_label("Fruit").filterable(true).show_index(
+                    ui,
+                    selected,
+                    FRUITS.len(),
+                    |i| FRUITS[i],
+                );
+            },
+            0,
+        );
+    harness.run();
+
+    harness.get_by_label("Fruit").click();
+    harness.run();
+
+    // The filter field is focused when the popup opens:
+    harness.get_by_role(Role::TextInput).type_text("bry");
+    harness.run();
+    assert!(harness.query_by_label("Apple").is_none());
+    assert!(harness.query_by_label("Cherry").is_none());
+
+    // "Blueberry" has a better match than "Banana", so it is highlighted first:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(*harness.state(), 2);
+    assert!(
+        harness.query_by_role(Role::TextInput).is_none(),
+        "popup closed"
+    );
+
+    harness.get_by_label("Fruit").click();
+    harness.run();
+    harness.key_press(Key::ArrowDown);
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(*harness.state(), 3);
+}
+
+#[test]
+fn editable_combo_box_allows_free_text() {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            |ui, text: &mut String| {
+                ComboBox::from_label("Fruit").show_editable(ui, text, FRUITS.len(), |i| FRUITS[i]);
+            },
+            String::new(),
+        );
+    harness.run();
+
+    harness.get_by_role(Role::TextInput).focus();
+    harness.run();
+    harness.get_by_role(Role::TextInput).type_text("gr");
+    harness.run();
+    assert!(
+        harness.query_by_label("Grape").is_some(),
+        "suggestions shown"
+    );
+    assert!(harness.query_by_label("Apple").is_none());
+
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(harness.state(), "Grape");
+
+    harness.get_by_role(Role::TextInput).type_text("fruit");
+    harness.run();
+    assert_eq!(harness.state(), "Grapefruit");
+}
+
+#[test]
+fn closed_combo_boxes_only_get_the_selected_entry() {
+    let entries: Vec<String> = (0..1000).map(|i| format!("Entry {i}")).collect();
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(300.0, 300.0))
+        .build_ui_state(
+            move |ui, (selected, text, gets): &mut (usize, String, Cell<usize>)| {
+                ComboBox::from_label("Filtered")
+                    .filterable(true)
+                    .show_index(ui, selected, entries.len(), |i| {
+                        gets.set(gets.get() + 1);
+                        entries[i].as_str()
+                    });
+                ComboBox::from_label("Editable").show_editable(ui, text, entries.len(), |i| {
+                    gets.set(gets.get() + 1);
+                    entries[i].as_str()
+                });
+            },
+            (0, String::new(), Cell::new(0)),
+        );
+    harness.run();
+    harness.state().2.set(0);
+    harness.step();
+    assert_eq!(
+        harness.state().2.get(),
+        1,
+        "Only the selected entry is shown"
+    );
+}
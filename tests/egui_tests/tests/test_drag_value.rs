@@ -0,0 +1,58 @@
+use egui::{DragValue, Key, Units, accesskit::Role};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn type_into_drag_value(harness: &mut Harness<'_, f64>, text: &str) {
+    harness.get_by_role(Role::SpinButton).click();
+    harness.run();
+    harness.get_by_role(Role::SpinButton).type_text(text);
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+}
+
+#[test]
+fn typed_expression_is_committed() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, value: &mut f64| {
+            ui.add(DragValue::new(value).expressions(true));
+        },
+        5.0,
+    );
+    harness.run();
+
+    // Typing replaces the selected text:
+    type_into_drag_value(&mut harness, "-2^2 + 3*(1 + 1)");
+    assert_eq!(*harness.state(), 2.0);
+
+    // Relative edits apply to the value from before the edit:
+    type_into_drag_value(&mut harness, "+=10");
+    assert_eq!(*harness.state(), 12.0);
+    type_into_drag_value(&mut harness, "*=2");
+    assert_eq!(*harness.state(), 24.0);
+}
+
+#[test]
+fn typed_units_are_converted_to_base_units() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, meters: &mut f64| {
+            ui.add(
+                DragValue::new(meters)
+                    .expressions(true)
+                    .units(Units::meters().display_unit("cm")),
+            );
+        },
+        1.0,
+    );
+    harness.run();
+
+    type_into_drag_value(&mut harness, "1 m + 50 cm");
+    assert!((*harness.state() - 1.5).abs() < 1e-9);
+
+    // A bare number is in the displayed unit:
+    type_into_drag_value(&mut harness, "20");
+    assert!((*harness.state() - 0.2).abs() < 1e-9);
+
+    // Ambiguous input is ignored:
+    type_into_drag_value(&mut harness, "1 m + 20");
+    assert!((*harness.state() - 0.2).abs() < 1e-9);
+}
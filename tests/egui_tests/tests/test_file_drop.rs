@@ -0,0 +1,117 @@
+use egui::{DroppedFile, Frame, HoveredFile, Pos2, Rect, pos2};
+use egui_kittest::Harness;
+
+struct State {
+    images_rect: Rect,
+    images_hovered: bool,
+    images: Vec<DroppedFile>,
+    anything_hovered: bool,
+    anything: Vec<DroppedFile>,
+}
+
+fn harness() -> Harness<'static, State> {
+    Harness::builder().build_ui_state(
+        |ui, state: &mut State| {
+            let (response, files) = ui.dnd_file_drop_zone(Frame::default(), &["png"], |ui| {
+                ui.label("Images");
+            });
+            state.images_rect = response.response.rect;
+            state.images_hovered = !response.response.dnd_hover_files(&["png"]).is_empty();
+            state.images.extend(files);
+
+            let (response, files) = ui.dnd_file_drop_zone(Frame::default(), &[], |ui| {
+                ui.label("Anything");
+            });
+            state.anything_hovered = !response.response.dnd_hover_files(&[]).is_empty();
+            state.anything.extend(files);
+        },
+        State {
+            images_rect: Rect::NOTHING,
+            images_hovered: false,
+            images: vec![],
+            anything_hovered: false,
+            anything: vec![],
+        },
+    )
+}
+
+fn file(name: &str) -> std::path::PathBuf {
+    std::path::PathBuf::from(name)
+}
+
+fn hover(harness: &mut Harness<'_, State>, name: &str, pos: Option<Pos2>) {
+    harness.input_mut().hovered_files = vec![HoveredFile {
+        path: Some(file(name)),
+        pos,
+        ..Default::default()
+    }];
+    harness.run();
+}
+
+fn drop_file(harness: &mut Harness<'_, State>, name: &str, pos: Option<Pos2>) {
+    harness.input_mut().hovered_files.clear();
+    harness.input_mut().dropped_files = vec![DroppedFile {
+        path: Some(file(name)),
+        pos,
+        ..Default::default()
+    }];
+    harness.run();
+}
+
+#[test]
+fn files_are_dropped_onto_the_zone_under_them() {
+    let mut harness = harness();
+    harness.run();
+    let images = harness.state().images_rect.center();
+    let below = pos2(images.x, harness.state().images_rect.bottom() + 10.0);
+
+    hover(&mut harness, "cat.PNG", Some(images));
+    assert!(harness.state().images_hovered);
+
+    drop_file(&mut harness, "cat.PNG", Some(images));
+    assert_eq!(harness.state().images.len(), 1);
+    assert!(harness.state().anything.is_empty());
+
+    drop_file(&mut harness, "notes.txt", Some(below));
+    assert_eq!(harness.state().images.len(), 1);
+    assert_eq!(harness.state().anything.len(), 1);
+}
+
+#[test]
+fn files_with_other_extensions_are_rejected() {
+    let mut harness = harness();
+    harness.run();
+    let images = harness.state().images_rect.center();
+
+    hover(&mut harness, "notes.txt", Some(images));
+    assert!(!harness.state().images_hovered);
+
+    drop_file(&mut harness, "notes.txt", Some(images));
+    assert!(harness.state().images.is_empty());
+}
+
+#[test]
+fn files_without_a_position_go_to_the_first_zone_that_accepts_them() {
+    let mut harness = harness();
+    harness.run();
+
+    // Like on native, where winit doesn't tell us where the files are:
+    hover(&mut harness, "cat.png", None);
+    assert!(harness.state().images_hovered);
+    assert!(
+        !harness.state().anything_hovered,
+        "Only one zone is highlighted"
+    );
+
+    drop_file(&mut harness, "cat.png", None);
+    assert_eq!(harness.state().images.len(), 1);
+    assert!(harness.state().anything.is_empty());
+
+    hover(&mut harness, "notes.txt", None);
+    assert!(!harness.state().images_hovered);
+    assert!(harness.state().anything_hovered);
+
+    drop_file(&mut harness, "notes.txt", None);
+    assert_eq!(harness.state().images.len(), 1);
+    assert_eq!(harness.state().anything.len(), 1);
+}
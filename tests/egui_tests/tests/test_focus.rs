@@ -0,0 +1,113 @@
+use egui::{CentralPanel, FocusScope, Id, Key, Modal, Modifiers};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn focused_label(harness: &Harness<'_, bool>, labels: &[&str]) -> Option<String> {
+    labels
+        .iter()
+        .find(|label| harness.get_by_label(label).is_focused())
+        .map(|label| (*label).to_owned())
+}
+
+fn tab(harness: &mut Harness<'_, bool>) {
+    harness.key_press(Key::Tab);
+    harness.run();
+}
+
+#[test]
+fn tab_is_trapped_in_modal_and_focus_is_restored_on_close() {
+    let mut harness = Harness::builder().build_state(
+        |ctx, modal_open: &mut bool| {
+            CentralPanel::default().show(ctx, |ui| {
+                _ = ui.button("Before");
+                if ui.button("Open").clicked() {
+                    *modal_open = true;
+                }
+                _ = ui.button("After");
+            });
+            if *modal_open {
+                Modal::new(Id::new("modal")).show(ctx, |ui| {
+                    _ = ui.button("First");
+                    if ui.button("Close").clicked() {
+                        *modal_open = false;
+                    }
+                });
+            }
+        },
+        false,
+    );
+    harness.run();
+
+    let labels = ["Before", "Open", "After", "First", "Close"];
+
+    tab(&mut harness);
+    tab(&mut harness);
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("Open"));
+
+    // Open the modal from the keyboard:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(*harness.state());
+
+    // Tab moves into the modal and then cycles inside it:
+    tab(&mut harness);
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("First"));
+    tab(&mut harness);
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("Close"));
+    tab(&mut harness);
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("First"));
+    harness.key_press_modifiers(Modifiers::SHIFT, Key::Tab);
+    harness.run();
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("Close"));
+
+    // Closing the modal gives the focus back to the button that opened it:
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert!(!*harness.state());
+    assert_eq!(focused_label(&harness, &labels).as_deref(), Some("Open"));
+}
+
+#[test]
+fn tab_is_trapped_in_ui_focus_scope() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, _: &mut bool| {
+            _ = ui.button("Outside");
+            ui.focus_scope("trap", FocusScope::TRAP, |ui| {
+                _ = ui.button("Trapped A");
+                _ = ui.button("Trapped B");
+            });
+        },
+        false,
+    );
+    harness.run();
+
+    let labels = ["Outside", "Trapped A", "Trapped B"];
+    let mut visited = vec![];
+    for _ in 0..3 {
+        tab(&mut harness);
+        visited.push(focused_label(&harness, &labels).unwrap_or_default());
+    }
+    // While the trapping scope is shown, Tab moves into it and stays there:
+    assert_eq!(visited, ["Trapped A", "Trapped B", "Trapped A"]);
+}
+
+#[test]
+fn tab_order_follows_tab_index() {
+    let mut harness = Harness::builder().build_ui_state(
+        |ui, _: &mut bool| {
+            _ = ui.button("Zero");
+            _ = ui.button("Two").tab_index(2);
+            _ = ui.button("One").tab_index(1);
+            _ = ui.button("Skipped").tab_index(-1);
+        },
+        false,
+    );
+    harness.run();
+
+    let labels = ["Zero", "Two", "One", "Skipped"];
+    let mut visited = vec![];
+    for _ in 0..4 {
+        tab(&mut harness);
+        visited.push(focused_label(&harness, &labels).unwrap_or_default());
+    }
+    assert_eq!(visited, ["One", "Two", "Zero", "One"]);
+}
@@ -0,0 +1,111 @@
+use egui::{Rect, Sense, Swipe, SwipeDirection, vec2};
+use egui_kittest::Harness;
+
+mod common;
+use common::{drag, drag_and_hold};
+
+struct State {
+    rect: Rect,
+    long_presses: usize,
+    swipes: Vec<Swipe>,
+    double_tap_dragged: bool,
+}
+
+fn harness() -> Harness<'static, State> {
+    Harness::builder().with_step_dt(1.0 / 60.0).build_ui_state(
+        |ui, state: &mut State| {
+            let (rect, response) =
+                ui.allocate_exact_size(vec2(300.0, 100.0), Sense::click_and_drag());
+            state.rect = rect;
+            if response.long_pressed() {
+                state.long_presses += 1;
+            }
+            state.swipes.extend(response.swiped());
+            state.double_tap_dragged |= response.double_tap_dragged();
+        },
+        State {
+            rect: Rect::NOTHING,
+            long_presses: 0,
+            swipes: vec![],
+            double_tap_dragged: false,
+        },
+    )
+}
+
+#[test]
+fn long_press_fires_once_after_the_duration() {
+    let mut harness = harness();
+    harness.run();
+    let center = harness.state().rect.center();
+
+    harness.hover_at(center);
+    harness.drag_at(center);
+    harness.step();
+
+    // 0.25 s:
+    for _ in 0..15 {
+        harness.step();
+    }
+    assert_eq!(harness.state().long_presses, 0);
+
+    // 1 s:
+    for _ in 0..45 {
+        harness.step();
+    }
+    assert_eq!(harness.state().long_presses, 1);
+
+    harness.drop_at(center);
+    harness.step();
+    assert_eq!(harness.state().long_presses, 1);
+}
+
+#[test]
+fn quick_drag_is_a_swipe() {
+    let mut harness = harness();
+    harness.run();
+    let rect = harness.state().rect;
+
+    drag(
+        &mut harness,
+        rect.left_center() + vec2(10.0, 0.0),
+        rect.center(),
+    );
+    let swipes = &harness.state().swipes;
+    assert_eq!(swipes.len(), 1);
+    assert_eq!(swipes[0].direction, SwipeDirection::Right);
+    assert!(swipes[0].velocity.x > 300.0, "{:?}", swipes[0]);
+}
+
+#[test]
+fn drag_that_stops_before_release_is_not_a_swipe() {
+    let mut harness = harness();
+    harness.run();
+    let rect = harness.state().rect;
+
+    drag_and_hold(
+        &mut harness,
+        rect.left_center() + vec2(10.0, 0.0),
+        rect.center(),
+        20,
+    );
+    assert!(harness.state().swipes.is_empty());
+}
+
+#[test]
+fn drag_after_tap_is_a_double_tap_drag() {
+    let mut harness = harness();
+    harness.run();
+    let center = harness.state().rect.center();
+
+    drag(&mut harness, center, center + vec2(0.0, 30.0));
+    assert!(!harness.state().double_tap_dragged);
+
+    // Tap:
+    harness.drag_at(center);
+    harness.step();
+    harness.drop_at(center);
+    harness.step();
+
+    drag(&mut harness, center, center + vec2(0.0, 30.0));
+    assert!(harness.state().double_tap_dragged);
+}
@@ -0,0 +1,73 @@
+use egui::Grid;
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+fn grid_harness(add_contents: impl Fn(&mut egui::Ui) + 'static) -> Harness<'static> {
+    let mut harness = Harness::builder()
+        .with_size((400.0, 200.0))
+        .build_ui(move |ui| {
+            Grid::new("grid").num_columns(3).show(ui, |ui| {
+                add_contents(ui);
+            });
+        });
+    // The first frame of a grid is a sizing pass:
+    harness.run();
+    harness
+}
+
+#[test]
+fn grid_column_span() {
+    let harness = grid_harness(|ui| {
+        ui.label("A");
+        ui.label("Second column is quite wide");
+        ui.label("C");
+        ui.end_row();
+
+        ui.set_grid_span(2, 1);
+        ui.label("Header");
+        ui.label("Third");
+        ui.end_row();
+    });
+
+    let a = harness.get_by_label("A").rect();
+    let c = harness.get_by_label("C").rect();
+    let header = harness.get_by_label("Header").rect();
+    let third = harness.get_by_label("Third").rect();
+
+    assert_eq!(header.left(), a.left());
+    assert_eq!(
+        third.left(),
+        c.left(),
+        "The spanned columns should be skipped"
+    );
+    assert!(a.bottom() < header.top());
+}
+
+#[test]
+fn grid_row_span() {
+    let harness = grid_harness(|ui| {
+        ui.set_grid_span(1, 2);
+        ui.label("Tall");
+        ui.label("B1");
+        ui.label("C1");
+        ui.end_row();
+
+        ui.label("B2");
+        ui.label("C2");
+        ui.end_row();
+
+        ui.label("A3");
+        ui.end_row();
+    });
+
+    let tall = harness.get_by_label("Tall").rect();
+    let b1 = harness.get_by_label("B1").rect();
+    let b2 = harness.get_by_label("B2").rect();
+    let c2 = harness.get_by_label("C2").rect();
+    let a3 = harness.get_by_label("A3").rect();
+
+    assert_eq!(b2.left(), b1.left(), "The covered cell should be skipped");
+    assert!(b1.bottom() < c2.top());
+    assert!(b1.top() < tall.center().y && tall.center().y < b2.bottom());
+    assert_eq!(a3.left(), tall.left());
+    assert!(b2.bottom() < a3.top());
+}
@@ -0,0 +1,78 @@
+use egui::{HitShape, Rect, Sense, pos2, vec2};
+use egui_kittest::Harness;
+
+struct State {
+    rect: Rect,
+    hovered: bool,
+    clicks: usize,
+}
+
+/// A round button in a square rect.
+fn harness() -> Harness<'static, State> {
+    Harness::builder().build_ui_state(
+        |ui, state: &mut State| {
+            let (rect, response) = ui.allocate_exact_size(vec2(100.0, 100.0), Sense::click());
+            let response = response.with_hit_shape(HitShape::circle(rect.center(), 50.0));
+            state.rect = rect;
+            state.hovered = response.hovered();
+            if response.clicked() {
+                state.clicks += 1;
+            }
+        },
+        State {
+            rect: Rect::NOTHING,
+            hovered: false,
+            clicks: 0,
+        },
+    )
+}
+
+#[test]
+fn pointer_outside_hit_shape_misses() {
+    let mut harness = harness();
+    harness.run();
+    let rect = harness.state().rect;
+
+    // Inside the rect, but outside the circle:
+    let corner = rect.min + vec2(5.0, 5.0);
+    harness.hover_at(corner);
+    harness.run();
+    assert!(!harness.state().hovered);
+
+    harness.drag_at(corner);
+    harness.run();
+    harness.drop_at(corner);
+    harness.run();
+    assert_eq!(harness.state().clicks, 0);
+
+    let center = rect.center();
+    harness.hover_at(center);
+    harness.run();
+    assert!(harness.state().hovered);
+
+    harness.drag_at(center);
+    harness.run();
+    harness.drop_at(center);
+    harness.run();
+    assert_eq!(harness.state().clicks, 1);
+}
+
+#[test]
+fn hit_shape_geometry() {
+    let circle = HitShape::circle(pos2(0.0, 0.0), 10.0);
+    assert!(circle.contains(pos2(7.0, 7.0)));
+    assert!(!circle.contains(pos2(8.0, 8.0)));
+    assert_eq!(circle.distance_sq_to_pos(pos2(13.0, 0.0)), 9.0);
+
+    let line = HitShape::polyline(vec![pos2(0.0, 0.0), pos2(100.0, 0.0)], 4.0);
+    assert!(line.contains(pos2(50.0, 3.0)));
+    assert!(!line.contains(pos2(50.0, 5.0)));
+    assert_eq!(
+        line.bounding_rect(),
+        Rect::from_min_max(pos2(-4.0, -4.0), pos2(104.0, 4.0))
+    );
+
+    let triangle = HitShape::convex_polygon(vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)]);
+    assert!(triangle.contains(pos2(2.0, 2.0)));
+    assert!(!triangle.contains(pos2(8.0, 8.0)));
+}
@@ -0,0 +1,111 @@
+use egui::{Event, Grid, Key, NavigationEvent};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+const LABELS: [&str; 6] = ["A", "B", "C", "D", "E", "F"];
+
+/// A 3x2 grid of buttons:
+///
+/// ```text
+/// A B C
+/// D E F
+/// ```
+fn grid_harness() -> Harness<'static, Vec<&'static str>> {
+    Harness::builder().build_ui_state(
+        |ui, clicked: &mut Vec<&'static str>| {
+            Grid::new("grid").show(ui, |ui| {
+                for (i, label) in LABELS.into_iter().enumerate() {
+                    if ui.button(label).clicked() {
+                        clicked.push(label);
+                    }
+                    if i % 3 == 2 {
+                        ui.end_row();
+                    }
+                }
+            });
+        },
+        vec![],
+    )
+}
+
+fn focused_label(harness: &Harness<'_, Vec<&'static str>>) -> Option<&'static str> {
+    LABELS
+        .into_iter()
+        .find(|label| harness.get_by_label(label).is_focused())
+}
+
+fn press(harness: &mut Harness<'_, Vec<&'static str>>, key: Key) {
+    harness.key_press(key);
+    harness.run();
+}
+
+fn navigate(harness: &mut Harness<'_, Vec<&'static str>>, navigation: NavigationEvent) {
+    harness.event(Event::Navigate(navigation));
+    harness.run();
+}
+
+#[test]
+fn arrow_keys_move_focus_spatially() {
+    let mut harness = grid_harness();
+    harness.run();
+
+    // With nothing focused, we start in the top-left corner:
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(focused_label(&harness), Some("A"));
+
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(focused_label(&harness), Some("B"));
+
+    press(&mut harness, Key::ArrowDown);
+    assert_eq!(focused_label(&harness), Some("E"));
+
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(focused_label(&harness), Some("F"));
+
+    // There is nothing further to the right:
+    press(&mut harness, Key::ArrowRight);
+    assert_eq!(focused_label(&harness), Some("F"));
+
+    press(&mut harness, Key::ArrowUp);
+    assert_eq!(focused_label(&harness), Some("C"));
+
+    press(&mut harness, Key::ArrowLeft);
+    assert_eq!(focused_label(&harness), Some("B"));
+
+    assert!(harness.ctx.memory(|mem| mem.focus_visible()));
+}
+
+#[test]
+fn navigation_events_move_focus_and_activate() {
+    let mut harness = grid_harness();
+    harness.run();
+
+    navigate(&mut harness, NavigationEvent::Down);
+    assert_eq!(focused_label(&harness), Some("A"));
+
+    navigate(&mut harness, NavigationEvent::Down);
+    assert_eq!(focused_label(&harness), Some("D"));
+
+    navigate(&mut harness, NavigationEvent::Next);
+    assert_eq!(focused_label(&harness), Some("E"));
+
+    navigate(&mut harness, NavigationEvent::Activate);
+    assert_eq!(harness.state(), &["E"]);
+
+    navigate(&mut harness, NavigationEvent::Cancel);
+    assert_eq!(focused_label(&harness), None);
+}
+
+#[test]
+fn focus_ring_is_hidden_after_pointer_press() {
+    let mut harness = grid_harness();
+    harness.run();
+
+    press(&mut harness, Key::Tab);
+    assert_eq!(focused_label(&harness), Some("A"));
+    assert!(harness.ctx.memory(|mem| mem.focus_visible()));
+
+    harness.get_by_label("B").click();
+    harness.run();
+    assert_eq!(harness.state(), &["B"]);
+    assert!(!harness.ctx.memory(|mem| mem.focus_visible()));
+}
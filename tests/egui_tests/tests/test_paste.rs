@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use egui::{ColorImage, Event, PasteFormat, PastedData, TextEdit};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+#[derive(Default)]
+struct State {
+    text: String,
+    pasted: Vec<PastedData>,
+    requested_formats: Vec<PasteFormat>,
+}
+
+fn harness() -> Harness<'static, State> {
+    Harness::builder().build_ui_state(
+        |ui, state: &mut State| {
+            let output = TextEdit::singleline(&mut state.text)
+                .paste_format(PasteFormat::Image)
+                .show(ui);
+            state.pasted.extend(output.pasted);
+            state.requested_formats = ui.output(|o| o.paste_formats.clone());
+        },
+        State::default(),
+    )
+}
+
+fn image() -> PastedData {
+    PastedData::Image(Arc::new(ColorImage::filled([2, 2], egui::Color32::RED)))
+}
+
+fn html() -> PastedData {
+    PastedData::Mime {
+        mime: "text/html".to_owned(),
+        bytes: Arc::from(&b"<b>bold</b>"[..]),
+    }
+}
+
+#[test]
+fn focused_text_edit_requests_and_receives_pasted_images() {
+    let mut harness = harness();
+    harness.run();
+    assert!(harness.state().requested_formats.is_empty());
+
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .click();
+    harness.run();
+    assert_eq!(harness.state().requested_formats, [PasteFormat::Image]);
+
+    harness.event(Event::Paste("text".to_owned()));
+    harness.event(Event::PasteData(image()));
+    harness.event(Event::PasteData(html()));
+    harness.run();
+
+    assert_eq!(harness.state().text, "text");
+    // HTML was not requested, so it is ignored:
+    assert_eq!(harness.state().pasted, [image()]);
+}
+
+#[test]
+fn pasted_data_as_text() {
+    assert_eq!(html().text(), Some("<b>bold</b>"));
+    assert_eq!(html().format(), PasteFormat::html());
+    assert_eq!(image().text(), None);
+}
@@ -0,0 +1,74 @@
+use egui::{Event, PenEvent, PenPhase, pos2, vec2};
+use egui_kittest::Harness;
+
+#[derive(Default)]
+struct State {
+    pen: Option<PenEvent>,
+    pressure: Option<f32>,
+    hovering: bool,
+    eraser: bool,
+    pressures_this_frame: Vec<f32>,
+}
+
+fn harness() -> Harness<'static, State> {
+    Harness::builder().build_ui_state(
+        |ui, state: &mut State| {
+            ui.input(|i| {
+                state.pen = i.pointer.pen().copied();
+                state.pressure = i.pointer.pen_pressure();
+                state.hovering = i.pointer.is_pen_hovering();
+                state.eraser = i.pointer.is_pen_eraser();
+                state.pressures_this_frame = i
+                    .pointer
+                    .pen_events()
+                    .iter()
+                    .map(|pen| pen.pressure)
+                    .collect();
+            });
+        },
+        State::default(),
+    )
+}
+
+#[test]
+fn pen_hover_contact_and_gone() {
+    let mut harness = harness();
+    harness.run();
+    assert_eq!(harness.state().pen, None);
+
+    harness.event(Event::Pen(PenEvent::new(
+        pos2(10.0, 10.0),
+        PenPhase::Hover,
+        0.0,
+    )));
+    harness.run();
+    assert!(harness.state().hovering);
+    assert_eq!(harness.state().pressure, None);
+
+    // Several samples in one frame:
+    for (x, pressure) in [(10.0, 0.25), (12.0, 0.75)] {
+        harness.input_mut().events.push(Event::Pen(PenEvent {
+            tilt: Some(vec2(0.5, 0.0)),
+            eraser: true,
+            ..PenEvent::new(pos2(x, 10.0), PenPhase::Contact, pressure)
+        }));
+    }
+    harness.step();
+    assert!(!harness.state().hovering);
+    assert!(harness.state().eraser);
+    assert_eq!(harness.state().pressure, Some(0.75));
+    assert_eq!(harness.state().pressures_this_frame, [0.25, 0.75]);
+    assert_eq!(
+        harness.state().pen.and_then(|pen| pen.tilt),
+        Some(vec2(0.5, 0.0))
+    );
+
+    harness.event(Event::Pen(PenEvent::new(
+        pos2(12.0, 10.0),
+        PenPhase::Gone,
+        0.0,
+    )));
+    harness.run();
+    assert_eq!(harness.state().pen, None);
+    assert!(!harness.state().eraser);
+}
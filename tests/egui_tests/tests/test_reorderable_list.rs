@@ -0,0 +1,91 @@
+use egui::{Pos2, ReorderableList, Vec2};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+mod common;
+
+struct Lists {
+    left: Vec<&'static str>,
+    right: Vec<&'static str>,
+}
+
+fn harness(left: Vec<&'static str>, right: Vec<&'static str>) -> Harness<'static, Lists> {
+    Harness::builder().build_ui_state(
+        |ui, lists: &mut Lists| {
+            ui.columns(2, |columns| {
+                let left = ReorderableList::new("left").group("lists").show(
+                    &mut columns[0],
+                    &lists.left,
+                    |ui, _, item| {
+                        ui.label(*item);
+                    },
+                );
+                let right = ReorderableList::new("right").group("lists").show(
+                    &mut columns[1],
+                    &lists.right,
+                    |ui, _, item| {
+                        ui.label(*item);
+                    },
+                );
+
+                for moved in [left.moved, right.moved].into_iter().flatten() {
+                    match (moved.from.list == left.id, moved.to.list == left.id) {
+                        (true, true) => moved.apply(&mut lists.left),
+                        (false, false) => moved.apply(&mut lists.right),
+                        (true, false) => moved.apply_between(&mut lists.left, &mut lists.right),
+                        (false, true) => moved.apply_between(&mut lists.right, &mut lists.left),
+                    }
+                }
+            });
+        },
+        Lists { left, right },
+    )
+}
+
+fn drag(harness: &mut Harness<'_, Lists>, from: Pos2, to: Pos2) {
+    common::drag(harness, from, to);
+    harness.run();
+}
+
+fn center_of(harness: &Harness<'_, Lists>, label: &str) -> Pos2 {
+    harness.get_by_label(label).rect().center()
+}
+
+#[test]
+fn drag_item_down_within_list() {
+    let mut harness = harness(vec!["A", "B", "C"], vec![]);
+    harness.run();
+
+    // Drop "A" just below the center of "C":
+    let to = center_of(&harness, "C") + Vec2::new(0.0, 2.0);
+    let from = center_of(&harness, "A");
+    drag(&mut harness, from, to);
+
+    assert_eq!(harness.state().left, ["B", "C", "A"]);
+}
+
+#[test]
+fn drag_item_up_within_list() {
+    let mut harness = harness(vec!["A", "B", "C"], vec![]);
+    harness.run();
+
+    // Drop "C" just above the center of "B":
+    let to = center_of(&harness, "B") - Vec2::new(0.0, 2.0);
+    let from = center_of(&harness, "C");
+    drag(&mut harness, from, to);
+
+    assert_eq!(harness.state().left, ["A", "C", "B"]);
+}
+
+#[test]
+fn drag_item_between_lists() {
+    let mut harness = harness(vec!["A", "B"], vec!["X", "Y"]);
+    harness.run();
+
+    // Drop "A" between "X" and "Y":
+    let to = center_of(&harness, "Y") - Vec2::new(0.0, 2.0);
+    let from = center_of(&harness, "A");
+    drag(&mut harness, from, to);
+
+    assert_eq!(harness.state().left, ["B"]);
+    assert_eq!(harness.state().right, ["X", "A", "Y"]);
+}
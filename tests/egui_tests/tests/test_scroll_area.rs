@@ -0,0 +1,76 @@
+use egui::{
+    Event, Modifiers, MouseWheelUnit, Pos2, ScrollArea, TouchPhase, Vec2,
+    containers::scroll_area::ScrollSnap, pos2, vec2,
+};
+use egui_kittest::Harness;
+
+mod common;
+
+#[derive(Default)]
+struct Output {
+    offset: f32,
+    overscroll: f32,
+    refresh_requested: bool,
+}
+
+fn harness(scroll_area: impl Fn() -> ScrollArea + 'static) -> Harness<'static, Output> {
+    Harness::builder()
+        .with_size(Vec2::new(200.0, 300.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            move |ui, output: &mut Output| {
+                let scroll_output = scroll_area().max_height(200.0).show(ui, |ui| {
+                    ui.allocate_space(vec2(100.0, 2000.0));
+                });
+                output.offset = scroll_output.state.offset.y;
+                output.overscroll = scroll_output.overscroll.y;
+                output.refresh_requested |= scroll_output.refresh_requested;
+            },
+            Output::default(),
+        )
+}
+
+/// Drag, and then hold still so there is no fling.
+fn drag(harness: &mut Harness<'_, Output>, from: Pos2, to: Pos2) {
+    common::drag_and_hold(harness, from, to, 10);
+}
+
+#[test]
+fn scroll_snaps_to_interval_after_wheel() {
+    let mut harness = harness(|| ScrollArea::vertical().snap(ScrollSnap::Interval(100.0)));
+    harness.run();
+
+    harness.hover_at(pos2(50.0, 100.0));
+    harness.event(Event::MouseWheel {
+        unit: MouseWheelUnit::Point,
+        delta: vec2(0.0, -130.0),
+        phase: TouchPhase::Move,
+        modifiers: Modifiers::NONE,
+    });
+    harness.run_steps(120);
+    assert_eq!(harness.state().offset, 100.0);
+}
+
+#[test]
+fn overscroll_springs_back_and_requests_refresh() {
+    let mut harness = harness(|| ScrollArea::vertical().pull_to_refresh(40.0));
+    harness.run();
+
+    drag(&mut harness, pos2(50.0, 20.0), pos2(50.0, 220.0));
+    assert!(harness.state().refresh_requested);
+    assert!(harness.state().overscroll < -40.0, "pulled past the top");
+    assert!(harness.state().overscroll > -200.0, "with resistance");
+
+    harness.run_steps(120);
+    assert_eq!(harness.state().overscroll, 0.0);
+    assert_eq!(harness.state().offset, 0.0);
+}
+
+#[test]
+fn short_pull_does_not_request_refresh() {
+    let mut harness = harness(|| ScrollArea::vertical().pull_to_refresh(40.0));
+    harness.run();
+
+    drag(&mut harness, pos2(50.0, 20.0), pos2(50.0, 50.0));
+    assert!(!harness.state().refresh_requested);
+}
@@ -0,0 +1,81 @@
+use egui::{Slider, Vec2};
+use egui_kittest::{
+    Harness,
+    kittest::{NodeT as _, Queryable as _},
+};
+
+fn range_harness(start: f32, end: f32) -> Harness<'static, (f32, f32)> {
+    Harness::builder()
+        .with_size(Vec2::new(300.0, 50.0))
+        .build_ui_state(
+            |ui, (start, end): &mut (f32, f32)| {
+                ui.add(
+                    Slider::new_range(start, end, 0.0..=100.0)
+                        .step_by(1.0)
+                        .text("Range"),
+                );
+            },
+            (start, end),
+        )
+}
+
+#[test]
+fn range_slider_handles_have_own_values() {
+    let mut harness = range_harness(20.0, 80.0);
+    harness.run();
+
+    let start = harness.get_by_label("Range start");
+    assert_eq!(start.accesskit_node().numeric_value(), Some(20.0));
+    let end = harness.get_by_label("Range end");
+    assert_eq!(end.accesskit_node().numeric_value(), Some(80.0));
+
+    harness.get_by_label("Range start").focus();
+    harness.run();
+    harness.key_press(egui::Key::ArrowRight);
+    harness.run();
+    assert_eq!(*harness.state(), (21.0, 80.0));
+
+    harness.get_by_label("Range end").focus();
+    harness.run();
+    harness.key_press(egui::Key::ArrowLeft);
+    harness.run();
+    assert_eq!(*harness.state(), (21.0, 79.0));
+}
+
+#[test]
+fn range_slider_handles_do_not_cross() {
+    let mut harness = range_harness(50.0, 51.0);
+    harness.run();
+
+    harness.get_by_label("Range start").focus();
+    harness.run();
+    for _ in 0..3 {
+        harness.key_press(egui::Key::ArrowRight);
+        harness.run();
+    }
+    assert_eq!(*harness.state(), (51.0, 51.0));
+}
+
+#[test]
+fn range_slider_drag_middle_moves_both() {
+    let mut harness = range_harness(20.0, 60.0);
+    harness.run();
+
+    let start = harness.get_by_label("Range start").rect().center();
+    let end = harness.get_by_label("Range end").rect().center();
+    let middle = start.lerp(end, 0.5);
+    let offset = 0.25 * (end.x - start.x);
+
+    harness.hover_at(middle);
+    harness.run();
+    harness.drag_at(middle);
+    harness.run();
+    harness.hover_at(middle + Vec2::new(offset, 0.0));
+    harness.run();
+    harness.drop_at(middle + Vec2::new(offset, 0.0));
+    harness.run();
+
+    let (new_start, new_end) = *harness.state();
+    assert!((new_start - 30.0).abs() <= 1.0, "{new_start}");
+    assert!((new_end - 70.0).abs() <= 1.0, "{new_end}");
+}
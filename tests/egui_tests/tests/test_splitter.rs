@@ -0,0 +1,124 @@
+use egui::{
+    Pos2, Rect, Vec2,
+    containers::{Splitter, SplitterPane},
+};
+use egui_kittest::Harness;
+
+mod common;
+use common::drag;
+
+fn splitter_harness(splitter: impl Fn() -> Splitter + 'static) -> Harness<'static, Vec<Rect>> {
+    let mut harness = Harness::builder()
+        .with_size(Vec2::new(410.0, 100.0))
+        .with_step_dt(0.05)
+        .build_ui_state(
+            move |ui, pane_rects: &mut Vec<Rect>| {
+                pane_rects.clear();
+                splitter().divider_width(10.0).show(ui, |_, ui| {
+                    pane_rects.push(ui.max_rect());
+                });
+            },
+            Vec::new(),
+        );
+    harness.run();
+    harness
+}
+
+fn divider_pos(harness: &Harness<'_, Vec<Rect>>) -> Pos2 {
+    let rects = harness.state();
+    Pos2::new(
+        0.5 * (rects[0].right() + rects[1].left()),
+        rects[0].center().y,
+    )
+}
+
+#[test]
+fn splitter_drag_respects_min_size() {
+    let mut harness = splitter_harness(|| {
+        Splitter::horizontal("splitter")
+            .pane(SplitterPane::new(1.0).min_size(100.0))
+            .pane(SplitterPane::new(1.0))
+    });
+
+    let initial_width = harness.state()[0].width();
+    assert!((initial_width - harness.state()[1].width()).abs() < 1.0);
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, from + Vec2::new(50.0, 0.0));
+    assert!((harness.state()[0].width() - (initial_width + 50.0)).abs() < 1.0);
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, Pos2::new(20.0, from.y));
+    assert!((harness.state()[0].width() - 100.0).abs() < 1.0);
+}
+
+#[test]
+fn splitter_collapse_and_reset() {
+    let mut harness = splitter_harness(|| {
+        Splitter::horizontal("splitter")
+            .pane(SplitterPane::new(1.0).min_size(50.0).collapsible(true))
+            .pane(SplitterPane::new(3.0))
+    });
+
+    let initial_width = harness.state()[0].width();
+    assert!((harness.state()[1].width() - 3.0 * initial_width).abs() < 1.0);
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, Pos2::new(20.0, from.y));
+    assert_eq!(
+        harness.state()[0].width(),
+        0.0,
+        "The pane should be collapsed"
+    );
+
+    // Double-click the divider to reset:
+    let pos = divider_pos(&harness);
+    harness.hover_at(pos);
+    harness.step();
+    for pressed in [true, false, true, false] {
+        harness.event(egui::Event::PointerButton {
+            pos,
+            button: egui::PointerButton::Primary,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        });
+        harness.step();
+    }
+    harness.run();
+    assert!((harness.state()[0].width() - initial_width).abs() < 1.0);
+}
+
+#[test]
+fn splitter_reopen_collapsed_pane_by_dragging() {
+    let mut harness = splitter_harness(|| {
+        Splitter::horizontal("splitter")
+            .pane(SplitterPane::new(1.0).min_size(50.0).collapsible(true))
+            .pane(SplitterPane::new(1.0).min_size(50.0).collapsible(true))
+    });
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, Pos2::new(10.0, from.y));
+    assert_eq!(
+        harness.state()[0].width(),
+        0.0,
+        "The first pane should be collapsed"
+    );
+
+    // Each step of the drag is less than half the min size,
+    // so this only works if the whole drag is taken into account:
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, from + Vec2::new(100.0, 0.0));
+    assert!((harness.state()[0].width() - 100.0).abs() < 1.0);
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, Pos2::new(400.0, from.y));
+    assert_eq!(
+        harness.state()[1].width(),
+        0.0,
+        "The second pane should be collapsed"
+    );
+
+    let from = divider_pos(&harness);
+    drag(&mut harness, from, from - Vec2::new(100.0, 0.0));
+    assert!((harness.state()[1].width() - 100.0).abs() < 1.0);
+}
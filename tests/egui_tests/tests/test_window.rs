@@ -0,0 +1,152 @@
+use egui::{
+    Id, Pos2, Rect, Vec2, Window, WindowTray, pos2,
+    window_layout::{self, kb_shortcuts},
+};
+use egui_kittest::{Harness, kittest::Queryable as _};
+
+mod common;
+
+const SCREEN: Vec2 = Vec2::new(800.0, 600.0);
+
+/// The title and default position of each window to show.
+type Windows = Vec<(&'static str, Pos2)>;
+
+fn harness(windows: Windows) -> Harness<'static, Windows> {
+    Harness::builder()
+        .with_size(SCREEN)
+        .with_step_dt(1.0 / 60.0)
+        .build_state(
+            |ctx, windows: &mut Windows| {
+                window_layout::arrange_with_keyboard(ctx);
+                WindowTray::new().show(ctx);
+                for &(title, pos) in windows.iter() {
+                    Window::new(title)
+                        .default_pos(pos)
+                        .default_size([200.0, 150.0])
+                        .snap(true)
+                        .tiling(true)
+                        .minimizable(true)
+                        .show(ctx, |ui| {
+                            ui.label("Contents");
+                            // Fill the window, so it takes the size it is given:
+                            ui.allocate_space(ui.available_size());
+                        });
+                }
+            },
+            windows,
+        )
+}
+
+fn window_rect(harness: &Harness<'_, Windows>, title: &str) -> Rect {
+    harness
+        .ctx
+        .memory(|mem| mem.area_rect(Id::new(title)))
+        .expect("window should be visible")
+}
+
+/// Drag the window by its title bar so that the pointer ends up at `to`.
+fn drag_title_bar(harness: &mut Harness<'_, Windows>, title: &str, to: Pos2) {
+    let from = window_rect(harness, title).center_top() + Vec2::new(0.0, 10.0);
+    common::drag(harness, from, to);
+    harness.run();
+}
+
+#[test]
+fn window_snaps_to_screen_edge() {
+    let mut harness = harness(vec![("Snappy", pos2(200.0, 200.0))]);
+    harness.run();
+
+    let rect = window_rect(&harness, "Snappy");
+    // Move the window so that its left edge ends up a few points from the screen edge:
+    let target_left = 4.0;
+    let grab = rect.center_top() + Vec2::new(0.0, 10.0);
+    let to = pos2(grab.x - (rect.left() - target_left), grab.y);
+    drag_title_bar(&mut harness, "Snappy", to);
+
+    assert_eq!(window_rect(&harness, "Snappy").left(), 0.0);
+}
+
+#[test]
+fn window_tiles_when_dropped_at_edge() {
+    let mut harness = harness(vec![("Tiled", pos2(200.0, 200.0))]);
+    harness.run();
+
+    drag_title_bar(&mut harness, "Tiled", pos2(SCREEN.x - 1.0, 300.0));
+
+    let rect = window_rect(&harness, "Tiled");
+    assert_eq!(rect.left(), SCREEN.x / 2.0);
+    assert_eq!(rect.right(), SCREEN.x);
+    assert_eq!(rect.top(), 0.0);
+    assert_eq!(rect.bottom(), SCREEN.y);
+}
+
+#[test]
+fn tile_shortcut_arranges_windows_side_by_side() {
+    let mut harness = harness(vec![("A", pos2(50.0, 50.0)), ("B", pos2(100.0, 100.0))]);
+    harness.run();
+
+    harness.key_press_modifiers(
+        kb_shortcuts::TILE_WINDOWS.modifiers,
+        kb_shortcuts::TILE_WINDOWS.logical_key,
+    );
+    harness.run();
+
+    let a = window_rect(&harness, "A");
+    let b = window_rect(&harness, "B");
+    assert_eq!(a.left(), 0.0);
+    assert_eq!(a.top(), 0.0);
+    assert_eq!(a.height(), SCREEN.y);
+    assert_eq!(b.right(), SCREEN.x);
+    assert!(a.right() <= b.left(), "{a:?} and {b:?} overlap");
+}
+
+#[test]
+fn minimized_window_is_restored_from_tray() {
+    let mut harness = harness(vec![("Minimize me", pos2(200.0, 200.0))]);
+    harness.run();
+
+    harness.get_by_label("Minimize window").click();
+    harness.run();
+
+    assert!(window_layout::is_minimized(
+        &harness.ctx,
+        Id::new("Minimize me")
+    ));
+    assert!(harness.query_by_label("Contents").is_none());
+
+    harness.get_by_label("Minimize me").click();
+    harness.run();
+
+    assert!(!window_layout::is_minimized(
+        &harness.ctx,
+        Id::new("Minimize me")
+    ));
+    assert!(harness.query_by_label("Contents").is_some());
+}
+
+#[test]
+fn windows_that_are_no_longer_shown_are_forgotten() {
+    let mut harness = harness(vec![("A", pos2(50.0, 50.0)), ("B", pos2(100.0, 100.0))]);
+    harness.run();
+
+    window_layout::minimize(&harness.ctx, Id::new("A"));
+    harness.run();
+    assert!(window_layout::is_minimized(&harness.ctx, Id::new("A")));
+
+    harness.state_mut().retain(|(title, _)| *title == "B");
+    harness.run();
+    // The tray is shown before the windows, so it notices one frame later:
+    harness.step();
+    assert!(!window_layout::is_minimized(&harness.ctx, Id::new("A")));
+    assert!(harness.query_by_label("A").is_none(), "The tray is empty");
+
+    // The window that is gone doesn't take up any space when tiling:
+    harness.key_press_modifiers(
+        kb_shortcuts::TILE_WINDOWS.modifiers,
+        kb_shortcuts::TILE_WINDOWS.logical_key,
+    );
+    harness.run();
+    let b = window_rect(&harness, "B");
+    assert_eq!(b.min, Pos2::ZERO);
+    assert_eq!(b.max, SCREEN.to_pos2());
+}